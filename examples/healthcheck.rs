@@ -0,0 +1,48 @@
+//! Checks whether the API is reachable and validates an API key.
+//!
+//! ```sh
+//! cargo run --example healthcheck -- <API_KEY>
+//! ```
+//!
+//! Without an API key only the availability of the API is checked. Exits with a non-zero status
+//! if the API is unreachable or the API key is invalid.
+
+use std::process::ExitCode;
+
+use gw2api_rs::health::TokenStatus;
+use gw2api_rs::{Builder, Client};
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let mut builder = Builder::new();
+    if let Some(token) = std::env::args().nth(1) {
+        builder = builder.access_token(token);
+    }
+    let client: Client = builder.into();
+
+    let health = match client.healthcheck().await {
+        Ok(health) => health,
+        Err(err) => {
+            eprintln!("API unreachable: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("API reachable, build {}", health.build);
+
+    match &health.token {
+        TokenStatus::Missing => println!("No API key given"),
+        TokenStatus::Valid(info) => {
+            println!("API key {:?} is valid", info.name);
+            for permission in &info.permissions {
+                println!("  {}", permission);
+            }
+        }
+        TokenStatus::Invalid(err) => {
+            eprintln!("API key is invalid: {}", err);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,120 @@
+//! Cancelling requests in flight.
+//!
+//! Dropping a request future already cancels the request, but is not always possible, e.g. when
+//! the future was moved into a spawned task. An [`AbortHandle`] returned by
+//! [`ResponseFuture::abort_handle`] cancels a single request from anywhere. [`Client::abort_all`]
+//! cancels all requests sent by a client and its clones, which is useful for GUI applications
+//! discarding all pending loads when the user switches to another view.
+//!
+//! An aborted request resolves to an [`Error`] for which [`is_aborted`] returns `true`. Requests
+//! sent after [`Client::abort_all`] was called are not affected.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use gw2api_rs::{Client, Result};
+//! # use gw2api_rs::v2::items::Item;
+//! #
+//! # async fn run() -> Result<()> {
+//! let client = Client::new();
+//!
+//! let request = Item::ids(&client);
+//! let handle = request.abort_handle();
+//! let task = tokio::spawn(request);
+//!
+//! handle.abort();
+//! assert!(task.await.unwrap().unwrap_err().is_aborted());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`ResponseFuture::abort_handle`]: crate::ResponseFuture::abort_handle
+//! [`Client::abort_all`]: crate::Client::abort_all
+//! [`Error`]: struct@crate::Error
+//! [`is_aborted`]: crate::Error::is_aborted
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::task::Context;
+
+use futures_util::task::AtomicWaker;
+
+/// A handle to abort a single request.
+///
+/// Handles are cheap to clone and can be sent to other threads. Aborting a request that already
+/// completed has no effect.
+#[derive(Clone, Debug, Default)]
+pub struct AbortHandle {
+    signal: Arc<Signal>,
+}
+
+impl AbortHandle {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Aborts the request. The request resolves to an error the next time it is polled.
+    pub fn abort(&self) {
+        self.signal.aborted.store(true, Ordering::Release);
+        self.signal.waker.wake();
+    }
+
+    /// Returns `true` if the request was aborted.
+    #[inline]
+    pub fn is_aborted(&self) -> bool {
+        self.signal.aborted.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if the request was aborted. Otherwise the task of `cx` is woken once the
+    /// request is aborted.
+    pub(crate) fn poll_aborted(&self, cx: &mut Context<'_>) -> bool {
+        // Check before registering to skip the registration for aborted requests, and after
+        // registering to not miss an abort racing with the registration.
+        if self.is_aborted() {
+            return true;
+        }
+
+        self.signal.waker.register(cx.waker());
+        self.is_aborted()
+    }
+}
+
+#[derive(Debug, Default)]
+struct Signal {
+    aborted: AtomicBool,
+    waker: AtomicWaker,
+}
+
+/// The requests sent by all clones of a client, aborted by [`Client::abort_all`].
+///
+/// [`Client::abort_all`]: crate::Client::abort_all
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Aborts {
+    requests: Arc<Mutex<Vec<Weak<Signal>>>>,
+}
+
+impl Aborts {
+    /// Registers the request of `handle` to be aborted by [`abort_all`].
+    ///
+    /// [`abort_all`]: Self::abort_all
+    pub(crate) fn register(&self, handle: &AbortHandle) {
+        let mut requests = self.requests.lock().unwrap();
+
+        // Remove completed requests before growing, so the list only grows with the number of
+        // requests in flight.
+        if requests.len() == requests.capacity() {
+            requests.retain(|signal| signal.strong_count() != 0);
+        }
+
+        requests.push(Arc::downgrade(&handle.signal));
+    }
+
+    /// Aborts all registered requests.
+    pub(crate) fn abort_all(&self) {
+        let requests = std::mem::take(&mut *self.requests.lock().unwrap());
+
+        for signal in requests.iter().filter_map(Weak::upgrade) {
+            AbortHandle { signal }.abort();
+        }
+    }
+}
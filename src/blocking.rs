@@ -17,11 +17,18 @@
 //! }
 //! ```
 
-use crate::{private, Builder, ClientExecutor, RequestBuilder, Result};
+use crate::datetime::DateTime;
+use crate::health::Health;
+use crate::lookup::Lookup;
+use crate::pagination::{self, PageExecutor, Paginator};
+use crate::v2::tokeninfo::{Scopes, TokenInfo, TokenPermission};
+use crate::{private, Builder, ClientExecutor, Executor, RequestBuilder, Result};
 
+use futures_util::StreamExt;
 use serde::de::DeserializeOwned;
 use tokio::runtime::{self, Runtime};
 
+use std::future::Future;
 use std::sync::Arc;
 
 /// The synchronous api client.
@@ -42,6 +49,121 @@ impl Client {
         Builder::new()
     }
 
+    /// Returns the items of the endpoint `T` with the given `ids`.
+    ///
+    /// The ids are split into chunks of 200 which are requested concurrently. Ids not found are
+    /// omitted from the returned list. See the [`lookup`] module for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::colors::Color;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let colors = client.lookup::<Color>(&[1, 2, 3])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`lookup`]: crate::lookup
+    pub fn lookup<T>(&self, ids: &[T::Id]) -> Result<Vec<T>>
+    where
+        T: Lookup,
+    {
+        self.runtime.block_on(self.inner.lookup(ids))
+    }
+
+    /// Returns a new `Client` using the given `access_token` for authenticated requests. All
+    /// other configuration, including the runtime, is shared with the current `Client`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::account::Account;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let tokens = [""];
+    /// let client = Client::new();
+    ///
+    /// for token in tokens {
+    ///     let account = Account::get(&client.with_access_token(token))?;
+    ///     println!("{}", account.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_access_token<T>(&self, access_token: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            inner: self.inner.with_access_token(access_token),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Returns a new `Client` that verifies the permissions of authenticated requests against
+    /// the given [`TokenInfo`] before sending them. All other configuration, including the
+    /// runtime, is shared with the current `Client`.
+    ///
+    /// See [`crate::Client::with_token_info`] for more details.
+    pub fn with_token_info(&self, token_info: &TokenInfo) -> Self {
+        Self {
+            inner: self.inner.with_token_info(token_info),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Creates a subtoken from the access token of the client and returns a new `Client` using
+    /// it for authenticated requests. All other configuration, including the runtime, is shared
+    /// with the current `Client`.
+    ///
+    /// See [`crate::Client::scoped`] for more details.
+    pub fn scoped(&self, permissions: &[TokenPermission], expire: DateTime) -> Result<Self> {
+        let inner = self
+            .runtime
+            .block_on(self.inner.scoped(permissions, expire))?;
+
+        Ok(Self {
+            inner,
+            runtime: self.runtime.clone(),
+        })
+    }
+
+    /// Returns the permissions granted to the access token of the client.
+    ///
+    /// See [`crate::Client::scopes`] for more details.
+    pub fn scopes(&self) -> Result<Scopes> {
+        self.runtime.block_on(self.inner.scopes())
+    }
+
+    /// Returns `true` if the access token of the client is granted the given `permission`.
+    ///
+    /// See [`crate::Client::has_scope`] for more details.
+    pub fn has_scope(&self, permission: TokenPermission) -> Result<bool> {
+        self.runtime.block_on(self.inner.has_scope(permission))
+    }
+
+    /// Aborts all requests in flight that were sent by this `Client` or any of its clones.
+    ///
+    /// Requests block the calling thread, so this is called from another thread holding a clone
+    /// of the `Client`. See [`crate::Client::abort_all`] for more details.
+    pub fn abort_all(&self) {
+        self.inner.abort_all();
+    }
+
+    /// Checks whether the API is reachable and the access token of the client is valid.
+    ///
+    /// See [`crate::Client::healthcheck`] for more details.
+    pub fn healthcheck(&self) -> Result<Health> {
+        self.runtime.block_on(self.inner.healthcheck())
+    }
+
     fn new_with_inner(inner: crate::Client) -> Self {
         let runtime = runtime::Builder::new_current_thread()
             .enable_io()
@@ -82,5 +204,105 @@ where
     }
 }
 
+impl Executor for Client {
+    type Output<T> = Result<T>;
+
+    fn execute<F, Fut, T>(&self, f: F) -> Self::Output<T>
+    where
+        F: FnOnce(crate::Client) -> Fut,
+        Fut: Future<Output = Result<T>> + Send + 'static,
+        T: Send + 'static,
+    {
+        self.runtime.block_on(f(self.inner.clone()))
+    }
+}
+
+impl<T> PageExecutor<T> for Client
+where
+    T: DeserializeOwned,
+{
+    type Pages = Pages<T>;
+
+    fn pages(&self, path: &'static str) -> Self::Pages {
+        Pages {
+            inner: self.inner.pages(path),
+            runtime: self.runtime.clone(),
+        }
+    }
+}
+
 #[doc(hidden)]
 impl private::Sealed for Client {}
+
+/// An [`Iterator`] of pages of an endpoint.
+///
+/// This is the blocking equivalent of a [`Paginator`]. See the [`pagination`] module for more
+/// details.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Pages<T>
+where
+    T: DeserializeOwned,
+{
+    inner: Paginator<T>,
+    runtime: Arc<Runtime>,
+}
+
+impl<T> Pages<T>
+where
+    T: DeserializeOwned,
+{
+    /// Sets the number of items per page. Defaults to [`MAX_PAGE_SIZE`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `page_size` is `0` or greater than [`MAX_PAGE_SIZE`].
+    ///
+    /// [`MAX_PAGE_SIZE`]: Paginator::MAX_PAGE_SIZE
+    pub fn page_size(mut self, page_size: u16) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Returns an [`Iterator`] of the individual items of all pages.
+    #[inline]
+    pub fn items(self) -> Items<T> {
+        Items {
+            inner: self.inner.items(),
+            runtime: self.runtime,
+        }
+    }
+}
+
+impl<T> Iterator for Pages<T>
+where
+    T: DeserializeOwned,
+{
+    type Item = Result<Vec<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.inner.next())
+    }
+}
+
+/// An [`Iterator`] of the individual items of [`Pages`].
+///
+/// Created by [`Pages::items`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Items<T>
+where
+    T: DeserializeOwned,
+{
+    inner: pagination::Items<T>,
+    runtime: Arc<Runtime>,
+}
+
+impl<T> Iterator for Items<T>
+where
+    T: DeserializeOwned,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.inner.next())
+    }
+}
@@ -0,0 +1,221 @@
+//! Fetching large lists of ids or whole endpoints in multiple requests.
+
+use std::fmt::Display;
+use std::future::Future;
+
+use futures_util::future;
+#[cfg(any(
+    feature = "account",
+    feature = "emblem",
+    feature = "items",
+    feature = "recipes",
+    feature = "skins"
+))]
+use futures_util::{StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+
+#[cfg(feature = "account")]
+use crate::v2::tokeninfo::TokenPermission;
+#[cfg(feature = "account")]
+use crate::Authentication;
+#[cfg(any(
+    feature = "achievements",
+    feature = "emblem",
+    feature = "items",
+    feature = "recipes",
+    feature = "skins"
+))]
+use crate::ResponseFuture;
+use crate::{Client, ClientExecutor, RequestBuilder, Result};
+
+/// The maximum number of ids accepted by the API in a single request.
+pub(crate) const MAX_IDS: usize = 200;
+
+/// The maximum number of pages requested concurrently by [`get_paged`].
+#[cfg(any(
+    feature = "achievements",
+    feature = "emblem",
+    feature = "items",
+    feature = "recipes",
+    feature = "skins"
+))]
+const MAX_PAGE_REQUESTS: usize = 8;
+
+/// Fetches the items with the given `ids` from the endpoint at `path`. The ids are split into
+/// chunks of [`MAX_IDS`] which are requested concurrently.
+///
+/// Ids not found are omitted from the returned list.
+pub(crate) async fn get_chunked<T, I>(client: &Client, path: &str, ids: &[I]) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+    I: Display,
+{
+    let chunks =
+        future::try_join_all(requests(client, ids, |chunk| unauthenticated(path, chunk))).await?;
+    Ok(chunks.into_iter().flatten().collect())
+}
+
+/// Fetches the items with the given `ids` from the endpoint at `path` like [`get_chunked`], but
+/// keeps at most `limit` requests in flight at the same time.
+///
+/// This should be used instead of [`get_chunked`] when `ids` may contain thousands of ids.
+#[cfg(feature = "account")]
+pub(crate) async fn get_chunked_limited<T, I>(
+    client: &Client,
+    path: &str,
+    ids: &[I],
+    limit: usize,
+) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+    I: Display,
+{
+    let chunks: Vec<Vec<T>> =
+        futures_util::stream::iter(requests(client, ids, |chunk| unauthenticated(path, chunk)))
+            .buffered(limit)
+            .try_collect()
+            .await?;
+    Ok(chunks.into_iter().flatten().collect())
+}
+
+/// Fetches the items with the given `ids` from the authenticated endpoint at `path` like
+/// [`get_chunked`]. The access token must have all given `permissions`.
+#[cfg(feature = "account")]
+pub(crate) async fn get_chunked_authenticated<T, I>(
+    client: &Client,
+    path: &str,
+    ids: &[I],
+    permissions: &'static [TokenPermission],
+) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+    I: Display,
+{
+    let chunks = future::try_join_all(requests(client, ids, |chunk| {
+        RequestBuilder::new(path.to_owned())
+            .query_list("ids", chunk)
+            .authenticated(Authentication::Required)
+            .permissions(permissions)
+    }))
+    .await?;
+    Ok(chunks.into_iter().flatten().collect())
+}
+
+/// Returns the request for a `chunk` of ids of the unauthenticated endpoint at `path`.
+fn unauthenticated<I>(path: &str, chunk: &[I]) -> RequestBuilder
+where
+    I: Display,
+{
+    RequestBuilder::new(path.to_owned())
+        .query_list("ids", chunk)
+        .localized(true)
+}
+
+/// Returns the requests fetching `ids` in chunks of [`MAX_IDS`], using `builder` to create the
+/// request of each chunk.
+///
+/// The API responds with 404 if none of the ids of a chunk exist, e.g. when requesting the
+/// prices of account bound items. Such chunks resolve to an empty list.
+fn requests<'a, T, I, F>(
+    client: &'a Client,
+    ids: &'a [I],
+    builder: F,
+) -> impl Iterator<Item = impl Future<Output = Result<Vec<T>>>> + 'a
+where
+    T: DeserializeOwned,
+    I: Display,
+    F: Fn(&[I]) -> RequestBuilder + 'a,
+{
+    ids.chunks(MAX_IDS).map(move |chunk| {
+        let fut = ClientExecutor::<Vec<T>>::send(client, builder(chunk));
+
+        async move {
+            match fut.await {
+                Ok(items) => Ok(items),
+                Err(err) if err.status() == Some(404) => Ok(Vec::new()),
+                Err(err) => Err(err),
+            }
+        }
+    })
+}
+
+/// Fetches all items of the endpoint at `path` one page of [`MAX_IDS`] items at a time. This
+/// is used by endpoints that reject `ids=all`, e.g. `/v2/items`.
+#[cfg(any(
+    feature = "achievements",
+    feature = "emblem",
+    feature = "items",
+    feature = "recipes",
+    feature = "skins"
+))]
+pub(crate) async fn get_paged<T>(
+    client: &Client,
+    path: &'static str,
+    localized: bool,
+) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+{
+    get_pages(client, || RequestBuilder::new(path).localized(localized)).await
+}
+
+/// Fetches all items of a paginated endpoint one page of [`MAX_IDS`] items at a time, using
+/// `builder` to create the request of each page.
+///
+/// The first page is requested alone to learn the total number of pages. The remaining pages are
+/// then requested with at most [`MAX_PAGE_REQUESTS`] requests in flight. If the total is not
+/// known, pages are requested one after another until a page is not full.
+#[cfg(any(
+    feature = "achievements",
+    feature = "emblem",
+    feature = "items",
+    feature = "recipes",
+    feature = "skins"
+))]
+pub(crate) async fn get_pages<T, F>(client: &Client, builder: F) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+    F: Fn() -> RequestBuilder,
+{
+    let (mut items, meta) = page::<T>(client, builder(), 0).with_meta().await?;
+
+    match meta.page_total {
+        Some(total) => {
+            let pages: Vec<Vec<T>> = futures_util::stream::iter(1..total)
+                .map(|index| page(client, builder(), index))
+                .buffered(MAX_PAGE_REQUESTS)
+                .try_collect()
+                .await?;
+            items.extend(pages.into_iter().flatten());
+        }
+        None => {
+            let mut len = items.len();
+            let mut index = 1;
+
+            while len == MAX_IDS {
+                let page = page(client, builder(), index).await?;
+                len = page.len();
+                items.extend(page);
+                index += 1;
+            }
+        }
+    }
+
+    Ok(items)
+}
+
+/// Returns the request for the page with the given `index` of the request `builder`.
+#[cfg(any(
+    feature = "achievements",
+    feature = "emblem",
+    feature = "items",
+    feature = "recipes",
+    feature = "skins"
+))]
+fn page<T>(client: &Client, builder: RequestBuilder, index: u64) -> ResponseFuture<Vec<T>>
+where
+    T: DeserializeOwned,
+{
+    let builder = builder.query("page", index).query("page_size", MAX_IDS);
+    ClientExecutor::<Vec<T>>::send(client, builder)
+}
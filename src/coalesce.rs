@@ -0,0 +1,95 @@
+//! Coalescing identical in-flight requests.
+//!
+//! When enabled using [`Builder::coalesce_requests`], requests with the same uri, language and
+//! access token that are sent while an identical request is still in flight wait for the
+//! response of the first request instead of sending their own. The response body is shared and
+//! deserialized separately by every waiting request.
+//!
+//! [`Builder::coalesce_requests`]: crate::Builder::coalesce_requests
+
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Formatter};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use futures_util::future::{FutureExt, Shared, WeakShared};
+use hyper::body::Bytes;
+use hyper::header::HeaderValue;
+
+use crate::response::ResponseMeta;
+use crate::Error;
+
+/// A response received over the network, shared by all coalesced requests.
+#[derive(Debug)]
+pub(crate) struct Fetched {
+    pub(crate) status: u16,
+    pub(crate) meta: ResponseMeta,
+    pub(crate) body: Bytes,
+}
+
+type FetchFuture = Pin<Box<dyn Future<Output = Result<Arc<Fetched>, Arc<Error>>> + Send + 'static>>;
+
+/// The future of an in-flight request, awaited by all coalesced requests.
+pub(crate) type SharedFetch = Shared<FetchFuture>;
+
+/// The key identifying identical requests.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct Key {
+    /// The full uri of the request, including the query.
+    pub(crate) uri: String,
+    pub(crate) language: Option<HeaderValue>,
+    pub(crate) authorization: Option<HeaderValue>,
+}
+
+/// The requests currently in flight.
+///
+/// Only weak references are kept, so that a request is cancelled once all requests waiting for
+/// it are dropped.
+#[derive(Clone, Default)]
+pub(crate) struct InFlight(Arc<Mutex<HashMap<Key, WeakShared<FetchFuture>>>>);
+
+impl InFlight {
+    /// Returns the in-flight request for `key`, or `None` if no such request exists.
+    pub(crate) fn get(&self, key: &Key) -> Option<SharedFetch> {
+        self.0.lock().unwrap().get(key)?.upgrade()
+    }
+
+    /// Registers `fut` as the in-flight request for `key` and returns it. If another request
+    /// for `key` was registered in the meantime, that request is returned and `fut` is dropped.
+    ///
+    /// The entry is removed once the request completes.
+    pub(crate) fn get_or_insert<F>(&self, key: Key, fut: F) -> SharedFetch
+    where
+        F: Future<Output = Result<Fetched, Error>> + Send + 'static,
+    {
+        let mut requests = self.0.lock().unwrap();
+        if let Some(fut) = requests.get(&key).and_then(WeakShared::upgrade) {
+            return fut;
+        }
+
+        let this = self.clone();
+        let entry = key.clone();
+        let fut: FetchFuture = Box::pin(async move {
+            let res = fut.await;
+            // Remove the entry before resolving, so later requests are sent again instead of
+            // receiving this response.
+            this.0.lock().unwrap().remove(&entry);
+            res.map(Arc::new).map_err(Arc::new)
+        });
+        let fut = fut.shared();
+
+        // Drop entries of requests that were cancelled before completing.
+        requests.retain(|_, fut| fut.upgrade().is_some());
+        requests.insert(key, fut.downgrade().unwrap());
+        fut
+    }
+}
+
+impl Debug for InFlight {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InFlight")
+            .field("len", &self.0.lock().unwrap().len())
+            .finish()
+    }
+}
@@ -0,0 +1,213 @@
+//! The datetime type used by the endpoint types.
+//!
+//! Timestamps returned by the API are deserialized into [`DateTime`], which is selected by one of
+//! two features:
+//!
+//! - `chrono` (default): [`DateTime`] is `chrono::DateTime<chrono::Utc>`.
+//! - `time`: [`DateTime`] is `time::OffsetDateTime`.
+//!
+//! The features are mutually exclusive. If both are enabled, `chrono` takes precedence. If
+//! neither is enabled the crate fails to compile. To use the `time` crate, disable the default
+//! features:
+//!
+//! ```toml
+//! gw2api-rs = { version = "0.3", default-features = false, features = ["full", "time"] }
+//! ```
+//!
+//! All timestamps of the crate use [`DateTime`], including the ones computed by the crate itself,
+//! e.g. in the [`reset`] module. Only the selected crate is compiled.
+//!
+//! [`reset`]: crate::reset
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+use std::time::Duration;
+
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+compile_error!("either the `chrono` or the `time` feature must be enabled");
+
+/// A point in time in UTC.
+#[cfg(feature = "chrono")]
+pub type DateTime = chrono::DateTime<chrono::Utc>;
+
+/// A point in time in UTC.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub type DateTime = time::OffsetDateTime;
+
+/// Returns the current time.
+#[cfg(feature = "chrono")]
+pub(crate) fn now() -> DateTime {
+    chrono::Utc::now()
+}
+
+/// Returns the current time.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub(crate) fn now() -> DateTime {
+    time::OffsetDateTime::now_utc()
+}
+
+/// Returns the time remaining until `datetime`. Returns a zero duration if `datetime` is in the
+/// past.
+#[cfg(feature = "chrono")]
+pub(crate) fn until(datetime: DateTime) -> Duration {
+    (datetime - now()).to_std().unwrap_or_default()
+}
+
+/// Returns the time remaining until `datetime`. Returns a zero duration if `datetime` is in the
+/// past.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub(crate) fn until(datetime: DateTime) -> Duration {
+    Duration::try_from(datetime - now()).unwrap_or_default()
+}
+
+/// Returns `datetime` moved by `secs` seconds.
+#[cfg(feature = "chrono")]
+pub(crate) fn add_secs(datetime: DateTime, secs: i64) -> DateTime {
+    datetime + chrono::Duration::seconds(secs)
+}
+
+/// Returns `datetime` moved by `secs` seconds.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub(crate) fn add_secs(datetime: DateTime, secs: i64) -> DateTime {
+    datetime + time::Duration::seconds(secs)
+}
+
+/// Returns 00:00 UTC of the day of `datetime`.
+#[cfg(feature = "chrono")]
+pub(crate) fn start_of_day(datetime: DateTime) -> DateTime {
+    use chrono::TimeZone;
+
+    chrono::Utc.from_utc_datetime(&datetime.date_naive().and_time(chrono::NaiveTime::MIN))
+}
+
+/// Returns 00:00 UTC of the day of `datetime`.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub(crate) fn start_of_day(datetime: DateTime) -> DateTime {
+    datetime
+        .to_offset(time::UtcOffset::UTC)
+        .replace_time(time::Time::MIDNIGHT)
+}
+
+/// Returns the number of days since Monday of the day of `datetime`, in the range of 0 to 6.
+#[cfg(feature = "chrono")]
+pub(crate) fn days_since_monday(datetime: DateTime) -> i64 {
+    use chrono::Datelike;
+
+    i64::from(datetime.weekday().num_days_from_monday())
+}
+
+/// Returns the number of days since Monday of the day of `datetime`, in the range of 0 to 6.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub(crate) fn days_since_monday(datetime: DateTime) -> i64 {
+    i64::from(
+        datetime
+            .to_offset(time::UtcOffset::UTC)
+            .weekday()
+            .number_days_from_monday(),
+    )
+}
+
+/// Formats `datetime` as an RFC 3339 string in UTC with whole seconds, as accepted by the API.
+#[cfg(feature = "chrono")]
+pub(crate) fn to_rfc3339_secs(datetime: DateTime) -> String {
+    datetime.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+/// Formats `datetime` as an RFC 3339 string in UTC with whole seconds, as accepted by the API.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub(crate) fn to_rfc3339_secs(datetime: DateTime) -> String {
+    let datetime = datetime.to_offset(time::UtcOffset::UTC);
+    let datetime = datetime - time::Duration::nanoseconds(datetime.nanosecond().into());
+
+    // Only fails for years outside of 0 to 9999.
+    datetime
+        .format(&time::format_description::well_known::Rfc3339)
+        .unwrap_or_default()
+}
+
+/// Parses an HTTP date header value, e.g. `Fri, 17 May 2024 13:37:00 GMT`.
+#[cfg(feature = "chrono")]
+pub(crate) fn parse_http_date(s: &str) -> Option<DateTime> {
+    chrono::DateTime::parse_from_rfc2822(s)
+        .ok()
+        .map(|datetime| datetime.with_timezone(&chrono::Utc))
+}
+
+/// Parses an HTTP date header value, e.g. `Fri, 17 May 2024 13:37:00 GMT`.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub(crate) fn parse_http_date(s: &str) -> Option<DateTime> {
+    time::OffsetDateTime::parse(s, &time::format_description::well_known::Rfc2822)
+        .ok()
+        .map(|datetime| datetime.to_offset(time::UtcOffset::UTC))
+}
+
+/// (De)serializes a [`DateTime`] as an RFC 3339 string.
+///
+/// `chrono::DateTime` already uses RFC 3339 strings by default, while `time::OffsetDateTime`
+/// requires an explicit format. Fields use `#[serde(with = "crate::datetime::rfc3339")]` to get
+/// the same representation regardless of the selected type.
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) mod rfc3339 {
+    use serde::{Deserializer, Serializer};
+
+    use super::DateTime;
+
+    #[cfg(feature = "chrono")]
+    pub fn serialize<S>(datetime: &DateTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serde::Serialize::serialize(datetime, serializer)
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        serde::Deserialize::deserialize(deserializer)
+    }
+
+    #[cfg(all(feature = "time", not(feature = "chrono")))]
+    pub fn serialize<S>(datetime: &DateTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        time::serde::rfc3339::serialize(datetime, serializer)
+    }
+
+    #[cfg(all(feature = "time", not(feature = "chrono")))]
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        time::serde::rfc3339::deserialize(deserializer)
+    }
+
+    /// (De)serializes an `Option<DateTime>` as an optional RFC 3339 string.
+    pub mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        use super::DateTime;
+
+        pub fn serialize<S>(datetime: &Option<DateTime>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match datetime {
+                Some(datetime) => super::serialize(datetime, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            #[derive(Deserialize)]
+            struct Wrapper(#[serde(with = "super")] DateTime);
+
+            let value = Option::<Wrapper>::deserialize(deserializer)?;
+            Ok(value.map(|Wrapper(datetime)| datetime))
+        }
+    }
+}
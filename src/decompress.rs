@@ -0,0 +1,88 @@
+//! Transparent decompression of response bodies.
+//!
+//! The encodings accepted by the client depend on the enabled features: `gzip`, `deflate` and
+//! `brotli`. If none of these features are enabled, no `Accept-Encoding` header is sent and
+//! bodies are always returned unmodified.
+
+use std::io;
+#[cfg(any(feature = "gzip", feature = "deflate", feature = "brotli"))]
+use std::io::Read;
+
+use hyper::body::Bytes;
+use hyper::header::{HeaderValue, CONTENT_ENCODING};
+use hyper::HeaderMap;
+
+/// The encodings supported by the client, in order of preference.
+const ENCODINGS: &[&str] = &[
+    #[cfg(feature = "brotli")]
+    "br",
+    #[cfg(feature = "gzip")]
+    "gzip",
+    #[cfg(feature = "deflate")]
+    "deflate",
+];
+
+/// Returns the value of the `Accept-Encoding` header, or `None` if no encodings are supported.
+pub(crate) fn accept_encoding() -> Option<HeaderValue> {
+    if ENCODINGS.is_empty() {
+        return None;
+    }
+
+    Some(HeaderValue::from_str(&ENCODINGS.join(", ")).unwrap())
+}
+
+/// Decompresses `body` according to the `Content-Encoding` header in `headers`.
+///
+/// At most `max_size + 1` bytes are decompressed, so callers can detect bodies exceeding
+/// `max_size` without buffering them completely.
+///
+/// Returns an error if the body is not valid for the given encoding or the encoding is not
+/// supported.
+#[cfg_attr(
+    not(any(feature = "gzip", feature = "deflate", feature = "brotli")),
+    allow(unused_variables)
+)]
+pub(crate) fn decompress(
+    headers: &HeaderMap,
+    body: Bytes,
+    max_size: Option<usize>,
+) -> io::Result<Bytes> {
+    let encoding = match headers.get(CONTENT_ENCODING) {
+        Some(encoding) => encoding
+            .to_str()
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?,
+        None => return Ok(body),
+    };
+
+    match encoding.trim() {
+        "" | "identity" => Ok(body),
+        #[cfg(feature = "gzip")]
+        "gzip" | "x-gzip" => read_to_end(flate2::read::GzDecoder::new(&body[..]), max_size),
+        #[cfg(feature = "deflate")]
+        "deflate" => read_to_end(flate2::read::ZlibDecoder::new(&body[..]), max_size),
+        #[cfg(feature = "brotli")]
+        "br" => read_to_end(
+            brotli_decompressor::Decompressor::new(&body[..], 4096),
+            max_size,
+        ),
+        encoding => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unsupported content encoding: {}", encoding),
+        )),
+    }
+}
+
+#[cfg(any(feature = "gzip", feature = "deflate", feature = "brotli"))]
+fn read_to_end<R>(reader: R, max_size: Option<usize>) -> io::Result<Bytes>
+where
+    R: Read,
+{
+    let limit = match max_size {
+        Some(max_size) => (max_size as u64).saturating_add(1),
+        None => u64::MAX,
+    };
+
+    let mut buf = Vec::new();
+    reader.take(limit).read_to_end(&mut buf)?;
+    Ok(Bytes::from(buf))
+}
@@ -0,0 +1,341 @@
+//! Exporting trading post transactions as flat records.
+//!
+//! [`TransactionRecords`] converts [`CurrentTransactions`] and [`HistoryTransactions`] into a
+//! single list of [`TransactionRecord`]s annotated with the name of the traded item, e.g. for
+//! importing into accounting tools. The records can be written as JSON lines using
+//! [`JsonLinesWriter`] or, with the `csv` feature enabled, as CSV using `CsvWriter`.
+//!
+//! Requires the `commerce` and `items` features to be enabled.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use std::io;
+//!
+//! use gw2api_rs::export::{JsonLinesWriter, TransactionRecords};
+//! use gw2api_rs::Client;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let token = "";
+//! let client: Client = Client::builder().access_token(token).into();
+//! let records = TransactionRecords::fetch(&client).await?;
+//!
+//! let mut writer = JsonLinesWriter::new(io::stdout().lock());
+//! for record in &records {
+//!     writer.write(record)?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::io::Write;
+
+use futures_util::future;
+use serde::{Deserialize, Serialize};
+
+use crate::datetime::DateTime;
+use crate::v2::commerce::{Coins, CurrentTransactions, HistoryTransactions};
+use crate::v2::items::Item;
+use crate::{bulk, collection, Error, Executor, Result};
+
+/// The side of the order book a transaction was placed on.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TransactionSide {
+    /// A buy order.
+    Buy,
+    /// A sell listing.
+    Sell,
+}
+
+/// Whether a transaction is still open or was fulfilled.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TransactionStatus {
+    /// The transaction is still listed on the trading post.
+    Current,
+    /// The transaction was fulfilled within the last 90 days.
+    History,
+}
+
+/// A single trading post transaction as a flat record.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TransactionRecord {
+    /// The id of the transaction.
+    pub id: u64,
+    /// The side of the transaction.
+    pub side: TransactionSide,
+    /// Whether the transaction is still open.
+    pub status: TransactionStatus,
+    /// The id of the traded item.
+    pub item_id: u64,
+    /// The name of the traded item. `None` if the item could not be resolved.
+    pub item_name: Option<String>,
+    /// The price per item.
+    pub price: Coins,
+    /// The number of items.
+    pub quantity: u64,
+    /// The total price of all items.
+    pub total: Coins,
+    /// The date the transaction was created.
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub created: DateTime,
+    /// The date the transaction was fulfilled. `None` for current transactions.
+    #[serde(default, with = "crate::datetime::rfc3339::option")]
+    pub purchased: Option<DateTime>,
+}
+
+/// A list of [`TransactionRecord`]s.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TransactionRecords(pub Vec<TransactionRecord>);
+
+collection!(TransactionRecords(TransactionRecord));
+
+impl TransactionRecords {
+    /// Creates a new `TransactionRecords` from current `transactions` on the given `side`.
+    ///
+    /// Item names are looked up in `items`.
+    pub fn from_current(
+        side: TransactionSide,
+        transactions: CurrentTransactions,
+        items: &[Item],
+    ) -> Self {
+        let names = item_names(items);
+
+        Self(
+            transactions
+                .transactions
+                .into_iter()
+                .map(|tx| TransactionRecord {
+                    id: tx.id,
+                    side,
+                    status: TransactionStatus::Current,
+                    item_id: tx.item_id,
+                    item_name: names.get(&tx.item_id).map(|name| (*name).to_owned()),
+                    price: tx.price,
+                    quantity: tx.quantity,
+                    total: tx.price * tx.quantity,
+                    created: tx.created,
+                    purchased: None,
+                })
+                .collect(),
+        )
+    }
+
+    /// Creates a new `TransactionRecords` from historic `transactions` on the given `side`.
+    ///
+    /// Item names are looked up in `items`.
+    pub fn from_history(
+        side: TransactionSide,
+        transactions: HistoryTransactions,
+        items: &[Item],
+    ) -> Self {
+        let names = item_names(items);
+
+        Self(
+            transactions
+                .transations
+                .into_iter()
+                .map(|tx| TransactionRecord {
+                    id: tx.id,
+                    side,
+                    status: TransactionStatus::History,
+                    item_id: tx.item_id,
+                    item_name: names.get(&tx.item_id).map(|name| (*name).to_owned()),
+                    price: tx.price,
+                    quantity: tx.quantity,
+                    total: tx.price * tx.quantity,
+                    created: tx.created,
+                    purchased: Some(tx.purchased),
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns all current and historic transactions of the currently authenticated account.
+    ///
+    /// All pages of the four transaction lists are fetched concurrently, followed by the names of
+    /// all traded items. Records are ordered by the date they were created, oldest first.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// When the access token is missing the `tradingpost` permission, an [`Error`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::export::TransactionRecords;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let records = TransactionRecords::fetch(&client).await?;
+    /// println!("{} transactions", records.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::export::TransactionRecords;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let records = TransactionRecords::fetch(&client)?;
+    /// println!("{} transactions", records.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn fetch<C>(client: &C) -> C::Output<Self>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let (current_buys, current_sells, history_buys, history_sells) = future::try_join4(
+                bulk::get_pages(&client, || CurrentTransactions::request("buys")),
+                bulk::get_pages(&client, || CurrentTransactions::request("sells")),
+                bulk::get_pages(&client, || HistoryTransactions::request("buys")),
+                bulk::get_pages(&client, || HistoryTransactions::request("sells")),
+            )
+            .await?;
+
+            let current_buys = CurrentTransactions {
+                transactions: current_buys,
+            };
+            let current_sells = CurrentTransactions {
+                transactions: current_sells,
+            };
+            let history_buys = HistoryTransactions {
+                transations: history_buys,
+            };
+            let history_sells = HistoryTransactions {
+                transations: history_sells,
+            };
+
+            let mut ids: Vec<u64> = current_buys
+                .transactions
+                .iter()
+                .chain(&current_sells.transactions)
+                .map(|tx| tx.item_id)
+                .chain(
+                    history_buys
+                        .transations
+                        .iter()
+                        .chain(&history_sells.transations)
+                        .map(|tx| tx.item_id),
+                )
+                .collect();
+            ids.sort_unstable();
+            ids.dedup();
+
+            let items: Vec<Item> = bulk::get_chunked(&client, Item::URI, &ids).await?;
+
+            let mut records = Self::from_current(TransactionSide::Buy, current_buys, &items);
+            records.0.extend(Self::from_current(
+                TransactionSide::Sell,
+                current_sells,
+                &items,
+            ));
+            records.0.extend(Self::from_history(
+                TransactionSide::Buy,
+                history_buys,
+                &items,
+            ));
+            records.0.extend(Self::from_history(
+                TransactionSide::Sell,
+                history_sells,
+                &items,
+            ));
+
+            records.0.sort_by_key(|record| record.created);
+            Ok(records)
+        })
+    }
+}
+
+fn item_names(items: &[Item]) -> HashMap<u64, &str> {
+    items
+        .iter()
+        .map(|item| (item.id, item.name.as_str()))
+        .collect()
+}
+
+/// A writer writing [`TransactionRecord`]s as JSON lines, one record per line.
+#[derive(Debug)]
+pub struct JsonLinesWriter<W>
+where
+    W: Write,
+{
+    writer: W,
+}
+
+impl<W> JsonLinesWriter<W>
+where
+    W: Write,
+{
+    /// Creates a new `JsonLinesWriter` writing to `writer`.
+    #[inline]
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Writes a single `record`.
+    pub fn write(&mut self, record: &TransactionRecord) -> Result<()> {
+        serde_json::to_writer(&mut self.writer, record).map_err(Error::from)?;
+        self.writer.write_all(b"\n").map_err(Error::io)
+    }
+
+    /// Flushes and returns the underlying writer.
+    pub fn into_inner(mut self) -> Result<W> {
+        self.writer.flush().map_err(Error::io)?;
+        Ok(self.writer)
+    }
+}
+
+/// A writer writing [`TransactionRecord`]s as CSV with a header row.
+///
+/// Requires the `csv` feature to be enabled.
+#[cfg(feature = "csv")]
+#[derive(Debug)]
+pub struct CsvWriter<W>
+where
+    W: Write,
+{
+    writer: csv::Writer<W>,
+}
+
+#[cfg(feature = "csv")]
+impl<W> CsvWriter<W>
+where
+    W: Write,
+{
+    /// Creates a new `CsvWriter` writing to `writer`.
+    #[inline]
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: csv::Writer::from_writer(writer),
+        }
+    }
+
+    /// Writes a single `record`. The header row is written before the first record.
+    pub fn write(&mut self, record: &TransactionRecord) -> Result<()> {
+        self.writer.serialize(record).map_err(Error::from)
+    }
+
+    /// Flushes and returns the underlying writer.
+    pub fn into_inner(self) -> Result<W> {
+        self.writer
+            .into_inner()
+            .map_err(|err| Error::from(csv::Error::from(err.into_error())))
+    }
+}
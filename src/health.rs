@@ -0,0 +1,100 @@
+//! Checking whether the API is reachable and the access token is valid.
+//!
+//! [`Client::healthcheck`] is meant for startup checks of services: it only sends two cheap
+//! requests, `/v2/build` and `/v2/tokeninfo` if the client has an access token.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use gw2api_rs::{Client, Result};
+//! # use gw2api_rs::health::TokenStatus;
+//! #
+//! # async fn run() -> Result<()> {
+//! # let token = "";
+//! let client: Client = Client::builder().access_token(token).into();
+//! let health = client.healthcheck().await?;
+//!
+//! match &health.token {
+//!     TokenStatus::Valid(info) => println!("token {} is valid", info.name),
+//!     TokenStatus::Invalid(err) => println!("token is invalid: {}", err),
+//!     TokenStatus::Missing => println!("no token set"),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Client::healthcheck`]: crate::Client::healthcheck
+
+use serde::Deserialize;
+
+use crate::v2::tokeninfo::{Scopes, TokenInfo, TokenPermission};
+use crate::{Client, ClientExecutor, Error, RequestBuilder, Result};
+
+/// The status of the API and the access token of a client, returned by
+/// [`Client::healthcheck`].
+///
+/// [`Client::healthcheck`]: crate::Client::healthcheck
+#[derive(Debug)]
+pub struct Health {
+    /// The id of the current game build.
+    pub build: u64,
+    /// The status of the access token of the client.
+    pub token: TokenStatus,
+}
+
+impl Health {
+    /// Returns `true` if the access token is valid or the client has no access token.
+    #[inline]
+    pub fn is_healthy(&self) -> bool {
+        !matches!(self.token, TokenStatus::Invalid(_))
+    }
+
+    /// Returns the permissions granted to the access token. Returns `None` if the access token
+    /// is missing or invalid.
+    pub fn permissions(&self) -> Option<&[TokenPermission]> {
+        match &self.token {
+            TokenStatus::Valid(info) => Some(&info.permissions),
+            _ => None,
+        }
+    }
+}
+
+/// The status of the access token of a client.
+#[derive(Debug)]
+pub enum TokenStatus {
+    /// The client has no access token.
+    Missing,
+    /// The access token is valid.
+    Valid(TokenInfo),
+    /// The access token was rejected by the API.
+    Invalid(Error),
+}
+
+#[derive(Deserialize)]
+struct BuildId {
+    id: u64,
+}
+
+/// Runs the health check for `client`. Only returns an error if the API could not be reached
+/// or failed to respond.
+pub(crate) async fn check(client: &Client) -> Result<Health> {
+    let build: BuildId = ClientExecutor::send(client, RequestBuilder::new("/v2/build")).await?;
+
+    let token = match &client.access_token {
+        None => TokenStatus::Missing,
+        Some(_) => match TokenInfo::get(client).await {
+            Ok(info) => {
+                // The permissions are known now, so cache them like `Client::scopes` does.
+                client.scopes.set(Scopes::new(info.permissions.clone()));
+                TokenStatus::Valid(info)
+            }
+            Err(err) if err.is_auth() => TokenStatus::Invalid(err),
+            Err(err) => return Err(err),
+        },
+    };
+
+    Ok(Health {
+        build: build.id,
+        token,
+    })
+}
@@ -15,14 +15,63 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! # Features
+//!
+//! The endpoint modules in [`v2`] are gated behind features of the same name, e.g. `account`,
+//! `commerce` or `wvw`. All of them are enabled by the default `full` feature. To only compile the
+//! modules you need, disable the default features and enable one of the [`datetime`] features:
+//!
+//! ```toml
+//! gw2api-rs = { version = "0.3", default-features = false, features = ["account", "chrono"] }
+//! ```
 
+#![forbid(unsafe_code)]
+
+pub mod abort;
+pub mod datetime;
+pub mod health;
+pub mod lookup;
+pub mod metrics;
+pub mod middleware;
+pub mod offline;
+pub mod pacing;
+pub mod pagination;
+pub mod reset;
+pub mod response;
 pub mod v2;
 
+mod bulk;
+mod coalesce;
+mod decompress;
+mod retry;
+
 #[cfg(feature = "blocking")]
 pub mod blocking;
 
-use hyper::{client::connect::HttpConnector, header::AUTHORIZATION, Body, Request};
+#[cfg(all(feature = "commerce", feature = "items"))]
+pub mod export;
+
+#[cfg(all(feature = "items", feature = "commerce"))]
+pub mod market;
+
+#[cfg(feature = "account")]
+pub mod snapshot;
+
+#[cfg(feature = "watch")]
+pub mod watch;
+
+use http_body_util::{BodyExt, Empty};
+use hyper::body::{Bytes, Incoming};
+use hyper::header::{
+    HeaderName, HeaderValue, InvalidHeaderName, InvalidHeaderValue, ACCEPT_ENCODING,
+    ACCEPT_LANGUAGE, AUTHORIZATION, CONTENT_LENGTH, USER_AGENT,
+};
+use hyper::{HeaderMap, Request};
 use hyper_tls::HttpsConnector;
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::rt::{TokioExecutor, TokioTimer};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
 use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use thiserror::Error;
@@ -32,35 +81,364 @@ use std::fmt::{self, Display, Formatter};
 use std::future::Future;
 use std::marker::PhantomData;
 use std::pin::Pin;
-use std::task::{Context, Poll};
+use std::sync::{Arc, Mutex};
+use std::task::{ready, Context, Poll};
+use std::time::Duration;
 
+use abort::{AbortHandle, Aborts};
+use coalesce::{Fetched, InFlight, Key, SharedFetch};
+use datetime::DateTime;
+use health::Health;
+use lookup::Lookup;
+use metrics::{Callback, Callbacks, Instrument, RequestInfo, RequestMetrics, ResponseInfo};
+use middleware::{Layers, Middleware};
+use offline::Dataset;
+use pacing::{Pacer, Pacing};
+use response::{ResponseMeta, WithMeta};
+use retry::SendFuture;
+use v2::tokeninfo::{Scopes, Subtoken, TokenInfo, TokenPermission};
+
+const BASE_URL: &str = "https://api.guildwars2.com";
 const SCHEMA_VERSION: &str = "2022-03-23T19:00:00.000Z";
+const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// The characters percent-encoded in path segments and query values. Commas are not encoded to
+/// keep lists of ids readable.
+const ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~')
+    .remove(b',');
 
 /// The Client for making requests.
 #[derive(Clone, Debug)]
 pub struct Client {
-    client: hyper::Client<HttpsConnector<HttpConnector>>,
+    transport: Transport,
     access_token: Option<String>,
     language: Language,
+    scopes: ScopeCache,
+    base_url: Arc<str>,
+    schema_version: HeaderValue,
+    /// Headers sent with every request, including the `User-Agent`.
+    headers: Arc<HeaderMap>,
+    layers: Arc<Layers>,
+    callbacks: Callbacks,
+    max_response_size: Option<usize>,
+    in_flight: Option<InFlight>,
+    pacer: Option<Pacer>,
+    retries: u32,
+    aborts: Aborts,
 }
 
 impl Client {
     /// Creates a new `Client`.
     pub fn new() -> Self {
-        let client = hyper::Client::builder().build(HttpsConnector::new());
+        Self::with_transport(Transport::Http(Box::new(HttpConfig::default().build())))
+    }
 
+    fn with_transport(transport: Transport) -> Self {
         Self {
-            client,
+            transport,
             access_token: None,
+            scopes: ScopeCache::default(),
             language: Language::default(),
+            base_url: Arc::from(BASE_URL),
+            schema_version: HeaderValue::from_static(SCHEMA_VERSION),
+            headers: Arc::new(default_headers()),
+            layers: Arc::default(),
+            callbacks: Callbacks::default(),
+            max_response_size: None,
+            in_flight: None,
+            pacer: None,
+            retries: 0,
+            aborts: Aborts::default(),
         }
     }
 
+    /// Creates a new `Client` that serves all requests from the given [`Dataset`] instead of
+    /// the network.
+    ///
+    /// See the [`offline`] module for more details.
+    pub fn offline(dataset: Dataset) -> Self {
+        Self::builder().offline(dataset).into()
+    }
+
     /// Creates a new [`Builder`] for a client.
     #[inline]
     pub fn builder() -> Builder {
         Builder::default()
     }
+
+    /// Returns the items of the endpoint `T` with the given `ids`.
+    ///
+    /// The ids are split into chunks of 200 which are requested concurrently. Ids not found are
+    /// omitted from the returned list. See the [`lookup`] module for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::colors::Color;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let colors = client.lookup::<Color>(&[1, 2, 3]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn lookup<T>(&self, ids: &[T::Id]) -> BoxFuture<Vec<T>>
+    where
+        T: Lookup,
+    {
+        let client = self.clone();
+        let ids = ids.to_vec();
+
+        Box::pin(async move { lookup::get(&client, &ids).await })
+    }
+
+    /// Returns a new `Client` using the given `access_token` for authenticated requests. All
+    /// other configuration is shared with the current `Client`.
+    ///
+    /// This is cheap and can be used to make requests for multiple accounts without building a
+    /// new `Client` for every access token.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::account::Account;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let tokens = [""];
+    /// let client = Client::new();
+    ///
+    /// for token in tokens {
+    ///     let account = Account::get(&client.with_access_token(token)).await?;
+    ///     println!("{}", account.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_access_token<T>(&self, access_token: T) -> Self
+    where
+        T: ToString,
+    {
+        let mut client = self.clone();
+        client.access_token = Some(access_token.to_string());
+        client.scopes = ScopeCache::default();
+        client
+    }
+
+    /// Returns a new `Client` that verifies the permissions of authenticated requests against
+    /// the given [`TokenInfo`] before sending them. All other configuration is shared with the
+    /// current `Client`.
+    ///
+    /// Requests to an endpoint requiring a permission not granted by `token_info` return an
+    /// [`Error`] without being sent. `token_info` should be the [`TokenInfo`] of the access
+    /// token of the client.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::account::AccountWallet;
+    /// # use gw2api_rs::v2::tokeninfo::TokenInfo;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let token_info = TokenInfo::get(&client).await?;
+    ///
+    /// let client = client.with_token_info(&token_info);
+    /// match AccountWallet::get(&client).await {
+    ///     Ok(wallet) => println!("{:?}", wallet),
+    ///     Err(err) => match err.missing_permission() {
+    ///         Some(permission) => println!("the token is missing the {} permission", permission),
+    ///         None => return Err(err),
+    ///     },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    pub fn with_token_info(&self, token_info: &TokenInfo) -> Self {
+        let mut client = self.clone();
+        client.scopes = ScopeCache::new(Scopes::new(&token_info.permissions[..]));
+        client
+    }
+
+    /// Creates a subtoken from the access token of the client and returns a new `Client` using
+    /// it for authenticated requests. All other configuration is shared with the current
+    /// `Client`.
+    ///
+    /// The subtoken is restricted to the given `permissions` and expires at `expire`. The
+    /// returned client verifies the permissions of requests like [`with_token_info`], so that
+    /// requests outside of the scope return an [`Error`] without being sent. This is useful to
+    /// pass restricted access to less trusted parts of an application.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the client has no access token or the subtoken could not be
+    /// created, e.g. because the access token is missing one of the `permissions`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::account::AccountWallet;
+    /// # use gw2api_rs::v2::tokeninfo::TokenPermission;
+    /// # #[cfg(feature = "chrono")]
+    /// # use chrono::{Duration, Utc};
+    /// #
+    /// # #[cfg(feature = "chrono")]
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    ///
+    /// let permissions = [TokenPermission::Account, TokenPermission::Wallet];
+    /// let scoped = client.scoped(&permissions, Utc::now() + Duration::hours(1)).await?;
+    /// let wallet = AccountWallet::get(&scoped).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`with_token_info`]: Self::with_token_info
+    /// [`Error`]: struct@crate::Error
+    pub fn scoped(&self, permissions: &[TokenPermission], expire: DateTime) -> BoxFuture<Self> {
+        let permissions = permissions.to_vec();
+
+        self.execute(move |client| async move {
+            let subtoken = Subtoken::create(&client, &permissions, expire).await?;
+
+            let mut scoped = client.with_access_token(subtoken.subtoken);
+            scoped.scopes = ScopeCache::new(Scopes::new(permissions));
+            Ok(scoped)
+        })
+    }
+
+    /// Returns the permissions granted to the access token of the client.
+    ///
+    /// The permissions are requested from [`TokenInfo`] on the first call and cached for all
+    /// clones of the client using the same access token. Once the permissions are known,
+    /// requests to an endpoint requiring a permission that is not granted return an [`Error`]
+    /// without being sent, like [`with_token_info`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the client has no access token or the [`TokenInfo`] could not be
+    /// requested.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::tokeninfo::TokenPermission;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let scopes = client.scopes().await?;
+    ///
+    /// for permission in &scopes {
+    ///     println!("{}", permission);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`with_token_info`]: Self::with_token_info
+    /// [`Error`]: struct@crate::Error
+    pub fn scopes(&self) -> BoxFuture<Scopes> {
+        self.execute(|client| async move {
+            if let Some(scopes) = client.scopes.get() {
+                return Ok(scopes);
+            }
+
+            let token_info = TokenInfo::get(&client).await?;
+            let scopes = Scopes::new(token_info.permissions);
+            client.scopes.set(scopes.clone());
+            Ok(scopes)
+        })
+    }
+
+    /// Returns `true` if the access token of the client is granted the given `permission`.
+    ///
+    /// The permissions are requested and cached like [`scopes`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::account::AccountWallet;
+    /// # use gw2api_rs::v2::tokeninfo::TokenPermission;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    ///
+    /// if client.has_scope(TokenPermission::Wallet).await? {
+    ///     let wallet = AccountWallet::get(&client).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`scopes`]: Self::scopes
+    pub fn has_scope(&self, permission: TokenPermission) -> BoxFuture<bool> {
+        let scopes = self.scopes();
+        Box::pin(async move { Ok(scopes.await?.contains(permission)) })
+    }
+
+    /// Aborts all requests in flight that were sent by this `Client` or any of its clones.
+    ///
+    /// Aborted requests resolve to an [`Error`] for which [`is_aborted`] returns `true`.
+    /// Requests sent afterwards are not affected. See the [`abort`] module for more details.
+    ///
+    /// [`Error`]: struct@Error
+    /// [`is_aborted`]: Error::is_aborted
+    pub fn abort_all(&self) {
+        self.aborts.abort_all();
+    }
+
+    /// Checks whether the API is reachable and the access token of the client is valid.
+    ///
+    /// This requests `/v2/build` and, if the client has an access token, `/v2/tokeninfo`. The
+    /// permissions of a valid access token are cached like [`scopes`]. See the [`health`]
+    /// module for more details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the API could not be reached or failed to respond. A rejected
+    /// access token is reported as [`TokenStatus::Invalid`] instead.
+    ///
+    /// [`scopes`]: Self::scopes
+    /// [`Error`]: struct@Error
+    /// [`TokenStatus::Invalid`]: health::TokenStatus::Invalid
+    pub fn healthcheck(&self) -> BoxFuture<Health> {
+        self.execute(|client| async move { health::check(&client).await })
+    }
+}
+
+/// The cached permissions of the access token of a [`Client`], shared by all clones using the
+/// same access token.
+#[derive(Clone, Debug, Default)]
+struct ScopeCache(Arc<Mutex<Option<Scopes>>>);
+
+impl ScopeCache {
+    fn new(scopes: Scopes) -> Self {
+        Self(Arc::new(Mutex::new(Some(scopes))))
+    }
+
+    /// Returns the cached permissions, or `None` if they are not known yet.
+    fn get(&self) -> Option<Scopes> {
+        self.0.lock().unwrap().clone()
+    }
+
+    fn set(&self, scopes: Scopes) {
+        *self.0.lock().unwrap() = Some(scopes);
+    }
 }
 
 impl Default for Client {
@@ -74,6 +452,17 @@ impl Default for Client {
 pub struct Builder {
     access_token: Option<String>,
     language: Language,
+    base_url: Option<String>,
+    schema_version: Option<HeaderValue>,
+    headers: HeaderMap,
+    dataset: Option<Arc<Dataset>>,
+    layers: Layers,
+    callbacks: Callbacks,
+    max_response_size: Option<usize>,
+    coalesce_requests: bool,
+    pacing: Option<Pacing>,
+    retries: u32,
+    http: HttpConfig,
 }
 
 impl Builder {
@@ -91,11 +480,388 @@ impl Builder {
     }
 
     /// Sets the prefered [`Language`] for this `Client`.
+    ///
+    /// The language is sent with all requests to localized endpoints. Localized endpoints also
+    /// provide `_localized` variants of their methods to override the language per request.
     #[inline]
     pub fn language(mut self, language: Language) -> Self {
         self.language = language;
         self
     }
+
+    /// Sets the base url all requests are sent to. Defaults to `https://api.guildwars2.com`.
+    ///
+    /// This can be used to send requests to a mirror or a caching proxy of the API. The url
+    /// must use the `http` or `https` scheme and may contain a path prefix, but no query. A
+    /// trailing slash is ignored.
+    ///
+    /// Returns an [`Error`] if `base_url` is not a valid url.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gw2api_rs::{Client, Result};
+    /// #
+    /// # fn run() -> Result<()> {
+    /// let client: Client = Client::builder()
+    ///     .base_url("http://localhost:8080/gw2")?
+    ///     .into();
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    pub fn base_url<T>(mut self, base_url: T) -> Result<Self>
+    where
+        T: AsRef<str>,
+    {
+        let base_url = base_url.as_ref().trim_end_matches('/');
+
+        let invalid = || Error::from(ErrorKind::InvalidBaseUrl(base_url.to_owned()));
+
+        let uri: hyper::Uri = base_url.parse().map_err(|_| invalid())?;
+        if !matches!(uri.scheme_str(), Some("http" | "https"))
+            || uri.authority().is_none()
+            || uri.query().is_some()
+        {
+            return Err(invalid());
+        }
+
+        self.base_url = Some(base_url.to_owned());
+        Ok(self)
+    }
+
+    /// Sets the schema version sent with every request in the `X-Schema-Version` header.
+    ///
+    /// Note that the types of this crate are written against the default schema version.
+    /// Responses using a different schema version may fail to deserialize.
+    ///
+    /// Returns an [`Error`] if `schema_version` is not a valid header value.
+    ///
+    /// [`Error`]: struct@crate::Error
+    pub fn schema_version<T>(mut self, schema_version: T) -> Result<Self>
+    where
+        T: AsRef<str>,
+    {
+        let value = HeaderValue::from_str(schema_version.as_ref()).map_err(Error::from)?;
+        self.schema_version = Some(value);
+        Ok(self)
+    }
+
+    /// Sets the `User-Agent` header sent with every request. Defaults to the name and version of
+    /// this crate, e.g. `gw2api-rs/0.3.0`.
+    ///
+    /// ArenaNet asks applications using the API to identify themselves, so this should name the
+    /// application and ideally contain a way to contact its author.
+    ///
+    /// Returns an [`Error`] if `user_agent` is not a valid header value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gw2api_rs::{Client, Result};
+    /// #
+    /// # fn run() -> Result<()> {
+    /// let client: Client = Client::builder()
+    ///     .user_agent("my-app/1.0 (https://example.com)")?
+    ///     .into();
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    pub fn user_agent<T>(self, user_agent: T) -> Result<Self>
+    where
+        T: AsRef<str>,
+    {
+        self.default_header(USER_AGENT.as_str(), user_agent)
+    }
+
+    /// Sets a header sent with every request, replacing any previous value of the header.
+    ///
+    /// Headers set by the `Client` itself, e.g. `Authorization` or `X-Schema-Version`, take
+    /// precedence over default headers of the same name.
+    ///
+    /// Returns an [`Error`] if `name` is not a valid header name or `value` is not a valid
+    /// header value.
+    ///
+    /// [`Error`]: struct@crate::Error
+    pub fn default_header<K, V>(mut self, name: K, value: V) -> Result<Self>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let name = HeaderName::from_bytes(name.as_ref().as_bytes()).map_err(Error::from)?;
+        let value = HeaderValue::from_str(value.as_ref()).map_err(Error::from)?;
+        self.headers.insert(name, value);
+        Ok(self)
+    }
+
+    /// Sets the maximum size of a response body in bytes. Defaults to no limit.
+    ///
+    /// Responses with a larger body, either as received or after decompression, fail with an
+    /// [`Error`] for which [`is_response_too_large`] returns `true`. The body is never buffered
+    /// beyond the limit.
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`is_response_too_large`]: Error::is_response_too_large
+    #[inline]
+    pub fn max_response_size(mut self, max_response_size: usize) -> Self {
+        self.max_response_size = Some(max_response_size);
+        self
+    }
+
+    /// Coalesces identical requests sent while the first of them is still in flight. Defaults
+    /// to `false`.
+    ///
+    /// Requests are identical if they have the same uri, language and access token. Only the
+    /// first request is sent, all others wait for its response, which is then deserialized
+    /// separately for every request. This reduces the number of requests when many tasks
+    /// request the same data at the same time, e.g. static data in a web backend.
+    ///
+    /// Coalesced requests invoke [`Middleware::on_request`], but no other middleware or
+    /// [`metrics`] callbacks, since they are not sent over the network.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gw2api_rs::Client;
+    /// #
+    /// let client: Client = Client::builder().coalesce_requests(true).into();
+    /// ```
+    #[inline]
+    pub fn coalesce_requests(mut self, enabled: bool) -> Self {
+        self.coalesce_requests = enabled;
+        self
+    }
+
+    /// Delays requests according to the given [`Pacing`]. Defaults to sending all requests
+    /// immediately.
+    ///
+    /// See the [`pacing`] module for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gw2api_rs::Client;
+    /// # use gw2api_rs::pacing::Pacing;
+    /// #
+    /// let client: Client = Client::builder().pacing(Pacing::smooth(300)).into();
+    /// ```
+    #[inline]
+    pub fn pacing(mut self, pacing: Pacing) -> Self {
+        self.pacing = Some(pacing);
+        self
+    }
+
+    /// Sends requests failing with a retryable error again, up to `retries` times. Defaults to
+    /// `0`.
+    ///
+    /// A request is retried if the rate limit was reached, the API responded with a server error
+    /// (`5xx`) or the connection failed, see [`Error::is_retryable`]. The first retry is sent
+    /// after 500ms and the delay doubles with every further retry. Retries are also delayed by
+    /// the [`Pacing`] of the client. The number of retries is reported to the [`metrics`]
+    /// callbacks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gw2api_rs::Client;
+    /// #
+    /// let client: Client = Client::builder().retries(3).into();
+    /// ```
+    #[inline]
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Only use HTTP/2 connections. Defaults to `false`.
+    ///
+    /// HTTP/2 multiplexes all concurrent requests over a single connection instead of opening a
+    /// connection per in-flight request. The server must support HTTP/2, otherwise all requests
+    /// fail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use gw2api_rs::Client;
+    /// #
+    /// let client: Client = Client::builder()
+    ///     .http2_only(true)
+    ///     .http2_adaptive_window(true)
+    ///     .http2_keep_alive_interval(Duration::from_secs(30))
+    ///     .into();
+    /// ```
+    #[inline]
+    pub fn http2_only(mut self, enabled: bool) -> Self {
+        self.http.http2_only = enabled;
+        self
+    }
+
+    /// Enables the adaptive flow control of HTTP/2 connections. Defaults to `false`.
+    ///
+    /// The flow control windows are adjusted to the measured bandwidth-delay product, which
+    /// increases the throughput of large responses. This only has an effect on HTTP/2
+    /// connections.
+    #[inline]
+    pub fn http2_adaptive_window(mut self, enabled: bool) -> Self {
+        self.http.http2_adaptive_window = enabled;
+        self
+    }
+
+    /// Sets the interval in which HTTP/2 PING frames are sent to keep connections alive.
+    /// Defaults to no PING frames.
+    ///
+    /// This only has an effect on HTTP/2 connections.
+    #[inline]
+    pub fn http2_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.http.http2_keep_alive_interval = Some(interval);
+        self
+    }
+
+    /// Sets the idle time after which TCP keepalive probes are sent on a connection. Defaults
+    /// to no keepalive probes.
+    #[inline]
+    pub fn tcp_keepalive(mut self, time: Duration) -> Self {
+        self.http.tcp_keepalive = Some(time);
+        self
+    }
+
+    /// Sets the time after which idle connections are closed. Defaults to 90 seconds.
+    #[inline]
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.http.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept open per host. Defaults to no limit.
+    #[inline]
+    pub fn pool_max_idle(mut self, max_idle: usize) -> Self {
+        self.http.pool_max_idle = Some(max_idle);
+        self
+    }
+
+    /// Serves all requests from the given [`Dataset`] instead of the network.
+    ///
+    /// See the [`offline`] module for more details.
+    #[inline]
+    pub fn offline(mut self, dataset: Dataset) -> Self {
+        self.dataset = Some(Arc::new(dataset));
+        self
+    }
+
+    /// Adds a [`Middleware`] invoked for every request made by the `Client`.
+    ///
+    /// See the [`middleware`] module for more details.
+    pub fn layer<M>(mut self, middleware: M) -> Self
+    where
+        M: Middleware,
+    {
+        self.layers.push(middleware);
+        self
+    }
+
+    /// Sets a callback receiving [`RequestMetrics`] for every request sent over the network.
+    ///
+    /// See the [`metrics`] module for more details.
+    pub fn on_metrics<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&RequestMetrics) + Send + Sync + 'static,
+    {
+        self.callbacks.on_metrics = Some(Callback::new(f));
+        self
+    }
+
+    /// Sets a callback receiving a [`RequestInfo`] for every request before it is sent over the
+    /// network. Access tokens are redacted.
+    ///
+    /// See the [`metrics`] module for more details.
+    pub fn on_request<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&RequestInfo) + Send + Sync + 'static,
+    {
+        self.callbacks.on_request = Some(Callback::new(f));
+        self
+    }
+
+    /// Sets a callback receiving a [`ResponseInfo`] for every request sent over the network once
+    /// the request completed, either successfully or with an error. Access tokens are redacted.
+    ///
+    /// See the [`metrics`] module for more details.
+    pub fn on_response<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&ResponseInfo) + Send + Sync + 'static,
+    {
+        self.callbacks.on_response = Some(Callback::new(f));
+        self
+    }
+}
+
+type HttpClient = hyper_util::client::legacy::Client<HttpsConnector<HttpConnector>, Empty<Bytes>>;
+
+/// The connection settings of the [`HttpClient`] set using the [`Builder`].
+#[derive(Clone, Debug, Default)]
+struct HttpConfig {
+    http2_only: bool,
+    http2_adaptive_window: bool,
+    http2_keep_alive_interval: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    pool_idle_timeout: Option<Duration>,
+    pool_max_idle: Option<usize>,
+}
+
+impl HttpConfig {
+    fn build(&self) -> HttpClient {
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        http.set_keepalive(self.tcp_keepalive);
+
+        let mut tls = native_tls::TlsConnector::builder();
+        // Without ALPN the server would not know to speak HTTP/2 on the TLS connection.
+        if self.http2_only {
+            tls.request_alpns(&["h2"]);
+        }
+
+        let tls = tls
+            .build()
+            .unwrap_or_else(|err| panic!("failed to create TLS connector: {}", err));
+
+        let mut builder = hyper_util::client::legacy::Client::builder(TokioExecutor::new());
+        builder
+            .http2_only(self.http2_only)
+            .http2_adaptive_window(self.http2_adaptive_window);
+
+        // Timers are only installed when needed, since they require the tokio runtime to have
+        // the time driver enabled.
+        if let Some(interval) = self.http2_keep_alive_interval {
+            builder
+                .timer(TokioTimer::new())
+                .http2_keep_alive_interval(interval);
+        }
+
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder
+                .pool_timer(TokioTimer::new())
+                .pool_idle_timeout(timeout);
+        }
+
+        if let Some(max_idle) = self.pool_max_idle {
+            builder.pool_max_idle_per_host(max_idle);
+        }
+
+        builder.build(HttpsConnector::from((http, tls.into())))
+    }
+}
+
+/// The backend used by a [`Client`] to fetch responses.
+#[derive(Clone, Debug)]
+enum Transport {
+    Http(Box<HttpClient>),
+    Offline(Arc<Dataset>),
 }
 
 /// A client used to make requests to the API.
@@ -112,40 +878,261 @@ where
     fn send(&self, request: RequestBuilder) -> Self::Result;
 }
 
+/// A client used to run operations spanning multiple requests.
+///
+/// The async [`Client`] returns a [`BoxFuture`] resolving to the result of the operation, while
+/// the [`blocking`] client returns the result directly.
+///
+/// This trait is sealed and cannot be implemented.
+///
+/// [`blocking`]: crate::blocking
+pub trait Executor: private::Sealed {
+    /// The return type of an operation resolving to `T`.
+    type Output<T>;
+
+    /// Runs the operation returned by `f` to completion.
+    #[doc(hidden)]
+    fn execute<F, Fut, T>(&self, f: F) -> Self::Output<T>
+    where
+        F: FnOnce(Client) -> Fut,
+        Fut: Future<Output = Result<T>> + Send + 'static,
+        T: Send + 'static;
+}
+
+/// A boxed future returned by operations spanning multiple requests.
+pub type BoxFuture<T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'static>>;
+
 pub(crate) mod private {
     pub trait Sealed {}
 }
 
 impl From<Builder> for Client {
     fn from(builder: Builder) -> Self {
-        let mut client = Client::new();
+        let transport = match builder.dataset {
+            Some(dataset) => Transport::Offline(dataset),
+            None => Transport::Http(Box::new(builder.http.build())),
+        };
+
+        let mut client = Client::with_transport(transport);
         client.access_token = builder.access_token;
         client.language = builder.language;
+        client.layers = Arc::new(builder.layers);
+        client.callbacks = builder.callbacks;
+        client.max_response_size = builder.max_response_size;
+
+        if builder.coalesce_requests {
+            client.in_flight = Some(InFlight::default());
+        }
+
+        client.pacer = builder.pacing.map(Pacer::new);
+        client.retries = builder.retries;
+
+        if let Some(base_url) = builder.base_url {
+            client.base_url = Arc::from(base_url);
+        }
+
+        if let Some(schema_version) = builder.schema_version {
+            client.schema_version = schema_version;
+        }
+
+        if !builder.headers.is_empty() {
+            let mut headers = default_headers();
+            headers.extend(builder.headers);
+            client.headers = Arc::new(headers);
+        }
+
         client
     }
 }
 
+/// Returns the headers sent with every request by default.
+fn default_headers() -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(USER_AGENT, HeaderValue::from_static(DEFAULT_USER_AGENT));
+    headers
+}
+
 /// An alias for `Result<T, Error>`.
 pub type Result<T> = std::result::Result<T, Error>;
 
 /// An error that may occur when making API requests.
-#[derive(Debug, Error)]
-#[error(transparent)]
+#[derive(Debug)]
 pub struct Error {
     kind: ErrorKind,
+    status: Option<u16>,
+    endpoint: Option<String>,
 }
 
 impl Error {
     /// Returns `true` if this error occured while making a HTTP request.
     #[inline]
     pub fn is_http(&self) -> bool {
-        matches!(self.kind, ErrorKind::Http(_))
+        matches!(self.kind(), ErrorKind::Http(_) | ErrorKind::Request(_))
     }
 
     /// Returns `true` if this error occured while deserializing json.
     #[inline]
     pub fn is_json(&self) -> bool {
-        matches!(self.kind, ErrorKind::Json(_))
+        match self.kind() {
+            ErrorKind::Json(_) => true,
+            #[cfg(feature = "simd-json")]
+            ErrorKind::SimdJson(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if this error occured while reading from or writing to an I/O resource,
+    /// e.g. when exporting records or loading a [`Dataset`].
+    #[inline]
+    pub fn is_io(&self) -> bool {
+        matches!(self.kind(), ErrorKind::Io(_))
+    }
+
+    /// Returns `true` if this error occured because an offline [`Dataset`] contained no
+    /// response for the request.
+    #[inline]
+    pub fn is_not_in_dataset(&self) -> bool {
+        matches!(self.kind(), ErrorKind::NotInDataset(_))
+    }
+
+    /// Returns `true` if this error occured because the response body exceeded the maximum size
+    /// set using [`Builder::max_response_size`].
+    #[inline]
+    pub fn is_response_too_large(&self) -> bool {
+        matches!(self.kind(), ErrorKind::ResponseTooLarge(_))
+    }
+
+    /// Returns `true` if this error occured because the request was aborted using an
+    /// [`AbortHandle`] or [`Client::abort_all`].
+    #[inline]
+    pub fn is_aborted(&self) -> bool {
+        matches!(self.kind(), ErrorKind::Aborted)
+    }
+
+    /// Returns the [`TokenPermission`] required by the endpoint if this error occured because
+    /// the access token is missing the permission.
+    ///
+    /// Permissions are only verified before sending a request on a client created using
+    /// [`Client::with_token_info`].
+    #[inline]
+    pub fn missing_permission(&self) -> Option<TokenPermission> {
+        match self.kind() {
+            ErrorKind::MissingPermission(permission) => Some(*permission),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this error is likely temporary and the request may succeed when sent
+    /// again.
+    ///
+    /// This is the case if the rate limit was reached, the API responded with a server error
+    /// (`5xx`) or the connection to the API failed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::build::Build;
+    /// # use std::time::Duration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let client = Client::new();
+    ///
+    /// let build = loop {
+    ///     match Build::get(&client).await {
+    ///         Err(err) if err.is_retryable() => {
+    ///             tokio::time::sleep(Duration::from_secs(1)).await;
+    ///         }
+    ///         res => break res?,
+    ///     }
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        if self.is_rate_limited() {
+            return true;
+        }
+
+        if matches!(self.status, Some(status) if (500..600).contains(&status)) {
+            return true;
+        }
+
+        match self.kind() {
+            ErrorKind::Http(err) => is_retryable_http(err),
+            ErrorKind::Request(err) => is_retryable_request(err),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if this error occured because the request was not authorized.
+    ///
+    /// This is the case if the client has no access token, the access token is invalid or it is
+    /// missing a permission required by the endpoint.
+    pub fn is_auth(&self) -> bool {
+        matches!(self.status, Some(401 | 403))
+            || matches!(
+                self.kind(),
+                ErrorKind::NoAccessToken | ErrorKind::MissingPermission(_)
+            )
+    }
+
+    /// Returns `true` if this error occured because the rate limit of the API was reached.
+    #[inline]
+    pub fn is_rate_limited(&self) -> bool {
+        self.status == Some(429)
+    }
+
+    /// Returns the status code of the response if this error occured after a response was
+    /// received.
+    #[inline]
+    pub fn status(&self) -> Option<u16> {
+        self.status
+    }
+
+    /// Returns the error message returned by the API, if any.
+    #[inline]
+    pub fn api_message(&self) -> Option<&str> {
+        match self.kind() {
+            ErrorKind::Api(err) => Some(&err.text),
+            _ => None,
+        }
+    }
+
+    /// Returns the path of the endpoint the failed request was sent to, e.g. `/v2/account`.
+    ///
+    /// Errors not caused by a request have no endpoint.
+    #[inline]
+    pub fn endpoint(&self) -> Option<&str> {
+        self.endpoint.as_deref()
+    }
+}
+
+/// Returns `true` if the connection failed in a way that may succeed when retried.
+fn is_retryable_http(err: &hyper::Error) -> bool {
+    err.is_timeout() || err.is_closed() || err.is_incomplete_message()
+}
+
+/// Returns `true` if sending the request failed in a way that may succeed when retried.
+fn is_retryable_request(err: &hyper_util::client::legacy::Error) -> bool {
+    err.is_connect()
+        || std::error::Error::source(err)
+            .and_then(|source| source.downcast_ref::<hyper::Error>())
+            .is_some_and(is_retryable_http)
+}
+
+impl Display for Error {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.kind, f)
+    }
+}
+
+impl std::error::Error for Error {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.kind.source()
     }
 }
 
@@ -154,7 +1141,47 @@ impl Error {
     where
         T: Into<ErrorKind>,
     {
-        Self { kind: err.into() }
+        Self {
+            kind: err.into(),
+            status: None,
+            endpoint: None,
+        }
+    }
+
+    /// Creates an error for a failed read from or write to an I/O resource.
+    #[cfg(all(feature = "commerce", feature = "items"))]
+    pub(crate) fn io(err: std::io::Error) -> Self {
+        Self::from(ErrorKind::Io(err))
+    }
+
+    /// Creates an error for a request that was coalesced with the request failing with `err`.
+    fn shared(err: Arc<Error>) -> Self {
+        Self {
+            status: err.status,
+            endpoint: err.endpoint.clone(),
+            kind: ErrorKind::Shared(err),
+        }
+    }
+
+    /// Returns the kind of this error, looking through errors shared by coalesced requests.
+    fn kind(&self) -> &ErrorKind {
+        match &self.kind {
+            ErrorKind::Shared(err) => err.kind(),
+            kind => kind,
+        }
+    }
+
+    /// Attaches the status code of the response and the endpoint of the request to this error.
+    fn with_context(mut self, status: Option<u16>, endpoint: &str) -> Self {
+        self.status = self.status.or(status);
+
+        if self.endpoint.is_none() && !endpoint.is_empty() {
+            // Strip the query so only the path of the endpoint remains.
+            let path = endpoint.split('?').next().unwrap_or_default();
+            self.endpoint = Some(path.to_owned());
+        }
+
+        self
     }
 }
 
@@ -165,9 +1192,49 @@ enum ErrorKind {
     #[error(transparent)]
     Http(#[from] hyper::Error),
     #[error(transparent)]
-    Json(#[from] serde_json::Error),
+    Request(#[from] hyper_util::client::legacy::Error),
+    #[error(transparent)]
+    Json(serde_json::Error),
+    #[cfg(feature = "simd-json")]
+    #[error(transparent)]
+    SimdJson(#[from] simd_json::Error),
+    #[cfg(feature = "csv")]
+    #[error(transparent)]
+    Csv(#[from] csv::Error),
+    #[error("failed to decompress response body: {0}")]
+    Decompress(#[from] std::io::Error),
+    #[error("io error: {0}")]
+    Io(std::io::Error),
     #[error("no access token")]
     NoAccessToken,
+    #[error("access token is missing the {0} permission")]
+    MissingPermission(TokenPermission),
+    #[error("no offline data for {0}")]
+    NotInDataset(String),
+    #[error("invalid base url: {0}")]
+    InvalidBaseUrl(String),
+    #[error(transparent)]
+    InvalidHeaderName(#[from] InvalidHeaderName),
+    #[error(transparent)]
+    InvalidHeaderValue(#[from] InvalidHeaderValue),
+    #[error("response body exceeds the maximum size of {0} bytes")]
+    ResponseTooLarge(usize),
+    #[error("request aborted")]
+    Aborted,
+    /// The error of a request shared by coalesced requests.
+    #[error(transparent)]
+    Shared(Arc<Error>),
+}
+
+impl From<serde_json::Error> for ErrorKind {
+    fn from(err: serde_json::Error) -> Self {
+        // Failing to read or write the underlying reader or writer is no json error.
+        if err.is_io() {
+            Self::Io(err.into())
+        } else {
+            Self::Json(err)
+        }
+    }
 }
 
 #[derive(Clone, Debug, Error, Deserialize)]
@@ -178,20 +1245,27 @@ struct ApiError {
 
 /// A builder for creating endpoint requests.
 pub struct RequestBuilder {
-    uri: Cow<'static, str>,
+    path: Cow<'static, str>,
+    /// The query parameters in the order they were first added.
+    query: Vec<(&'static str, String)>,
     authentication: Authentication,
+    permissions: &'static [TokenPermission],
     localized: bool,
+    language: Option<Language>,
 }
 
 impl RequestBuilder {
-    pub(crate) fn new<T>(uri: T) -> Self
+    pub(crate) fn new<T>(path: T) -> Self
     where
         T: Into<Cow<'static, str>>,
     {
         Self {
-            uri: uri.into(),
+            path: path.into(),
+            query: Vec::new(),
             authentication: Authentication::None,
+            permissions: &[],
             localized: false,
+            language: None,
         }
     }
 
@@ -200,15 +1274,123 @@ impl RequestBuilder {
         self
     }
 
+    /// Sets the permissions required by the endpoint.
+    #[cfg(any(
+        feature = "account",
+        feature = "characters",
+        feature = "commerce",
+        feature = "guild",
+        feature = "pvp"
+    ))]
+    pub(crate) fn permissions(mut self, v: &'static [TokenPermission]) -> Self {
+        self.permissions = v;
+        self
+    }
+
+    /// Marks the endpoint as localized, sending the language of the client with the request.
     pub(crate) fn localized(mut self, v: bool) -> Self {
         self.localized = v;
         self
     }
+
+    /// Sends the request in the given `language` instead of the language of the client.
+    #[cfg(any(
+        feature = "achievements",
+        feature = "colors",
+        feature = "currencies",
+        feature = "finishers",
+        feature = "gliders",
+        feature = "guild",
+        feature = "homestead",
+        feature = "items",
+        feature = "jadebots",
+        feature = "maps",
+        feature = "materials",
+        feature = "minis",
+        feature = "mounts",
+        feature = "novelties",
+        feature = "outfits",
+        feature = "professions",
+        feature = "pvp",
+        feature = "quests",
+        feature = "skiffs",
+        feature = "skins",
+        feature = "stories",
+        feature = "titles",
+        feature = "worlds",
+        feature = "wvw"
+    ))]
+    pub(crate) fn language(mut self, language: Language) -> Self {
+        self.localized = true;
+        self.language = Some(language);
+        self
+    }
+
+    /// Appends the percent-encoded path `segment` to the path.
+    #[cfg(any(
+        feature = "characters",
+        feature = "commerce",
+        feature = "guild",
+        feature = "pvp",
+        feature = "wvw"
+    ))]
+    pub(crate) fn segment<T>(mut self, segment: T) -> Self
+    where
+        T: Display,
+    {
+        let path = self.path.to_mut();
+        path.push('/');
+        path.extend(utf8_percent_encode(&segment.to_string(), ENCODE_SET));
+        self
+    }
+
+    /// Sets the query parameter `key` to `value`.
+    ///
+    /// Parameters are sent in the order they were first added. Setting a parameter again replaces
+    /// its previous value in place.
+    pub(crate) fn query<T>(mut self, key: &'static str, value: T) -> Self
+    where
+        T: Display,
+    {
+        let value = value.to_string();
+        match self.query.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, v)) => *v = value,
+            None => self.query.push((key, value)),
+        }
+        self
+    }
+
+    /// Sets the query parameter `key` to a comma separated list of `values`.
+    pub(crate) fn query_list<T>(self, key: &'static str, values: &[T]) -> Self
+    where
+        T: Display,
+    {
+        let values: Vec<String> = values.iter().map(ToString::to_string).collect();
+        self.query(key, values.join(","))
+    }
+
+    /// Returns the path and the percent-encoded query of the request.
+    pub(crate) fn uri(&self) -> String {
+        let mut uri = self.path.to_string();
+        for (index, (key, value)) in self.query.iter().enumerate() {
+            uri.push(if index == 0 { '?' } else { '&' });
+            uri.push_str(key);
+            uri.push('=');
+            uri.extend(utf8_percent_encode(value, ENCODE_SET));
+        }
+        uri
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub(crate) enum Authentication {
+    /// The access token is never sent.
     None,
+    /// The access token is sent if set. The endpoint returns additional data for authenticated
+    /// requests.
+    #[cfg(feature = "achievements")]
+    Optional,
+    /// The access token is always sent. Requests fail if no access token is set.
     Required,
 }
 
@@ -217,6 +1399,11 @@ impl Authentication {
     pub fn is_none(&self) -> bool {
         matches!(self, Self::None)
     }
+
+    #[inline]
+    pub fn is_required(&self) -> bool {
+        matches!(self, Self::Required)
+    }
 }
 
 /// All possible api languages. The default language is `En`.
@@ -259,35 +1446,142 @@ where
     state: State<T>,
     _marker: PhantomData<T>,
     is_error: bool,
+    layers: Arc<Layers>,
+    instrument: Option<Instrument>,
+    uri: String,
+    meta: Option<ResponseMeta>,
+    max_response_size: Option<usize>,
+    /// The path and query of the request, used as the endpoint of errors.
+    endpoint: String,
+    status: Option<u16>,
+    abort: AbortHandle,
 }
 
 impl<T> ResponseFuture<T>
 where
     T: DeserializeOwned,
 {
-    fn new(fut: hyper::client::ResponseFuture) -> Self {
+    fn new(
+        fut: SendFuture,
+        layers: Arc<Layers>,
+        instrument: Option<Instrument>,
+        uri: String,
+        endpoint: String,
+        max_response_size: Option<usize>,
+    ) -> Self {
         Self {
             state: State::Response(fut),
             _marker: PhantomData,
             is_error: false,
+            layers,
+            instrument,
+            uri,
+            meta: None,
+            max_response_size,
+            endpoint,
+            status: None,
+            abort: AbortHandle::new(),
         }
     }
 
-    fn result(res: Result<T>) -> Self {
+    fn result(res: Result<T>, endpoint: String) -> Self {
         Self {
             state: State::Result(Some(res)),
             _marker: PhantomData,
             is_error: false,
+            layers: Arc::default(),
+            instrument: None,
+            uri: String::new(),
+            meta: None,
+            max_response_size: None,
+            endpoint,
+            status: None,
+            abort: AbortHandle::new(),
+        }
+    }
+
+    /// Creates a `ResponseFuture` waiting for the response of a coalesced request.
+    fn shared(fut: SharedFetch, endpoint: String) -> Self {
+        Self {
+            state: State::Shared(fut),
+            _marker: PhantomData,
+            is_error: false,
+            layers: Arc::default(),
+            instrument: None,
+            uri: String::new(),
+            meta: None,
+            max_response_size: None,
+            endpoint,
+            status: None,
+            abort: AbortHandle::new(),
+        }
+    }
+
+    /// Returns a future that resolves to the result of the request together with the
+    /// [`ResponseMeta`] of the response.
+    ///
+    /// See the [`response`] module for more details.
+    #[inline]
+    pub fn with_meta(self) -> WithMeta<T> {
+        WithMeta { inner: self }
+    }
+
+    /// Returns an [`AbortHandle`] to abort the request from anywhere, e.g. after the future
+    /// was moved into a spawned task.
+    ///
+    /// See the [`abort`] module for more details.
+    #[inline]
+    pub fn abort_handle(&self) -> AbortHandle {
+        self.abort.clone()
+    }
+
+    /// Deserializes the response body `buf`.
+    fn decode(&mut self, buf: Bytes) -> Result<T> {
+        if let Some(instrument) = &mut self.instrument {
+            instrument.record_body_size(buf.len());
+        }
+
+        if self.is_error {
+            return match from_json::<ApiError>(buf) {
+                Ok(st) => Err(Error::from(st)),
+                Err(err) => Err(err),
+            };
         }
+
+        from_json(buf)
     }
 }
 
+/// Deserializes a json response body.
+#[cfg(not(feature = "simd-json"))]
+fn from_json<T>(buf: Bytes) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_slice(&buf).map_err(Error::from)
+}
+
+/// Deserializes a json response body.
+///
+/// simd-json parses the body in place and requires a mutable buffer. This only copies `buf` if
+/// it is shared.
+#[cfg(feature = "simd-json")]
+fn from_json<T>(buf: Bytes) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    let mut buf = Vec::from(buf);
+    simd_json::serde::from_slice(&mut buf).map_err(Error::from)
+}
+
 enum State<T>
 where
     T: DeserializeOwned,
 {
-    Response(hyper::client::ResponseFuture),
-    Body(Pin<Box<dyn Future<Output = hyper::Result<hyper::body::Bytes>> + Send + Sync + 'static>>),
+    Response(SendFuture),
+    Body(Pin<Box<dyn Future<Output = Result<Bytes>> + Send + Sync + 'static>>),
+    /// Waiting for the response of a request coalesced with identical requests.
+    Shared(SharedFetch),
     Result(Option<Result<T>>),
 }
 
@@ -298,82 +1592,212 @@ where
     type Output = Result<T>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        match &mut self.state {
-            State::Response(_) => {
-                let fut = unsafe {
-                    self.as_mut()
-                        .map_unchecked_mut(|this| match &mut this.state {
-                            State::Response(resp) => resp,
-                            _ => unreachable!(),
-                        })
-                };
-
-                match fut.poll(cx) {
-                    Poll::Pending => Poll::Pending,
-                    Poll::Ready(Err(err)) => Poll::Ready(Err(Error::from(err))),
-                    Poll::Ready(Ok(resp)) => {
-                        if !resp.status().is_success() {
-                            self.is_error = true;
-                        }
-                        let is_error = self.is_error;
-
-                        self.state =
-                            State::Body(Box::pin(async move { hyper::body::to_bytes(resp).await }));
-
-                        let fut = unsafe {
-                            self.map_unchecked_mut(|this| match &mut this.state {
-                                State::Body(body) => body,
-                                _ => unreachable!(),
-                            })
-                        };
-
-                        match fut.poll(cx) {
-                            Poll::Pending => Poll::Pending,
-                            Poll::Ready(Err(err)) => Poll::Ready(Err(Error::from(err))),
-                            Poll::Ready(Ok(buf)) => {
-                                if is_error {
-                                    return match serde_json::from_slice::<ApiError>(&buf) {
-                                        Ok(st) => Poll::Ready(Err(Error::from(st))),
-                                        Err(err) => Poll::Ready(Err(Error::from(err))),
-                                    };
-                                }
-
-                                match serde_json::from_slice(&buf) {
-                                    Ok(st) => Poll::Ready(Ok(st)),
-                                    Err(err) => Poll::Ready(Err(Error::from(err))),
-                                }
-                            }
-                        }
-                    }
-                }
+        #[cfg(feature = "tracing")]
+        let span = self.instrument.as_ref().map(Instrument::span);
+        #[cfg(feature = "tracing")]
+        let _guard = span.as_ref().map(tracing::Span::enter);
+
+        let poll = match self.as_mut().poll_response(cx) {
+            Poll::Ready(Err(err)) => {
+                Poll::Ready(Err(err.with_context(self.status, &self.endpoint)))
             }
-            State::Body(fut) => {
-                let fut = fut.as_mut();
-                match fut.poll(cx) {
-                    Poll::Pending => Poll::Pending,
-                    Poll::Ready(Err(err)) => Poll::Ready(Err(Error::from(err))),
-                    Poll::Ready(Ok(buf)) => {
-                        if self.is_error {
-                            return match serde_json::from_slice::<ApiError>(&buf) {
-                                Ok(st) => Poll::Ready(Err(Error::from(st))),
-                                Err(err) => Poll::Ready(Err(Error::from(err))),
-                            };
-                        }
-
-                        match serde_json::from_slice(&buf) {
-                            Ok(st) => Poll::Ready(Ok(st)),
-                            Err(err) => Poll::Ready(Err(Error::from(err))),
-                        }
+            poll => poll,
+        };
+
+        if let Poll::Ready(res) = &poll {
+            if let Some(instrument) = &mut self.instrument {
+                instrument.finish(res.is_ok());
+            }
+        }
+
+        poll
+    }
+}
+
+impl<T> ResponseFuture<T>
+where
+    T: DeserializeOwned,
+{
+    fn poll_response(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<T>> {
+        // All states are `Unpin`, so the future never needs to be projected structurally.
+        let this = self.get_mut();
+
+        if this.abort.poll_aborted(cx) {
+            return Poll::Ready(Err(Error::from(ErrorKind::Aborted)));
+        }
+
+        loop {
+            match &mut this.state {
+                State::Response(fut) => {
+                    let sent = ready!(fut.as_mut().poll(cx));
+                    if let Some(instrument) = &mut this.instrument {
+                        instrument.record_attempts(sent.retries, sent.wait);
+                    }
+
+                    let resp = sent.res.map_err(Error::from)?;
+
+                    let (parts, body) = resp.into_parts();
+                    this.layers.on_response(&parts);
+
+                    if let Some(instrument) = &mut this.instrument {
+                        instrument.record_status(parts.status.as_u16());
+                    }
+
+                    if !parts.status.is_success() {
+                        this.is_error = true;
                     }
+                    this.status = Some(parts.status.as_u16());
+
+                    let uri = std::mem::take(&mut this.uri);
+                    this.meta = Some(ResponseMeta::new(uri, &parts));
+
+                    let max_size = this.max_response_size;
+                    this.state = State::Body(Box::pin(async move {
+                        receive_body(&parts.headers, body, max_size).await
+                    }));
+                }
+                State::Body(fut) => {
+                    let buf = ready!(fut.as_mut().poll(cx))?;
+                    return Poll::Ready(this.decode(buf));
                 }
+                State::Shared(fut) => {
+                    let fetched = ready!(Pin::new(fut).poll(cx)).map_err(Error::shared)?;
+
+                    this.is_error = !(200..300).contains(&fetched.status);
+                    this.status = Some(fetched.status);
+                    this.meta = Some(fetched.meta.clone());
+
+                    return Poll::Ready(this.decode(fetched.body.clone()));
+                }
+                State::Result(res) => return Poll::Ready(res.take().unwrap()),
             }
-            State::Result(res) => Poll::Ready(res.take().unwrap()),
         }
     }
 }
 
 impl<T> Unpin for ResponseFuture<T> where T: DeserializeOwned {}
 
+/// Reads and decompresses the response `body`, failing as soon as it exceeds `max_size` bytes.
+async fn receive_body(
+    headers: &HeaderMap,
+    body: Incoming,
+    max_size: Option<usize>,
+) -> Result<Bytes> {
+    let buf = read_body(headers, body, max_size).await?;
+    let buf = decompress::decompress(headers, buf, max_size).map_err(Error::from)?;
+
+    match max_size {
+        Some(max_size) if buf.len() > max_size => {
+            Err(Error::from(ErrorKind::ResponseTooLarge(max_size)))
+        }
+        _ => Ok(buf),
+    }
+}
+
+/// Receives the response of a request coalesced with identical requests.
+///
+/// Unlike [`ResponseFuture`] the body is not deserialized, since every coalesced request
+/// deserializes it separately. The request is considered successful if the body was received and
+/// the response has a success status.
+async fn fetch(
+    fut: SendFuture,
+    layers: Arc<Layers>,
+    mut instrument: Option<Instrument>,
+    uri: String,
+    endpoint: String,
+    max_size: Option<usize>,
+) -> Result<Fetched> {
+    #[cfg(feature = "tracing")]
+    let span = instrument
+        .as_ref()
+        .map(Instrument::span)
+        .unwrap_or_else(tracing::Span::none);
+
+    let mut status = None;
+    let res = async {
+        let sent = fut.await;
+        if let Some(instrument) = &mut instrument {
+            instrument.record_attempts(sent.retries, sent.wait);
+        }
+
+        let resp = sent.res.map_err(Error::from)?;
+
+        let (parts, body) = resp.into_parts();
+        layers.on_response(&parts);
+
+        status = Some(parts.status.as_u16());
+        if let Some(instrument) = &mut instrument {
+            instrument.record_status(parts.status.as_u16());
+        }
+
+        let meta = ResponseMeta::new(uri, &parts);
+        let body = receive_body(&parts.headers, body, max_size).await?;
+
+        Ok(Fetched {
+            status: parts.status.as_u16(),
+            meta,
+            body,
+        })
+    };
+
+    #[cfg(feature = "tracing")]
+    let res = tracing::Instrument::instrument(res, span);
+
+    let res: Result<Fetched> = res.await;
+
+    if let Some(instrument) = &mut instrument {
+        if let Ok(fetched) = &res {
+            instrument.record_body_size(fetched.body.len());
+        }
+
+        instrument.finish(matches!(&res, Ok(fetched) if (200..300).contains(&fetched.status)));
+    }
+
+    res.map_err(|err| err.with_context(status, &endpoint))
+}
+
+/// Collects the response `body`, failing as soon as it exceeds `max_size` bytes.
+async fn read_body(
+    headers: &HeaderMap,
+    mut body: Incoming,
+    max_size: Option<usize>,
+) -> Result<Bytes> {
+    let max_size = match max_size {
+        Some(max_size) => max_size,
+        None => {
+            let body = body.collect().await.map_err(Error::from)?;
+            return Ok(body.to_bytes());
+        }
+    };
+
+    let too_large = || Error::from(ErrorKind::ResponseTooLarge(max_size));
+
+    let content_length = headers
+        .get(CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<usize>().ok());
+    if matches!(content_length, Some(len) if len > max_size) {
+        return Err(too_large());
+    }
+
+    let mut buf = Vec::with_capacity(content_length.unwrap_or(0));
+    while let Some(frame) = body.frame().await {
+        let chunk = match frame.map_err(Error::from)?.into_data() {
+            Ok(chunk) => chunk,
+            // Trailers carry no data.
+            Err(_) => continue,
+        };
+
+        if buf.len() + chunk.len() > max_size {
+            return Err(too_large());
+        }
+
+        buf.extend_from_slice(&chunk);
+    }
+
+    Ok(buf.into())
+}
+
 impl<T> ClientExecutor<T> for Client
 where
     T: DeserializeOwned,
@@ -381,27 +1805,183 @@ where
     type Result = ResponseFuture<T>;
 
     fn send(&self, builder: RequestBuilder) -> Self::Result {
-        let mut req = Request::builder().uri(format!("https://api.guildwars2.com{}", builder.uri));
-        req = req.header("X-Schema-Version", SCHEMA_VERSION);
+        let fut = self.request(builder);
+        self.aborts.register(&fut.abort);
+        fut
+    }
+}
+
+impl Client {
+    /// Creates the [`ResponseFuture`] for the request of `builder`.
+    fn request<T>(&self, builder: RequestBuilder) -> ResponseFuture<T>
+    where
+        T: DeserializeOwned,
+    {
+        let uri = builder.uri();
+
+        if builder.authentication.is_required() && self.access_token.is_none() {
+            return ResponseFuture::result(Err(Error::from(ErrorKind::NoAccessToken)), uri);
+        }
+
+        if let Some(scopes) = self.scopes.get() {
+            if let Some(permission) = scopes.missing(builder.permissions) {
+                let err = Error::from(ErrorKind::MissingPermission(permission));
+                return ResponseFuture::result(Err(err), uri);
+            }
+        }
+
+        let client = match &self.transport {
+            Transport::Http(client) => client,
+            Transport::Offline(dataset) => {
+                let res = dataset.response(&uri);
+                let mut fut = ResponseFuture::result(res, uri.clone());
+                fut.meta = Some(ResponseMeta::offline(uri));
+                return fut;
+            }
+        };
+
+        let mut req = Request::builder().uri(format!("{}{}", self.base_url, uri));
+        req = req.header("X-Schema-Version", self.schema_version.clone());
+
+        if builder.localized {
+            let language = builder.language.unwrap_or(self.language);
+            req = req.header(ACCEPT_LANGUAGE, language.to_string());
+        }
+
+        if let Some(accept_encoding) = decompress::accept_encoding() {
+            req = req.header(ACCEPT_ENCODING, accept_encoding);
+        }
 
         if !builder.authentication.is_none() {
-            let access_token = match &self.access_token {
-                Some(access_token) => access_token,
-                None => return ResponseFuture::result(Err(Error::from(ErrorKind::NoAccessToken))),
+            if let Some(access_token) = &self.access_token {
+                req = req.header(AUTHORIZATION, format!("Bearer {}", access_token));
+            }
+        }
+
+        let (mut parts, ()) = req.body(()).unwrap().into_parts();
+        for (name, value) in self.headers.iter() {
+            parts.headers.entry(name).or_insert_with(|| value.clone());
+        }
+
+        self.layers.on_request(&mut parts);
+
+        let full_uri = parts.uri.to_string();
+
+        let coalesce = self.in_flight.as_ref().map(|in_flight| {
+            let key = Key {
+                uri: full_uri.clone(),
+                language: parts.headers.get(ACCEPT_LANGUAGE).cloned(),
+                authorization: parts.headers.get(AUTHORIZATION).cloned(),
             };
 
-            req = req.header(AUTHORIZATION, format!("Bearer {}", access_token));
+            (in_flight, key)
+        });
+
+        if let Some((in_flight, key)) = &coalesce {
+            if let Some(fut) = in_flight.get(key) {
+                return ResponseFuture::shared(fut, uri);
+            }
+        }
+
+        let instrument = if cfg!(feature = "tracing") || !self.callbacks.is_empty() {
+            Some(Instrument::new(
+                &parts.method,
+                &uri,
+                &full_uri,
+                &parts.headers,
+                self.callbacks.clone(),
+            ))
+        } else {
+            None
+        };
+
+        let slot = self.pacer.as_ref().map(Pacer::schedule);
+        let fut = retry::send(
+            (**client).clone(),
+            parts,
+            self.pacer.clone(),
+            slot,
+            self.retries,
+        );
+
+        if let Some((in_flight, key)) = coalesce {
+            let fut = fetch(
+                fut,
+                self.layers.clone(),
+                instrument,
+                full_uri,
+                uri.clone(),
+                self.max_response_size,
+            );
+            return ResponseFuture::shared(in_flight.get_or_insert(key, fut), uri);
         }
-        let req = req.body(Body::empty()).unwrap();
 
-        let fut = self.client.request(req);
-        ResponseFuture::new(fut)
+        ResponseFuture::new(
+            fut,
+            self.layers.clone(),
+            instrument,
+            full_uri,
+            uri,
+            self.max_response_size,
+        )
+    }
+}
+
+impl Executor for Client {
+    type Output<T> = BoxFuture<T>;
+
+    fn execute<F, Fut, T>(&self, f: F) -> Self::Output<T>
+    where
+        F: FnOnce(Client) -> Fut,
+        Fut: Future<Output = Result<T>> + Send + 'static,
+        T: Send + 'static,
+    {
+        Box::pin(f(self.clone()))
     }
 }
 
 #[doc(hidden)]
 impl private::Sealed for Client {}
 
+#[cfg(any(
+    feature = "achievements",
+    feature = "build",
+    feature = "colors",
+    feature = "currencies",
+    feature = "dailycrafting",
+    feature = "dungeons",
+    feature = "emblem",
+    feature = "emotes",
+    feature = "files",
+    feature = "finishers",
+    feature = "gliders",
+    feature = "guild",
+    feature = "home",
+    feature = "homestead",
+    feature = "items",
+    feature = "jadebots",
+    feature = "legendaryarmory",
+    feature = "mapchests",
+    feature = "maps",
+    feature = "materials",
+    feature = "minis",
+    feature = "mounts",
+    feature = "novelties",
+    feature = "outfits",
+    feature = "professions",
+    feature = "pvp",
+    feature = "quaggans",
+    feature = "quests",
+    feature = "raids",
+    feature = "recipes",
+    feature = "skiffs",
+    feature = "skins",
+    feature = "stories",
+    feature = "titles",
+    feature = "worldbosses",
+    feature = "worlds",
+    feature = "wvw"
+))]
 macro_rules! endpoint {
     // Basic endpoint (single path, no ids)
     ($target:ty, $path:expr ) => {
@@ -415,31 +1995,100 @@ macro_rules! endpoint {
             }
         }
     };
-    ($target:ty, $path:expr, $id:ty $(,$get_all:tt)?) => {
+    // Endpoint with ids returning localized items, supporting `ids=all`
+    ($target:ty, $path:expr, $id:ty, localized, get_all) => {
+        crate::endpoint!(@ids true, $target, $path, $id, get_all);
+        crate::endpoint!(@localized $target, $path, $id, get_all);
+    };
+    // Endpoint with ids returning localized items
+    ($target:ty, $path:expr, $id:ty, localized) => {
+        crate::endpoint!(@ids true, $target, $path, $id, paged);
+        crate::endpoint!(@localized $target, $path, $id, paged);
+    };
+    // Endpoint with ids, supporting `ids=all`
+    ($target:ty, $path:expr, $id:ty, get_all) => {
+        crate::endpoint!(@ids false, $target, $path, $id, get_all);
+    };
+    // Endpoint with ids
+    ($target:ty, $path:expr, $id:ty) => {
+        crate::endpoint!(@ids false, $target, $path, $id, paged);
+    };
+    // Requests all items at once using `ids=all`.
+    (@get_all get_all, $localized:literal, $path:expr) => {
+        /// Returns all items.
+        pub fn get_all<C>(client: &C) -> C::Result
+        where
+            C: crate::ClientExecutor<Vec<Self>>,
+        {
+            client.send(
+                crate::RequestBuilder::new($path)
+                    .query("ids", "all")
+                    .localized($localized),
+            )
+        }
+    };
+    // The endpoint rejects `ids=all`, so all items are requested one page at a time.
+    (@get_all paged, $localized:literal, $path:expr) => {
+        /// Returns all items.
+        ///
+        /// The endpoint does not support requesting all items at once, so the items are
+        /// requested one page at a time. The pages are requested concurrently once the number of
+        /// pages is known. Use [`pages`] to process the items while they are received.
+        ///
+        /// [`pages`]: Self::pages
+        pub fn get_all<C>(client: &C) -> C::Output<Vec<Self>>
+        where
+            C: crate::Executor,
+        {
+            client.execute(|client| async move { crate::bulk::get_paged(&client, $path, $localized).await })
+        }
+    };
+    (@ids $localized:literal, $target:ty, $path:expr, $id:ty, $get_all:tt) => {
+        impl crate::private::Sealed for $target {}
+
+        impl crate::lookup::Lookup for $target {
+            type Id = $id;
+
+            const PATH: &'static str = $path;
+
+            #[inline]
+            fn id(&self) -> &$id {
+                &self.id
+            }
+        }
+
         impl $target {
+            #[allow(dead_code)]
+            pub(crate) const URI: &'static str = $path;
+
             /// Returns the item with the given `id`.
             pub fn get<C>(client: &C, id: $id) -> C::Result
             where
                 C: crate::ClientExecutor<Self>,
             {
-                let uri = format!("{}?id={}", $path, id);
-                client.send(crate::RequestBuilder::new(uri))
+                client.send(
+                    crate::RequestBuilder::new($path)
+                        .query("id", id)
+                        .localized($localized),
+                )
             }
 
-            $(
-
-            /// Returns all items.
-            pub fn get_all<C>(client: &C) -> C::Result
+            /// Returns the items with the given `ids`.
+            ///
+            /// Ids not found are omitted from the returned list. Note that the API accepts at
+            /// most 200 `ids` per request.
+            pub fn get_many<C>(client: &C, ids: &[$id]) -> C::Result
             where
                 C: crate::ClientExecutor<Vec<Self>>,
             {
-                stringify!($get_all);
-
-                let uri = format!("{}?ids=all", $path);
-                client.send(crate::RequestBuilder::new(uri))
+                client.send(
+                    crate::RequestBuilder::new($path)
+                        .query_list("ids", ids)
+                        .localized($localized),
+                )
             }
 
-            )?
+            crate::endpoint!(@get_all $get_all, $localized, $path);
 
             /// Returns a list of all item ids.
             ///
@@ -478,8 +2127,290 @@ macro_rules! endpoint {
             {
                 client.send(crate::RequestBuilder::new($path))
             }
+
+            /// Returns a [`Paginator`] requesting all items one page at a time.
+            ///
+            /// The [`blocking`] client returns a blocking [`Pages`] iterator instead.
+            ///
+            /// [`Paginator`]: crate::pagination::Paginator
+            /// [`blocking`]: crate::blocking
+            /// [`Pages`]: crate::blocking::Pages
+            pub fn pages<C>(client: &C) -> C::Pages
+            where
+                C: crate::pagination::PageExecutor<Self>,
+            {
+                client.pages($path)
+            }
+
+            /// Returns the items with the given `ids`, splitting them into multiple requests
+            /// of at most 200 ids each.
+            ///
+            /// Ids not found are omitted from the returned list.
+            pub fn get_many_chunked<C>(client: &C, ids: &[$id]) -> C::Output<Vec<Self>>
+            where
+                C: crate::Executor,
+            {
+                let ids = ids.to_vec();
+
+                client.execute(|client| async move {
+                    crate::bulk::get_chunked(&client, $path, &ids).await
+                })
+            }
+
+            /// Returns a stream of all item ids, requested one page at a time.
+            ///
+            /// The API does not support paging through ids alone, so the items are requested
+            /// using [`pages`] and only their ids are kept. This transfers more data than [`ids`]
+            /// but keeps the peak memory usage low and yields the first ids earlier.
+            ///
+            /// [`pages`]: Self::pages
+            /// [`ids`]: Self::ids
+            pub fn ids_stream(
+                client: &crate::Client,
+            ) -> impl futures_util::Stream<Item = crate::Result<$id>> {
+                futures_util::StreamExt::map(Self::pages(client).items(), |item| {
+                    item.map(|item| item.id)
+                })
+            }
+        }
+    };
+    // Requests all items at once using `ids=all` in the given language.
+    (@get_all_localized get_all, $path:expr) => {
+        /// Returns all items in the given `language`, overriding the language of the client.
+        pub fn get_all_localized<C>(client: &C, language: crate::Language) -> C::Result
+        where
+            C: crate::ClientExecutor<Vec<Self>>,
+        {
+            client.send(
+                crate::RequestBuilder::new($path)
+                    .query("ids", "all")
+                    .language(language),
+            )
+        }
+    };
+    // The endpoint rejects `ids=all`, so all items are requested one page at a time.
+    (@get_all_localized paged, $path:expr) => {
+        /// Returns all items in the given `language`, overriding the language of the client.
+        ///
+        /// The items are requested one page at a time like [`get_all`].
+        ///
+        /// [`get_all`]: Self::get_all
+        pub fn get_all_localized<C>(client: &C, language: crate::Language) -> C::Output<Vec<Self>>
+        where
+            C: crate::Executor,
+        {
+            client.execute(move |client| async move {
+                crate::bulk::get_pages(&client, || {
+                    crate::RequestBuilder::new($path).language(language)
+                })
+                .await
+            })
+        }
+    };
+    (@localized $target:ty, $path:expr, $id:ty, $get_all:tt) => {
+        impl $target {
+            /// Returns the item with the given `id` in the given `language`, overriding the
+            /// language of the client.
+            pub fn get_localized<C>(client: &C, id: $id, language: crate::Language) -> C::Result
+            where
+                C: crate::ClientExecutor<Self>,
+            {
+                client.send(crate::RequestBuilder::new($path).query("id", id).language(language))
+            }
+
+            /// Returns the items with the given `ids` in the given `language`, overriding the
+            /// language of the client.
+            ///
+            /// Ids not found are omitted from the returned list. Note that the API accepts at
+            /// most 200 `ids` per request.
+            pub fn get_many_localized<C>(
+                client: &C,
+                ids: &[$id],
+                language: crate::Language,
+            ) -> C::Result
+            where
+                C: crate::ClientExecutor<Vec<Self>>,
+            {
+                client.send(
+                    crate::RequestBuilder::new($path)
+                        .query_list("ids", ids)
+                        .language(language),
+                )
+            }
+
+            crate::endpoint!(@get_all_localized $get_all, $path);
         }
     };
 }
 
+#[cfg(any(
+    feature = "achievements",
+    feature = "build",
+    feature = "colors",
+    feature = "currencies",
+    feature = "dailycrafting",
+    feature = "dungeons",
+    feature = "emblem",
+    feature = "emotes",
+    feature = "files",
+    feature = "finishers",
+    feature = "gliders",
+    feature = "guild",
+    feature = "home",
+    feature = "homestead",
+    feature = "items",
+    feature = "jadebots",
+    feature = "legendaryarmory",
+    feature = "mapchests",
+    feature = "maps",
+    feature = "materials",
+    feature = "minis",
+    feature = "mounts",
+    feature = "novelties",
+    feature = "outfits",
+    feature = "professions",
+    feature = "pvp",
+    feature = "quaggans",
+    feature = "quests",
+    feature = "raids",
+    feature = "recipes",
+    feature = "skiffs",
+    feature = "skins",
+    feature = "stories",
+    feature = "titles",
+    feature = "worldbosses",
+    feature = "worlds",
+    feature = "wvw"
+))]
 pub(crate) use endpoint;
+
+/// Implements common collection methods and traits for a type wrapping a `Vec`.
+#[cfg(any(
+    feature = "account",
+    feature = "characters",
+    feature = "commerce",
+    feature = "guild",
+    feature = "pvp"
+))]
+macro_rules! collection {
+    // Tuple struct wrapping a `Vec`, e.g. `struct AccountDyes(pub Vec<u64>)`.
+    ($target:ident($item:ty)) => {
+        crate::collection!(@impl $target, $item, 0, |items| Self(items));
+    };
+    // Struct with a single named `Vec` field.
+    ($target:ident { $field:ident: $item:ty }) => {
+        crate::collection!(@impl $target, $item, $field, |items| Self { $field: items });
+    };
+    (@impl $target:ident, $item:ty, $field:tt, |$items:ident| $new:expr) => {
+        impl $target {
+            /// Returns the number of elements.
+            #[inline]
+            pub fn len(&self) -> usize {
+                self.$field.len()
+            }
+
+            /// Returns `true` if there are no elements.
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.$field.is_empty()
+            }
+
+            /// Returns an iterator over all elements.
+            #[inline]
+            pub fn iter(&self) -> std::slice::Iter<'_, $item> {
+                self.$field.iter()
+            }
+        }
+
+        impl IntoIterator for $target {
+            type Item = $item;
+            type IntoIter = std::vec::IntoIter<$item>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.$field.into_iter()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a $target {
+            type Item = &'a $item;
+            type IntoIter = std::slice::Iter<'a, $item>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.$field.iter()
+            }
+        }
+
+        impl FromIterator<$item> for $target {
+            fn from_iter<I>(iter: I) -> Self
+            where
+                I: IntoIterator<Item = $item>,
+            {
+                let $items = iter.into_iter().collect();
+                $new
+            }
+        }
+
+        impl<I> std::ops::Index<I> for $target
+        where
+            I: std::slice::SliceIndex<[$item]>,
+        {
+            type Output = I::Output;
+
+            #[inline]
+            fn index(&self, index: I) -> &Self::Output {
+                &self.$field[index]
+            }
+        }
+    };
+}
+
+#[cfg(any(
+    feature = "account",
+    feature = "characters",
+    feature = "commerce",
+    feature = "guild",
+    feature = "pvp"
+))]
+pub(crate) use collection;
+
+/// Declares the `PERMISSIONS` constant of an authenticated endpoint, which is passed to
+/// [`RequestBuilder::permissions`]. The documentation of the constant is generated from the
+/// permissions, so that both cannot diverge.
+#[cfg(any(
+    feature = "account",
+    feature = "characters",
+    feature = "commerce",
+    feature = "guild",
+    feature = "pvp"
+))]
+macro_rules! permissions {
+    ($($permission:ident),+) => {
+        crate::permissions!(
+            #[doc = "The permissions required to access this endpoint:"]
+            $($permission),+
+        );
+    };
+    (#[doc = $doc:expr] $($permission:ident),+) => {
+        #[doc = $doc]
+        #[doc = ""]
+        $(
+            #[doc = concat!(
+                "- [`", stringify!($permission), "`]",
+                "(crate::v2::tokeninfo::TokenPermission::", stringify!($permission), ")"
+            )]
+        )+
+        pub const PERMISSIONS: &'static [crate::v2::tokeninfo::TokenPermission] =
+            &[$(crate::v2::tokeninfo::TokenPermission::$permission),+];
+    };
+}
+
+#[cfg(any(
+    feature = "account",
+    feature = "characters",
+    feature = "commerce",
+    feature = "guild",
+    feature = "pvp"
+))]
+pub(crate) use permissions;
@@ -0,0 +1,197 @@
+//! Looking up items of any endpoint by id.
+//!
+//! All endpoint types with ids, e.g. [`Color`] or [`Item`], implement [`Lookup`]. This allows
+//! writing code generic over the endpoint using [`Client::lookup`], which splits the ids into
+//! chunks of at most 200 ids that are requested concurrently.
+//!
+//! A [`LookupCache`] keeps items which were already looked up and only requests the missing
+//! ones. This is useful for metadata that rarely changes, e.g. resolving the item ids of many
+//! inventories.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use gw2api_rs::v2::colors::Color;
+//! use gw2api_rs::Client;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new();
+//! let colors = client.lookup::<Color>(&[1, 2, 3]).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Color`]: crate::v2::colors::Color
+//! [`Item`]: crate::v2::items::Item
+
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+use serde::de::DeserializeOwned;
+
+use crate::{bulk, private, Client, Executor, Result};
+
+/// An endpoint type whose items can be requested by id.
+///
+/// This trait is sealed and cannot be implemented.
+pub trait Lookup: DeserializeOwned + Send + 'static + private::Sealed {
+    /// The type of the id of the items.
+    type Id: Clone + Display + Eq + Hash + Send + Sync + 'static;
+
+    /// The path of the endpoint.
+    #[doc(hidden)]
+    const PATH: &'static str;
+
+    /// Returns the id of this item.
+    fn id(&self) -> &Self::Id;
+}
+
+/// Requests the items with the given `ids` in chunks.
+pub(crate) async fn get<T>(client: &Client, ids: &[T::Id]) -> Result<Vec<T>>
+where
+    T: Lookup,
+{
+    bulk::get_chunked(client, T::PATH, ids).await
+}
+
+/// A cache of items looked up by id.
+///
+/// Cloning a `LookupCache` returns a handle to the same cache. Items are cached regardless of the
+/// language of the client, a `LookupCache` should only be used with clients using the same
+/// [`Language`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use gw2api_rs::{Client, Result};
+/// # use gw2api_rs::lookup::LookupCache;
+/// # use gw2api_rs::v2::items::Item;
+/// #
+/// # async fn run() -> Result<()> {
+/// let client = Client::new();
+/// let cache = LookupCache::<Item>::new();
+///
+/// let items = cache.lookup(&client, &[19721, 24295]).await?;
+/// // Only requests the item 12345.
+/// let items = cache.lookup(&client, &[19721, 12345]).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Language`]: crate::Language
+pub struct LookupCache<T>
+where
+    T: Lookup,
+{
+    items: Arc<Mutex<HashMap<T::Id, T>>>,
+}
+
+impl<T> LookupCache<T>
+where
+    T: Lookup + Clone,
+{
+    /// Creates a new, empty `LookupCache`.
+    pub fn new() -> Self {
+        Self {
+            items: Arc::default(),
+        }
+    }
+
+    /// Returns the number of cached items.
+    pub fn len(&self) -> usize {
+        self.items.lock().unwrap().len()
+    }
+
+    /// Returns `true` if no items are cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the cached item with the given `id`. Returns `None` if the item is not cached.
+    pub fn get(&self, id: &T::Id) -> Option<T> {
+        self.items.lock().unwrap().get(id).cloned()
+    }
+
+    /// Inserts an `item` into the cache, replacing a previous item with the same id.
+    pub fn insert(&self, item: T) {
+        self.items.lock().unwrap().insert(item.id().clone(), item);
+    }
+
+    /// Removes all cached items.
+    pub fn clear(&self) {
+        self.items.lock().unwrap().clear();
+    }
+
+    /// Returns the items with the given `ids`, only requesting items not contained in the cache.
+    ///
+    /// The items are returned in the order of `ids`. Ids not found are omitted from the returned
+    /// list.
+    pub fn lookup<C>(&self, client: &C, ids: &[T::Id]) -> C::Output<Vec<T>>
+    where
+        C: Executor,
+    {
+        let cache = self.clone();
+        let ids = ids.to_vec();
+
+        client.execute(|client| async move {
+            let mut missing: Vec<T::Id> = {
+                let items = cache.items.lock().unwrap();
+                ids.iter()
+                    .filter(|id| !items.contains_key(id))
+                    .cloned()
+                    .collect()
+            };
+
+            if !missing.is_empty() {
+                // Request each id only once.
+                let mut seen = HashMap::new();
+                missing.retain(|id| seen.insert(id.clone(), ()).is_none());
+
+                let fetched: Vec<T> = get(&client, &missing).await?;
+
+                let mut items = cache.items.lock().unwrap();
+                for item in fetched {
+                    items.insert(item.id().clone(), item);
+                }
+            }
+
+            let items = cache.items.lock().unwrap();
+            Ok(ids.iter().filter_map(|id| items.get(id).cloned()).collect())
+        })
+    }
+}
+
+impl<T> Clone for LookupCache<T>
+where
+    T: Lookup,
+{
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+        }
+    }
+}
+
+impl<T> Default for LookupCache<T>
+where
+    T: Lookup + Clone,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for LookupCache<T>
+where
+    T: Lookup,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LookupCache")
+            .field("len", &self.items.lock().unwrap().len())
+            .finish()
+    }
+}
@@ -0,0 +1,204 @@
+//! Combined trading post quotes.
+//!
+//! A [`Quote`] joins the [`Item`] with its current [`Prices`] and the best [`Listing`]s on
+//! both sides of the order book. All parts are fetched concurrently. Items which cannot be traded
+//! on the trading post are still returned, with `prices` and both listings set to `None`.
+//!
+//! Requires the `items` and `commerce` features to be enabled.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use gw2api_rs::market;
+//! use gw2api_rs::Client;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new();
+//! let quote = market::quote(&client, 19721).await?;
+//!
+//! if let Some(spread) = quote.spread() {
+//!     println!("{} has a spread of {}", quote.item.name, spread);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use futures_util::future;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::commerce::{Coins, Listing, Listings, Prices};
+use crate::v2::items::Item;
+use crate::{bulk, Executor};
+
+/// An [`Item`] together with its current state on the trading post.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Quote {
+    /// The quoted item.
+    pub item: Item,
+    /// The current prices of the item. `None` if the item is not traded on the trading post.
+    pub prices: Option<Prices>,
+    /// The buy order with the highest price. `None` if there are no buy orders.
+    pub best_buy: Option<Listing>,
+    /// The sell listing with the lowest price. `None` if there are no sell listings.
+    pub best_sell: Option<Listing>,
+}
+
+impl Quote {
+    /// Creates a new `Quote` from an `item`, its `prices` and its `listings`.
+    pub fn new(item: Item, prices: Option<Prices>, listings: Option<Listings>) -> Self {
+        let (best_buy, best_sell) = match listings {
+            Some(listings) => (
+                listings
+                    .buys
+                    .into_iter()
+                    .max_by_key(|listing| listing.unit_price),
+                listings
+                    .sells
+                    .into_iter()
+                    .min_by_key(|listing| listing.unit_price),
+            ),
+            None => (None, None),
+        };
+
+        Self {
+            item,
+            prices,
+            best_buy,
+            best_sell,
+        }
+    }
+
+    /// Returns the difference between the lowest sell listing and the highest buy order.
+    /// Returns `None` if either side of the order book is empty.
+    pub fn spread(&self) -> Option<Coins> {
+        let buy = self.best_buy.as_ref()?.unit_price;
+        let sell = self.best_sell.as_ref()?.unit_price;
+        Some(Coins(sell.0.saturating_sub(buy.0)))
+    }
+}
+
+/// Returns the [`Quote`] of the item with the given `item_id`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use gw2api_rs::{Client, Result};
+/// # use gw2api_rs::market;
+/// #
+/// # async fn run() -> Result<()> {
+/// let client = Client::new();
+/// let quote = market::quote(&client, 19721).await?;
+/// println!("{:?}", quote.best_sell);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Using the [`blocking`] client:
+///
+/// ```no_run
+/// # use gw2api_rs::Result;
+/// # use gw2api_rs::blocking::Client;
+/// # use gw2api_rs::market;
+/// #
+/// # fn run() -> Result<()> {
+/// let client = Client::new();
+/// let quote = market::quote(&client, 19721)?;
+/// println!("{:?}", quote.best_sell);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`blocking`]: crate::blocking
+pub fn quote<C>(client: &C, item_id: u64) -> C::Output<Quote>
+where
+    C: Executor,
+{
+    client.execute(|client| async move {
+        // Requested by ids because the API responds with 404 for items that cannot be traded,
+        // which the bulk requests treat as not found.
+        let (item, prices, listings) = future::try_join3(
+            Item::get(&client, item_id),
+            bulk::get_chunked::<Prices, _>(&client, Prices::URI, &[item_id]),
+            bulk::get_chunked::<Listings, _>(&client, Listings::URI, &[item_id]),
+        )
+        .await?;
+
+        Ok(Quote::new(
+            item,
+            prices.into_iter().next(),
+            listings.into_iter().next(),
+        ))
+    })
+}
+
+/// Returns the [`Quote`]s of all items with the given `item_ids`.
+///
+/// The ids are split into chunks of 200 which are requested concurrently. Items that do not
+/// exist are omitted from the returned list.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use gw2api_rs::{Client, Result};
+/// # use gw2api_rs::market;
+/// #
+/// # async fn run() -> Result<()> {
+/// let client = Client::new();
+/// let quotes = market::quote_many(&client, &[19721, 24295]).await?;
+/// println!("{:?}", quotes);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Using the [`blocking`] client:
+///
+/// ```no_run
+/// # use gw2api_rs::Result;
+/// # use gw2api_rs::blocking::Client;
+/// # use gw2api_rs::market;
+/// #
+/// # fn run() -> Result<()> {
+/// let client = Client::new();
+/// let quotes = market::quote_many(&client, &[19721, 24295])?;
+/// println!("{:?}", quotes);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`blocking`]: crate::blocking
+pub fn quote_many<C>(client: &C, item_ids: &[u64]) -> C::Output<Vec<Quote>>
+where
+    C: Executor,
+{
+    let item_ids = item_ids.to_vec();
+
+    client.execute(|client| async move {
+        let (items, prices, listings) = future::try_join3(
+            bulk::get_chunked::<Item, _>(&client, Item::URI, &item_ids),
+            bulk::get_chunked::<Prices, _>(&client, Prices::URI, &item_ids),
+            bulk::get_chunked::<Listings, _>(&client, Listings::URI, &item_ids),
+        )
+        .await?;
+
+        let mut prices: HashMap<u64, Prices> = prices
+            .into_iter()
+            .map(|prices| (prices.id, prices))
+            .collect();
+        let mut listings: HashMap<u64, Listings> = listings
+            .into_iter()
+            .map(|listings| (listings.id, listings))
+            .collect();
+
+        Ok(items
+            .into_iter()
+            .map(|item| {
+                let prices = prices.remove(&item.id);
+                let listings = listings.remove(&item.id);
+                Quote::new(item, prices, listings)
+            })
+            .collect())
+    })
+}
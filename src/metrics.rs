@@ -0,0 +1,338 @@
+//! Metrics and logs collected for requests.
+//!
+//! A callback registered using [`Builder::on_metrics`] receives a [`RequestMetrics`] for every
+//! request sent over the network once the request completed, either successfully or with an
+//! error.
+//!
+//! Besides the latency and status of the request, the metrics report how often the request was
+//! retried and how long it was delayed by the [`Pacing`] of the client.
+//!
+//! When the `tracing` feature is enabled every request is additionally instrumented with a
+//! `request` span containing the `uri`, `status`, `latency_ms`, `retries` and `wait_ms` of the
+//! request.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::sync::atomic::{AtomicU64, Ordering};
+//! use std::sync::Arc;
+//!
+//! use gw2api_rs::Client;
+//!
+//! let requests = Arc::new(AtomicU64::new(0));
+//!
+//! let counter = requests.clone();
+//! let client: Client = Client::builder()
+//!     .on_metrics(move |metrics| {
+//!         counter.fetch_add(1, Ordering::Relaxed);
+//!         println!("{} took {:?}", metrics.uri, metrics.latency);
+//!     })
+//!     .into();
+//! ```
+//!
+//! # Logging
+//!
+//! Callbacks registered using [`Builder::on_request`] and [`Builder::on_response`] receive a
+//! [`RequestInfo`] before each request is sent and a [`ResponseInfo`] once it completed. Unlike
+//! [`RequestMetrics`] these include the method and headers of the request, making them suitable
+//! for audit logs. Access tokens are always redacted: the value of the `Authorization` header and
+//! any `access_token` query parameter are replaced with [`REDACTED`].
+//!
+//! ```
+//! use gw2api_rs::Client;
+//!
+//! let client: Client = Client::builder()
+//!     .on_request(|request| println!("{} {}", request.method, request.uri))
+//!     .on_response(|response| {
+//!         println!("{} {:?} in {:?}", response.uri, response.status, response.latency)
+//!     })
+//!     .into();
+//! ```
+//!
+//! [`Builder::on_metrics`]: crate::Builder::on_metrics
+//! [`Pacing`]: crate::pacing::Pacing
+//! [`Builder::on_request`]: crate::Builder::on_request
+//! [`Builder::on_response`]: crate::Builder::on_response
+
+use std::borrow::Cow;
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use hyper::header::{HeaderValue, AUTHORIZATION};
+use hyper::{HeaderMap, Method};
+
+/// The value replacing access tokens in [`RequestInfo`] and [`ResponseInfo`].
+pub const REDACTED: &str = "[redacted]";
+
+/// Metrics about a completed request.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RequestMetrics {
+    /// The uri of the request, excluding the host.
+    pub uri: String,
+    /// The status code of the response. This is `None` if no response was received.
+    pub status: Option<u16>,
+    /// The time elapsed between sending the request and receiving the complete response,
+    /// excluding the [`wait`] time.
+    ///
+    /// [`wait`]: Self::wait
+    pub latency: Duration,
+    /// The number of times the request was retried, see [`Builder::retries`].
+    ///
+    /// [`Builder::retries`]: crate::Builder::retries
+    pub retries: u32,
+    /// The time the request was delayed by the [`Pacing`] of the client, summed over all
+    /// retries.
+    ///
+    /// [`Pacing`]: crate::pacing::Pacing
+    pub wait: Duration,
+    /// The size of the response body in bytes, after decompression.
+    pub body_size: usize,
+    /// Whether the request completed successfully.
+    pub success: bool,
+}
+
+/// Information about a request about to be sent, passed to [`Builder::on_request`].
+///
+/// [`Builder::on_request`]: crate::Builder::on_request
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RequestInfo {
+    /// The method of the request.
+    pub method: Method,
+    /// The full uri of the request, with access tokens redacted.
+    pub uri: String,
+    /// The headers of the request, with access tokens redacted.
+    pub headers: HeaderMap,
+}
+
+impl RequestInfo {
+    pub(crate) fn new(method: &Method, uri: &str, headers: &HeaderMap) -> Self {
+        let mut headers = headers.clone();
+        if let Some(value) = headers.get_mut(AUTHORIZATION) {
+            *value = HeaderValue::from_static(REDACTED);
+        }
+
+        Self {
+            method: method.clone(),
+            uri: redact_uri(uri).into_owned(),
+            headers,
+        }
+    }
+}
+
+/// Information about a completed request, passed to [`Builder::on_response`].
+///
+/// [`Builder::on_response`]: crate::Builder::on_response
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ResponseInfo {
+    /// The method of the request.
+    pub method: Method,
+    /// The full uri of the request, with access tokens redacted.
+    pub uri: String,
+    /// The status code of the response. This is `None` if no response was received.
+    pub status: Option<u16>,
+    /// The time elapsed between sending the request and receiving the complete response,
+    /// excluding the [`wait`] time.
+    ///
+    /// [`wait`]: Self::wait
+    pub latency: Duration,
+    /// The number of times the request was retried, see [`Builder::retries`].
+    ///
+    /// [`Builder::retries`]: crate::Builder::retries
+    pub retries: u32,
+    /// The time the request was delayed by the [`Pacing`] of the client, summed over all
+    /// retries.
+    ///
+    /// [`Pacing`]: crate::pacing::Pacing
+    pub wait: Duration,
+    /// Whether the request completed successfully.
+    pub success: bool,
+}
+
+/// Replaces the values of all `access_token` query parameters in `uri` with [`REDACTED`].
+fn redact_uri(uri: &str) -> Cow<'_, str> {
+    let (path, query) = match uri.split_once('?') {
+        Some((path, query)) if query.split('&').any(is_access_token) => (path, query),
+        _ => return Cow::Borrowed(uri),
+    };
+
+    let query: Vec<Cow<'_, str>> = query
+        .split('&')
+        .map(|pair| {
+            if is_access_token(pair) {
+                Cow::Owned(format!("access_token={}", REDACTED))
+            } else {
+                Cow::Borrowed(pair)
+            }
+        })
+        .collect();
+
+    Cow::Owned(format!("{}?{}", path, query.join("&")))
+}
+
+fn is_access_token(pair: &str) -> bool {
+    pair.split('=').next() == Some("access_token")
+}
+
+/// A callback receiving a `T`, e.g. [`RequestMetrics`].
+pub(crate) struct Callback<T>(Arc<dyn Fn(&T) + Send + Sync + 'static>);
+
+impl<T> Callback<T> {
+    pub(crate) fn new<F>(f: F) -> Self
+    where
+        F: Fn(&T) + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    pub(crate) fn call(&self, value: &T) {
+        (self.0)(value)
+    }
+}
+
+impl<T> Clone for Callback<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Debug for Callback<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Callback").finish_non_exhaustive()
+    }
+}
+
+/// The callbacks invoked for every request.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Callbacks {
+    pub(crate) on_metrics: Option<Callback<RequestMetrics>>,
+    pub(crate) on_request: Option<Callback<RequestInfo>>,
+    pub(crate) on_response: Option<Callback<ResponseInfo>>,
+}
+
+impl Callbacks {
+    /// Returns `true` if no callbacks are registered.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.on_metrics.is_none() && self.on_request.is_none() && self.on_response.is_none()
+    }
+}
+
+/// The state used to instrument a single request.
+pub(crate) struct Instrument {
+    method: Method,
+    uri: String,
+    /// The full uri of the request, with access tokens redacted.
+    full_uri: String,
+    start: Instant,
+    status: Option<u16>,
+    retries: u32,
+    wait: Duration,
+    body_size: usize,
+    callbacks: Callbacks,
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+impl Instrument {
+    /// Creates the instrumentation of a request, invoking the `on_request` callback. `uri` is the
+    /// path of the request and `full_uri` the uri including the base url.
+    pub(crate) fn new(
+        method: &Method,
+        uri: &str,
+        full_uri: &str,
+        headers: &HeaderMap,
+        callbacks: Callbacks,
+    ) -> Self {
+        if let Some(on_request) = &callbacks.on_request {
+            on_request.call(&RequestInfo::new(method, full_uri, headers));
+        }
+
+        Self {
+            method: method.clone(),
+            uri: uri.to_owned(),
+            full_uri: redact_uri(full_uri).into_owned(),
+            start: Instant::now(),
+            status: None,
+            retries: 0,
+            wait: Duration::ZERO,
+            body_size: 0,
+            callbacks,
+            #[cfg(feature = "tracing")]
+            span: tracing::debug_span!(
+                "request",
+                uri = uri,
+                status = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                retries = tracing::field::Empty,
+                wait_ms = tracing::field::Empty,
+            ),
+        }
+    }
+
+    /// Returns the span of the request.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn span(&self) -> tracing::Span {
+        self.span.clone()
+    }
+
+    pub(crate) fn record_status(&mut self, status: u16) {
+        self.status = Some(status);
+
+        #[cfg(feature = "tracing")]
+        self.span.record("status", status);
+    }
+
+    /// Records the number of `retries` of the request and the time it was delayed by pacing.
+    pub(crate) fn record_attempts(&mut self, retries: u32, wait: Duration) {
+        self.retries = retries;
+        self.wait = wait;
+
+        #[cfg(feature = "tracing")]
+        {
+            self.span.record("retries", retries);
+            self.span.record("wait_ms", wait.as_millis() as u64);
+        }
+    }
+
+    pub(crate) fn record_body_size(&mut self, body_size: usize) {
+        self.body_size = body_size;
+    }
+
+    /// Completes the instrumentation of the request.
+    pub(crate) fn finish(&mut self, success: bool) {
+        let latency = self.start.elapsed().saturating_sub(self.wait);
+
+        #[cfg(feature = "tracing")]
+        {
+            self.span.record("latency_ms", latency.as_millis() as u64);
+            tracing::debug!(parent: &self.span, success, "request completed");
+        }
+
+        if let Some(on_metrics) = &self.callbacks.on_metrics {
+            on_metrics.call(&RequestMetrics {
+                uri: self.uri.clone(),
+                status: self.status,
+                latency,
+                retries: self.retries,
+                wait: self.wait,
+                body_size: self.body_size,
+                success,
+            });
+        }
+
+        if let Some(on_response) = &self.callbacks.on_response {
+            on_response.call(&ResponseInfo {
+                method: self.method.clone(),
+                uri: self.full_uri.clone(),
+                status: self.status,
+                latency,
+                retries: self.retries,
+                wait: self.wait,
+                success,
+            });
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Hooks for inspecting and modifying requests and responses.
+//!
+//! A [`Middleware`] is registered on a [`Builder`] using [`Builder::layer`] and is invoked for
+//! every request made by the resulting [`Client`]. Multiple middlewares are invoked in the order
+//! they were registered for requests and in reverse order for responses.
+//!
+//! Middlewares are not invoked for requests served from an offline [`Dataset`].
+//!
+//! # Examples
+//!
+//! ```
+//! use gw2api_rs::middleware::{Middleware, RequestParts, ResponseParts};
+//! use gw2api_rs::Client;
+//!
+//! struct Logger;
+//!
+//! impl Middleware for Logger {
+//!     fn on_request(&self, request: &mut RequestParts) {
+//!         println!("--> {}", request.uri);
+//!     }
+//!
+//!     fn on_response(&self, response: &ResponseParts) {
+//!         println!("<-- {}", response.status);
+//!     }
+//! }
+//!
+//! let client: Client = Client::builder().layer(Logger).into();
+//! ```
+//!
+//! [`Builder`]: crate::Builder
+//! [`Builder::layer`]: crate::Builder::layer
+//! [`Client`]: crate::Client
+//! [`Dataset`]: crate::offline::Dataset
+
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+
+pub use hyper::http::request::Parts as RequestParts;
+pub use hyper::http::response::Parts as ResponseParts;
+
+/// A hook invoked for every request made by a [`Client`].
+///
+/// All methods have a default implementation that does nothing.
+///
+/// [`Client`]: crate::Client
+pub trait Middleware: Send + Sync + 'static {
+    /// Called before a request is sent. The request can be modified, e.g. to add custom headers.
+    #[allow(unused_variables)]
+    fn on_request(&self, request: &mut RequestParts) {}
+
+    /// Called once the head of a response has been received, before the body is read.
+    #[allow(unused_variables)]
+    fn on_response(&self, response: &ResponseParts) {}
+}
+
+impl<M> Middleware for Arc<M>
+where
+    M: Middleware,
+{
+    #[inline]
+    fn on_request(&self, request: &mut RequestParts) {
+        (**self).on_request(request);
+    }
+
+    #[inline]
+    fn on_response(&self, response: &ResponseParts) {
+        (**self).on_response(response);
+    }
+}
+
+/// The list of middlewares registered on a client.
+#[derive(Clone, Default)]
+pub(crate) struct Layers(Vec<Arc<dyn Middleware>>);
+
+impl Layers {
+    pub(crate) fn push<M>(&mut self, middleware: M)
+    where
+        M: Middleware,
+    {
+        self.0.push(Arc::new(middleware));
+    }
+
+    pub(crate) fn on_request(&self, request: &mut RequestParts) {
+        for layer in &self.0 {
+            layer.on_request(request);
+        }
+    }
+
+    pub(crate) fn on_response(&self, response: &ResponseParts) {
+        for layer in self.0.iter().rev() {
+            layer.on_response(response);
+        }
+    }
+}
+
+impl Debug for Layers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Layers")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
@@ -0,0 +1,136 @@
+//! Serving requests from a local dataset.
+//!
+//! A [`Dataset`] holds previously exported responses keyed by the request uri. A [`Client`]
+//! created with [`Client::offline`] (or [`Builder::offline`]) serves all requests from the
+//! dataset instead of the network. This is useful for demos, tests and development without
+//! network access.
+//!
+//! The uri of a request is the path including the query string, e.g. `/v2/build` or
+//! `/v2/colors?ids=all`. Requests with a uri that is not contained in the dataset return an
+//! [`Error`].
+//!
+//! Note that endpoints requiring authentication still require an access token to be set on the
+//! client, even if the token is never sent anywhere.
+//!
+//! # Examples
+//!
+//! ```
+//! use gw2api_rs::offline::Dataset;
+//! use gw2api_rs::v2::build::Build;
+//! use gw2api_rs::Client;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut dataset = Dataset::new();
+//! dataset.insert("/v2/build", &Build { id: 115267 })?;
+//!
+//! let client = Client::offline(dataset);
+//! let build = Build::get(&client).await?;
+//!
+//! assert_eq!(build.id, 115267);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Client`]: crate::Client
+//! [`Client::offline`]: crate::Client::offline
+//! [`Builder::offline`]: crate::Builder::offline
+//! [`Error`]: struct@crate::Error
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Error, ErrorKind, Result};
+
+/// A set of responses keyed by the request uri.
+///
+/// A `Dataset` can be exported using [`to_writer`] and loaded again using [`from_reader`]. The
+/// exported format is a json object mapping each uri to its response.
+///
+/// [`to_writer`]: Self::to_writer
+/// [`from_reader`]: Self::from_reader
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Dataset {
+    responses: HashMap<String, Value>,
+}
+
+impl Dataset {
+    /// Creates a new, empty `Dataset`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts the response `value` for the given `uri`. If the dataset already contained a
+    /// response for the `uri` it is replaced.
+    ///
+    /// Returns an [`Error`] if `value` cannot be serialized into json.
+    ///
+    /// [`Error`]: struct@crate::Error
+    pub fn insert<U, T>(&mut self, uri: U, value: &T) -> Result<()>
+    where
+        U: ToString,
+        T: Serialize,
+    {
+        let value = serde_json::to_value(value).map_err(Error::from)?;
+        self.responses.insert(uri.to_string(), value);
+        Ok(())
+    }
+
+    /// Removes the response for the given `uri`. Returns `true` if the dataset contained a
+    /// response for the `uri`.
+    #[inline]
+    pub fn remove(&mut self, uri: &str) -> bool {
+        self.responses.remove(uri).is_some()
+    }
+
+    /// Returns `true` if the dataset contains a response for the given `uri`.
+    #[inline]
+    pub fn contains(&self, uri: &str) -> bool {
+        self.responses.contains_key(uri)
+    }
+
+    /// Returns the number of responses in the dataset.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.responses.len()
+    }
+
+    /// Returns `true` if the dataset contains no responses.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.responses.is_empty()
+    }
+
+    /// Loads a previously exported `Dataset` from the given `reader`.
+    pub fn from_reader<R>(reader: R) -> Result<Self>
+    where
+        R: Read,
+    {
+        serde_json::from_reader(reader).map_err(Error::from)
+    }
+
+    /// Exports the `Dataset` into the given `writer`.
+    pub fn to_writer<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        serde_json::to_writer(writer, self).map_err(Error::from)
+    }
+
+    /// Returns the response for the given `uri` deserialized as `T`.
+    pub(crate) fn response<T>(&self, uri: &str) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        match self.responses.get(uri) {
+            Some(value) => T::deserialize(value).map_err(Error::from),
+            None => Err(Error::from(ErrorKind::NotInDataset(uri.to_owned()))),
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Pacing requests sent over the network.
+//!
+//! A [`Pacing`] set using [`Builder::pacing`] delays requests so that the client stays below a
+//! number of requests per minute. Requests are delayed instead of failing, which makes pacing
+//! useful for long-running crawls that would otherwise run into the rate limit of the API.
+//!
+//! [`Pacing::limit`] allows short bursts of requests as long as the average stays below the
+//! limit. [`Pacing::smooth`] spaces all requests evenly and never sends bursts.
+//!
+//! Pacing is shared by all clones of a [`Client`]. Requests served from an offline [`Dataset`]
+//! or coalesced with an identical request in flight are not delayed. Every retry of a request
+//! is delayed like a new request.
+//!
+//! A request reserves its time slot when it is created and is sent once the slot has passed, no
+//! matter how late the request is first polled. A request dropped before it is sent gives its
+//! slot back, unless a later request was created in the meantime.
+//!
+//! # Examples
+//!
+//! ```
+//! # use gw2api_rs::Client;
+//! # use gw2api_rs::pacing::Pacing;
+//! #
+//! let client: Client = Client::builder().pacing(Pacing::smooth(300)).into();
+//! ```
+//!
+//! [`Builder::pacing`]: crate::Builder::pacing
+//! [`Client`]: crate::Client
+//! [`Dataset`]: crate::offline::Dataset
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+const MINUTE: Duration = Duration::from_secs(60);
+
+/// The pacing of requests sent by a [`Client`].
+///
+/// See the [module documentation](self) for more details.
+///
+/// [`Client`]: crate::Client
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Pacing {
+    interval: Duration,
+    burst: u32,
+}
+
+impl Pacing {
+    /// Creates a `Pacing` sending at most `requests_per_minute` requests per minute on average.
+    /// Up to `requests_per_minute` requests are sent at once if no requests were sent before.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `requests_per_minute` is `0`.
+    pub fn limit(requests_per_minute: u32) -> Self {
+        Self::new(requests_per_minute, requests_per_minute)
+    }
+
+    /// Creates a `Pacing` spacing requests evenly, so that at most `requests_per_minute`
+    /// requests are sent per minute without sending bursts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `requests_per_minute` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use gw2api_rs::pacing::Pacing;
+    /// #
+    /// let pacing = Pacing::smooth(300);
+    /// assert_eq!(pacing.interval(), Duration::from_millis(200));
+    /// assert_eq!(pacing.burst(), 1);
+    /// ```
+    pub fn smooth(requests_per_minute: u32) -> Self {
+        Self::new(requests_per_minute, 1)
+    }
+
+    fn new(requests_per_minute: u32, burst: u32) -> Self {
+        assert!(
+            requests_per_minute != 0,
+            "requests_per_minute must not be 0"
+        );
+
+        Self {
+            interval: MINUTE / requests_per_minute,
+            burst,
+        }
+    }
+
+    /// Returns the average time between two requests.
+    #[inline]
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Returns the maximum number of requests sent at once.
+    #[inline]
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+}
+
+/// Schedules the requests of all clones of a client according to a [`Pacing`].
+#[derive(Clone, Debug)]
+pub(crate) struct Pacer {
+    pacing: Pacing,
+    /// The time at which the next request would be sent if no bursts were allowed.
+    next: Arc<Mutex<Option<Instant>>>,
+}
+
+impl Pacer {
+    pub(crate) fn new(pacing: Pacing) -> Self {
+        Self {
+            pacing,
+            next: Arc::default(),
+        }
+    }
+
+    /// Reserves a slot for a request and returns it. The request may be sent at [`Slot::at`].
+    pub(crate) fn schedule(&self) -> Slot {
+        let now = Instant::now();
+        let mut next = self.next.lock().unwrap();
+
+        let reserved = next.map_or(now, |next| next.max(now));
+        *next = Some(reserved + self.pacing.interval);
+
+        let tolerance = self.pacing.interval * (self.pacing.burst - 1);
+        let at = reserved
+            .checked_sub(tolerance)
+            .map_or(now, |at| at.max(now));
+
+        Slot {
+            pacer: self.clone(),
+            reserved,
+            at,
+            used: false,
+        }
+    }
+}
+
+/// A slot reserved for a request by [`Pacer::schedule`].
+///
+/// Dropping a slot without calling [`Slot::used`] releases it again, as long as no later slot was
+/// reserved in the meantime.
+#[derive(Debug)]
+pub(crate) struct Slot {
+    pacer: Pacer,
+    /// The time the slot was reserved at, ignoring bursts.
+    reserved: Instant,
+    at: Instant,
+    used: bool,
+}
+
+impl Slot {
+    /// Returns the time at which the request may be sent.
+    #[inline]
+    pub(crate) fn at(&self) -> Instant {
+        self.at
+    }
+
+    /// Marks the slot as used by a request sent over the network.
+    #[inline]
+    pub(crate) fn used(mut self) {
+        self.used = true;
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        if self.used {
+            return;
+        }
+
+        let mut next = self.pacer.next.lock().unwrap();
+        if *next == Some(self.reserved + self.pacer.pacing.interval) {
+            *next = Some(self.reserved);
+        }
+    }
+}
@@ -0,0 +1,255 @@
+//! Requesting large endpoints page by page.
+//!
+//! A [`Paginator`] requests the items of an endpoint one page at a time, starting with the
+//! first page. Compared to requesting all items at once, this keeps the peak memory usage low and
+//! allows processing to start as soon as the first page is received.
+//!
+//! A `Paginator` is created using the `pages` method of an endpoint, e.g. [`Item::pages`].
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use futures_util::TryStreamExt;
+//! use gw2api_rs::v2::items::Item;
+//! use gw2api_rs::Client;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new();
+//! let mut items = Item::pages(&client).items();
+//!
+//! while let Some(item) = items.try_next().await? {
+//!     println!("{}", item.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The [`blocking`] client returns an [`Iterator`] of pages instead.
+//!
+//! ```no_run
+//! use gw2api_rs::v2::items::Item;
+//! use gw2api_rs::blocking::Client;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new();
+//!
+//! for item in Item::pages(&client).items() {
+//!     println!("{}", item?.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Item::pages`]: crate::v2::items::Item::pages
+//! [`blocking`]: crate::blocking
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::Stream;
+use serde::de::DeserializeOwned;
+
+use crate::response::WithMeta;
+use crate::{private, Client, ClientExecutor, RequestBuilder, Result};
+
+/// A client used to request an endpoint one page at a time.
+///
+/// The async [`Client`] returns a [`Paginator`], while the [`blocking`] client returns an
+/// [`Iterator`] of pages.
+///
+/// This trait is sealed and cannot be implemented.
+///
+/// [`blocking`]: crate::blocking
+pub trait PageExecutor<T>: private::Sealed
+where
+    T: DeserializeOwned,
+{
+    /// The pages returned by this client.
+    type Pages;
+
+    /// Returns the pages of the endpoint at `path`.
+    #[doc(hidden)]
+    fn pages(&self, path: &'static str) -> Self::Pages;
+}
+
+impl<T> PageExecutor<T> for Client
+where
+    T: DeserializeOwned,
+{
+    type Pages = Paginator<T>;
+
+    #[inline]
+    fn pages(&self, path: &'static str) -> Self::Pages {
+        Paginator::new(self, path)
+    }
+}
+
+/// A [`Stream`] of pages of an endpoint.
+///
+/// See the [module level documentation] for more details.
+///
+/// [module level documentation]: self
+#[must_use = "streams do nothing unless polled"]
+pub struct Paginator<T>
+where
+    T: DeserializeOwned,
+{
+    client: Client,
+    path: &'static str,
+    page: u64,
+    page_size: u16,
+    /// The total number of pages, if known from a previous response.
+    page_total: Option<u64>,
+    fut: Option<WithMeta<Vec<T>>>,
+    done: bool,
+}
+
+impl<T> Paginator<T>
+where
+    T: DeserializeOwned,
+{
+    /// The maximum number of items per page accepted by the API.
+    pub const MAX_PAGE_SIZE: u16 = 200;
+
+    pub(crate) fn new(client: &Client, path: &'static str) -> Self {
+        Self {
+            client: client.clone(),
+            path,
+            page: 0,
+            page_size: Self::MAX_PAGE_SIZE,
+            page_total: None,
+            fut: None,
+            done: false,
+        }
+    }
+
+    /// Sets the number of items per page. Defaults to [`MAX_PAGE_SIZE`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `page_size` is `0` or greater than [`MAX_PAGE_SIZE`].
+    ///
+    /// [`MAX_PAGE_SIZE`]: Self::MAX_PAGE_SIZE
+    pub fn page_size(mut self, page_size: u16) -> Self {
+        assert!(
+            page_size != 0 && page_size <= Self::MAX_PAGE_SIZE,
+            "invalid page size: {}",
+            page_size
+        );
+
+        self.page_size = page_size;
+        self
+    }
+
+    /// Returns a [`Stream`] of the individual items of all pages.
+    #[inline]
+    pub fn items(self) -> Items<T> {
+        Items {
+            pages: self,
+            items: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl<T> Stream for Paginator<T>
+where
+    T: DeserializeOwned,
+{
+    type Item = Result<Vec<T>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        if this.done {
+            return Poll::Ready(None);
+        }
+
+        let fut = match &mut this.fut {
+            Some(fut) => fut,
+            None => {
+                if matches!(this.page_total, Some(total) if this.page >= total) {
+                    this.done = true;
+                    return Poll::Ready(None);
+                }
+
+                let builder = RequestBuilder::new(this.path)
+                    .query("page", this.page)
+                    .query("page_size", this.page_size)
+                    .localized(true);
+
+                let fut = ClientExecutor::<Vec<T>>::send(&this.client, builder).with_meta();
+                this.fut.insert(fut)
+            }
+        };
+
+        let res = match Pin::new(fut).poll(cx) {
+            Poll::Ready(res) => res,
+            Poll::Pending => return Poll::Pending,
+        };
+        this.fut = None;
+
+        match res {
+            Ok((items, meta)) => {
+                this.page += 1;
+                this.page_total = meta.page_total;
+
+                // Without the page count a short page can only be the last one.
+                if items.is_empty()
+                    || (this.page_total.is_none() && items.len() < usize::from(this.page_size))
+                {
+                    this.done = true;
+                }
+
+                if items.is_empty() {
+                    Poll::Ready(None)
+                } else {
+                    Poll::Ready(Some(Ok(items)))
+                }
+            }
+            Err(err) => {
+                this.done = true;
+                Poll::Ready(Some(Err(err)))
+            }
+        }
+    }
+}
+
+impl<T> Unpin for Paginator<T> where T: DeserializeOwned {}
+
+/// A [`Stream`] of the individual items of a [`Paginator`].
+///
+/// Created by [`Paginator::items`].
+#[must_use = "streams do nothing unless polled"]
+pub struct Items<T>
+where
+    T: DeserializeOwned,
+{
+    pages: Paginator<T>,
+    items: std::vec::IntoIter<T>,
+}
+
+impl<T> Stream for Items<T>
+where
+    T: DeserializeOwned,
+{
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(item) = self.items.next() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            match Pin::new(&mut self.pages).poll_next(cx) {
+                Poll::Ready(Some(Ok(items))) => self.items = items.into_iter(),
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl<T> Unpin for Items<T> where T: DeserializeOwned {}
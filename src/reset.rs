@@ -0,0 +1,94 @@
+//! Daily and weekly reset times.
+//!
+//! Progress returned by some endpoints, e.g. [`AccountWorldBosses`] or [`AccountRaids`], is reset
+//! at fixed times. Responses of these endpoints stay valid until the next reset and can be cached
+//! until then.
+//!
+//! - The daily reset happens every day at 00:00 UTC.
+//! - The weekly reset happens every Monday at 07:30 UTC.
+//!
+//! # Examples
+//!
+//! ```
+//! use gw2api_rs::reset;
+//!
+//! let daily = reset::next_daily_reset();
+//! let weekly = reset::next_weekly_reset();
+//!
+//! println!("next daily reset at {}, next weekly reset at {}", daily, weekly);
+//! ```
+//!
+//! [`AccountWorldBosses`]: crate::v2::account::AccountWorldBosses
+//! [`AccountRaids`]: crate::v2::account::AccountRaids
+
+use crate::datetime::{self, DateTime};
+
+/// The number of seconds in a day.
+const DAY_SECS: i64 = 24 * 3600;
+
+/// The time of day of the weekly reset: 07:30 UTC.
+const WEEKLY_RESET_SECS: i64 = 7 * 3600 + 30 * 60;
+
+/// Returns the time of the next daily reset.
+///
+/// See [`daily_reset_after`] for details.
+#[inline]
+pub fn next_daily_reset() -> DateTime {
+    daily_reset_after(datetime::now())
+}
+
+/// Returns the time of the next weekly reset.
+///
+/// See [`weekly_reset_after`] for details.
+#[inline]
+pub fn next_weekly_reset() -> DateTime {
+    weekly_reset_after(datetime::now())
+}
+
+/// Returns the time of the first daily reset strictly after `time`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "chrono")]
+/// # {
+/// use chrono::{TimeZone, Utc};
+/// use gw2api_rs::reset::daily_reset_after;
+///
+/// let time = Utc.with_ymd_and_hms(2024, 5, 17, 13, 37, 0).unwrap();
+/// assert_eq!(daily_reset_after(time), Utc.with_ymd_and_hms(2024, 5, 18, 0, 0, 0).unwrap());
+/// # }
+/// ```
+pub fn daily_reset_after(time: DateTime) -> DateTime {
+    datetime::add_secs(datetime::start_of_day(time), DAY_SECS)
+}
+
+/// Returns the time of the first weekly reset strictly after `time`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "chrono")]
+/// # {
+/// use chrono::{TimeZone, Utc};
+/// use gw2api_rs::reset::weekly_reset_after;
+///
+/// // Friday
+/// let time = Utc.with_ymd_and_hms(2024, 5, 17, 13, 37, 0).unwrap();
+/// assert_eq!(weekly_reset_after(time), Utc.with_ymd_and_hms(2024, 5, 20, 7, 30, 0).unwrap());
+/// # }
+/// ```
+pub fn weekly_reset_after(time: DateTime) -> DateTime {
+    let days_since_monday = datetime::days_since_monday(time);
+
+    let reset = datetime::add_secs(
+        datetime::start_of_day(time),
+        WEEKLY_RESET_SECS - days_since_monday * DAY_SECS,
+    );
+
+    if reset > time {
+        reset
+    } else {
+        datetime::add_secs(reset, 7 * DAY_SECS)
+    }
+}
@@ -0,0 +1,154 @@
+//! Metadata about responses.
+//!
+//! The [`ResponseFuture`] returned by all endpoints of the async [`Client`] can be turned into a
+//! future also returning the [`ResponseMeta`] of the response using
+//! [`ResponseFuture::with_meta`].
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use gw2api_rs::v2::items::Item;
+//! use gw2api_rs::Client;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new();
+//! let (ids, meta) = Item::ids(&client).with_meta().await?;
+//!
+//! println!("{} ids, {:?} requests remaining", ids.len(), meta.rate_limit_remaining);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Client`]: crate::Client
+
+use std::future::Future;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::task::{Context, Poll};
+
+use hyper::header::{CACHE_CONTROL, EXPIRES, LAST_MODIFIED};
+use hyper::HeaderMap;
+use serde::de::DeserializeOwned;
+
+use crate::datetime::{self, DateTime};
+use crate::middleware::ResponseParts;
+use crate::{ResponseFuture, Result};
+
+/// Metadata about a response.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ResponseMeta {
+    /// The status code of the response.
+    pub status: u16,
+    /// The full uri the request was sent to.
+    pub uri: String,
+    /// The total number of results available from the endpoint (`X-Result-Total`).
+    pub result_total: Option<u64>,
+    /// The number of results in the response (`X-Result-Count`).
+    pub result_count: Option<u64>,
+    /// The total number of pages available from the endpoint (`X-Page-Total`).
+    pub page_total: Option<u64>,
+    /// The number of results per page (`X-Page-Size`).
+    pub page_size: Option<u64>,
+    /// The maximum number of requests allowed by the rate limit (`X-Rate-Limit-Limit`).
+    pub rate_limit_limit: Option<u64>,
+    /// The number of requests remaining before the rate limit is reached
+    /// (`X-Rate-Limit-Remaining`).
+    pub rate_limit_remaining: Option<u64>,
+    /// The value of the `Cache-Control` header.
+    pub cache_control: Option<String>,
+    /// The time after which the response is considered stale (`Expires`).
+    pub expires: Option<DateTime>,
+    /// The time the data of the response was last modified (`Last-Modified`).
+    pub last_modified: Option<DateTime>,
+    /// All headers of the response.
+    pub headers: HeaderMap,
+}
+
+impl ResponseMeta {
+    pub(crate) fn new(uri: String, parts: &ResponseParts) -> Self {
+        let headers = &parts.headers;
+
+        Self {
+            status: parts.status.as_u16(),
+            uri,
+            result_total: parse(headers, "X-Result-Total"),
+            result_count: parse(headers, "X-Result-Count"),
+            page_total: parse(headers, "X-Page-Total"),
+            page_size: parse(headers, "X-Page-Size"),
+            rate_limit_limit: parse(headers, "X-Rate-Limit-Limit"),
+            rate_limit_remaining: parse(headers, "X-Rate-Limit-Remaining"),
+            cache_control: header(headers, CACHE_CONTROL.as_str()).map(str::to_owned),
+            expires: date(headers, EXPIRES.as_str()),
+            last_modified: date(headers, LAST_MODIFIED.as_str()),
+            headers: headers.clone(),
+        }
+    }
+
+    /// Creates the metadata for a response served from an offline [`Dataset`].
+    ///
+    /// [`Dataset`]: crate::offline::Dataset
+    pub(crate) fn offline(uri: String) -> Self {
+        Self {
+            status: 200,
+            uri,
+            result_total: None,
+            result_count: None,
+            page_total: None,
+            page_size: None,
+            rate_limit_limit: None,
+            rate_limit_remaining: None,
+            cache_control: None,
+            expires: None,
+            last_modified: None,
+            headers: HeaderMap::new(),
+        }
+    }
+}
+
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name)?.to_str().ok()
+}
+
+fn parse<T>(headers: &HeaderMap, name: &str) -> Option<T>
+where
+    T: FromStr,
+{
+    header(headers, name)?.trim().parse().ok()
+}
+
+fn date(headers: &HeaderMap, name: &str) -> Option<DateTime> {
+    datetime::parse_http_date(header(headers, name)?)
+}
+
+/// A future returning the result of a request together with the [`ResponseMeta`] of the
+/// response.
+///
+/// Created by [`ResponseFuture::with_meta`].
+#[must_use = "futures do nothing unless polled"]
+pub struct WithMeta<T>
+where
+    T: DeserializeOwned,
+{
+    pub(crate) inner: ResponseFuture<T>,
+}
+
+impl<T> Future for WithMeta<T>
+where
+    T: DeserializeOwned,
+{
+    type Output = Result<(T, ResponseMeta)>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match Pin::new(&mut self.inner).poll(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Err(err)) => Poll::Ready(Err(err)),
+            Poll::Ready(Ok(value)) => {
+                // Every successful response has recorded its metadata.
+                let meta = self.inner.meta.take().unwrap();
+                Poll::Ready(Ok((value, meta)))
+            }
+        }
+    }
+}
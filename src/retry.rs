@@ -0,0 +1,87 @@
+//! Sending requests over the network, retrying requests failing with a retryable error.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::time::{Duration, Instant};
+
+use http_body_util::Empty;
+use hyper::body::Incoming;
+use hyper::http::request::Parts;
+use hyper::rt::Timer;
+use hyper::{Request, Response, StatusCode};
+use hyper_util::rt::TokioTimer;
+
+use crate::pacing::{Pacer, Slot};
+use crate::{is_retryable_request, HttpClient};
+
+/// The delay before the first retry of a request. The delay doubles with every further retry.
+const RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// The future returned by [`send`].
+pub(crate) type SendFuture = Pin<Box<dyn Future<Output = Sent> + Send + 'static>>;
+
+/// The outcome of sending a request with [`send`].
+pub(crate) struct Sent {
+    pub(crate) res: Result<Response<Incoming>, hyper_util::client::legacy::Error>,
+    /// The number of times the request was sent again after the first attempt.
+    pub(crate) retries: u32,
+    /// The total time all attempts were delayed by the pacer.
+    pub(crate) wait: Duration,
+}
+
+/// Sends the request described by `parts`, retrying it up to `max_retries` times while it fails
+/// with a retryable error.
+///
+/// The first attempt is sent at the `slot`, which must be reserved from the `pacer` when the
+/// request is created. Every retry reserves a new slot from the `pacer`.
+pub(crate) fn send(
+    client: HttpClient,
+    parts: Parts,
+    pacer: Option<Pacer>,
+    slot: Option<Slot>,
+    max_retries: u32,
+) -> SendFuture {
+    Box::pin(async move {
+        let mut slot = slot;
+        let mut retries = 0;
+        let mut wait = Duration::ZERO;
+
+        loop {
+            if let Some(slot) = slot.take() {
+                let delay = slot.at().saturating_duration_since(Instant::now());
+                if !delay.is_zero() {
+                    TokioTimer::new().sleep_until(slot.at()).await;
+                    wait += delay;
+                }
+                slot.used();
+            }
+
+            let res = client
+                .request(Request::from_parts(parts.clone(), Empty::new()))
+                .await;
+
+            if retries == max_retries || !is_retryable(&res) {
+                return Sent { res, retries, wait };
+            }
+
+            TokioTimer::new()
+                .sleep(RETRY_DELAY * 2u32.saturating_pow(retries))
+                .await;
+
+            retries += 1;
+            slot = pacer.as_ref().map(Pacer::schedule);
+        }
+    })
+}
+
+/// Returns `true` if the request should be sent again. See [`Error::is_retryable`].
+///
+/// [`Error::is_retryable`]: crate::Error::is_retryable
+fn is_retryable(res: &Result<Response<Incoming>, hyper_util::client::legacy::Error>) -> bool {
+    match res {
+        Ok(resp) => {
+            resp.status() == StatusCode::TOO_MANY_REQUESTS || resp.status().is_server_error()
+        }
+        Err(err) => is_retryable_request(err),
+    }
+}
@@ -0,0 +1,551 @@
+//! Exporting the state of an account in a single call.
+//!
+//! An [`AccountSnapshot`] fetches multiple account endpoints concurrently. Before fetching, the
+//! permissions of the access token are requested from [`TokenInfo`] and all parts the token has
+//! no access to are skipped instead of failing. A part that fails to be fetched does not fail
+//! the whole snapshot, the error is recorded in [`AccountSnapshot::failures`] instead.
+//!
+//! Snapshots can be serialized to be stored and compared later. [`AccountSnapshot::diff`]
+//! returns the changes between two snapshots, e.g. to track the progress of an account per day.
+//!
+//! Requires the `account` feature to be enabled.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use gw2api_rs::snapshot::{AccountSnapshot, SnapshotPart};
+//! use gw2api_rs::Client;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let token = "";
+//! let client: Client = Client::builder().access_token(token).into();
+//! let snapshot = AccountSnapshot::fetch(&client).await?;
+//!
+//! if let Some(wallet) = &snapshot.wallet {
+//!     println!("{} currencies", wallet.len());
+//! }
+//!
+//! for part in &snapshot.skipped {
+//!     println!("the access token has no access to {:?}", part);
+//! }
+//!
+//! for failure in &snapshot.failures {
+//!     println!("failed to fetch {:?}: {}", failure.part, failure.error);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`TokenInfo`]: crate::v2::tokeninfo::TokenInfo
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::future::Future;
+
+use futures_util::future;
+use serde::{Deserialize, Serialize};
+
+use crate::datetime::{self, DateTime};
+use crate::v2::account::{
+    AccountBank, AccountDyes, AccountFinishers, AccountGliders, AccountInventory,
+    AccountMailCarriers, AccountMaterials, AccountMinis, AccountNovelties, AccountOutfits,
+    AccountRecipes, AccountSkins, AccountTitles, AccountWallet,
+};
+use crate::v2::tokeninfo::{TokenInfo, TokenPermission};
+use crate::{Client, Error, Executor, Result};
+
+/// A part of an [`AccountSnapshot`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SnapshotPart {
+    /// The [`AccountWallet`].
+    Wallet,
+    /// The [`AccountBank`].
+    Bank,
+    /// The [`AccountMaterials`].
+    Materials,
+    /// The shared [`AccountInventory`].
+    Inventory,
+    /// The [`AccountUnlocks`].
+    Unlocks,
+}
+
+impl SnapshotPart {
+    /// All parts of a snapshot.
+    pub const ALL: &'static [Self] = &[
+        Self::Wallet,
+        Self::Bank,
+        Self::Materials,
+        Self::Inventory,
+        Self::Unlocks,
+    ];
+
+    /// Returns the permissions required to fetch this part.
+    pub fn permissions(self) -> &'static [TokenPermission] {
+        match self {
+            Self::Wallet => AccountWallet::PERMISSIONS,
+            Self::Bank => AccountBank::PERMISSIONS,
+            Self::Materials => AccountMaterials::PERMISSIONS,
+            Self::Inventory => AccountInventory::PERMISSIONS,
+            Self::Unlocks => AccountSkins::PERMISSIONS,
+        }
+    }
+}
+
+/// The state of an account at a single point in time.
+///
+/// See the [module level documentation] for more details.
+///
+/// [module level documentation]: self
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccountSnapshot {
+    /// The time at which the snapshot was started.
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub taken_at: DateTime,
+    /// The wallet of the account.
+    pub wallet: Option<AccountWallet>,
+    /// The bank of the account.
+    pub bank: Option<AccountBank>,
+    /// The material storage of the account.
+    pub materials: Option<AccountMaterials>,
+    /// The shared inventory slots of the account.
+    pub inventory: Option<AccountInventory>,
+    /// The unlocks of the account.
+    pub unlocks: Option<AccountUnlocks>,
+    /// The requested parts that were skipped because the access token is missing a required
+    /// permission.
+    pub skipped: Vec<SnapshotPart>,
+    /// The requested parts that failed to be fetched.
+    ///
+    /// Failures are not serialized and always empty for a deserialized snapshot.
+    #[serde(skip)]
+    pub failures: Vec<SnapshotFailure>,
+}
+
+impl AccountSnapshot {
+    /// Fetches all [`SnapshotPart`]s of the currently authenticated account.
+    ///
+    /// See [`fetch_parts`] for details.
+    ///
+    /// [`fetch_parts`]: Self::fetch_parts
+    #[inline]
+    pub fn fetch<C>(client: &C) -> C::Output<Self>
+    where
+        C: Executor,
+    {
+        Self::fetch_parts(client, SnapshotPart::ALL)
+    }
+
+    /// Fetches the given `parts` of the currently authenticated account.
+    ///
+    /// All parts are requested concurrently. Parts the access token has no permission for are
+    /// recorded in [`skipped`], parts that fail to be fetched are recorded in [`failures`]. Parts
+    /// that are not contained in `parts` are `None`.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set
+    /// or the [`TokenInfo`] of the access token cannot be fetched.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::snapshot::{AccountSnapshot, SnapshotPart};
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let parts = [SnapshotPart::Bank, SnapshotPart::Materials];
+    /// let snapshot = AccountSnapshot::fetch_parts(&client, &parts).await?;
+    /// println!("{:?}", snapshot.bank);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::snapshot::{AccountSnapshot, SnapshotPart};
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let parts = [SnapshotPart::Bank, SnapshotPart::Materials];
+    /// let snapshot = AccountSnapshot::fetch_parts(&client, &parts)?;
+    /// println!("{:?}", snapshot.bank);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`skipped`]: Self::skipped
+    /// [`failures`]: Self::failures
+    /// [`TokenInfo`]: crate::v2::tokeninfo::TokenInfo
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn fetch_parts<C>(client: &C, parts: &[SnapshotPart]) -> C::Output<Self>
+    where
+        C: Executor,
+    {
+        let parts = parts.to_vec();
+
+        client.execute(|client| async move {
+            let taken_at = datetime::now();
+            let token: TokenInfo = TokenInfo::get(&client).await?;
+
+            let mut skipped = Vec::new();
+            let mut enabled = |part: SnapshotPart| {
+                if !parts.contains(&part) {
+                    return false;
+                }
+
+                let allowed = part
+                    .permissions()
+                    .iter()
+                    .all(|permission| token.permissions.contains(permission));
+                if !allowed {
+                    skipped.push(part);
+                }
+
+                allowed
+            };
+
+            let (wallet, bank, materials, inventory, unlocks) = future::join5(
+                fetch_part(enabled(SnapshotPart::Wallet), || {
+                    AccountWallet::get(&client)
+                }),
+                fetch_part(enabled(SnapshotPart::Bank), || AccountBank::get(&client)),
+                fetch_part(enabled(SnapshotPart::Materials), || {
+                    AccountMaterials::get(&client)
+                }),
+                fetch_part(enabled(SnapshotPart::Inventory), || {
+                    AccountInventory::get(&client)
+                }),
+                fetch_part(enabled(SnapshotPart::Unlocks), || {
+                    AccountUnlocks::get(&client)
+                }),
+            )
+            .await;
+
+            let mut failures = Vec::new();
+            Ok(Self {
+                taken_at,
+                wallet: record(SnapshotPart::Wallet, wallet, &mut failures),
+                bank: record(SnapshotPart::Bank, bank, &mut failures),
+                materials: record(SnapshotPart::Materials, materials, &mut failures),
+                inventory: record(SnapshotPart::Inventory, inventory, &mut failures),
+                unlocks: record(SnapshotPart::Unlocks, unlocks, &mut failures),
+                skipped,
+                failures,
+            })
+        })
+    }
+
+    /// Returns `true` if all requested parts were fetched successfully.
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.skipped.is_empty() && self.failures.is_empty()
+    }
+
+    /// Returns all changes between the `previous` and `current` snapshot of an account.
+    ///
+    /// Only parts contained in both snapshots are compared. Item counts are summed over the
+    /// bank, the material storage and the shared inventory slots.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::snapshot::AccountSnapshot;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// # let previous: AccountSnapshot = serde_json::from_str("").unwrap();
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let current = AccountSnapshot::fetch(&client).await?;
+    ///
+    /// let diff = AccountSnapshot::diff(&previous, &current);
+    /// for change in &diff.currencies {
+    ///     println!("currency {} changed by {}", change.id, change.delta());
+    /// }
+    /// println!("{} new skins", diff.unlocks.skins.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn diff(previous: &Self, current: &Self) -> SnapshotDiff {
+        let mut diff = SnapshotDiff::default();
+
+        if let (Some(previous), Some(current)) = (&previous.wallet, &current.wallet) {
+            diff.currencies = CountChange::diff(wallet_counts(previous), wallet_counts(current));
+        }
+
+        diff.items =
+            CountChange::diff(previous.item_counts(current), current.item_counts(previous));
+
+        if let (Some(previous), Some(current)) = (&previous.unlocks, &current.unlocks) {
+            diff.unlocks = UnlockChanges::diff(previous, current);
+        }
+
+        diff
+    }
+
+    /// Returns the number of items per id stored in all item parts contained in both `self` and
+    /// `other`.
+    fn item_counts(&self, other: &Self) -> BTreeMap<u64, u64> {
+        let mut counts = BTreeMap::new();
+        let mut add = |id, count| *counts.entry(id).or_insert(0) += count;
+
+        if let (Some(bank), Some(_)) = (&self.bank, &other.bank) {
+            for item in bank.0.iter().flatten() {
+                add(item.id, u64::from(item.count));
+            }
+        }
+
+        if let (Some(materials), Some(_)) = (&self.materials, &other.materials) {
+            for material in &materials.0 {
+                add(material.id, u64::from(material.count));
+            }
+        }
+
+        if let (Some(inventory), Some(_)) = (&self.inventory, &other.inventory) {
+            for item in inventory.0.iter().flatten() {
+                add(item.id, item.count);
+            }
+        }
+
+        counts
+    }
+}
+
+fn wallet_counts(wallet: &AccountWallet) -> BTreeMap<u64, u64> {
+    wallet
+        .0
+        .iter()
+        .map(|currency| (currency.id, currency.value))
+        .collect()
+}
+
+/// The changes between two [`AccountSnapshot`]s.
+///
+/// Returned by [`AccountSnapshot::diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotDiff {
+    /// The currencies whose amount changed, ordered by id.
+    pub currencies: Vec<CountChange>,
+    /// The items whose total count changed, ordered by id.
+    pub items: Vec<CountChange>,
+    /// The newly unlocked ids.
+    pub unlocks: UnlockChanges,
+}
+
+impl SnapshotDiff {
+    /// Returns `true` if there are no changes between the snapshots.
+    pub fn is_empty(&self) -> bool {
+        self.currencies.is_empty() && self.items.is_empty() && self.unlocks.is_empty()
+    }
+
+    /// Returns an iterator over all items whose count increased.
+    pub fn items_gained(&self) -> impl Iterator<Item = &CountChange> + '_ {
+        self.items.iter().filter(|change| change.delta() > 0)
+    }
+
+    /// Returns an iterator over all items whose count decreased.
+    pub fn items_lost(&self) -> impl Iterator<Item = &CountChange> + '_ {
+        self.items.iter().filter(|change| change.delta() < 0)
+    }
+}
+
+/// A change of the amount of a currency or the count of an item.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CountChange {
+    /// The id of the currency or item.
+    pub id: u64,
+    /// The amount in the previous snapshot.
+    pub previous: u64,
+    /// The amount in the current snapshot.
+    pub current: u64,
+}
+
+impl CountChange {
+    /// Returns the difference between the current and previous amount.
+    #[inline]
+    pub fn delta(&self) -> i64 {
+        self.current as i64 - self.previous as i64
+    }
+
+    /// Returns all changed amounts between `previous` and `current`. Ids missing from one of
+    /// the maps have an amount of `0`.
+    fn diff(previous: BTreeMap<u64, u64>, current: BTreeMap<u64, u64>) -> Vec<Self> {
+        let ids: BTreeSet<u64> = previous.keys().chain(current.keys()).copied().collect();
+
+        ids.into_iter()
+            .map(|id| Self {
+                id,
+                previous: previous.get(&id).copied().unwrap_or(0),
+                current: current.get(&id).copied().unwrap_or(0),
+            })
+            .filter(|change| change.previous != change.current)
+            .collect()
+    }
+}
+
+/// The ids unlocked between two [`AccountSnapshot`]s, ordered by id.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnlockChanges {
+    pub dyes: Vec<u64>,
+    pub finishers: Vec<u64>,
+    pub gliders: Vec<u64>,
+    pub mail_carriers: Vec<u64>,
+    pub minis: Vec<u64>,
+    pub novelties: Vec<u64>,
+    pub outfits: Vec<u64>,
+    pub recipes: Vec<u64>,
+    pub skins: Vec<u64>,
+    pub titles: Vec<u64>,
+}
+
+impl UnlockChanges {
+    /// Returns the total number of new unlocks.
+    pub fn len(&self) -> usize {
+        self.lists().iter().map(|ids| ids.len()).sum()
+    }
+
+    /// Returns `true` if nothing was unlocked.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lists(&self) -> [&Vec<u64>; 10] {
+        [
+            &self.dyes,
+            &self.finishers,
+            &self.gliders,
+            &self.mail_carriers,
+            &self.minis,
+            &self.novelties,
+            &self.outfits,
+            &self.recipes,
+            &self.skins,
+            &self.titles,
+        ]
+    }
+
+    fn diff(previous: &AccountUnlocks, current: &AccountUnlocks) -> Self {
+        fn new_ids<'a>(
+            previous: impl IntoIterator<Item = &'a u64>,
+            current: impl IntoIterator<Item = &'a u64>,
+        ) -> Vec<u64> {
+            let previous: BTreeSet<u64> = previous.into_iter().copied().collect();
+            let current: BTreeSet<u64> = current.into_iter().copied().collect();
+            current.difference(&previous).copied().collect()
+        }
+
+        Self {
+            dyes: new_ids(&previous.dyes.0, &current.dyes.0),
+            finishers: new_ids(
+                previous.finishers.0.iter().map(|finisher| &finisher.id),
+                current.finishers.0.iter().map(|finisher| &finisher.id),
+            ),
+            gliders: new_ids(&previous.gliders.0, &current.gliders.0),
+            mail_carriers: new_ids(&previous.mail_carriers.0, &current.mail_carriers.0),
+            minis: new_ids(&previous.minis.0, &current.minis.0),
+            novelties: new_ids(&previous.novelties.0, &current.novelties.0),
+            outfits: new_ids(&previous.outfits.0, &current.outfits.0),
+            recipes: new_ids(&previous.recipes.0, &current.recipes.0),
+            skins: new_ids(&previous.skins.0, &current.skins.0),
+            titles: new_ids(&previous.titles.0, &current.titles.0),
+        }
+    }
+}
+
+/// A part of an [`AccountSnapshot`] that failed to be fetched.
+#[derive(Debug)]
+pub struct SnapshotFailure {
+    /// The part that failed.
+    pub part: SnapshotPart,
+    /// The error returned when fetching the part.
+    pub error: Error,
+}
+
+/// The ids of all unlocks of an account.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AccountUnlocks {
+    pub dyes: AccountDyes,
+    pub finishers: AccountFinishers,
+    pub gliders: AccountGliders,
+    pub mail_carriers: AccountMailCarriers,
+    pub minis: AccountMinis,
+    pub novelties: AccountNovelties,
+    pub outfits: AccountOutfits,
+    pub recipes: AccountRecipes,
+    pub skins: AccountSkins,
+    pub titles: AccountTitles,
+}
+
+impl AccountUnlocks {
+    /// Fetches all unlocks of the currently authenticated account.
+    async fn get(client: &Client) -> Result<Self> {
+        let (
+            (dyes, finishers, gliders, mail_carriers, minis),
+            (novelties, outfits, recipes, skins, titles),
+        ) = future::try_join(
+            future::try_join5(
+                AccountDyes::get(client),
+                AccountFinishers::get(client),
+                AccountGliders::get(client),
+                AccountMailCarriers::get(client),
+                AccountMinis::get(client),
+            ),
+            future::try_join5(
+                AccountNovelties::get(client),
+                AccountOutfits::get(client),
+                AccountRecipes::get(client),
+                AccountSkins::get(client),
+                AccountTitles::get(client),
+            ),
+        )
+        .await?;
+
+        Ok(Self {
+            dyes,
+            finishers,
+            gliders,
+            mail_carriers,
+            minis,
+            novelties,
+            outfits,
+            recipes,
+            skins,
+            titles,
+        })
+    }
+}
+
+/// Runs the request returned by `f` if `enabled` is `true`.
+async fn fetch_part<T, F, Fut>(enabled: bool, f: F) -> Option<Result<T>>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    if enabled {
+        Some(f().await)
+    } else {
+        None
+    }
+}
+
+/// Returns the value of a fetched part, recording the error in `failures` if it failed.
+fn record<T>(
+    part: SnapshotPart,
+    res: Option<Result<T>>,
+    failures: &mut Vec<SnapshotFailure>,
+) -> Option<T> {
+    match res? {
+        Ok(value) => Some(value),
+        Err(error) => {
+            failures.push(SnapshotFailure { part, error });
+            None
+        }
+    }
+}
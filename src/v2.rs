@@ -1,21 +1,89 @@
 //! # /v2 endpoint
 //!
 //! Types for the [v2](https://api.guildwars2.com/v2) endpoint.
+//!
+//! Every module except [`common`] and [`tokeninfo`] is gated behind a feature of the same name.
+//! All modules are enabled by the default `full` feature.
 
+#[cfg(feature = "account")]
 pub mod account;
+#[cfg(feature = "achievements")]
 pub mod achievements;
+#[cfg(feature = "build")]
 pub mod build;
+#[cfg(feature = "characters")]
+pub mod characters;
+#[cfg(feature = "colors")]
 pub mod colors;
+#[cfg(feature = "commerce")]
 pub mod commerce;
+pub mod common;
+#[cfg(feature = "currencies")]
 pub mod currencies;
+#[cfg(feature = "dailycrafting")]
+pub mod dailycrafting;
+#[cfg(feature = "dungeons")]
 pub mod dungeons;
+#[cfg(feature = "emblem")]
+pub mod emblem;
+#[cfg(feature = "emotes")]
+pub mod emotes;
+#[cfg(feature = "files")]
 pub mod files;
+#[cfg(feature = "finishers")]
+pub mod finishers;
+#[cfg(feature = "gliders")]
+pub mod gliders;
+#[cfg(feature = "guild")]
 pub mod guild;
+#[cfg(feature = "home")]
+pub mod home;
+#[cfg(feature = "homestead")]
+pub mod homestead;
+#[cfg(feature = "items")]
+pub mod items;
+#[cfg(feature = "jadebots")]
+pub mod jadebots;
+#[cfg(feature = "legendaryarmory")]
+pub mod legendaryarmory;
+#[cfg(feature = "mapchests")]
+pub mod mapchests;
+#[cfg(feature = "maps")]
+pub mod maps;
+#[cfg(feature = "materials")]
+pub mod materials;
+#[cfg(feature = "minis")]
 pub mod minis;
+#[cfg(feature = "mounts")]
+pub mod mounts;
+#[cfg(feature = "novelties")]
 pub mod novelties;
+#[cfg(feature = "outfits")]
+pub mod outfits;
+#[cfg(feature = "professions")]
+pub mod professions;
+#[cfg(feature = "pvp")]
+pub mod pvp;
+#[cfg(feature = "quaggans")]
 pub mod quaggans;
+#[cfg(feature = "quests")]
+pub mod quests;
+#[cfg(feature = "raids")]
 pub mod raids;
+#[cfg(feature = "recipes")]
+pub mod recipes;
+#[cfg(feature = "skiffs")]
+pub mod skiffs;
+#[cfg(feature = "skins")]
+pub mod skins;
+#[cfg(feature = "stories")]
+pub mod stories;
+#[cfg(feature = "titles")]
 pub mod titles;
 pub mod tokeninfo;
+#[cfg(feature = "worldbosses")]
+pub mod worldbosses;
+#[cfg(feature = "worlds")]
 pub mod worlds;
+#[cfg(feature = "wvw")]
 pub mod wvw;
@@ -1,18 +1,90 @@
 //! Exposes details about player accounts. All endpoints in this module required authentication.
 
+#[cfg(all(feature = "items", feature = "skins"))]
+use std::collections::BTreeSet;
 use std::collections::HashMap;
+#[cfg(feature = "skins")]
+use std::collections::HashSet;
 use std::fmt::{self, Formatter};
+use std::hash::Hash;
 use std::num::NonZeroU8;
-
-use chrono::{DateTime, Utc};
-use serde::de::{Error, MapAccess, SeqAccess, Visitor};
+#[cfg(all(feature = "watch", feature = "achievements"))]
+use std::time::Duration;
+
+#[cfg(any(
+    feature = "dungeons",
+    feature = "materials",
+    feature = "pvp",
+    feature = "raids",
+    feature = "wvw",
+    all(feature = "items", any(feature = "skins", feature = "legendaryarmory"))
+))]
+use futures_util::future;
+use serde::de::{Error, SeqAccess, Visitor};
 use serde::ser::{SerializeMap, SerializeSeq};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::{Authentication, ClientExecutor, RequestBuilder};
+use crate::datetime::DateTime;
+#[cfg(feature = "colors")]
+use crate::v2::colors::Color;
+use crate::v2::common::Binding;
+#[cfg(feature = "skins")]
+use crate::v2::common::WeightClass;
+#[cfg(feature = "dungeons")]
+use crate::v2::dungeons::{self, Dungeon, DungeonCompletion};
+#[cfg(feature = "emotes")]
+use crate::v2::emotes::Emote;
+#[cfg(feature = "finishers")]
+use crate::v2::finishers::Finisher;
+#[cfg(feature = "gliders")]
+use crate::v2::gliders::Glider;
+#[cfg(feature = "home")]
+use crate::v2::home::{Cat, Node};
+#[cfg(feature = "homestead")]
+use crate::v2::homestead::{Decoration, Glyph};
+#[cfg(all(feature = "items", any(feature = "skins", feature = "legendaryarmory")))]
+use crate::v2::items::Item;
+#[cfg(all(feature = "items", feature = "legendaryarmory"))]
+use crate::v2::items::ItemKind;
+#[cfg(feature = "jadebots")]
+use crate::v2::jadebots::JadeBot;
+#[cfg(all(feature = "items", feature = "legendaryarmory"))]
+use crate::v2::legendaryarmory::LegendaryItem;
+#[cfg(feature = "materials")]
+use crate::v2::materials::MaterialCategory;
+#[cfg(feature = "minis")]
+use crate::v2::minis::Mini;
+use crate::v2::mounts::{Mount, MountType};
+#[cfg(feature = "novelties")]
+use crate::v2::novelties::Novelty;
+#[cfg(feature = "outfits")]
+use crate::v2::outfits::Outfit;
+#[cfg(feature = "pvp")]
+use crate::v2::pvp::{Hero, HeroSkin};
+#[cfg(feature = "raids")]
+use crate::v2::raids::{Raid, RaidCompletion};
+#[cfg(feature = "skiffs")]
+use crate::v2::skiffs::Skiff;
+#[cfg(feature = "skins")]
+use crate::v2::skins::{Skin, SkinKind};
+#[cfg(feature = "titles")]
+use crate::v2::titles::Title;
+#[cfg(feature = "wvw")]
+use crate::v2::wvw::{Match, TeamColor};
+#[cfg(all(feature = "watch", feature = "achievements"))]
+use crate::watch::AchievementWatcher;
+#[cfg(any(
+    all(feature = "items", feature = "skins"),
+    all(feature = "watch", feature = "achievements")
+))]
+use crate::Client;
+use crate::{
+    bulk, collection, permissions, reset, Authentication, ClientExecutor, Executor, RequestBuilder,
+};
 
 /// Basic information about an account.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Account {
     /// A globally unique GUID for the account.
     pub id: String,
@@ -30,7 +102,8 @@ pub struct Account {
     /// **Requires the `guilds` scope.** This is `None` if the scope is missing.
     pub guild_leader: Option<Vec<String>>,
     /// The account creation date.
-    pub created: DateTime<Utc>,
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub created: DateTime,
     /// A list of content the account has access to.
     pub access: AccountAccess,
     /// Whether the account has unlocked the commander tag.
@@ -52,7 +125,8 @@ pub struct Account {
     /// **Requires the `progression` scope.** This is `None` if the scope is missing.
     pub wvw_rank: Option<u16>,
     /// The date when the account information was last changed.
-    pub last_modified: DateTime<Utc>,
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub last_modified: DateTime,
     /// The amount of build storage slots unlocked by the account.
     ///
     /// **Requires the `builds` scope.** This is `None` if the scope is missing.
@@ -62,6 +136,8 @@ pub struct Account {
 impl Account {
     const URI: &'static str = "/v2/account";
 
+    permissions!(Account);
+
     /// Returns the information about the currently authenticated account.
     ///
     /// # Authentication
@@ -106,10 +182,117 @@ impl Account {
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns the WvW team of the currently authenticated account together with its current
+    /// match.
+    ///
+    /// Since the World Restructuring the WvW team of an account is independent of its
+    /// [`world`]. This fetches the [`Account`] and [`AccountWvw`] concurrently, then fetches the
+    /// [`Match`] of the team, if the account is assigned to one.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::account::Account;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let team = Account::wvw_team(&client).await?;
+    ///
+    /// match (team.team, team.color) {
+    ///     (Some(id), Some(color)) => println!("team {} plays {:?}", id, color),
+    ///     _ => println!("not assigned to a team"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::account::Account;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let team = Account::wvw_team(&client)?;
+    ///
+    /// match (team.team, team.color) {
+    ///     (Some(id), Some(color)) => println!("team {} plays {:?}", id, color),
+    ///     _ => println!("not assigned to a team"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`world`]: Self::world
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    #[cfg(feature = "wvw")]
+    pub fn wvw_team<C>(client: &C) -> C::Output<AccountWvwTeam>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let (account, wvw) =
+                future::try_join(Self::get(&client), AccountWvw::get(&client)).await?;
+
+            let wvw_match = match wvw.team {
+                Some(team) => Some(Match::get_by_world(&client, team).await?),
+                None => None,
+            };
+
+            let color = wvw
+                .team
+                .zip(wvw_match.as_ref())
+                .and_then(|(team, wvw_match)| wvw_match.team_of(team));
+
+            Ok(AccountWvwTeam {
+                world: account.world,
+                team: wvw.team,
+                rank: wvw.rank,
+                color,
+                wvw_match,
+            })
+        })
     }
 }
 
+/// The WvW team of an account together with its current match.
+///
+/// Returned by [`Account::wvw_team`].
+#[cfg(feature = "wvw")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct AccountWvwTeam {
+    /// The id of the home world of the account.
+    pub world: u64,
+    /// The id of the WvW team of the account. This is `None` if the account is not assigned to a
+    /// team.
+    pub team: Option<u64>,
+    /// The WvW rank of the account.
+    pub rank: Option<u64>,
+    /// The side of the team in its current match. This is `None` if the account is not assigned
+    /// to a team or the team was not found in the match.
+    pub color: Option<TeamColor>,
+    /// The current match of the team. This is `None` if the account is not assigned to a team.
+    pub wvw_match: Option<Match>,
+}
+
 /// A list of content an [`Account`] has access to.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct AccountAccess(u8);
@@ -229,9 +412,9 @@ impl<'de> Deserialize<'de> for AccountAccess {
                 let mut state = 0;
 
                 loop {
-                    let elem = seq.next_element::<&str>()?;
+                    let elem = seq.next_element::<String>()?;
 
-                    match elem {
+                    match elem.as_deref() {
                         Some(AccountAccess::NONE_STR) => {
                             state |= AccountAccess::NONE;
                         }
@@ -266,9 +449,13 @@ impl<'de> Deserialize<'de> for AccountAccess {
 #[serde(transparent)]
 pub struct AccountAchievements(pub Vec<AccountAchievement>);
 
+collection!(AccountAchievements(AccountAchievement));
+
 impl AccountAchievements {
     const URI: &'static str = "/v2/account/achievements";
 
+    permissions!(Account, Progression);
+
     /// Returns a list of achievements unlocked by the currently authenticated account.
     ///
     /// # Authentication
@@ -314,12 +501,116 @@ impl AccountAchievements {
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns the progress of the currently authenticated account for the achievements with the
+    /// given `ids`.
+    ///
+    /// The ids are split into chunks of 200 which are requested concurrently. Achievements
+    /// without any progress are omitted from the returned list.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::account::AccountAchievements;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let achievements = AccountAchievements::get_many(&client, &[1, 2963]).await?;
+    /// println!("{:?}", achievements);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::account::AccountAchievements;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let achievements = AccountAchievements::get_many(&client, &[1, 2963])?;
+    /// println!("{:?}", achievements);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn get_many<C>(client: &C, ids: &[u64]) -> C::Output<Self>
+    where
+        C: Executor,
+    {
+        let ids = ids.to_vec();
+
+        client.execute(|client| async move {
+            let achievements =
+                bulk::get_chunked_authenticated(&client, Self::URI, &ids, Self::PERMISSIONS)
+                    .await?;
+            Ok(Self(achievements))
+        })
+    }
+
+    /// Returns an [`AchievementWatcher`] polling the progress of the currently authenticated
+    /// account on all achievements in the category with the given `category_id` every
+    /// `interval`.
+    ///
+    /// Requires the `watch` feature to be enabled. See the [`watch`] module for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use futures_util::StreamExt;
+    /// use gw2api_rs::v2::account::AccountAchievements;
+    /// use gw2api_rs::watch::AchievementEvent;
+    /// use gw2api_rs::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let mut watcher = AccountAchievements::watch_category(&client, 88, Duration::from_secs(60));
+    ///
+    /// while let Some(event) = watcher.next().await {
+    ///     if let AchievementEvent::Completed { id } = event? {
+    ///         println!("completed achievement {}", id);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`AchievementWatcher`]: crate::watch::AchievementWatcher
+    /// [`watch`]: crate::watch
+    #[cfg(all(feature = "watch", feature = "achievements"))]
+    pub fn watch_category(
+        client: &Client,
+        category_id: u64,
+        interval: Duration,
+    ) -> AchievementWatcher {
+        AchievementWatcher::new(client, category_id, interval)
     }
 }
 
 /// An achievement unlocked by an account.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct AccountAchievement {
     /// The id of the achievement.
     pub id: u64,
@@ -352,9 +643,13 @@ impl AccountAchievement {
 #[serde(transparent)]
 pub struct AccountBank(pub Vec<Option<BankItem>>);
 
+collection!(AccountBank(Option<BankItem>));
+
 impl AccountBank {
     const URI: &'static str = "/v2/account/bank";
 
+    permissions!(Account, Inventories);
+
     /// Returns a list of items stored in the account's bank.
     ///
     /// # Authentication
@@ -397,12 +692,81 @@ impl AccountBank {
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    #[cfg(all(feature = "items", feature = "skins"))]
+    /// Returns a list of items stored in the account's bank joined with the metadata of the
+    /// items, their skins and upgrades.
+    ///
+    /// Empty slots are `None`. The referenced items and skins are fetched in bulk requests.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// When authenticated it returns the account's bank of the current access token.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::account::AccountBank;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let bank = AccountBank::resolve(&client).await?;
+    ///
+    /// for slot in bank.iter().flatten() {
+    ///     if let Some(item) = &slot.item {
+    ///         println!("{}x {}", slot.slot.count, item.name);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::account::AccountBank;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let bank = AccountBank::resolve(&client)?;
+    ///
+    /// for slot in bank.iter().flatten() {
+    ///     if let Some(item) = &slot.item {
+    ///         println!("{}x {}", slot.slot.count, item.name);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn resolve<C>(client: &C) -> C::Output<Vec<Option<ResolvedItem<BankItem>>>>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let bank = Self::get(&client).await?;
+            resolve_slots(&client, bank.0).await
+        })
     }
 }
 
 /// A single item stored in an [`AccountBank`].
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct BankItem {
     /// The id of the item.
     pub id: u64,
@@ -420,20 +784,19 @@ pub struct BankItem {
     /// A list of infusions applied on this item. Only avaliable for some items.
     pub infusions: Option<Vec<u64>>,
     /// Whom the item is bound to. If `None` the item is not bound at all.
-    pub binding: Option<ItemBinding>,
+    pub binding: Option<Binding>,
     /// The name of the character the item is bound to. Only avaliable if `binding` is
     /// `Character`.
     pub bound_to: Option<String>,
     pub stats: Option<Vec<ItemStats>>,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-pub enum ItemBinding {
-    Account,
-    Character,
-}
+/// Whom an item is bound to.
+#[deprecated(note = "use `v2::common::Binding` instead")]
+pub type ItemBinding = Binding;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct ItemStats {
     pub id: u64,
     pub attributes: HashMap<String, f64>,
@@ -444,9 +807,13 @@ pub struct ItemStats {
 #[serde(transparent)]
 pub struct AccountDailyCrafting(pub Vec<String>);
 
+collection!(AccountDailyCrafting(String));
+
 impl AccountDailyCrafting {
     const URI: &'static str = "/v2/account/dailycrafting";
 
+    permissions!(Account, Progression);
+
     /// Returns a list of items that have been crafted by the currently authenticated account since
     /// daily reset.
     ///
@@ -491,7 +858,20 @@ impl AccountDailyCrafting {
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns the time of the next daily reset, at which the crafted items are cleared.
+    /// Responses of this endpoint can be cached until then.
+    ///
+    /// See the [`reset`] module for details.
+    #[inline]
+    pub fn expires_at() -> DateTime {
+        reset::next_daily_reset()
     }
 }
 
@@ -499,9 +879,13 @@ impl AccountDailyCrafting {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AccountDungeons(pub Vec<String>);
 
+collection!(AccountDungeons(String));
+
 impl AccountDungeons {
     const URI: &'static str = "/v2/account/dungeons";
 
+    permissions!(Account, Progression);
+
     /// Returns a list of dungeon paths completed by the currently authenticatd account since the
     /// daily reset.
     ///
@@ -546,7 +930,166 @@ impl AccountDungeons {
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns the completion of all `dungeons` given the paths completed by this account.
+    ///
+    /// See [`Dungeon::completion`] for details.
+    #[cfg(feature = "dungeons")]
+    pub fn completion(&self, dungeons: &[Dungeon]) -> Vec<DungeonCompletion> {
+        dungeons
+            .iter()
+            .map(|dungeon| dungeon.completion(&self.0))
+            .collect()
+    }
+
+    /// Returns the daily completion of all dungeons together with the progress of the account
+    /// towards the Dungeon Frequenter achievement.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if any of the requests fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::account::AccountDungeons;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let progress = AccountDungeons::get_completion(&client).await?;
+    ///
+    /// println!("{} paths left today", progress.remaining());
+    /// println!("{} paths left for Dungeon Frequenter", progress.frequenter.remaining());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::account::AccountDungeons;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let progress = AccountDungeons::get_completion(&client)?;
+    ///
+    /// println!("{} paths left today", progress.remaining());
+    /// println!("{} paths left for Dungeon Frequenter", progress.frequenter.remaining());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    #[cfg(feature = "dungeons")]
+    pub fn get_completion<C>(client: &C) -> C::Output<AccountDungeonProgress>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let (completed, dungeons, achievements) = future::try_join3(
+                Self::get(&client),
+                Dungeon::get_all(&client),
+                AccountAchievements::get(&client),
+            )
+            .await?;
+
+            let frequenter = achievements
+                .iter()
+                .find(|achievement| achievement.id == dungeons::FREQUENTER_ACHIEVEMENT)
+                .map(DungeonFrequenter::from)
+                .unwrap_or_default();
+
+            Ok(AccountDungeonProgress {
+                dungeons: completed.completion(&dungeons),
+                frequenter,
+            })
+        })
+    }
+}
+
+/// The daily dungeon completion of an account.
+///
+/// Returned by [`AccountDungeons::get_completion`].
+#[cfg(feature = "dungeons")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct AccountDungeonProgress {
+    /// The completion of all dungeons.
+    pub dungeons: Vec<DungeonCompletion>,
+    /// The progress towards the Dungeon Frequenter achievement.
+    pub frequenter: DungeonFrequenter,
+}
+
+#[cfg(feature = "dungeons")]
+impl AccountDungeonProgress {
+    /// Returns the number of paths completed today over all dungeons.
+    pub fn completed(&self) -> usize {
+        self.dungeons.iter().map(DungeonCompletion::completed).sum()
+    }
+
+    /// Returns the number of paths not yet completed today over all dungeons.
+    pub fn remaining(&self) -> usize {
+        self.dungeons
+            .iter()
+            .map(|dungeon| dungeon.remaining().count())
+            .sum()
+    }
+}
+
+/// The progress of an account in the current cycle of the repeatable Dungeon Frequenter
+/// achievement.
+#[cfg(feature = "dungeons")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct DungeonFrequenter {
+    /// The number of distinct paths completed in the current cycle.
+    pub current: u64,
+    /// The number of distinct paths required to complete the cycle.
+    pub max: u64,
+    /// The number of completed cycles.
+    pub repeated: u64,
+}
+
+#[cfg(feature = "dungeons")]
+impl DungeonFrequenter {
+    /// Returns the number of distinct paths left to complete the current cycle.
+    #[inline]
+    pub fn remaining(&self) -> u64 {
+        self.max.saturating_sub(self.current)
+    }
+}
+
+#[cfg(feature = "dungeons")]
+impl Default for DungeonFrequenter {
+    fn default() -> Self {
+        Self {
+            current: 0,
+            max: dungeons::FREQUENTER_PATHS,
+            repeated: 0,
+        }
+    }
+}
+
+#[cfg(feature = "dungeons")]
+impl From<&AccountAchievement> for DungeonFrequenter {
+    fn from(achievement: &AccountAchievement) -> Self {
+        Self {
+            current: achievement.current.unwrap_or(0),
+            max: achievement.max.unwrap_or(dungeons::FREQUENTER_PATHS),
+            repeated: achievement.repeated.unwrap_or(0),
+        }
     }
 }
 
@@ -555,9 +1098,13 @@ impl AccountDungeons {
 #[serde(transparent)]
 pub struct AccountDyes(pub Vec<u64>);
 
+collection!(AccountDyes(u64));
+
 impl AccountDyes {
     const URI: &'static str = "/v2/account/dyes";
 
+    permissions!(Account, Unlocks);
+
     /// Returns a list of dyes unlocked by the currently authenticated account.
     ///
     /// # Authentication
@@ -600,36 +1147,44 @@ impl AccountDyes {
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
-/// A list of finishers unlocked.
+/// A list of emotes unlocked by an account.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(transparent)]
-pub struct AccountFinishers(pub Vec<AccountFinisher>);
+pub struct AccountEmotes(pub Vec<String>);
 
-impl AccountFinishers {
-    const URI: &'static str = "/v2/account/finishers";
+collection!(AccountEmotes(String));
 
-    /// Returns a list of finishers unlocked by the currently authenticated account.
+impl AccountEmotes {
+    const URI: &'static str = "/v2/account/emotes";
+
+    permissions!(Account, Unlocks);
+
+    /// Returns a list of emotes unlocked by the currently authenticated account.
     ///
     /// # Authentication
     ///
     /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
-    /// When authenticated it returns a list of finishers unlocked by the account of the current
-    /// access token.
+    /// When authenticated it returns a list of emotes unlocked by the account of the current access
+    /// token.
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use gw2api_rs::{Client, Result};
-    /// # use gw2api_rs::v2::account::AccountFinishers;
+    /// # use gw2api_rs::v2::account::AccountEmotes;
     /// #
     /// # async fn run() -> Result<()> {
     /// # let token = "";
     /// let client: Client = Client::builder().access_token(token).into();
-    /// let finishers = AccountFinishers::get(&client).await?;
+    /// let emotes = AccountEmotes::get(&client).await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -639,12 +1194,12 @@ impl AccountFinishers {
     /// ```no_run
     /// # use gw2api_rs::Result;
     /// # use gw2api_rs::blocking::Client;
-    /// # use gw2api_rs::v2::account::AccountFinishers;
+    /// # use gw2api_rs::v2::account::AccountEmotes;
     /// #
     /// # fn run() -> Result<()> {
     /// # let token = "";
     /// let client: Client = Client::builder().access_token(token).into();
-    /// let finishers = AccountFinishers::get(&client)?;
+    /// let emotes = AccountEmotes::get(&client)?;
     /// # Ok(())
     /// # }
     /// ```
@@ -655,24 +1210,92 @@ impl AccountFinishers {
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
-/// A single finisher unlocked by an account.
+/// A list of finishers unlocked.
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct AccountFinisher {
-    /// The id of the finisher.
-    pub id: u64,
-    /// Whether the finisher is unlocked permanently.
-    #[serde(default = "AccountFinisher::serde_default_permanent")]
-    pub permanent: bool,
-    /// The number of uses remaining if `permanent` is `false`.
-    ///
-    /// This field is always `0` if `permanent` is `true`.
-    #[serde(default)]
-    pub quantity: u64,
-}
+#[serde(transparent)]
+pub struct AccountFinishers(pub Vec<AccountFinisher>);
+
+collection!(AccountFinishers(AccountFinisher));
+
+impl AccountFinishers {
+    const URI: &'static str = "/v2/account/finishers";
+
+    permissions!(Account, Unlocks);
+
+    /// Returns a list of finishers unlocked by the currently authenticated account.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// When authenticated it returns a list of finishers unlocked by the account of the current
+    /// access token.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::account::AccountFinishers;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let finishers = AccountFinishers::get(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::account::AccountFinishers;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let finishers = AccountFinishers::get(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+}
+
+/// A single finisher unlocked by an account.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct AccountFinisher {
+    /// The id of the finisher.
+    pub id: u64,
+    /// Whether the finisher is unlocked permanently.
+    #[serde(default = "AccountFinisher::serde_default_permanent")]
+    pub permanent: bool,
+    /// The number of uses remaining if `permanent` is `false`.
+    ///
+    /// This field is always `0` if `permanent` is `true`.
+    #[serde(default)]
+    pub quantity: u64,
+}
 
 impl AccountFinisher {
     #[inline]
@@ -686,9 +1309,13 @@ impl AccountFinisher {
 #[serde(transparent)]
 pub struct AccountGliders(pub Vec<u64>);
 
+collection!(AccountGliders(u64));
+
 impl AccountGliders {
     const URI: &'static str = "/v2/account/gliders";
 
+    permissions!(Account, Unlocks);
+
     /// Returns a list of gliders unlocked by the currently authenticated account.
     ///
     /// # Authentication
@@ -732,7 +1359,11 @@ impl AccountGliders {
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
@@ -741,9 +1372,13 @@ impl AccountGliders {
 #[serde(transparent)]
 pub struct AccountHomeCats(pub Vec<u64>);
 
+collection!(AccountHomeCats(u64));
+
 impl AccountHomeCats {
     const URI: &'static str = "/v2/account/home/cats";
 
+    permissions!(Account, Progression);
+
     /// Returns a list of home cats unlocked by the currently authenticated account.
     ///
     /// # Authentication
@@ -787,7 +1422,11 @@ impl AccountHomeCats {
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
@@ -796,9 +1435,13 @@ impl AccountHomeCats {
 #[serde(transparent)]
 pub struct AccountHomeNodes(pub Vec<String>);
 
+collection!(AccountHomeNodes(String));
+
 impl AccountHomeNodes {
     const URI: &'static str = "/v2/account/home/nodes";
 
+    permissions!(Account, Progression);
+
     /// Returns a list of home nodes unlocked by the currently authenticated account.
     ///
     /// # Authentication
@@ -842,7 +1485,147 @@ impl AccountHomeNodes {
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+}
+
+/// A list of homestead decorations unlocked by an account.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountHomesteadDecorations(pub Vec<AccountDecoration>);
+
+collection!(AccountHomesteadDecorations(AccountDecoration));
+
+impl AccountHomesteadDecorations {
+    const URI: &'static str = "/v2/account/homestead/decorations";
+
+    permissions!(Account, Unlocks);
+
+    /// Returns a list of homestead decorations unlocked by the currently authenticated account.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// When authenticated it returns the decorations unlocked by the account of the current access
+    /// token.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::account::AccountHomesteadDecorations;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let decorations = AccountHomesteadDecorations::get(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::account::AccountHomesteadDecorations;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let decorations = AccountHomesteadDecorations::get(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+}
+
+/// A homestead decoration unlocked by an account.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct AccountDecoration {
+    /// The id of the [`Decoration`].
+    pub id: u64,
+    /// The number of decorations stored.
+    pub count: u64,
+}
+
+/// A list of homestead glyphs unlocked by an account.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountHomesteadGlyphs(pub Vec<String>);
+
+collection!(AccountHomesteadGlyphs(String));
+
+impl AccountHomesteadGlyphs {
+    const URI: &'static str = "/v2/account/homestead/glyphs";
+
+    permissions!(Account, Unlocks);
+
+    /// Returns a list of homestead glyphs unlocked by the currently authenticated account.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// When authenticated it returns the glyphs unlocked by the account of the current access
+    /// token.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::account::AccountHomesteadGlyphs;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let glyphs = AccountHomesteadGlyphs::get(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::account::AccountHomesteadGlyphs;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let glyphs = AccountHomesteadGlyphs::get(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
@@ -853,9 +1636,13 @@ impl AccountHomeNodes {
 #[serde(transparent)]
 pub struct AccountInventory(pub Vec<Option<InventoryItem>>);
 
+collection!(AccountInventory(Option<InventoryItem>));
+
 impl AccountInventory {
     const URI: &'static str = "/v2/account/inventory";
 
+    permissions!(Account, Inventories);
+
     /// Returns a list of items stored in the shared inventory slots of the currently authenticated
     /// account.
     ///
@@ -900,11 +1687,69 @@ impl AccountInventory {
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    #[cfg(all(feature = "items", feature = "skins"))]
+    /// Returns a list of items stored in the shared inventory slots of the account joined with
+    /// the metadata of the items, their skins and upgrades.
+    ///
+    /// Empty slots are `None`. The referenced items and skins are fetched in bulk requests.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// When authenticated it returns the shared inventory of the account of the current access
+    /// token.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::account::AccountInventory;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let inventory = AccountInventory::resolve(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::account::AccountInventory;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let inventory = AccountInventory::resolve(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn resolve<C>(client: &C) -> C::Output<Vec<Option<ResolvedItem<InventoryItem>>>>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let inventory = Self::get(&client).await?;
+            resolve_slots(&client, inventory.0).await
+        })
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct InventoryItem {
     pub id: u64,
     pub count: u64,
@@ -912,33 +1757,154 @@ pub struct InventoryItem {
     pub skin: Option<u64>,
     pub upgrades: Option<Vec<u64>>,
     pub infusions: Option<Vec<u64>>,
-    pub binding: ItemBinding,
+    pub binding: Binding,
 }
 
-/// The current luck value of an account.
-pub struct AccountLuck(pub u64);
+#[cfg(all(feature = "items", feature = "skins"))]
+/// An item slot joined with the metadata of the item, its skin and upgrades.
+///
+/// Returned by [`AccountBank::resolve`] and [`AccountInventory::resolve`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct ResolvedItem<S> {
+    /// The item slot.
+    pub slot: S,
+    /// The item stored in the slot. This is `None` if the item could not be found.
+    pub item: Option<Item>,
+    /// The skin applied on the item. This is `None` if no skin is applied or the skin could not
+    /// be found.
+    pub skin: Option<Skin>,
+    /// The upgrades applied on the item.
+    pub upgrades: Vec<Item>,
+    /// The infusions applied on the item.
+    pub infusions: Vec<Item>,
+}
 
-impl AccountLuck {
-    const URI: &'static str = "/v2/account/luck";
+#[cfg(all(feature = "items", feature = "skins"))]
+/// An item slot referencing items and skins.
+trait ItemSlot {
+    fn item_id(&self) -> u64;
+    fn skin_id(&self) -> Option<u64>;
+    fn upgrade_ids(&self) -> &[u64];
+    fn infusion_ids(&self) -> &[u64];
+}
 
-    /// Returns the unlocked luck value of the currently authenticated account.
+#[cfg(all(feature = "items", feature = "skins"))]
+impl ItemSlot for BankItem {
+    fn item_id(&self) -> u64 {
+        self.id
+    }
+
+    fn skin_id(&self) -> Option<u64> {
+        self.skin
+    }
+
+    fn upgrade_ids(&self) -> &[u64] {
+        self.upgrades.as_deref().unwrap_or_default()
+    }
+
+    fn infusion_ids(&self) -> &[u64] {
+        self.infusions.as_deref().unwrap_or_default()
+    }
+}
+
+#[cfg(all(feature = "items", feature = "skins"))]
+impl ItemSlot for InventoryItem {
+    fn item_id(&self) -> u64 {
+        self.id
+    }
+
+    fn skin_id(&self) -> Option<u64> {
+        self.skin
+    }
+
+    fn upgrade_ids(&self) -> &[u64] {
+        self.upgrades.as_deref().unwrap_or_default()
+    }
+
+    fn infusion_ids(&self) -> &[u64] {
+        self.infusions.as_deref().unwrap_or_default()
+    }
+}
+
+#[cfg(all(feature = "items", feature = "skins"))]
+/// Joins the `slots` with the metadata of all referenced items and skins.
+async fn resolve_slots<S>(
+    client: &Client,
+    slots: Vec<Option<S>>,
+) -> crate::Result<Vec<Option<ResolvedItem<S>>>>
+where
+    S: ItemSlot,
+{
+    let mut item_ids = BTreeSet::new();
+    let mut skin_ids = BTreeSet::new();
+
+    for slot in slots.iter().flatten() {
+        item_ids.insert(slot.item_id());
+        item_ids.extend(slot.upgrade_ids());
+        item_ids.extend(slot.infusion_ids());
+        skin_ids.extend(slot.skin_id());
+    }
+
+    let item_ids: Vec<u64> = item_ids.into_iter().collect();
+    let skin_ids: Vec<u64> = skin_ids.into_iter().collect();
+
+    let (items, skins) = future::try_join(
+        bulk::get_chunked::<Item, _>(client, Item::URI, &item_ids),
+        bulk::get_chunked::<Skin, _>(client, Skin::URI, &skin_ids),
+    )
+    .await?;
+
+    let items: HashMap<u64, Item> = items.into_iter().map(|item| (item.id, item)).collect();
+    let skins: HashMap<u64, Skin> = skins.into_iter().map(|skin| (skin.id, skin)).collect();
+
+    let resolve =
+        |ids: &[u64]| -> Vec<Item> { ids.iter().filter_map(|id| items.get(id).cloned()).collect() };
+
+    Ok(slots
+        .into_iter()
+        .map(|slot| {
+            slot.map(|slot| ResolvedItem {
+                item: items.get(&slot.item_id()).cloned(),
+                skin: slot.skin_id().and_then(|id| skins.get(&id).cloned()),
+                upgrades: resolve(slot.upgrade_ids()),
+                infusions: resolve(slot.infusion_ids()),
+                slot,
+            })
+        })
+        .collect())
+}
+
+/// A list of jade bots unlocked by an account.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountJadeBots(pub Vec<u64>);
+
+collection!(AccountJadeBots(u64));
+
+impl AccountJadeBots {
+    const URI: &'static str = "/v2/account/jadebots";
+
+    permissions!(Account, Unlocks);
+
+    /// Returns a list of jade bots unlocked by the currently authenticated account.
     ///
     /// # Authentication
     ///
     /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
-    /// When authenticated it returns unlocked luck value of the account of the current access
-    /// token.
+    /// When authenticated it returns a list of jade bots unlocked by the account of the current
+    /// access token.
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use gw2api_rs::{Client, Result};
-    /// # use gw2api_rs::v2::account::AccountLuck;
+    /// # use gw2api_rs::v2::account::AccountJadeBots;
     /// #
     /// # async fn run() -> Result<()> {
     /// # let token = "";
     /// let client: Client = Client::builder().access_token(token).into();
-    /// let luck = AccountLuck::get(&client).await?;
+    /// let jade_bots = AccountJadeBots::get(&client).await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -948,12 +1914,12 @@ impl AccountLuck {
     /// ```no_run
     /// # use gw2api_rs::Result;
     /// # use gw2api_rs::blocking::Client;
-    /// # use gw2api_rs::v2::account::AccountLuck;
+    /// # use gw2api_rs::v2::account::AccountJadeBots;
     /// #
     /// # fn run() -> Result<()> {
     /// # let token = "";
     /// let client: Client = Client::builder().access_token(token).into();
-    /// let luck = AccountLuck::get(&client)?;
+    /// let jade_bots = AccountJadeBots::get(&client)?;
     /// # Ok(())
     /// # }
     /// ```
@@ -964,11 +1930,149 @@ impl AccountLuck {
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
-impl Serialize for AccountLuck {
+/// The current luck value of an account.
+///
+/// Luck is consumed from essences of luck and grants up to 300% account-wide magic find, see
+/// [`magic_find_percent`].
+///
+/// [`magic_find_percent`]: Self::magic_find_percent
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AccountLuck(pub u64);
+
+impl AccountLuck {
+    const URI: &'static str = "/v2/account/luck";
+
+    /// The maximum magic find percentage granted by luck.
+    pub const MAX_MAGIC_FIND: u64 = 300;
+
+    /// The luck required for each percent of magic find, as `(last percent, luck per percent)`
+    /// tiers.
+    const MAGIC_FIND_TIERS: &'static [(u64, u64)] = &[
+        (25, 500),
+        (50, 1_000),
+        (100, 2_000),
+        (200, 4_000),
+        (300, 8_000),
+    ];
+
+    permissions!(Account, Progression, Unlocks);
+
+    /// Returns the unlocked luck value of the currently authenticated account.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// When authenticated it returns unlocked luck value of the account of the current access
+    /// token.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::account::AccountLuck;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let luck = AccountLuck::get(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::account::AccountLuck;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let luck = AccountLuck::get(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns the account-wide magic find percentage granted by the luck of the account.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gw2api_rs::v2::account::AccountLuck;
+    /// #
+    /// assert_eq!(AccountLuck(0).magic_find_percent(), 0);
+    /// assert_eq!(AccountLuck(12_500).magic_find_percent(), 25);
+    /// assert_eq!(AccountLuck(13_499).magic_find_percent(), 25);
+    /// assert_eq!(AccountLuck(u64::MAX).magic_find_percent(), 300);
+    /// ```
+    pub fn magic_find_percent(&self) -> u64 {
+        let mut luck = self.0;
+        let mut percent = 0;
+
+        for &(last, cost) in Self::MAGIC_FIND_TIERS {
+            let steps = (last - percent).min(luck / cost);
+            percent += steps;
+            luck -= steps * cost;
+
+            if percent < last {
+                break;
+            }
+        }
+
+        percent
+    }
+
+    /// Returns the total luck required for the given magic find `percent`. Percentages above
+    /// [`MAX_MAGIC_FIND`] are treated as [`MAX_MAGIC_FIND`].
+    ///
+    /// [`MAX_MAGIC_FIND`]: Self::MAX_MAGIC_FIND
+    pub fn luck_for_magic_find(percent: u64) -> u64 {
+        let mut luck = 0;
+        let mut prev = 0;
+
+        for &(last, cost) in Self::MAGIC_FIND_TIERS {
+            luck += (percent.min(last).saturating_sub(prev)) * cost;
+            prev = last;
+        }
+
+        luck
+    }
+
+    /// Returns the luck missing for the next percent of magic find. Returns `None` if the
+    /// maximum magic find is already reached.
+    pub fn luck_to_next_percent(&self) -> Option<u64> {
+        let percent = self.magic_find_percent();
+        if percent >= Self::MAX_MAGIC_FIND {
+            return None;
+        }
+
+        Some(Self::luck_for_magic_find(percent + 1) - self.0)
+    }
+}
+
+impl Serialize for AccountLuck {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -1004,111 +2108,170 @@ impl<'de> Deserialize<'de> for AccountLuck {
     where
         D: Deserializer<'de>,
     {
-        struct LuckVisitor;
-
-        impl<'de> Visitor<'de> for LuckVisitor {
-            type Value = AccountLuck;
-
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                write!(formatter, "a sequence with one or zero elements")
-            }
-
-            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-            where
-                A: SeqAccess<'de>,
-            {
-                struct Map(AccountLuck);
-
-                impl<'de> Deserialize<'de> for Map {
-                    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-                    where
-                        D: Deserializer<'de>,
-                    {
-                        struct LuckMapVisitor;
-
-                        impl<'de> Visitor<'de> for LuckMapVisitor {
-                            type Value = Map;
-
-                            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                                write!(formatter, "a map containing account luck")
-                            }
-
-                            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
-                            where
-                                A: MapAccess<'de>,
-                            {
-                                let value;
-
-                                match map.next_key::<&str>()? {
-                                    Some(key) => match key {
-                                        "id" => match map.next_value()? {
-                                            Some("luck") => (),
-                                            _ => {
-                                                return Err(A::Error::custom(
-                                                    "expected a luck id value",
-                                                ))
-                                            }
-                                        },
-                                        _ => {
-                                            return Err(A::Error::custom(
-                                                "expected a luck id value",
-                                            ))
-                                        }
-                                    },
-                                    None => {
-                                        return Err(A::Error::custom("missing fields id, value"))
-                                    }
-                                }
-
-                                match map.next_key::<&str>()? {
-                                    Some(key) => match key {
-                                        "value" => match map.next_value()? {
-                                            Some(val) => value = val,
-                                            None => {
-                                                return Err(A::Error::custom("expected a value"))
-                                            }
-                                        },
-                                        _ => return Err(A::Error::custom("expected a value")),
-                                    },
-                                    None => return Err(A::Error::custom("missing fields value")),
-                                }
-
-                                Ok(Map(AccountLuck(value)))
-                            }
-                        }
-
-                        deserializer.deserialize_map(LuckMapVisitor)
-                    }
-                }
+        // The endpoint returns an array containing a single `luck` entry, or an empty array if
+        // the account has no luck. Entries with other ids and unknown fields are ignored.
+        #[derive(Deserialize)]
+        struct Entry {
+            id: String,
+            #[serde(default)]
+            value: serde_json::Value,
+        }
 
-                match seq.next_element::<Map>()? {
-                    Some(map) => Ok(map.0),
-                    None => Ok(AccountLuck(0)),
-                }
+        let mut luck = 0;
+        for entry in Vec::<Entry>::deserialize(deserializer)? {
+            if entry.id == "luck" {
+                let value = entry
+                    .value
+                    .as_u64()
+                    .ok_or_else(|| D::Error::custom("expected an integer luck value"))?;
+                luck += value;
             }
         }
 
-        deserializer.deserialize_seq(LuckVisitor)
+        Ok(AccountLuck(luck))
     }
 }
 
 /// A list of legendary items unlocked in the account armory.
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct AccountLegendaryArmory(Vec<LegendaryArmoryItem>);
+pub struct AccountLegendaryArmory(pub Vec<LegendaryArmoryItem>);
+
+collection!(AccountLegendaryArmory(LegendaryArmoryItem));
 
 impl AccountLegendaryArmory {
     const URI: &'static str = "/v2/account/legendaryarmory";
 
+    permissions!(Account, Inventories, Unlocks);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    #[cfg(all(feature = "items", feature = "legendaryarmory"))]
+    /// Returns the unlock capacity of the legendary armory of the currently authenticated
+    /// account, grouped by the [`ItemKind`] of the legendary items.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// When authenticated it returns the capacity of the armory of the account of the current
+    /// access token.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::account::AccountLegendaryArmory;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let capacity = AccountLegendaryArmory::capacity(&client).await?;
+    ///
+    /// for slot in capacity {
+    ///     println!("{:?}: {} remaining", slot.kind, slot.remaining());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::account::AccountLegendaryArmory;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let capacity = AccountLegendaryArmory::capacity(&client)?;
+    ///
+    /// for slot in capacity {
+    ///     println!("{:?}: {} remaining", slot.kind, slot.remaining());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn capacity<C>(client: &C) -> C::Output<Vec<ArmoryCapacity>>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let (armory, legendaries): (Self, Vec<LegendaryItem>) =
+                future::try_join(Self::get(&client), LegendaryItem::get_all(&client)).await?;
+
+            let ids: Vec<u64> = legendaries.iter().map(|item| item.id).collect();
+            let items: Vec<Item> = bulk::get_chunked(&client, Item::URI, &ids).await?;
+
+            let kinds: HashMap<u64, ItemKind> =
+                items.iter().map(|item| (item.id, item.kind)).collect();
+            let unlocked: HashMap<u64, u8> = armory
+                .0
+                .iter()
+                .map(|item| (item.id, item.count.get()))
+                .collect();
+
+            let mut capacity: Vec<ArmoryCapacity> = Vec::new();
+            for item in &legendaries {
+                let kind = kinds.get(&item.id).copied().unwrap_or(ItemKind::Unknown);
+
+                let index = match capacity.iter().position(|slot| slot.kind == kind) {
+                    Some(index) => index,
+                    None => {
+                        capacity.push(ArmoryCapacity {
+                            kind,
+                            unlocked: 0,
+                            max_count: 0,
+                        });
+                        capacity.len() - 1
+                    }
+                };
+
+                let slot = &mut capacity[index];
+                slot.max_count += u64::from(item.max_count.get());
+                slot.unlocked += unlocked.get(&item.id).copied().map_or(0, u64::from);
+            }
+
+            Ok(capacity)
+        })
+    }
+}
+
+#[cfg(all(feature = "items", feature = "legendaryarmory"))]
+/// The unlock capacity of the legendary armory for a single kind of item.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ArmoryCapacity {
+    /// The kind of the legendary items.
+    pub kind: ItemKind,
+    /// The number of items of this kind unlocked in the armory.
+    pub unlocked: u64,
+    /// The maximum number of items of this kind that can be unlocked in the armory.
+    pub max_count: u64,
+}
+
+#[cfg(all(feature = "items", feature = "legendaryarmory"))]
+impl ArmoryCapacity {
+    /// Returns the number of items of this kind that can still be unlocked.
+    #[inline]
+    pub fn remaining(&self) -> u64 {
+        self.max_count.saturating_sub(self.unlocked)
     }
 }
 
 /// An item in unlocked in the legendary armory.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct LegendaryArmoryItem {
     /// The id of the legendary item.
     pub id: u64,
@@ -1120,28 +2283,54 @@ pub struct LegendaryArmoryItem {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AccountMailCarriers(pub Vec<u64>);
 
+collection!(AccountMailCarriers(u64));
+
 impl AccountMailCarriers {
     const URI: &'static str = "/v2/account/mailcarriers";
 
+    permissions!(Account, Unlocks);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
+/// A list of ids of map chests opened by an account since the last daily reset.
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct AccountMapChests(Vec<String>);
+pub struct AccountMapChests(pub Vec<String>);
+
+collection!(AccountMapChests(String));
 
 impl AccountMapChests {
     const URI: &'static str = "/v2/account/mapchests";
 
+    permissions!(Account, Progression);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns the time of the next daily reset, at which the opened map chests are cleared.
+    /// Responses of this endpoint can be cached until then.
+    ///
+    /// See the [`reset`] module for details.
+    #[inline]
+    pub fn expires_at() -> DateTime {
+        reset::next_daily_reset()
     }
 }
 
@@ -1149,19 +2338,28 @@ impl AccountMapChests {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AccountMasteries(pub Vec<AccountMastery>);
 
+collection!(AccountMasteries(AccountMastery));
+
 impl AccountMasteries {
     const URI: &'static str = "/v2/account/masteries";
 
+    permissions!(Account, Progression);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
 /// A single unlocked mastery.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct AccountMastery {
     /// The id of the mastery track.
     pub id: u64,
@@ -1170,6 +2368,7 @@ pub struct AccountMastery {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct AccountMasteryPoints {
     pub totals: Vec<RegionMasteryPoints>,
     pub unlocked: Vec<u64>,
@@ -1178,15 +2377,22 @@ pub struct AccountMasteryPoints {
 impl AccountMasteryPoints {
     const URI: &'static str = "/v2/account/mastery/points";
 
+    permissions!(Account, Progression);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct RegionMasteryPoints {
     /// The mastery region.
     pub region: String,
@@ -1198,43 +2404,275 @@ pub struct RegionMasteryPoints {
 
 /// The account's material storage.
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct AccountMaterials(Vec<AccountMaterial>);
+pub struct AccountMaterials(pub Vec<AccountMaterial>);
+
+collection!(AccountMaterials(AccountMaterial));
 
 impl AccountMaterials {
     const URI: &'static str = "/v2/account/materials";
 
+    /// The number of items a storage expansion adds to every slot.
+    const SLOT_SIZE: u16 = 250;
+
+    permissions!(Account, Inventories);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns the number of items a single slot of the material storage holds.
+    ///
+    /// The limit is not returned by the API. It is deduced from the largest stack stored,
+    /// rounded up to the next multiple of 250 items, which every storage expansion adds to the
+    /// limit. The real limit may be larger if no slot is filled beyond the previous multiple.
+    pub fn storage_limit(&self) -> u16 {
+        let max = self
+            .0
+            .iter()
+            .map(|material| material.count)
+            .max()
+            .unwrap_or(0);
+        max.div_ceil(Self::SLOT_SIZE).max(1) * Self::SLOT_SIZE
+    }
+
+    #[cfg(feature = "materials")]
+    /// Groups the stored materials by the given `categories`, sorted by the [`order`] of the
+    /// categories. Materials of categories not contained in `categories` are omitted.
+    ///
+    /// [`order`]: MaterialCategory::order
+    pub fn group(&self, categories: &[MaterialCategory]) -> MaterialStorage {
+        let limit = self.storage_limit();
+        let materials: HashMap<u64, &AccountMaterial> = self
+            .0
+            .iter()
+            .map(|material| (material.id, material))
+            .collect();
+
+        let mut categories: Vec<&MaterialCategory> = categories.iter().collect();
+        categories.sort_by_key(|category| category.order);
+
+        let categories = categories
+            .into_iter()
+            .map(|category| MaterialStorageCategory {
+                id: category.id,
+                name: category.name.clone(),
+                slots: category.items.len() as u64,
+                limit,
+                materials: category
+                    .items
+                    .iter()
+                    .filter_map(|id| materials.get(id).map(|material| (*material).clone()))
+                    .collect(),
+            })
+            .collect();
+
+        MaterialStorage { limit, categories }
+    }
+
+    #[cfg(feature = "materials")]
+    /// Returns the material storage of the currently authenticated account grouped by the
+    /// categories returned by `/v2/materials`, including how full each category is.
+    ///
+    /// See [`group`] for details.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::account::AccountMaterials;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let storage = AccountMaterials::storage(&client).await?;
+    ///
+    /// for category in &storage.categories {
+    ///     println!("{}: {:.0}% full", category.name, category.fill() * 100.0);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::account::AccountMaterials;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let storage = AccountMaterials::storage(&client)?;
+    ///
+    /// for category in &storage.categories {
+    ///     println!("{}: {:.0}% full", category.name, category.fill() * 100.0);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`group`]: Self::group
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn storage<C>(client: &C) -> C::Output<MaterialStorage>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let (materials, categories): (Self, Vec<MaterialCategory>) =
+                future::try_join(Self::get(&client), MaterialCategory::get_all(&client)).await?;
+
+            Ok(materials.group(&categories))
+        })
     }
 }
 
 /// A material slot in the account's material storage.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct AccountMaterial {
     /// The id of the item.
     pub id: u64,
-    /// The id of the category this item belongs to.
+    /// The id of the category this item belongs to. See [`material_categories`] for the ids of
+    /// all categories.
     pub category: u8,
     /// The binding of the item.
-    pub binding: Option<ItemBinding>,
+    pub binding: Option<Binding>,
     /// The number of items of this material stored.
     pub count: u16,
 }
 
+#[cfg(feature = "materials")]
+/// The material storage of an account grouped by category, returned by
+/// [`AccountMaterials::storage`].
+#[derive(Clone, Debug)]
+pub struct MaterialStorage {
+    /// The number of items a single slot holds. See [`AccountMaterials::storage_limit`].
+    pub limit: u16,
+    /// The categories of the storage, sorted by their order.
+    pub categories: Vec<MaterialStorageCategory>,
+}
+
+#[cfg(feature = "materials")]
+impl MaterialStorage {
+    /// Returns the number of items stored in all categories.
+    pub fn stored(&self) -> u64 {
+        self.categories
+            .iter()
+            .map(|category| category.stored())
+            .sum()
+    }
+
+    /// Returns the number of items all categories can hold.
+    pub fn capacity(&self) -> u64 {
+        self.categories
+            .iter()
+            .map(|category| category.capacity())
+            .sum()
+    }
+}
+
+#[cfg(feature = "materials")]
+/// A single category of a [`MaterialStorage`].
+#[derive(Clone, Debug)]
+pub struct MaterialStorageCategory {
+    /// The id of the category.
+    pub id: u64,
+    /// The localized name of the category.
+    pub name: String,
+    /// The number of slots in the category.
+    pub slots: u64,
+    /// The number of items a single slot holds.
+    pub limit: u16,
+    /// The materials stored in the category, in the order they are displayed in.
+    pub materials: Vec<AccountMaterial>,
+}
+
+#[cfg(feature = "materials")]
+impl MaterialStorageCategory {
+    /// Returns the number of items stored in the category.
+    pub fn stored(&self) -> u64 {
+        self.materials
+            .iter()
+            .map(|material| u64::from(material.count))
+            .sum()
+    }
+
+    /// Returns the number of items the category can hold.
+    #[inline]
+    pub fn capacity(&self) -> u64 {
+        self.slots * u64::from(self.limit)
+    }
+
+    /// Returns how full the category is, in the range of `0.0` to `1.0`.
+    pub fn fill(&self) -> f64 {
+        match self.capacity() {
+            0 => 0.0,
+            capacity => self.stored() as f64 / capacity as f64,
+        }
+    }
+
+    /// Returns an iterator over the materials whose slot is full.
+    pub fn full(&self) -> impl Iterator<Item = &AccountMaterial> + '_ {
+        self.materials
+            .iter()
+            .filter(move |material| material.count >= self.limit)
+    }
+}
+
+/// Ids of the categories of the material storage.
+pub mod material_categories {
+    /// Cooking Materials.
+    pub const COOKING_MATERIALS: u8 = 5;
+    /// Common Crafting Materials.
+    pub const COMMON_CRAFTING_MATERIALS: u8 = 6;
+    /// Fine Crafting Materials.
+    pub const FINE_CRAFTING_MATERIALS: u8 = 29;
+    /// Gemstones and Jewels.
+    pub const GEMSTONES_AND_JEWELS: u8 = 30;
+    /// Rare Crafting Materials.
+    pub const RARE_CRAFTING_MATERIALS: u8 = 37;
+    /// Festive Materials.
+    pub const FESTIVE_MATERIALS: u8 = 38;
+    /// Ascended Materials.
+    pub const ASCENDED_MATERIALS: u8 = 46;
+    /// Cooking Ingredients.
+    pub const COOKING_INGREDIENTS: u8 = 49;
+    /// Scribing Materials.
+    pub const SCRIBING_MATERIALS: u8 = 50;
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AccountMinis(pub Vec<u64>);
 
+collection!(AccountMinis(u64));
+
 impl AccountMinis {
     const URI: &'static str = "/v2/account/minis";
 
+    permissions!(Account, Unlocks);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
@@ -1242,29 +2680,45 @@ impl AccountMinis {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AccountMountSkins(pub Vec<u64>);
 
+collection!(AccountMountSkins(u64));
+
 impl AccountMountSkins {
     const URI: &'static str = "/v2/account/mounts/skins";
 
+    permissions!(Account, Unlocks);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
 /// A list of unlocked mount types.
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct AccountMountTypes(pub Vec<String>);
+pub struct AccountMountTypes(pub Vec<MountType>);
+
+collection!(AccountMountTypes(MountType));
 
 impl AccountMountTypes {
     const URI: &'static str = "/v2/account/mounts/types";
 
+    permissions!(Account, Unlocks);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
@@ -1272,14 +2726,22 @@ impl AccountMountTypes {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AccountNovelties(pub Vec<u64>);
 
+collection!(AccountNovelties(u64));
+
 impl AccountNovelties {
     const URI: &'static str = "/v2/account/novelties";
 
+    permissions!(Account, Unlocks);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
@@ -1287,14 +2749,22 @@ impl AccountNovelties {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AccountOutfits(pub Vec<u64>);
 
+collection!(AccountOutfits(u64));
+
 impl AccountOutfits {
     const URI: &'static str = "/v2/account/outfits";
 
+    permissions!(Account, Unlocks);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
@@ -1302,18 +2772,27 @@ impl AccountOutfits {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AccountProgression(pub Vec<AccountProgressionItem>);
 
+collection!(AccountProgression(AccountProgressionItem));
+
 impl AccountProgression {
     const URI: &'static str = "/v2/account/progression";
 
+    permissions!(Account, Progression);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct AccountProgressionItem {
     /// The name of the progression item.
     pub id: String,
@@ -1325,14 +2804,22 @@ pub struct AccountProgressionItem {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AccountPvPHeroes(pub Vec<u64>);
 
+collection!(AccountPvPHeroes(u64));
+
 impl AccountPvPHeroes {
     const URI: &'static str = "/v2/account/pvp/heroes";
 
+    permissions!(Account, Unlocks);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
@@ -1340,14 +2827,102 @@ impl AccountPvPHeroes {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AccountRaids(pub Vec<String>);
 
+collection!(AccountRaids(String));
+
 impl AccountRaids {
     const URI: &'static str = "/v2/account/raids";
 
+    permissions!(Account, Progression);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns the time of the next weekly reset, at which the completed encounters are cleared.
+    /// Responses of this endpoint can be cached until then.
+    ///
+    /// See the [`reset`] module for details.
+    #[inline]
+    pub fn expires_at() -> DateTime {
+        reset::next_weekly_reset()
+    }
+
+    /// Returns the completion of all `raids` given the encounters cleared by this account.
+    ///
+    /// See [`Raid::completion`] for details.
+    #[cfg(feature = "raids")]
+    pub fn completion(&self, raids: &[Raid]) -> Vec<RaidCompletion> {
+        raids.iter().map(|raid| raid.completion(&self.0)).collect()
+    }
+
+    /// Returns the weekly completion of all raids, joining the cleared encounters of the account
+    /// with the structure of all raids.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if either request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::account::AccountRaids;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let raids = AccountRaids::get_completion(&client).await?;
+    ///
+    /// for raid in raids {
+    ///     for wing in raid.wings {
+    ///         println!("{}: {}/{}", wing.id, wing.cleared(), wing.events.len());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::account::AccountRaids;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let raids = AccountRaids::get_completion(&client)?;
+    ///
+    /// for raid in raids {
+    ///     for wing in raid.wings {
+    ///         println!("{}: {}/{}", wing.id, wing.cleared(), wing.events.len());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    #[cfg(feature = "raids")]
+    pub fn get_completion<C>(client: &C) -> C::Output<Vec<RaidCompletion>>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let (cleared, raids) =
+                future::try_join(Self::get(&client), Raid::get_all(&client)).await?;
+
+            Ok(cleared.completion(&raids))
+        })
     }
 }
 
@@ -1355,14 +2930,85 @@ impl AccountRaids {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AccountRecipes(pub Vec<u64>);
 
+collection!(AccountRecipes(u64));
+
 impl AccountRecipes {
     const URI: &'static str = "/v2/account/recipes";
 
+    permissions!(Account, Unlocks);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+}
+
+/// A list of skiffs unlocked by an account.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountSkiffs(pub Vec<u64>);
+
+collection!(AccountSkiffs(u64));
+
+impl AccountSkiffs {
+    const URI: &'static str = "/v2/account/skiffs";
+
+    permissions!(Account, Unlocks);
+
+    /// Returns a list of skiffs unlocked by the currently authenticated account.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// When authenticated it returns a list of skiffs unlocked by the account of the current
+    /// access token.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::account::AccountSkiffs;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let skiffs = AccountSkiffs::get(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::account::AccountSkiffs;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let skiffs = AccountSkiffs::get(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
@@ -1370,14 +3016,186 @@ impl AccountRecipes {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AccountSkins(pub Vec<u64>);
 
+collection!(AccountSkins(u64));
+
 impl AccountSkins {
     const URI: &'static str = "/v2/account/skins";
 
+    permissions!(Account, Unlocks);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+}
+
+/// A filter over the skins of the wardrobe used to find skins not yet unlocked by an account.
+///
+/// By default all skins shown in the wardrobe are included. Each filter further restricts the
+/// skins to the ones matching it.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use gw2api_rs::{Client, Result};
+/// # use gw2api_rs::v2::account::Wardrobe;
+/// # use gw2api_rs::v2::common::WeightClass;
+/// # use gw2api_rs::v2::skins::SkinKind;
+/// #
+/// # async fn run() -> Result<()> {
+/// # let token = "";
+/// let client: Client = Client::builder().access_token(token).into();
+/// let missing = Wardrobe::new()
+///     .kind(SkinKind::Armor)
+///     .weight_class(WeightClass::Heavy)
+///     .missing(&client)
+///     .await?;
+///
+/// for skin in missing {
+///     println!("{}", skin.name);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Using the [`blocking`] client:
+///
+/// ```no_run
+/// # use gw2api_rs::Result;
+/// # use gw2api_rs::blocking::Client;
+/// # use gw2api_rs::v2::account::Wardrobe;
+/// # use gw2api_rs::v2::common::WeightClass;
+/// # use gw2api_rs::v2::skins::SkinKind;
+/// #
+/// # fn run() -> Result<()> {
+/// # let token = "";
+/// let client: Client = Client::builder().access_token(token).into();
+/// let missing = Wardrobe::new()
+///     .kind(SkinKind::Armor)
+///     .weight_class(WeightClass::Heavy)
+///     .missing(&client)?;
+///
+/// for skin in missing {
+///     println!("{}", skin.name);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`blocking`]: crate::blocking
+#[cfg(feature = "skins")]
+#[derive(Clone, Debug, Default)]
+pub struct Wardrobe {
+    set: Option<Vec<u64>>,
+    kind: Option<SkinKind>,
+    weight_class: Option<WeightClass>,
+    detail_type: Option<String>,
+}
+
+#[cfg(feature = "skins")]
+impl Wardrobe {
+    /// The maximum number of concurrent requests used to fetch the missing skins.
+    const MAX_CONCURRENT_REQUESTS: usize = 8;
+
+    /// Creates a new `Wardrobe` including all skins.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only includes the skins with the given `ids`, e.g. the pieces of an armor set.
+    pub fn set(mut self, ids: &[u64]) -> Self {
+        self.set = Some(ids.to_vec());
+        self
+    }
+
+    /// Only includes skins of the given `kind`.
+    #[inline]
+    pub fn kind(mut self, kind: SkinKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// Only includes armor skins of the given `weight_class`.
+    #[inline]
+    pub fn weight_class(mut self, weight_class: WeightClass) -> Self {
+        self.weight_class = Some(weight_class);
+        self
+    }
+
+    /// Only includes skins whose [`SkinDetails`] have the given `type`, e.g. `Coat` or
+    /// `Greatsword`.
+    ///
+    /// [`SkinDetails`]: crate::v2::skins::SkinDetails
+    pub fn detail_type<T>(mut self, kind: T) -> Self
+    where
+        T: ToString,
+    {
+        self.detail_type = Some(kind.to_string());
+        self
+    }
+
+    /// Returns `true` if the `skin` is shown in the wardrobe and matches all filters.
+    pub fn matches(&self, skin: &Skin) -> bool {
+        let detail_type = skin.details.as_ref().map(|details| details.kind.as_str());
+
+        skin.flags.iter().any(|flag| flag == "ShowInWardrobe")
+            && self.set.as_ref().map_or(true, |set| set.contains(&skin.id))
+            && self.kind.map_or(true, |kind| skin.kind == kind)
+            && self.weight_class.map_or(true, |weight_class| {
+                skin.weight_class() == Some(weight_class)
+            })
+            && self
+                .detail_type
+                .as_deref()
+                .map_or(true, |kind| detail_type == Some(kind))
+    }
+
+    /// Returns all skins matching the filters which are not unlocked by the currently
+    /// authenticated account.
+    ///
+    /// The ids of all skins are compared with the [`AccountSkins`] of the account. The missing
+    /// skins are then requested in chunks of at most 200 ids, with at most 8 requests in flight at
+    /// the same time. If a [`set`] is given, only its skins are requested.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// [`set`]: Self::set
+    /// [`Error`]: struct@crate::Error
+    pub fn missing<C>(&self, client: &C) -> C::Output<Vec<Skin>>
+    where
+        C: Executor,
+    {
+        let filter = self.clone();
+
+        client.execute(|client| async move {
+            let unlocked: HashSet<u64> = AccountSkins::get(&client).await?.into_iter().collect();
+
+            let ids = match &filter.set {
+                Some(set) => set.clone(),
+                None => Skin::ids(&client).await?,
+            };
+            let ids: Vec<u64> = ids
+                .into_iter()
+                .filter(|id| !unlocked.contains(id))
+                .collect();
+
+            let skins: Vec<Skin> =
+                bulk::get_chunked_limited(&client, Skin::URI, &ids, Self::MAX_CONCURRENT_REQUESTS)
+                    .await?;
+            Ok(skins
+                .into_iter()
+                .filter(|skin| filter.matches(skin))
+                .collect())
+        })
     }
 }
 
@@ -1385,14 +3203,22 @@ impl AccountSkins {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AccountTitles(pub Vec<u64>);
 
+collection!(AccountTitles(u64));
+
 impl AccountTitles {
     const URI: &'static str = "/v2/account/titles";
 
+    permissions!(Account, Unlocks);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
@@ -1400,19 +3226,28 @@ impl AccountTitles {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AccountWallet(pub Vec<AccountCurrency>);
 
+collection!(AccountWallet(AccountCurrency));
+
 impl AccountWallet {
     const URI: &'static str = "/v2/account/wallet";
 
+    permissions!(Account, Wallet);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
 /// A currency in an account's wallet.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct AccountCurrency {
     /// The id of the currency.
     pub id: u64,
@@ -1424,13 +3259,334 @@ pub struct AccountCurrency {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AccountWorldBosses(pub Vec<String>);
 
+collection!(AccountWorldBosses(String));
+
 impl AccountWorldBosses {
     const URI: &'static str = "/v2/account/worldbosses";
 
+    permissions!(Account, Progression);
+
     pub fn get<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns the time of the next daily reset, at which the completed world bosses are cleared.
+    /// Responses of this endpoint can be cached until then.
+    ///
+    /// See the [`reset`] module for details.
+    #[inline]
+    pub fn expires_at() -> DateTime {
+        reset::next_daily_reset()
+    }
+}
+
+/// The WvW information of an account.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct AccountWvw {
+    /// The id of the WvW team the account is currently assigned to. This is `None` if the
+    /// account is not assigned to a team.
+    pub team: Option<u64>,
+    /// The WvW rank of the account. Only avaliable in newer schema versions.
+    #[serde(default)]
+    pub rank: Option<u64>,
+}
+
+impl AccountWvw {
+    const URI: &'static str = "/v2/account/wvw";
+
+    permissions!(Account);
+
+    /// Returns the WvW information of the currently authenticated account.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// When authenticated it returns the WvW information of the account of the current access
+    /// token.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::account::AccountWvw;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let wvw = AccountWvw::get(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::account::AccountWvw;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let wvw = AccountWvw::get(&client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+}
+
+/// An account endpoint listing unlocks that can be joined with the static metadata of the
+/// unlocks.
+///
+/// This is implemented by the account endpoints listing unlocked dyes, emotes, finishers, gliders,
+/// home cats and nodes, homestead decorations and glyphs, jade bots, minis, mounts, novelties,
+/// outfits, PvP hero skins, skiffs, skins and titles.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use gw2api_rs::{Client, Result};
+/// # use gw2api_rs::v2::account::{AccountTitles, ResolveUnlocks};
+/// #
+/// # async fn run() -> Result<()> {
+/// # let token = "";
+/// let client: Client = Client::builder().access_token(token).into();
+/// let titles = AccountTitles::resolve_unlocks(&client).await?;
+///
+/// for title in &titles {
+///     println!("{}", title.name);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Using the [`blocking`] client:
+///
+/// ```no_run
+/// # use gw2api_rs::Result;
+/// # use gw2api_rs::blocking::Client;
+/// # use gw2api_rs::v2::account::{AccountTitles, ResolveUnlocks};
+/// #
+/// # fn run() -> Result<()> {
+/// # let token = "";
+/// let client: Client = Client::builder().access_token(token).into();
+/// let titles = AccountTitles::resolve_unlocks(&client)?;
+///
+/// for title in &titles {
+///     println!("{}", title.name);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`blocking`]: crate::blocking
+pub trait ResolveUnlocks {
+    /// The static metadata of an unlock.
+    type Item;
+    /// The id of an unlock.
+    type Id;
+
+    /// Returns the unlocks of the currently authenticated account joined with their metadata.
+    ///
+    /// The metadata is requested in chunks of at most 200 ids, with at most 8 chunks requested
+    /// concurrently.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// [`Error`]: struct@crate::Error
+    fn resolve_unlocks<C>(client: &C) -> C::Output<Unlocked<Self::Item, Self::Id>>
+    where
+        C: Executor;
+}
+
+/// The maximum number of concurrent requests used to fetch the metadata of unlocks.
+const MAX_CONCURRENT_UNLOCK_REQUESTS: usize = 8;
+
+// Unlocks which are objects instead of plain ids name the field holding the id, e.g. `.id`.
+macro_rules! resolve_unlocks {
+    ($($feature:literal: $target:ty => $item:ty, $id:ty $(, .$field:ident)?;)*) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl ResolveUnlocks for $target {
+                type Item = $item;
+                type Id = $id;
+
+                fn resolve_unlocks<C>(client: &C) -> C::Output<Unlocked<$item, $id>>
+                where
+                    C: Executor,
+                {
+                    client.execute(|client| async move {
+                        let unlocks = Self::get(&client).await?;
+                        let ids: Vec<$id> = unlocks
+                            .0
+                            .into_iter()
+                            .map(|unlock| unlock$(.$field)?)
+                            .collect();
+                        let items: Vec<$item> = bulk::get_chunked_limited(
+                            &client,
+                            <$item>::URI,
+                            &ids,
+                            MAX_CONCURRENT_UNLOCK_REQUESTS,
+                        )
+                        .await?;
+
+                        Ok(Unlocked::new(ids, items, |item| &item.id))
+                    })
+                }
+            }
+        )*
+    };
+}
+
+resolve_unlocks! {
+    "colors": AccountDyes => Color, u64;
+    "emotes": AccountEmotes => Emote, String;
+    "finishers": AccountFinishers => Finisher, u64, .id;
+    "gliders": AccountGliders => Glider, u64;
+    "home": AccountHomeCats => Cat, u64;
+    "home": AccountHomeNodes => Node, String;
+    "homestead": AccountHomesteadDecorations => Decoration, u64, .id;
+    "homestead": AccountHomesteadGlyphs => Glyph, String;
+    "jadebots": AccountJadeBots => JadeBot, u64;
+    "minis": AccountMinis => Mini, u64;
+    "mounts": AccountMountTypes => Mount, MountType;
+    "novelties": AccountNovelties => Novelty, u64;
+    "outfits": AccountOutfits => Outfit, u64;
+    "skiffs": AccountSkiffs => Skiff, u64;
+    "skins": AccountSkins => Skin, u64;
+    "titles": AccountTitles => Title, u64;
+}
+
+/// Hero skins are not available from a dedicated endpoint and are resolved from all [`Hero`]s.
+#[cfg(feature = "pvp")]
+impl ResolveUnlocks for AccountPvPHeroes {
+    type Item = HeroSkin;
+    type Id = u64;
+
+    fn resolve_unlocks<C>(client: &C) -> C::Output<Unlocked<HeroSkin, u64>>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let (unlocks, heroes) =
+                future::try_join(Self::get(&client), Hero::get_all(&client)).await?;
+
+            let mut skins: HashMap<u64, HeroSkin> = heroes
+                .into_iter()
+                .flat_map(|hero: Hero| hero.skins)
+                .map(|skin| (skin.id, skin))
+                .collect();
+
+            let items = unlocks.0.iter().filter_map(|id| skins.remove(id)).collect();
+
+            Ok(Unlocked::new(unlocks.0, items, |skin| &skin.id))
+        })
+    }
+}
+
+/// The unlocks of an account joined with their static metadata.
+///
+/// Returned by [`ResolveUnlocks::resolve_unlocks`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Unlocked<T, I = u64> {
+    /// The metadata of all unlocks, in the order of the unlocks.
+    pub items: Vec<T>,
+    /// The ids of unlocks whose metadata could not be found.
+    pub missing: Vec<I>,
+}
+
+impl<T, I> Unlocked<T, I>
+where
+    I: Hash + Eq,
+{
+    fn new<F>(ids: Vec<I>, mut items: Vec<T>, id: F) -> Self
+    where
+        F: Fn(&T) -> &I,
+    {
+        let mut found = vec![false; ids.len()];
+        {
+            let order: HashMap<&I, usize> = ids.iter().enumerate().map(|(i, id)| (id, i)).collect();
+
+            for item in &items {
+                if let Some(&index) = order.get(id(item)) {
+                    found[index] = true;
+                }
+            }
+
+            // The metadata is returned in an unspecified order.
+            items.sort_by_key(|item| order.get(id(item)).copied().unwrap_or(usize::MAX));
+        }
+
+        let missing = ids
+            .into_iter()
+            .zip(found)
+            .filter(|(_, found)| !found)
+            .map(|(id, _)| id)
+            .collect();
+
+        Self { items, missing }
+    }
+}
+
+impl<T, I> Unlocked<T, I> {
+    /// Returns the number of unlocks with metadata.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if there are no unlocks with metadata.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns an iterator over the metadata of all unlocks.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+}
+
+impl<T, I> IntoIterator for Unlocked<T, I> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T, I> IntoIterator for &'a Unlocked<T, I> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
     }
 }
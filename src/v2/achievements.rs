@@ -1,7 +1,14 @@
-use crate::endpoint;
+use std::collections::HashMap;
+
+use futures_util::future;
 use serde::{Deserialize, Serialize};
 
+use crate::v2::account::{Account, AccountAccess, AccountAchievement, AccountAchievements};
+use crate::v2::characters::CharacterCore;
+use crate::{bulk, endpoint, Authentication, ClientExecutor, Executor, Language, RequestBuilder};
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Achievement {
     pub id: u64,
     pub icon: Option<String>,
@@ -29,6 +36,7 @@ pub enum AchievementKind {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct AchievementTier {
     pub count: u64,
     pub points: u64,
@@ -52,4 +60,834 @@ pub enum AchievementBit {
     Skin { id: u64 },
 }
 
-endpoint!(Achievement, "/v2/achievements", u64);
+impl Achievement {
+    /// Returns `true` if the achievement can be completed repeatedly.
+    #[inline]
+    pub fn is_repeatable(&self) -> bool {
+        self.flags.iter().any(|flag| flag == "Repeatable")
+    }
+
+    /// Returns the number of points awarded by completing all tiers once.
+    pub fn tier_points(&self) -> u64 {
+        self.tiers.iter().map(|tier| tier.points).sum()
+    }
+
+    /// Returns the number of points earned with the given `progress` of an account.
+    ///
+    /// A tier awards its points once the progress reaches the count of the tier. Every repeated
+    /// completion of a repeatable achievement awards the points of all tiers again, until the
+    /// [`point_cap`] is reached.
+    ///
+    /// [`point_cap`]: Self::point_cap
+    pub fn points(&self, progress: &AccountAchievement) -> u64 {
+        let repeatable = self.is_repeatable();
+        let current = progress.current.unwrap_or(0);
+
+        let mut points: u64 = self
+            .tiers
+            .iter()
+            .filter(|tier| (progress.done && !repeatable) || current >= tier.count)
+            .map(|tier| tier.points)
+            .sum();
+
+        if repeatable {
+            points += progress.repeated.unwrap_or(0) * self.tier_points();
+
+            if let Some(cap) = self.point_cap {
+                points = points.min(cap);
+            }
+        }
+
+        points
+    }
+}
+
+endpoint!(Achievement, "/v2/achievements", u64, localized);
+
+/// The achievement points of an account.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct AchievementPoints {
+    /// The points earned from [`Achievement`]s.
+    pub achievements: u64,
+    /// The points earned from daily achievements.
+    pub daily: u64,
+    /// The points earned from monthly achievements.
+    pub monthly: u64,
+}
+
+impl AchievementPoints {
+    /// The maximum number of concurrent requests used to fetch the achievements.
+    const MAX_CONCURRENT_REQUESTS: usize = 8;
+
+    /// Calculates the achievement points of an `account` with the given `progress`.
+    ///
+    /// Progress of achievements not contained in `achievements` is ignored. The daily and monthly
+    /// points are only avaliable if the [`Account`] was requested with the `progression` scope.
+    pub fn new(
+        account: &Account,
+        progress: &AccountAchievements,
+        achievements: &[Achievement],
+    ) -> Self {
+        let achievements: HashMap<u64, &Achievement> = achievements
+            .iter()
+            .map(|achievement| (achievement.id, achievement))
+            .collect();
+
+        let points = progress
+            .0
+            .iter()
+            .filter_map(|progress| {
+                let achievement = achievements.get(&progress.id)?;
+                Some(achievement.points(progress))
+            })
+            .sum();
+
+        Self {
+            achievements: points,
+            daily: account.daily_ap.map(u64::from).unwrap_or(0),
+            monthly: account.monthly_ap.map(u64::from).unwrap_or(0),
+        }
+    }
+
+    /// Returns the total number of achievement points.
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.achievements + self.daily + self.monthly
+    }
+
+    /// Returns the achievement points of the currently authenticated account.
+    ///
+    /// This fetches the [`Account`], the [`AccountAchievements`] and all [`Achievement`]s with
+    /// progress of the account and combines them as described in [`new`].
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// The access token requires the `progression` scope.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::achievements::AchievementPoints;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let points = AchievementPoints::get(&client).await?;
+    /// println!("{} AP", points.total());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::achievements::AchievementPoints;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let points = AchievementPoints::get(&client)?;
+    /// println!("{} AP", points.total());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`new`]: Self::new
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C) -> C::Output<Self>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let (account, progress) =
+                future::try_join(Account::get(&client), AccountAchievements::get(&client)).await?;
+
+            let ids: Vec<u64> = progress.0.iter().map(|progress| progress.id).collect();
+            let achievements: Vec<Achievement> = bulk::get_chunked_limited(
+                &client,
+                Achievement::URI,
+                &ids,
+                Self::MAX_CONCURRENT_REQUESTS,
+            )
+            .await?;
+
+            Ok(Self::new(&account, &progress, &achievements))
+        })
+    }
+}
+
+/// A category grouping [`Achievement`]s.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct AchievementCategory {
+    /// The id of the category.
+    pub id: u64,
+    /// The name of the category.
+    pub name: String,
+    /// The description of the category.
+    pub description: String,
+    /// The sort order of the category.
+    pub order: u64,
+    /// A url pointing to the icon of the category.
+    pub icon: String,
+    /// The ids of all [`Achievement`]s in the category.
+    pub achievements: Vec<u64>,
+}
+
+impl AchievementCategory {
+    /// The id of the category containing the daily fractal achievements.
+    pub const DAILY_FRACTALS: u64 = 88;
+}
+
+endpoint!(
+    AchievementCategory,
+    "/v2/achievements/categories",
+    u64,
+    localized,
+    get_all
+);
+
+/// A group of [`AchievementCategory`]s, e.g. `Heart of Thorns`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct AchievementGroup {
+    /// The id of the group.
+    pub id: String,
+    /// The name of the group.
+    pub name: String,
+    /// The description of the group.
+    pub description: String,
+    /// The sort order of the group.
+    pub order: u64,
+    /// The ids of all [`AchievementCategory`]s in the group.
+    pub categories: Vec<u64>,
+}
+
+endpoint!(
+    AchievementGroup,
+    "/v2/achievements/groups",
+    String,
+    localized,
+    get_all
+);
+
+/// All [`AchievementGroup`]s with their categories and achievements, as shown in the achievement
+/// panel of the game.
+///
+/// Groups and categories are ordered by their sort order, achievements in the order listed by
+/// their category.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct AchievementTree {
+    /// All groups of the tree.
+    pub groups: Vec<AchievementTreeGroup>,
+}
+
+/// An [`AchievementGroup`] in an [`AchievementTree`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct AchievementTreeGroup {
+    /// The group.
+    pub group: AchievementGroup,
+    /// All categories of the group.
+    pub categories: Vec<AchievementTreeCategory>,
+}
+
+/// An [`AchievementCategory`] in an [`AchievementTree`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct AchievementTreeCategory {
+    /// The category.
+    pub category: AchievementCategory,
+    /// All achievements of the category.
+    pub achievements: Vec<Achievement>,
+}
+
+impl AchievementTreeCategory {
+    /// Returns the icon of the given `achievement`, falling back to the icon of the category for
+    /// achievements without an icon.
+    pub fn icon<'a>(&'a self, achievement: &'a Achievement) -> &'a str {
+        achievement.icon.as_deref().unwrap_or(&self.category.icon)
+    }
+}
+
+impl AchievementTree {
+    /// The maximum number of concurrent requests used to fetch the achievements.
+    const MAX_CONCURRENT_REQUESTS: usize = 8;
+
+    /// Creates a new `AchievementTree` from all `groups`, `categories` and `achievements`.
+    ///
+    /// Categories not contained in any group and ids not found in `categories` or
+    /// `achievements` are omitted.
+    pub fn new(
+        groups: Vec<AchievementGroup>,
+        categories: Vec<AchievementCategory>,
+        achievements: Vec<Achievement>,
+    ) -> Self {
+        let mut categories: HashMap<u64, AchievementCategory> = categories
+            .into_iter()
+            .map(|category| (category.id, category))
+            .collect();
+        let achievements: HashMap<u64, Achievement> = achievements
+            .into_iter()
+            .map(|achievement| (achievement.id, achievement))
+            .collect();
+
+        let mut groups: Vec<AchievementTreeGroup> = groups
+            .into_iter()
+            .map(|group| {
+                let mut categories: Vec<AchievementTreeCategory> = group
+                    .categories
+                    .iter()
+                    .filter_map(|id| categories.remove(id))
+                    .map(|category| AchievementTreeCategory {
+                        achievements: category
+                            .achievements
+                            .iter()
+                            .filter_map(|id| achievements.get(id).cloned())
+                            .collect(),
+                        category,
+                    })
+                    .collect();
+                categories.sort_by_key(|category| category.category.order);
+
+                AchievementTreeGroup { group, categories }
+            })
+            .collect();
+        groups.sort_by_key(|group| group.group.order);
+
+        Self { groups }
+    }
+
+    /// Returns the tree of all achievements.
+    ///
+    /// All groups and categories are fetched concurrently, followed by all achievements listed
+    /// in the categories. Achievements are fetched in chunks with at most 8 requests in flight
+    /// at the same time.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::achievements::AchievementTree;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let tree = AchievementTree::build(&client).await?;
+    ///
+    /// for group in &tree.groups {
+    ///     println!("{}", group.group.name);
+    ///     for category in &group.categories {
+    ///         println!("  {} ({})", category.category.name, category.achievements.len());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::achievements::AchievementTree;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let tree = AchievementTree::build(&client)?;
+    /// println!("{} groups", tree.groups.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`blocking`]: crate::blocking
+    pub fn build<C>(client: &C) -> C::Output<Self>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let (groups, categories) = future::try_join(
+                AchievementGroup::get_all(&client),
+                AchievementCategory::get_all(&client),
+            )
+            .await?;
+
+            let mut ids: Vec<u64> = categories
+                .iter()
+                .flat_map(|category| category.achievements.iter().copied())
+                .collect();
+            ids.sort_unstable();
+            ids.dedup();
+
+            let achievements = bulk::get_chunked_limited(
+                &client,
+                Achievement::URI,
+                &ids,
+                Self::MAX_CONCURRENT_REQUESTS,
+            )
+            .await?;
+
+            Ok(Self::new(groups, categories, achievements))
+        })
+    }
+
+    /// Returns the category with the given `id`.
+    pub fn category(&self, id: u64) -> Option<&AchievementTreeCategory> {
+        self.groups
+            .iter()
+            .flat_map(|group| &group.categories)
+            .find(|category| category.category.id == id)
+    }
+
+    /// Returns the group containing the category with the given `category_id`.
+    pub fn group_of(&self, category_id: u64) -> Option<&AchievementTreeGroup> {
+        self.groups.iter().find(|group| {
+            group
+                .categories
+                .iter()
+                .any(|category| category.category.id == category_id)
+        })
+    }
+
+    /// Returns the achievement with the given `id` together with the first category containing
+    /// it.
+    pub fn achievement(&self, id: u64) -> Option<(&AchievementTreeCategory, &Achievement)> {
+        self.groups
+            .iter()
+            .flat_map(|group| &group.categories)
+            .find_map(|category| {
+                category
+                    .achievements
+                    .iter()
+                    .find(|achievement| achievement.id == id)
+                    .map(|achievement| (category, achievement))
+            })
+    }
+}
+
+/// The daily achievements of the current day, grouped by game mode.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct DailyAchievements {
+    pub pve: Vec<DailyAchievement>,
+    pub pvp: Vec<DailyAchievement>,
+    pub wvw: Vec<DailyAchievement>,
+    pub fractals: Vec<DailyAchievement>,
+    pub special: Vec<DailyAchievement>,
+}
+
+impl DailyAchievements {
+    const URI: &'static str = "/v2/achievements/daily";
+
+    /// Returns the daily achievements of the current day.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint optionally uses authentication. If an access token is set, the dailies are
+    /// filtered by the access of the account of the access token. Use [`for_account`] to also
+    /// filter by the levels of the characters.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::achievements::DailyAchievements;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let dailies = DailyAchievements::get(&client).await?;
+    /// println!("{:?}", dailies);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::achievements::DailyAchievements;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let dailies = DailyAchievements::get(&client)?;
+    /// println!("{:?}", dailies);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`for_account`]: Self::for_account
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(RequestBuilder::new(Self::URI).authenticated(Authentication::Optional))
+    }
+
+    /// Returns the daily achievements of the current day that are available to the currently
+    /// authenticated account.
+    ///
+    /// This fetches the [`DailyAchievements`], the [`Account`] and all characters of the account
+    /// concurrently and removes all dailies whose [`RequiredAccess`] is not met by the account or
+    /// whose [`LevelRange`] does not contain the highest level of all characters of the account.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::achievements::DailyAchievements;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let dailies = DailyAchievements::for_account(&client).await?;
+    ///
+    /// for daily in dailies.pve {
+    ///     println!("{}", daily.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::achievements::DailyAchievements;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let dailies = DailyAchievements::for_account(&client)?;
+    ///
+    /// for daily in dailies.pve {
+    ///     println!("{}", daily.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn for_account<C>(client: &C) -> C::Output<Self>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let (dailies, account, characters) = future::try_join3(
+                Self::get(&client),
+                Account::get(&client),
+                CharacterCore::get_all(&client),
+            )
+            .await?;
+
+            let level = characters
+                .iter()
+                .map(|character| character.level)
+                .max()
+                .unwrap_or(0);
+
+            Ok(dailies.filter(&account.access, level))
+        })
+    }
+
+    /// Removes all dailies that are not available to an account with the given `access` and a
+    /// character with the given `level`.
+    pub fn filter(mut self, access: &AccountAccess, level: u8) -> Self {
+        for dailies in [
+            &mut self.pve,
+            &mut self.pvp,
+            &mut self.wvw,
+            &mut self.fractals,
+            &mut self.special,
+        ] {
+            dailies.retain(|daily| daily.is_available(access, level));
+        }
+
+        self
+    }
+}
+
+/// A single daily achievement.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct DailyAchievement {
+    /// The id of the [`Achievement`].
+    pub id: u64,
+    /// The range of character levels required for the daily to be visible.
+    pub level: LevelRange,
+    /// The access required for the daily to be visible. If `None` the daily is visible to all
+    /// accounts.
+    pub required_access: Option<RequiredAccess>,
+}
+
+impl DailyAchievement {
+    /// Returns `true` if the daily is visible for an account with the given `access` and a
+    /// character with the given `level`.
+    pub fn is_available(&self, access: &AccountAccess, level: u8) -> bool {
+        let has_access = match &self.required_access {
+            Some(required_access) => required_access.is_met(access),
+            None => true,
+        };
+
+        has_access && self.level.contains(level)
+    }
+}
+
+/// An inclusive range of character levels.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct LevelRange {
+    /// The minimum level.
+    pub min: u8,
+    /// The maximum level.
+    pub max: u8,
+}
+
+impl LevelRange {
+    /// Returns `true` if `level` is contained in the range.
+    #[inline]
+    pub fn contains(&self, level: u8) -> bool {
+        self.min <= level && level <= self.max
+    }
+}
+
+/// The access to a product required for a [`DailyAchievement`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct RequiredAccess {
+    /// The product the condition refers to.
+    pub product: Product,
+    /// Whether the account must or must not have access to the `product`.
+    pub condition: AccessCondition,
+}
+
+impl RequiredAccess {
+    /// Returns `true` if an account with the given `access` meets the requirement.
+    ///
+    /// Requirements on a [`Product::Unknown`] are never met.
+    pub fn is_met(&self, access: &AccountAccess) -> bool {
+        let has_access = match self.product {
+            Product::GuildWars2 => access.guild_wars_2(),
+            Product::HeartOfThorns => access.heart_of_thorns(),
+            Product::PathOfFire => access.path_of_fire(),
+            Product::EndOfDragons => access.end_of_dragons(),
+            Product::Unknown => return false,
+        };
+
+        match self.condition {
+            AccessCondition::HasAccess => has_access,
+            AccessCondition::NoAccess => !has_access,
+        }
+    }
+}
+
+/// A game product.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Product {
+    GuildWars2,
+    HeartOfThorns,
+    PathOfFire,
+    EndOfDragons,
+    /// A product unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The condition of a [`RequiredAccess`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AccessCondition {
+    /// The account must have access to the product.
+    HasAccess,
+    /// The account must not have access to the product.
+    NoAccess,
+}
+
+/// The daily fractals of the current day.
+///
+/// This is resolved from the fractal dailies of [`DailyAchievements`] by parsing the names of
+/// the daily achievements, e.g. `Daily Tier 4 Deepstone` or
+/// `Daily Recommended Fractal—Scale 47`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct DailyFractals {
+    /// The daily fractals for each tier.
+    pub tiers: Vec<DailyFractal>,
+    /// The recommended fractal scales.
+    pub recommended: Vec<RecommendedFractal>,
+}
+
+impl DailyFractals {
+    /// Returns the daily fractals of the current day.
+    ///
+    /// This fetches the [`DailyAchievements`] and the [`AchievementCategory`] of daily fractals
+    /// concurrently, then fetches the names of all fractal dailies contained in the category.
+    /// Achievements are always requested in [`Language::En`], regardless of the language of the
+    /// client, since the names are parsed. Dailies with an unrecognized name are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::achievements::DailyFractals;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let fractals = DailyFractals::get(&client).await?;
+    ///
+    /// for fractal in fractals.tiers {
+    ///     println!("T{} {}", fractal.tier, fractal.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::achievements::DailyFractals;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let fractals = DailyFractals::get(&client)?;
+    ///
+    /// for fractal in fractals.tiers {
+    ///     println!("T{} {}", fractal.tier, fractal.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C) -> C::Output<Self>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let (dailies, category) = future::try_join(
+                DailyAchievements::get(&client),
+                AchievementCategory::get(&client, AchievementCategory::DAILY_FRACTALS),
+            )
+            .await?;
+
+            let ids: Vec<u64> = dailies
+                .fractals
+                .iter()
+                .map(|daily| daily.id)
+                .filter(|id| category.achievements.contains(id))
+                .collect();
+
+            if ids.is_empty() {
+                return Ok(Self {
+                    tiers: Vec::new(),
+                    recommended: Vec::new(),
+                });
+            }
+
+            let achievements: Vec<Achievement> =
+                Achievement::get_many_localized(&client, &ids, Language::En).await?;
+
+            Ok(Self::from_achievements(&achievements))
+        })
+    }
+
+    /// Creates a new `DailyFractals` from the given daily fractal `achievements`. Achievements
+    /// with a name that is not recognized as a daily fractal are skipped.
+    ///
+    /// The names of the `achievements` must be in [`Language::En`].
+    pub fn from_achievements(achievements: &[Achievement]) -> Self {
+        let mut tiers = Vec::new();
+        let mut recommended = Vec::new();
+
+        for achievement in achievements {
+            if let Some(fractal) = DailyFractal::parse(achievement) {
+                tiers.push(fractal);
+            } else if let Some(fractal) = RecommendedFractal::parse(achievement) {
+                recommended.push(fractal);
+            }
+        }
+
+        Self { tiers, recommended }
+    }
+
+    /// Returns the names of all daily fractals, without duplicates, in the order they first
+    /// appear in [`tiers`].
+    ///
+    /// [`tiers`]: Self::tiers
+    pub fn fractals(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+
+        for fractal in &self.tiers {
+            if !names.contains(&fractal.name.as_str()) {
+                names.push(&fractal.name);
+            }
+        }
+
+        names
+    }
+}
+
+/// The daily fractal of a single tier.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct DailyFractal {
+    /// The id of the daily [`Achievement`].
+    pub achievement_id: u64,
+    /// The fractal tier, from `1` to `4`.
+    pub tier: u8,
+    /// The name of the fractal, e.g. `Deepstone`.
+    pub name: String,
+}
+
+impl DailyFractal {
+    fn parse(achievement: &Achievement) -> Option<Self> {
+        let (tier, name) = achievement
+            .name
+            .strip_prefix("Daily Tier ")?
+            .split_once(' ')?;
+
+        Some(Self {
+            achievement_id: achievement.id,
+            tier: tier.parse().ok()?,
+            name: name.trim().to_owned(),
+        })
+    }
+}
+
+/// A recommended fractal scale of the day.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct RecommendedFractal {
+    /// The id of the daily [`Achievement`].
+    pub achievement_id: u64,
+    /// The recommended fractal scale.
+    pub scale: u8,
+}
+
+impl RecommendedFractal {
+    fn parse(achievement: &Achievement) -> Option<Self> {
+        let (_, scale) = achievement
+            .name
+            .strip_prefix("Daily Recommended Fractal")?
+            .rsplit_once("Scale ")?;
+
+        Some(Self {
+            achievement_id: achievement.id,
+            scale: scale.trim().parse().ok()?,
+        })
+    }
+}
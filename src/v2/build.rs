@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 /// The current build id of the game.
 #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Build {
     pub id: u64,
 }
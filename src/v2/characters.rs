@@ -0,0 +1,413 @@
+use serde::{Deserialize, Serialize};
+
+use crate::datetime::DateTime;
+pub use crate::v2::common::Profession;
+use crate::v2::common::{Discipline, Gender, Race};
+use crate::{collection, permissions, Authentication, ClientExecutor, RequestBuilder};
+
+/// Core information about a character.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct CharacterCore {
+    /// The name of the character.
+    pub name: String,
+    /// The race of the character.
+    pub race: Race,
+    /// The gender of the character.
+    pub gender: Gender,
+    /// The profession of the character.
+    pub profession: Profession,
+    /// The level of the character.
+    pub level: u8,
+    /// The id of the guild the character is currently representing.
+    pub guild: Option<String>,
+    /// The amount of seconds the character has been played.
+    pub age: u64,
+    /// The character creation date.
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub created: DateTime,
+    /// The number of times the character has died.
+    pub deaths: u64,
+    /// The id of the currently selected title of the character.
+    pub title: Option<u64>,
+}
+
+impl CharacterCore {
+    const URI: &'static str = "/v2/characters";
+
+    permissions!(Account, Characters);
+
+    /// Returns the core information about the character with the given `name`.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// If the character does not belong to the account of the current access token, an [`Error`]
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::characters::CharacterCore;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let character = CharacterCore::get(&client, "Example Character").await?;
+    /// println!("{} is level {}", character.name, character.level);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::characters::CharacterCore;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let character = CharacterCore::get(&client, "Example Character")?;
+    /// println!("{} is level {}", character.name, character.level);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C, name: &str) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .segment(name)
+                .segment("core")
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns the core information about all characters of the currently authenticated
+    /// account.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// [`Error`]: struct@crate::Error
+    pub fn get_all<C>(client: &C) -> C::Result
+    where
+        C: ClientExecutor<Vec<Self>>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .query("ids", "all")
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns the names of all characters of the currently authenticated account.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// [`Error`]: struct@crate::Error
+    pub fn ids<C>(client: &C) -> C::Result
+    where
+        C: ClientExecutor<Vec<String>>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+}
+
+/// The crafting disciplines of a character.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct CharacterCrafting {
+    /// The disciplines the character has learned.
+    pub crafting: Vec<CraftingDiscipline>,
+}
+
+impl CharacterCrafting {
+    const URI: &'static str = "/v2/characters";
+
+    permissions!(Account, Characters);
+
+    /// Returns the crafting disciplines of the character with the given `name`.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// If the character does not belong to the account of the current access token, an [`Error`]
+    /// is returned.
+    ///
+    /// [`Error`]: struct@crate::Error
+    pub fn get<C>(client: &C, name: &str) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .segment(name)
+                .segment("crafting")
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns the rating of the character in the given `discipline`. Returns `None` if the
+    /// character has not learned the discipline.
+    pub fn rating(&self, discipline: Discipline) -> Option<u16> {
+        self.crafting
+            .iter()
+            .find(|crafting| crafting.discipline == discipline)
+            .map(|crafting| crafting.rating)
+    }
+}
+
+/// A crafting discipline learned by a character.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct CraftingDiscipline {
+    /// The discipline.
+    pub discipline: Discipline,
+    /// The rating of the character in the discipline.
+    pub rating: u16,
+    /// Whether the discipline is currently active. Only active disciplines can be used for
+    /// crafting.
+    pub active: bool,
+}
+
+/// The quests completed by a character.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CharacterQuests(pub Vec<u64>);
+
+collection!(CharacterQuests(u64));
+
+impl CharacterQuests {
+    const URI: &'static str = "/v2/characters";
+
+    permissions!(Account, Characters, Progression);
+
+    /// Returns the ids of the quests completed by the character with the given `name`.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// If the character does not belong to the account of the current access token, an [`Error`]
+    /// is returned.
+    ///
+    /// [`Error`]: struct@crate::Error
+    pub fn get<C>(client: &C, name: &str) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .segment(name)
+                .segment("quests")
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+}
+
+/// The recipes discovered by a character.
+///
+/// Recipes learned from items are unlocked for the whole account and listed by
+/// [`AccountRecipes`] instead.
+///
+/// [`AccountRecipes`]: crate::v2::account::AccountRecipes
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct CharacterRecipes {
+    /// The ids of the discovered recipes.
+    pub recipes: Vec<u64>,
+}
+
+collection!(CharacterRecipes { recipes: u64 });
+
+impl CharacterRecipes {
+    const URI: &'static str = "/v2/characters";
+
+    permissions!(Account, Characters, Unlocks);
+
+    /// Returns the recipes discovered by the character with the given `name`.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// If the character does not belong to the account of the current access token, an [`Error`]
+    /// is returned.
+    ///
+    /// [`Error`]: struct@crate::Error
+    pub fn get<C>(client: &C, name: &str) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .segment(name)
+                .segment("recipes")
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+}
+
+/// The Super Adventure Box progress of a character.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct CharacterSab {
+    /// The zones completed by the character.
+    pub zones: Vec<SabZone>,
+    /// The upgrades unlocked by the character.
+    pub unlocks: Vec<SabUnlock>,
+    /// The songs unlocked by the character.
+    pub songs: Vec<SabSong>,
+}
+
+impl CharacterSab {
+    const URI: &'static str = "/v2/characters";
+
+    permissions!(Account, Characters, Progression);
+
+    /// Returns the Super Adventure Box progress of the character with the given `name`.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// If the character does not belong to the account of the current access token, an [`Error`]
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::characters::CharacterSab;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let sab = CharacterSab::get(&client, "Example Character").await?;
+    ///
+    /// for zone in sab.zones {
+    ///     println!("Completed W{}-{} ({:?})", zone.world, zone.zone, zone.mode);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::characters::CharacterSab;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let sab = CharacterSab::get(&client, "Example Character")?;
+    ///
+    /// for zone in sab.zones {
+    ///     println!("Completed W{}-{} ({:?})", zone.world, zone.zone, zone.mode);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C, name: &str) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .segment(name)
+                .segment("sab")
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns `true` if the character completed the `zone` of `world` in the given `mode`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gw2api_rs::v2::characters::{CharacterSab, SabMode, SabZone};
+    /// #
+    /// let sab = CharacterSab {
+    ///     zones: vec![SabZone { id: 1, mode: SabMode::Normal, world: 1, zone: 1 }],
+    ///     unlocks: Vec::new(),
+    ///     songs: Vec::new(),
+    /// };
+    ///
+    /// assert!(sab.is_completed(1, 1, SabMode::Normal));
+    /// assert!(!sab.is_completed(1, 1, SabMode::Tribulation));
+    /// ```
+    pub fn is_completed(&self, world: u8, zone: u8, mode: SabMode) -> bool {
+        self.zones.iter().any(|completed| {
+            completed.world == world && completed.zone == zone && completed.mode == mode
+        })
+    }
+}
+
+/// A Super Adventure Box zone completed by a character.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct SabZone {
+    /// The id of the zone.
+    pub id: u64,
+    /// The mode the zone was completed in.
+    pub mode: SabMode,
+    /// The world of the zone.
+    pub world: u8,
+    /// The number of the zone within its world.
+    pub zone: u8,
+}
+
+/// The difficulty mode of the Super Adventure Box.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SabMode {
+    Infantile,
+    Normal,
+    Tribulation,
+    /// A mode unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A Super Adventure Box upgrade unlocked by a character.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct SabUnlock {
+    /// The id of the upgrade.
+    pub id: u64,
+    /// The name of the upgrade, e.g. `chain_stick`.
+    pub name: String,
+}
+
+/// A Super Adventure Box song unlocked by a character.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct SabSong {
+    /// The id of the song.
+    pub id: u64,
+    /// The name of the song, e.g. `secret_song`.
+    pub name: String,
+}
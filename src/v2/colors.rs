@@ -1,8 +1,13 @@
-use serde::{Deserialize, Serialize};
+use std::fmt::{self, Formatter};
 
-use crate::endpoint;
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{endpoint, Executor};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Color {
     pub id: u64,
     pub name: String,
@@ -12,11 +17,106 @@ pub struct Color {
     pub metal: ArmorColor,
     pub fur: Option<ArmorColor>,
     pub item: Option<u64>,
-    pub categories: Vec<String>,
+    /// The categories of the color. This is `None` for colors without categories, e.g. the
+    /// default dye.
+    #[serde(
+        default,
+        serialize_with = "serialize_categories",
+        deserialize_with = "deserialize_categories"
+    )]
+    pub categories: Option<ColorCategories>,
+}
+
+impl Color {
+    /// Returns all colors matching the given `filter`.
+    ///
+    /// The API does not support filtering colors, so all colors are requested and filtered
+    /// locally.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::colors::{Color, ColorFilter, ColorHue, ColorMaterial};
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let filter = ColorFilter::new()
+    ///     .hue(ColorHue::Red)
+    ///     .material(ColorMaterial::Metal);
+    /// let colors = Color::search(&client, filter).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::colors::{Color, ColorFilter, ColorHue, ColorMaterial};
+    /// #
+    /// # fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let filter = ColorFilter::new()
+    ///     .hue(ColorHue::Red)
+    ///     .material(ColorMaterial::Metal);
+    /// let colors = Color::search(&client, filter)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`blocking`]: crate::blocking
+    pub fn search<C>(client: &C, filter: ColorFilter) -> C::Output<Vec<Self>>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let colors = Self::get_all(&client).await?;
+
+            Ok(colors
+                .into_iter()
+                .filter(|color| filter.matches(color))
+                .collect())
+        })
+    }
+
+    /// Returns the color in `colors` that looks closest to the given `rgb` color on cloth
+    /// armor. Returns `None` if `colors` is empty.
+    ///
+    /// Colors are compared by their distance in the CIELAB color space, which approximates the
+    /// perceived difference between two colors better than the distance of their rgb values.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::colors::Color;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let colors = Color::get_all(&client).await?;
+    ///
+    /// if let Some(color) = Color::closest_to(&colors, [200, 30, 30]) {
+    ///     println!("{}", color.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn closest_to(colors: &[Self], rgb: [u8; 3]) -> Option<&Self> {
+        let lab = Lab::from_rgb(rgb.map(f64::from));
+
+        colors
+            .iter()
+            .map(|color| (color, color.cloth.lab().distance(&lab)))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(color, _)| color)
+    }
 }
 
 /// Information about a color applied to an armor.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct ArmorColor {
     pub brightness: i64,
     pub contrast: f64,
@@ -26,4 +126,243 @@ pub struct ArmorColor {
     pub rgb: Vec<u16>,
 }
 
-endpoint!(Color, "/v2/colors", u64, get_all);
+impl ArmorColor {
+    /// Returns the color in the CIELAB color space.
+    fn lab(&self) -> Lab {
+        let channel = |index: usize| self.rgb.get(index).copied().unwrap_or_default();
+        Lab::from_rgb([channel(0), channel(1), channel(2)].map(f64::from))
+    }
+}
+
+/// A color in the CIELAB color space using the D65 white point.
+#[derive(Copy, Clone, Debug)]
+struct Lab {
+    l: f64,
+    a: f64,
+    b: f64,
+}
+
+impl Lab {
+    /// Converts a color with sRGB channels in the range `0..=255`.
+    fn from_rgb(rgb: [f64; 3]) -> Self {
+        let [r, g, b] = rgb.map(|channel| {
+            let channel = (channel / 255.0).clamp(0.0, 1.0);
+            if channel <= 0.04045 {
+                channel / 12.92
+            } else {
+                ((channel + 0.055) / 1.055).powf(2.4)
+            }
+        });
+
+        // Linear sRGB to XYZ, normalized by the D65 white point.
+        let x = (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.95047;
+        let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+        let z = (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.08883;
+
+        let f = |t: f64| {
+            if t > 216.0 / 24389.0 {
+                t.cbrt()
+            } else {
+                (24389.0 / 27.0 * t + 16.0) / 116.0
+            }
+        };
+        let (x, y, z) = (f(x), f(y), f(z));
+
+        Self {
+            l: 116.0 * y - 16.0,
+            a: 500.0 * (x - y),
+            b: 200.0 * (y - z),
+        }
+    }
+
+    /// Returns the CIE76 color difference between two colors.
+    fn distance(&self, other: &Self) -> f64 {
+        ((self.l - other.l).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2))
+            .sqrt()
+    }
+}
+
+/// A filter for the categories of [`Color`]s used by [`Color::search`].
+///
+/// Categories not set match all colors. Colors without categories only match a filter without
+/// any categories set.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ColorFilter {
+    /// The hue category to match.
+    pub hue: Option<ColorHue>,
+    /// The material category to match.
+    pub material: Option<ColorMaterial>,
+    /// The rarity category to match.
+    pub rarity: Option<ColorRarity>,
+}
+
+impl ColorFilter {
+    /// Creates a new `ColorFilter` matching all colors.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only matches colors with the given `hue`.
+    #[inline]
+    pub fn hue(mut self, hue: ColorHue) -> Self {
+        self.hue = Some(hue);
+        self
+    }
+
+    /// Only matches colors with the given `material`.
+    #[inline]
+    pub fn material(mut self, material: ColorMaterial) -> Self {
+        self.material = Some(material);
+        self
+    }
+
+    /// Only matches colors with the given `rarity`.
+    #[inline]
+    pub fn rarity(mut self, rarity: ColorRarity) -> Self {
+        self.rarity = Some(rarity);
+        self
+    }
+
+    /// Returns `true` if `color` matches the filter.
+    pub fn matches(&self, color: &Color) -> bool {
+        let categories = match color.categories {
+            Some(categories) => categories,
+            None => return *self == Self::default(),
+        };
+
+        self.hue.map_or(true, |hue| hue == categories.hue)
+            && self
+                .material
+                .map_or(true, |material| material == categories.material)
+            && self
+                .rarity
+                .map_or(true, |rarity| rarity == categories.rarity)
+    }
+}
+
+/// The categories of a [`Color`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ColorCategories {
+    /// The hue category of the color.
+    pub hue: ColorHue,
+    /// The material category of the color.
+    pub material: ColorMaterial,
+    /// The rarity category of the color.
+    pub rarity: ColorRarity,
+}
+
+impl Serialize for ColorCategories {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(3))?;
+        seq.serialize_element(&self.hue)?;
+        seq.serialize_element(&self.material)?;
+        seq.serialize_element(&self.rarity)?;
+        seq.end()
+    }
+}
+
+fn serialize_categories<S>(
+    categories: &Option<ColorCategories>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match categories {
+        Some(categories) => categories.serialize(serializer),
+        None => serializer.serialize_seq(Some(0))?.end(),
+    }
+}
+
+fn deserialize_categories<'de, D>(deserializer: D) -> Result<Option<ColorCategories>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct CategoriesVisitor;
+
+    impl<'de> Visitor<'de> for CategoriesVisitor {
+        type Value = Option<ColorCategories>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+            formatter.write_str("a sequence of zero or three color categories")
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let hue = match seq.next_element()? {
+                Some(hue) => hue,
+                None => return Ok(None),
+            };
+
+            let material = seq
+                .next_element()?
+                .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+            let rarity = seq
+                .next_element()?
+                .ok_or_else(|| A::Error::invalid_length(2, &self))?;
+
+            Ok(Some(ColorCategories {
+                hue,
+                material,
+                rarity,
+            }))
+        }
+    }
+
+    deserializer.deserialize_any(CategoriesVisitor)
+}
+
+/// The hue category of a [`Color`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ColorHue {
+    Gray,
+    Brown,
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+    /// A hue unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The material category of a [`Color`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ColorMaterial {
+    Vibrant,
+    Leather,
+    Metal,
+    /// A material unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The rarity category of a [`Color`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ColorRarity {
+    Starter,
+    Common,
+    Uncommon,
+    Rare,
+    Exclusive,
+    /// A rarity unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+endpoint!(Color, "/v2/colors", u64, localized, get_all);
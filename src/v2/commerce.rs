@@ -1,23 +1,244 @@
-use chrono::{DateTime, Utc};
+use std::fmt::{self, Display, Formatter};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+use std::str::FromStr;
+#[cfg(feature = "watch")]
+use std::time::Duration;
+
+use futures_util::future;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::datetime::DateTime;
+#[cfg(feature = "watch")]
+use crate::watch::DeliveryWatcher;
+use crate::{
+    bulk, collection, permissions, Authentication, ClientExecutor, Executor, RequestBuilder,
+};
+
+/// An amount of coins, stored in copper.
+///
+/// One gold is worth 100 silver, one silver is worth 100 copper. `Coins` are displayed in the
+/// format used ingame, e.g. `12g 34s 56c`, omitting leading zero units.
+///
+/// # Examples
+///
+/// ```
+/// use gw2api_rs::v2::commerce::Coins;
+///
+/// let coins = Coins::from_parts(12, 34, 56);
+/// assert_eq!(coins, Coins(123456));
+/// assert_eq!(coins.to_string(), "12g 34s 56c");
+/// assert_eq!(Coins(502).to_string(), "5s 2c");
+///
+/// assert_eq!("12g 34s 56c".parse(), Ok(coins));
+/// assert_eq!("5s".parse(), Ok(Coins(500)));
+/// ```
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Coins(pub u64);
+
+impl Coins {
+    /// No coins.
+    pub const ZERO: Self = Self(0);
+
+    /// Creates a new `Coins` value from the given amount of `gold`, `silver` and `copper`.
+    #[inline]
+    pub const fn from_parts(gold: u64, silver: u64, copper: u64) -> Self {
+        Self(gold * 10_000 + silver * 100 + copper)
+    }
+
+    /// Returns the gold part of the amount.
+    #[inline]
+    pub const fn gold(self) -> u64 {
+        self.0 / 10_000
+    }
+
+    /// Returns the silver part of the amount, in the range of 0 to 99.
+    #[inline]
+    pub const fn silver(self) -> u64 {
+        self.0 / 100 % 100
+    }
+
+    /// Returns the copper part of the amount, in the range of 0 to 99.
+    #[inline]
+    pub const fn copper(self) -> u64 {
+        self.0 % 100
+    }
+
+    /// Returns the total amount in copper.
+    #[inline]
+    pub const fn as_copper(self) -> u64 {
+        self.0
+    }
+
+    /// Subtracts `rhs` from `self`, returning `None` if the result would be negative.
+    #[inline]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(copper) => Some(Self(copper)),
+            None => None,
+        }
+    }
+}
+
+impl Display for Coins {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.gold() != 0 {
+            write!(f, "{}g {}s {}c", self.gold(), self.silver(), self.copper())
+        } else if self.silver() != 0 {
+            write!(f, "{}s {}c", self.silver(), self.copper())
+        } else {
+            write!(f, "{}c", self.copper())
+        }
+    }
+}
+
+impl FromStr for Coins {
+    type Err = ParseCoinsError;
+
+    /// Parses an amount in the format `12g 34s 56c`. Each unit is optional, but units must be
+    /// given in descending order. A plain number is parsed as an amount of copper.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseCoinsError(()));
+        }
+
+        if let Ok(copper) = s.parse() {
+            return Ok(Self(copper));
+        }
+
+        let mut coins: u64 = 0;
+        // The factor of the previous unit. Units must be strictly decreasing.
+        let mut previous = u64::MAX;
+
+        for part in s.split_whitespace() {
+            let (value, factor) = if let Some(value) = part.strip_suffix('g') {
+                (value, 10_000)
+            } else if let Some(value) = part.strip_suffix('s') {
+                (value, 100)
+            } else if let Some(value) = part.strip_suffix('c') {
+                (value, 1)
+            } else {
+                return Err(ParseCoinsError(()));
+            };
+
+            if factor >= previous {
+                return Err(ParseCoinsError(()));
+            }
+            previous = factor;
+
+            let value: u64 = value.parse().map_err(|_| ParseCoinsError(()))?;
+            coins = value
+                .checked_mul(factor)
+                .and_then(|value| coins.checked_add(value))
+                .ok_or(ParseCoinsError(()))?;
+        }
+
+        Ok(Self(coins))
+    }
+}
+
+impl From<u64> for Coins {
+    #[inline]
+    fn from(copper: u64) -> Self {
+        Self(copper)
+    }
+}
+
+impl From<Coins> for u64 {
+    #[inline]
+    fn from(coins: Coins) -> Self {
+        coins.0
+    }
+}
+
+impl Add for Coins {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Coins {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
 
-use crate::{Authentication, ClientExecutor, RequestBuilder};
+impl Sub for Coins {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Coins {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Mul<u64> for Coins {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: u64) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl Sum for Coins {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Coins> for Coins {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.copied().sum()
+    }
+}
+
+/// An error returned when parsing [`Coins`] fails.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
+#[error("invalid coins")]
+pub struct ParseCoinsError(());
 
 /// The coins and items currently waiting in trading post delivery.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Delivery {
-    pub coins: u64,
+    pub coins: Coins,
     pub items: Vec<DeliveryItem>,
 }
 
 /// An item in the delivery box.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct DeliveryItem {
     pub id: u64,
     pub count: u64,
 }
 
 impl Delivery {
+    permissions!(Account, TradingPost);
+
     /// Returns the delivery box for the currently authenticated account.
     ///
     /// # Authentication
@@ -62,7 +283,49 @@ impl Delivery {
         C: ClientExecutor<Self>,
     {
         let uri = "/v2/commerce/delivery";
-        client.send(crate::RequestBuilder::new(uri).authenticated(Authentication::Required))
+        client.send(
+            crate::RequestBuilder::new(uri)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns a [`DeliveryWatcher`] polling the delivery box of the currently authenticated
+    /// account every `interval` and yielding a [`DeliveryEvent`] when coins or items arrive.
+    ///
+    /// Requires the `watch` feature to be enabled. See the [`watch`] module for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use futures_util::StreamExt;
+    /// use gw2api_rs::v2::commerce::Delivery;
+    /// use gw2api_rs::watch::DeliveryEvent;
+    /// use gw2api_rs::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let mut watcher = Delivery::watch(&client, Duration::from_secs(60));
+    ///
+    /// while let Some(event) = watcher.next().await {
+    ///     if let DeliveryEvent::CoinsReceived { amount, .. } = event? {
+    ///         println!("received {}", amount);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`DeliveryWatcher`]: crate::watch::DeliveryWatcher
+    /// [`DeliveryEvent`]: crate::watch::DeliveryEvent
+    /// [`watch`]: crate::watch
+    #[cfg(feature = "watch")]
+    pub fn watch(client: &crate::Client, interval: Duration) -> DeliveryWatcher {
+        DeliveryWatcher::new(client, interval)
     }
 }
 
@@ -70,9 +333,10 @@ impl Delivery {
 ///
 /// `Exchange` handles both the coins to gems and gems to coins exchange rates.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Exchange {
     /// The current coins to gem exchange rate.
-    pub coins_per_gem: u64,
+    pub coins_per_gem: Coins,
     /// The amount of coins/gems you get for the requested amount of coins/gems.
     pub quantity: u64,
 }
@@ -86,11 +350,11 @@ impl Exchange {
     ///
     /// ```no_run
     /// # use gw2api_rs::{Client, Result};
-    /// # use gw2api_rs::v2::commerce::Exchange;
+    /// # use gw2api_rs::v2::commerce::{Coins, Exchange};
     /// #
     /// # async fn run() -> Result<()> {
     /// let client = Client::new();
-    /// let exchange = Exchange::coins(&client, 100).await?;
+    /// let exchange = Exchange::coins(&client, Coins(100)).await?;
     /// println!("You get {} gems for 100 coins.", exchange.quantity);
     /// # Ok(())
     /// # }
@@ -100,23 +364,24 @@ impl Exchange {
     /// ```no_run
     /// # use gw2api_rs::Result;
     /// # use gw2api_rs::blocking::Client;
-    /// # use gw2api_rs::v2::commerce::Exchange;
+    /// # use gw2api_rs::v2::commerce::{Coins, Exchange};
     /// #
     /// # fn run() -> Result<()> {
     /// let client = Client::new();
-    /// let exchange = Exchange::coins(&client, 100)?;
+    /// let exchange = Exchange::coins(&client, Coins(100))?;
     /// println!("You get {} gems for 100 coins.", exchange.quantity);
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// [`blocking`]: crate::blocking
-    pub fn coins<C>(client: &C, coins: u64) -> C::Result
+    pub fn coins<C>(client: &C, coins: Coins) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        let uri = format!("/v2/commerce/exchange/coins?quantity={}", coins);
-        client.send(RequestBuilder::new(uri))
+        client.send(
+            RequestBuilder::new("/v2/commerce/exchange/coins").query("quantity", coins.as_copper()),
+        )
     }
 
     /// Returns the current gems to coins exchange rate for the provided amount of `gems`.
@@ -156,33 +421,181 @@ impl Exchange {
     where
         C: ClientExecutor<Self>,
     {
-        let uri = format!("/v2/commerce/exchange/gems?quantity={}", gems);
-        client.send(RequestBuilder::new(uri))
+        client.send(RequestBuilder::new("/v2/commerce/exchange/gems").query("quantity", gems))
+    }
+
+    /// Returns the current exchange rates in both directions.
+    ///
+    /// This requests the coins to gems rate for [`ExchangeRate::COINS`] and the gems to coins
+    /// rate for [`ExchangeRate::GEMS`] concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::commerce::Exchange;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let rate = Exchange::rate(&client).await?;
+    /// println!("Buy: {} per gem, sell: {} per gem", rate.buy_price(), rate.sell_price());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::commerce::Exchange;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let rate = Exchange::rate(&client)?;
+    /// println!("Buy: {} per gem, sell: {} per gem", rate.buy_price(), rate.sell_price());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`blocking`]: crate::blocking
+    pub fn rate<C>(client: &C) -> C::Output<ExchangeRate>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let (coins, gems) = future::try_join(
+                Self::coins(&client, ExchangeRate::COINS),
+                Self::gems(&client, ExchangeRate::GEMS),
+            )
+            .await?;
+
+            Ok(ExchangeRate {
+                coins_to_gems: coins,
+                gems_to_coins: gems,
+            })
+        })
+    }
+}
+
+/// The exchange rates of the currency exchange in both directions.
+///
+/// An `ExchangeRate` is a snapshot of the rates at the time it was requested. It can be kept
+/// around to convert between coins and gems without requesting the rates again, e.g. in a
+/// converter UI. Note that the effective rates of the exchange depend on the exchanged amount, so
+/// converted values are only estimates.
+///
+/// Returned by [`Exchange::rate`].
+///
+/// # Examples
+///
+/// ```
+/// # use gw2api_rs::v2::commerce::{Coins, Exchange, ExchangeRate};
+/// #
+/// let rate = ExchangeRate {
+///     coins_to_gems: Exchange { coins_per_gem: Coins(2500), quantity: 400 },
+///     gems_to_coins: Exchange { coins_per_gem: Coins(2000), quantity: 200_000 },
+/// };
+///
+/// assert_eq!(rate.buy_price(), Coins(2500));
+/// assert_eq!(rate.sell_price(), Coins(2000));
+/// assert_eq!(rate.spread(), Coins(500));
+///
+/// assert_eq!(rate.gems_for(Coins::from_parts(10, 0, 0)), 40);
+/// assert_eq!(rate.coins_for(40), Coins::from_parts(8, 0, 0));
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct ExchangeRate {
+    /// The coins to gems exchange of [`COINS`].
+    ///
+    /// [`COINS`]: Self::COINS
+    pub coins_to_gems: Exchange,
+    /// The gems to coins exchange of [`GEMS`].
+    ///
+    /// [`GEMS`]: Self::GEMS
+    pub gems_to_coins: Exchange,
+}
+
+impl ExchangeRate {
+    /// The amount of coins used to request the coins to gems rate (100 gold).
+    pub const COINS: Coins = Coins(1_000_000);
+
+    /// The amount of gems used to request the gems to coins rate.
+    pub const GEMS: u64 = 100;
+
+    /// Returns the effective price of a single gem when exchanging coins to gems.
+    ///
+    /// Returns [`Coins::ZERO`] if the exchange returned no gems.
+    pub fn buy_price(&self) -> Coins {
+        match self.coins_to_gems.quantity {
+            0 => Coins::ZERO,
+            gems => Coins(Self::COINS.as_copper().div_ceil(gems)),
+        }
+    }
+
+    /// Returns the effective amount of coins received for a single gem when exchanging gems to
+    /// coins.
+    pub fn sell_price(&self) -> Coins {
+        Coins(self.gems_to_coins.quantity / Self::GEMS)
+    }
+
+    /// Returns the difference between the [`buy_price`] and the [`sell_price`] of a single gem.
+    ///
+    /// [`buy_price`]: Self::buy_price
+    /// [`sell_price`]: Self::sell_price
+    pub fn spread(&self) -> Coins {
+        self.buy_price()
+            .checked_sub(self.sell_price())
+            .unwrap_or(Coins::ZERO)
+    }
+
+    /// Returns the estimated amount of gems received for exchanging `coins`.
+    pub fn gems_for(&self, coins: Coins) -> u64 {
+        match self.buy_price().as_copper() {
+            0 => 0,
+            price => coins.as_copper() / price,
+        }
+    }
+
+    /// Returns the estimated amount of coins received for exchanging `gems`.
+    ///
+    /// The amount is computed from the quote for [`GEMS`] instead of the truncated
+    /// [`sell_price`], so it does not lose up to one copper per gem.
+    ///
+    /// [`GEMS`]: Self::GEMS
+    /// [`sell_price`]: Self::sell_price
+    pub fn coins_for(&self, gems: u64) -> Coins {
+        let coins =
+            u128::from(self.gems_to_coins.quantity) * u128::from(gems) / u128::from(Self::GEMS);
+        Coins(u64::try_from(coins).unwrap_or(u64::MAX))
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Listings {
     pub id: u64,
-    pub buys: Listing,
+    pub buys: Vec<Listing>,
+    pub sells: Vec<Listing>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Listing {
     pub listings: u64,
-    pub unit_price: u64,
+    pub unit_price: Coins,
     pub quantity: u64,
 }
 
 impl Listings {
-    const URI: &'static str = "/v2/commerce/listings";
+    pub(crate) const URI: &'static str = "/v2/commerce/listings";
 
     pub fn get<C>(client: &C, id: u64) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        let uri = format!("{}?id={}", Self::URI, id);
-        client.send(RequestBuilder::new(uri))
+        client.send(RequestBuilder::new(Self::URI).query("id", id))
     }
 
     /// Returns a list of all items avaliable on the trading post.
@@ -225,22 +638,76 @@ impl Listings {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Prices {
     pub id: u64,
     /// Whether free to play accounts are allowed to buy/sell this item.
-    pub whilelisted: bool,
+    pub whitelisted: bool,
     pub buys: Price,
     pub sells: Price,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Price {
-    pub unit_price: u64,
+    pub unit_price: Coins,
     pub quantity: u64,
 }
 
 impl Prices {
-    const URI: &'static str = "/v2/commerce/prices";
+    pub(crate) const URI: &'static str = "/v2/commerce/prices";
+
+    /// Returns the current buy and sell prices of the item with the given `id`.
+    pub fn get<C>(client: &C, id: u64) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(RequestBuilder::new(Self::URI).query("id", id))
+    }
+
+    /// Returns the current buy and sell prices of all items with the given `ids`.
+    ///
+    /// The ids are split into chunks of 200 which are requested concurrently. Items not
+    /// avaliable on the trading post are omitted from the returned list.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::commerce::Prices;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let prices = Prices::get_many(&client, &[19721, 24295]).await?;
+    /// println!("{:?}", prices);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::commerce::Prices;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let prices = Prices::get_many(&client, &[19721, 24295])?;
+    /// println!("{:?}", prices);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`blocking`]: crate::blocking
+    pub fn get_many<C>(client: &C, ids: &[u64]) -> C::Output<Vec<Self>>
+    where
+        C: Executor,
+    {
+        let ids = ids.to_vec();
+
+        client.execute(|client| async move { bulk::get_chunked(&client, Self::URI, &ids).await })
+    }
 
     /// Returns a list of all items avaliable on the trading post.
     ///
@@ -287,18 +754,26 @@ pub struct CurrentTransactions {
     pub transactions: Vec<CurrentTransaction>,
 }
 
+collection!(CurrentTransactions {
+    transactions: CurrentTransaction
+});
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct CurrentTransaction {
     pub id: u64,
     pub item_id: u64,
-    pub price: u64,
+    pub price: Coins,
     pub quantity: u64,
-    pub created: DateTime<Utc>,
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub created: DateTime,
 }
 
 impl CurrentTransactions {
     const URI: &'static str = "/v2/commerce/transactions/current";
 
+    permissions!(Account, TradingPost);
+
     /// Returns all outstanding *buy* transactions for the currently authenticated account.
     ///
     /// # Authentication
@@ -342,8 +817,7 @@ impl CurrentTransactions {
     where
         C: ClientExecutor<Self>,
     {
-        let uri = format!("{}/buys", Self::URI);
-        client.send(RequestBuilder::new(uri).authenticated(Authentication::Required))
+        client.send(Self::request("buys"))
     }
 
     /// Returns all outstanding *sell* transactions for the currently authenticated account.
@@ -389,8 +863,15 @@ impl CurrentTransactions {
     where
         C: ClientExecutor<Self>,
     {
-        let uri = format!("{}/sells", Self::URI);
-        client.send(RequestBuilder::new(uri).authenticated(Authentication::Required))
+        client.send(Self::request("sells"))
+    }
+
+    /// Returns the request for the transactions on the given `side`, `buys` or `sells`.
+    pub(crate) fn request(side: &'static str) -> RequestBuilder {
+        RequestBuilder::new(Self::URI)
+            .segment(side)
+            .authenticated(Authentication::Required)
+            .permissions(Self::PERMISSIONS)
     }
 }
 
@@ -400,19 +881,28 @@ pub struct HistoryTransactions {
     pub transations: Vec<HistoryTransaction>,
 }
 
+collection!(HistoryTransactions {
+    transations: HistoryTransaction
+});
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct HistoryTransaction {
     pub id: u64,
     pub item_id: u64,
-    pub price: u64,
+    pub price: Coins,
     pub quantity: u64,
-    pub created: DateTime<Utc>,
-    pub purchased: DateTime<Utc>,
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub created: DateTime,
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub purchased: DateTime,
 }
 
 impl HistoryTransactions {
     const URI: &'static str = "/v2/commerce/transactions/history";
 
+    permissions!(Account, TradingPost);
+
     /// Returns all *buy* transactions that were fulfilled in the past 90 days for the currently
     /// authenticated account.
     ///
@@ -457,8 +947,7 @@ impl HistoryTransactions {
     where
         C: ClientExecutor<Self>,
     {
-        let uri = format!("{}/buys", Self::URI);
-        client.send(RequestBuilder::new(uri).authenticated(Authentication::Required))
+        client.send(Self::request("buys"))
     }
 
     /// Returns all *sell* transactions that were fulfilled in the past 90 days for the currently
@@ -505,7 +994,14 @@ impl HistoryTransactions {
     where
         C: ClientExecutor<Self>,
     {
-        let uri = format!("{}/sells", Self::URI);
-        client.send(RequestBuilder::new(uri).authenticated(Authentication::Required))
+        client.send(Self::request("sells"))
+    }
+
+    /// Returns the request for the transactions on the given `side`, `buys` or `sells`.
+    pub(crate) fn request(side: &'static str) -> RequestBuilder {
+        RequestBuilder::new(Self::URI)
+            .segment(side)
+            .authenticated(Authentication::Required)
+            .permissions(Self::PERMISSIONS)
     }
 }
@@ -0,0 +1,710 @@
+//! Types shared by multiple endpoints.
+//!
+//! This module is always available and not gated behind a feature.
+
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use base64::prelude::{Engine, BASE64_STANDARD};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The rarity of an item or skin.
+///
+/// Rarities are ordered from [`Junk`] to [`Legendary`]. [`Unknown`] rarities are ordered before
+/// all known rarities, so they never pass a minimum rarity check.
+///
+/// [`Junk`]: Self::Junk
+/// [`Legendary`]: Self::Legendary
+/// [`Unknown`]: Self::Unknown
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Rarity {
+    Junk,
+    Basic,
+    Fine,
+    Masterwork,
+    Rare,
+    Exotic,
+    Ascended,
+    Legendary,
+    /// A rarity unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+impl Rarity {
+    /// Returns the position of the rarity in the order of all rarities.
+    fn rank(self) -> u8 {
+        match self {
+            Self::Unknown => 0,
+            Self::Junk => 1,
+            Self::Basic => 2,
+            Self::Fine => 3,
+            Self::Masterwork => 4,
+            Self::Rare => 5,
+            Self::Exotic => 6,
+            Self::Ascended => 7,
+            Self::Legendary => 8,
+        }
+    }
+}
+
+impl PartialOrd for Rarity {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rarity {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+/// The weight class of an armor piece.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WeightClass {
+    Heavy,
+    Medium,
+    Light,
+    /// Town clothing.
+    Clothing,
+    /// A weight class unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The damage type of a weapon, which determines the visual effect of its attacks.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DamageType {
+    Choking,
+    Fire,
+    Ice,
+    Lightning,
+    Physical,
+    /// A damage type unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The profession of a character.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Profession {
+    Elementalist,
+    Engineer,
+    Guardian,
+    Mesmer,
+    Necromancer,
+    Ranger,
+    Revenant,
+    Thief,
+    Warrior,
+    /// A profession unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+impl Profession {
+    /// Returns the name of the profession, e.g. `Guardian`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Elementalist => "Elementalist",
+            Self::Engineer => "Engineer",
+            Self::Guardian => "Guardian",
+            Self::Mesmer => "Mesmer",
+            Self::Necromancer => "Necromancer",
+            Self::Ranger => "Ranger",
+            Self::Revenant => "Revenant",
+            Self::Thief => "Thief",
+            Self::Warrior => "Warrior",
+            Self::Unknown => "Unknown",
+        }
+    }
+}
+
+impl Display for Profession {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An elite specialization of a [`Profession`].
+///
+/// Core specializations are not represented by this type.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EliteSpecialization {
+    Druid,
+    Daredevil,
+    Berserker,
+    Dragonhunter,
+    Reaper,
+    Chronomancer,
+    Scrapper,
+    Tempest,
+    Herald,
+    Soulbeast,
+    Weaver,
+    Holosmith,
+    Deadeye,
+    Mirage,
+    Scourge,
+    Spellbreaker,
+    Firebrand,
+    Renegade,
+    Harbinger,
+    Willbender,
+    Virtuoso,
+    Catalyst,
+    Bladesworn,
+    Vindicator,
+    Mechanist,
+    Specter,
+    Untamed,
+    /// An elite specialization unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+impl EliteSpecialization {
+    const IDS: &'static [(Self, u64)] = &[
+        (Self::Druid, 5),
+        (Self::Daredevil, 7),
+        (Self::Berserker, 18),
+        (Self::Dragonhunter, 27),
+        (Self::Reaper, 34),
+        (Self::Chronomancer, 40),
+        (Self::Scrapper, 43),
+        (Self::Tempest, 48),
+        (Self::Herald, 52),
+        (Self::Soulbeast, 55),
+        (Self::Weaver, 56),
+        (Self::Holosmith, 57),
+        (Self::Deadeye, 58),
+        (Self::Mirage, 59),
+        (Self::Scourge, 60),
+        (Self::Spellbreaker, 61),
+        (Self::Firebrand, 62),
+        (Self::Renegade, 63),
+        (Self::Harbinger, 64),
+        (Self::Willbender, 65),
+        (Self::Virtuoso, 66),
+        (Self::Catalyst, 67),
+        (Self::Bladesworn, 68),
+        (Self::Vindicator, 69),
+        (Self::Mechanist, 70),
+        (Self::Specter, 71),
+        (Self::Untamed, 72),
+    ];
+
+    /// Returns the id of the specialization as used by `/v2/specializations` and build
+    /// templates. Returns `None` for [`Unknown`].
+    ///
+    /// [`Unknown`]: Self::Unknown
+    pub fn id(self) -> Option<u64> {
+        Self::IDS
+            .iter()
+            .find(|(spec, _)| *spec == self)
+            .map(|(_, id)| *id)
+    }
+
+    /// Returns the elite specialization with the given `id`. Returns `None` if `id` is not the
+    /// id of a known elite specialization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gw2api_rs::v2::common::{EliteSpecialization, Profession};
+    ///
+    /// let spec = EliteSpecialization::from_id(27).unwrap();
+    /// assert_eq!(spec, EliteSpecialization::Dragonhunter);
+    /// assert_eq!(spec.profession(), Profession::Guardian);
+    /// ```
+    pub fn from_id(id: u64) -> Option<Self> {
+        Self::IDS
+            .iter()
+            .find(|(_, spec_id)| *spec_id == id)
+            .map(|(spec, _)| *spec)
+    }
+
+    /// Returns the [`Profession`] the specialization belongs to. Returns [`Profession::Unknown`]
+    /// for [`Unknown`].
+    ///
+    /// [`Unknown`]: Self::Unknown
+    pub fn profession(self) -> Profession {
+        match self {
+            Self::Tempest | Self::Weaver | Self::Catalyst => Profession::Elementalist,
+            Self::Scrapper | Self::Holosmith | Self::Mechanist => Profession::Engineer,
+            Self::Dragonhunter | Self::Firebrand | Self::Willbender => Profession::Guardian,
+            Self::Chronomancer | Self::Mirage | Self::Virtuoso => Profession::Mesmer,
+            Self::Reaper | Self::Scourge | Self::Harbinger => Profession::Necromancer,
+            Self::Druid | Self::Soulbeast | Self::Untamed => Profession::Ranger,
+            Self::Herald | Self::Renegade | Self::Vindicator => Profession::Revenant,
+            Self::Daredevil | Self::Deadeye | Self::Specter => Profession::Thief,
+            Self::Berserker | Self::Spellbreaker | Self::Bladesworn => Profession::Warrior,
+            Self::Unknown => Profession::Unknown,
+        }
+    }
+}
+
+/// The race of a character.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Race {
+    Asura,
+    Charr,
+    Human,
+    Norn,
+    Sylvari,
+    /// A race unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The gender of a character.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Gender {
+    Male,
+    Female,
+    /// A gender unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A crafting discipline.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Discipline {
+    Armorsmith,
+    Artificer,
+    Chef,
+    Huntsman,
+    Jeweler,
+    Leatherworker,
+    Scribe,
+    Tailor,
+    Weaponsmith,
+    /// A discipline unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Whom an item is bound to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Binding {
+    /// The item is bound to the account.
+    Account,
+    /// The item is bound to a single character.
+    Character,
+}
+
+/// A region used by region specific endpoints.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Region {
+    /// North America
+    Na,
+    /// Europe
+    Eu,
+}
+
+impl Display for Region {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let string = match self {
+            Self::Na => "na",
+            Self::Eu => "eu",
+        };
+
+        write!(f, "{}", string)
+    }
+}
+
+/// A url pointing to an asset on the render service, e.g. an icon.
+///
+/// Render service urls have the format
+/// `https://render.guildwars2.com/file/<signature>/<file_id>.<format>`.
+///
+/// # Examples
+///
+/// ```
+/// use gw2api_rs::v2::common::RenderUrl;
+///
+/// let url = RenderUrl::from(
+///     "https://render.guildwars2.com/file/015D365A08AAE105287A100AAE04529FDAE14155/102532.png",
+/// );
+/// assert_eq!(url.signature(), Some("015D365A08AAE105287A100AAE04529FDAE14155"));
+/// assert_eq!(url.file_id(), Some(102532));
+/// assert_eq!(url.format(), Some("png"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RenderUrl(pub String);
+
+impl RenderUrl {
+    /// Returns the url as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the signature of the file. Returns `None` if the url is not a render service url.
+    pub fn signature(&self) -> Option<&str> {
+        self.parts().map(|(signature, _)| signature)
+    }
+
+    /// Returns the id of the file. Returns `None` if the url is not a render service url.
+    pub fn file_id(&self) -> Option<u64> {
+        let (_, file) = self.parts()?;
+        let (id, _) = file.split_once('.')?;
+        id.parse().ok()
+    }
+
+    /// Returns the format of the file, e.g. `png`. Returns `None` if the url is not a render
+    /// service url.
+    pub fn format(&self) -> Option<&str> {
+        let (_, file) = self.parts()?;
+        let (_, format) = file.split_once('.')?;
+        Some(format)
+    }
+
+    fn parts(&self) -> Option<(&str, &str)> {
+        let path = self.0.split_once("/file/")?.1;
+        path.split_once('/')
+    }
+}
+
+impl AsRef<str> for RenderUrl {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for RenderUrl {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for RenderUrl {
+    #[inline]
+    fn from(url: String) -> Self {
+        Self(url)
+    }
+}
+
+impl From<&str> for RenderUrl {
+    #[inline]
+    fn from(url: &str) -> Self {
+        Self(url.to_owned())
+    }
+}
+
+/// A chat link as pasted into the in-game chat, e.g. `[&AgEYAAAA]`.
+///
+/// Chat links are base64 encoded binary data enclosed in `[&` and `]`. The first byte
+/// determines what the link refers to. Links of unknown kinds are kept as [`Other`], so that
+/// every valid link can be encoded again unchanged.
+///
+/// Parsing a chat link with [`FromStr`] fails for malformed links. When deserializing, malformed
+/// links are kept as [`Raw`] instead, so that a single bad link returned by the API does not fail
+/// the whole response.
+///
+/// # Examples
+///
+/// ```
+/// use gw2api_rs::v2::common::{ChatLink, ItemLink};
+///
+/// let link: ChatLink = "[&AgEYAAAA]".parse().unwrap();
+/// assert_eq!(link, ChatLink::Item(ItemLink::new(24)));
+///
+/// let link = ChatLink::Item(ItemLink::new(30).with_count(5));
+/// assert_eq!(link.to_string(), "[&AgUeAAAA]");
+/// ```
+///
+/// [`Other`]: Self::Other
+/// [`Raw`]: Self::Raw
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChatLink {
+    /// An amount of coins in copper.
+    Coin(u32),
+    /// An item stack.
+    Item(ItemLink),
+    /// The id of an NPC text.
+    Text(u32),
+    /// The id of a point of interest, waypoint or vista on a map.
+    Map(u32),
+    /// The id of a skill.
+    Skill(u32),
+    /// The id of a trait.
+    Trait(u32),
+    /// The id of a recipe.
+    Recipe(u32),
+    /// The id of a skin.
+    Skin(u32),
+    /// The id of an outfit.
+    Outfit(u32),
+    /// A WvW objective.
+    Objective {
+        /// The id of the map the objective is on.
+        map: u32,
+        /// The id of the objective on the map.
+        objective: u32,
+    },
+    /// A chat link of a kind unknown to this version of the crate.
+    Other {
+        /// The first byte of the link.
+        header: u8,
+        /// The remaining bytes of the link.
+        data: Vec<u8>,
+    },
+    /// A string that is not a valid chat link, kept unchanged.
+    ///
+    /// This is only created when deserializing.
+    Raw(String),
+}
+
+impl ChatLink {
+    const COIN: u8 = 0x01;
+    const ITEM: u8 = 0x02;
+    const TEXT: u8 = 0x03;
+    const MAP: u8 = 0x04;
+    const SKILL: u8 = 0x06;
+    const TRAIT: u8 = 0x07;
+    const RECIPE: u8 = 0x09;
+    const SKIN: u8 = 0x0A;
+    const OUTFIT: u8 = 0x0B;
+    const OBJECTIVE: u8 = 0x0C;
+
+    /// Decodes the binary data of a chat link, i.e. the link without the enclosing `[&` and `]`
+    /// and after base64 decoding.
+    ///
+    /// Returns an error if the data is too short or too long for the kind of the link.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ParseChatLinkError> {
+        let (&header, data) = bytes.split_first().ok_or(ParseChatLinkError)?;
+        let mut reader = Reader(data);
+
+        let link = match header {
+            Self::COIN => Self::Coin(reader.u32()?),
+            Self::ITEM => {
+                let count = reader.u8()?;
+                let id = reader.u32()?;
+                let flags = id >> 24;
+
+                let mut read_if = |flag: u32| match flags & flag {
+                    0 => Ok(None),
+                    _ => reader.u32().map(Some),
+                };
+
+                Self::Item(ItemLink {
+                    id: id & ItemLink::ID_MASK,
+                    count,
+                    skin: read_if(ItemLink::SKIN)?,
+                    upgrades: [
+                        read_if(ItemLink::UPGRADE)?,
+                        read_if(ItemLink::SECOND_UPGRADE)?,
+                    ],
+                })
+            }
+            Self::TEXT => Self::Text(reader.u32()?),
+            Self::MAP => Self::Map(reader.u32()?),
+            Self::SKILL => Self::Skill(reader.u32()?),
+            Self::TRAIT => Self::Trait(reader.u32()?),
+            Self::RECIPE => Self::Recipe(reader.u32()?),
+            Self::SKIN => Self::Skin(reader.u32()?),
+            Self::OUTFIT => Self::Outfit(reader.u32()?),
+            Self::OBJECTIVE => Self::Objective {
+                objective: reader.u32()?,
+                map: reader.u32()?,
+            },
+            _ => {
+                return Ok(Self::Other {
+                    header,
+                    data: data.to_vec(),
+                })
+            }
+        };
+
+        if !reader.0.is_empty() {
+            return Err(ParseChatLinkError);
+        }
+
+        Ok(link)
+    }
+
+    /// Encodes the link into its binary data, i.e. the link without the enclosing `[&` and `]`
+    /// and before base64 encoding.
+    ///
+    /// Returns `None` for [`Raw`] links, which have no binary data.
+    ///
+    /// [`Raw`]: Self::Raw
+    pub fn to_bytes(&self) -> Option<Vec<u8>> {
+        let (header, ids): (u8, &[u32]) = match self {
+            Self::Coin(amount) => (Self::COIN, &[*amount]),
+            Self::Item(item) => return Some(item.to_bytes()),
+            Self::Text(id) => (Self::TEXT, &[*id]),
+            Self::Map(id) => (Self::MAP, &[*id]),
+            Self::Skill(id) => (Self::SKILL, &[*id]),
+            Self::Trait(id) => (Self::TRAIT, &[*id]),
+            Self::Recipe(id) => (Self::RECIPE, &[*id]),
+            Self::Skin(id) => (Self::SKIN, &[*id]),
+            Self::Outfit(id) => (Self::OUTFIT, &[*id]),
+            Self::Objective { map, objective } => (Self::OBJECTIVE, &[*objective, *map]),
+            Self::Other { header, data } => {
+                let mut bytes = vec![*header];
+                bytes.extend_from_slice(data);
+                return Some(bytes);
+            }
+            Self::Raw(_) => return None,
+        };
+
+        let mut bytes = vec![header];
+        for id in ids {
+            bytes.extend_from_slice(&id.to_le_bytes());
+        }
+        Some(bytes)
+    }
+}
+
+impl Display for ChatLink {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Raw(s) => f.write_str(s),
+            _ => {
+                let bytes = self.to_bytes().unwrap_or_default();
+                write!(f, "[&{}]", BASE64_STANDARD.encode(bytes))
+            }
+        }
+    }
+}
+
+impl FromStr for ChatLink {
+    type Err = ParseChatLinkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data = s
+            .strip_prefix("[&")
+            .and_then(|s| s.strip_suffix(']'))
+            .ok_or(ParseChatLinkError)?;
+
+        let bytes = BASE64_STANDARD
+            .decode(data)
+            .map_err(|_| ParseChatLinkError)?;
+        Self::from_bytes(&bytes)
+    }
+}
+
+impl From<ItemLink> for ChatLink {
+    #[inline]
+    fn from(item: ItemLink) -> Self {
+        Self::Item(item)
+    }
+}
+
+impl Serialize for ChatLink {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChatLink {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.parse() {
+            Ok(link) => Ok(link),
+            Err(_) => Ok(Self::Raw(s)),
+        }
+    }
+}
+
+/// The item stack referred to by a [`ChatLink::Item`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ItemLink {
+    /// The id of the item.
+    pub id: u32,
+    /// The number of items in the stack.
+    pub count: u8,
+    /// The id of the skin applied to the item.
+    pub skin: Option<u32>,
+    /// The ids of the upgrades in the upgrade slots of the item.
+    pub upgrades: [Option<u32>; 2],
+}
+
+impl ItemLink {
+    const ID_MASK: u32 = 0x00FF_FFFF;
+    const SKIN: u32 = 0x80;
+    const UPGRADE: u32 = 0x40;
+    const SECOND_UPGRADE: u32 = 0x20;
+
+    /// Creates a link to a single item with the given `id`, without a skin or upgrades.
+    #[inline]
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            count: 1,
+            skin: None,
+            upgrades: [None, None],
+        }
+    }
+
+    /// Sets the number of items in the stack.
+    #[inline]
+    pub fn with_count(mut self, count: u8) -> Self {
+        self.count = count;
+        self
+    }
+
+    fn to_bytes(self) -> Vec<u8> {
+        let mut flags = 0;
+        let mut extra = Vec::new();
+        for (flag, id) in [
+            (Self::SKIN, self.skin),
+            (Self::UPGRADE, self.upgrades[0]),
+            (Self::SECOND_UPGRADE, self.upgrades[1]),
+        ] {
+            if let Some(id) = id {
+                flags |= flag;
+                extra.extend_from_slice(&id.to_le_bytes());
+            }
+        }
+
+        let mut bytes = vec![ChatLink::ITEM, self.count];
+        bytes.extend_from_slice(&((self.id & Self::ID_MASK) | flags << 24).to_le_bytes());
+        bytes.extend(extra);
+        bytes
+    }
+}
+
+/// An error returned when parsing an invalid [`ChatLink`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ParseChatLinkError;
+
+impl Display for ParseChatLinkError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("invalid chat link")
+    }
+}
+
+impl std::error::Error for ParseChatLinkError {}
+
+/// Reads little endian integers from the data of a chat link.
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn u8(&mut self) -> Result<u8, ParseChatLinkError> {
+        let (&byte, rest) = self.0.split_first().ok_or(ParseChatLinkError)?;
+        self.0 = rest;
+        Ok(byte)
+    }
+
+    fn u32(&mut self) -> Result<u32, ParseChatLinkError> {
+        let (bytes, rest) = self.0.split_first_chunk().ok_or(ParseChatLinkError)?;
+        self.0 = rest;
+        Ok(u32::from_le_bytes(*bytes))
+    }
+}
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use crate::endpoint;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Currency {
     pub id: u64,
     pub name: String,
@@ -11,4 +12,52 @@ pub struct Currency {
     pub order: u64,
 }
 
-endpoint!(Currency, "/v2/currencies", u64, get_all);
+endpoint!(Currency, "/v2/currencies", u64, localized, get_all);
+
+/// Ids of well-known currencies.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use gw2api_rs::{Client, Result};
+/// # use gw2api_rs::v2::currencies::{ids, Currency};
+/// #
+/// # async fn run() -> Result<()> {
+/// let client = Client::new();
+/// let karma = Currency::get(&client, ids::KARMA).await?;
+/// println!("{}", karma.description);
+/// # Ok(())
+/// # }
+/// ```
+pub mod ids {
+    /// Coin.
+    pub const COIN: u64 = 1;
+    /// Karma.
+    pub const KARMA: u64 = 2;
+    /// Laurel.
+    pub const LAUREL: u64 = 3;
+    /// Gem.
+    pub const GEM: u64 = 4;
+    /// Fractal Relic.
+    pub const FRACTAL_RELIC: u64 = 7;
+    /// Badge of Honor.
+    pub const BADGE_OF_HONOR: u64 = 15;
+    /// Guild Commendation.
+    pub const GUILD_COMMENDATION: u64 = 16;
+    /// Transmutation Charge.
+    pub const TRANSMUTATION_CHARGE: u64 = 18;
+    /// Spirit Shard.
+    pub const SPIRIT_SHARD: u64 = 23;
+    /// Pristine Fractal Relic.
+    pub const PRISTINE_FRACTAL_RELIC: u64 = 24;
+    /// Magnetite Shard.
+    pub const MAGNETITE_SHARD: u64 = 28;
+    /// Unbound Magic.
+    pub const UNBOUND_MAGIC: u64 = 32;
+    /// Volatile Magic.
+    pub const VOLATILE_MAGIC: u64 = 45;
+    /// Research Note.
+    pub const RESEARCH_NOTE: u64 = 61;
+    /// Astral Acclaim.
+    pub const ASTRAL_ACCLAIM: u64 = 63;
+}
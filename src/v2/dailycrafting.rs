@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+
+use futures_util::future;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::account::AccountDailyCrafting;
+use crate::{endpoint, Executor};
+
+/// A time-gated recipe that can be crafted once per day.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct DailyCrafting {
+    /// The id of the crafted item, e.g. `lump_of_mithrillium`.
+    pub id: String,
+}
+
+impl DailyCrafting {
+    /// Returns the ids of all time-gated recipes which the currently authenticated account can
+    /// still craft today.
+    ///
+    /// The time-gated recipes and the [`AccountDailyCrafting`] of the account are requested
+    /// concurrently. Every recipe crafted by the account is removed from the list. Crafting is
+    /// possible again after the next daily reset, see [`AccountDailyCrafting::expires_at`].
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::dailycrafting::DailyCrafting;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// for id in DailyCrafting::remaining(&client).await? {
+    ///     println!("{} can still be crafted today", id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::dailycrafting::DailyCrafting;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// for id in DailyCrafting::remaining(&client)? {
+    ///     println!("{} can still be crafted today", id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn remaining<C>(client: &C) -> C::Output<Vec<String>>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let (ids, crafted) =
+                future::try_join(Self::ids(&client), AccountDailyCrafting::get(&client)).await?;
+            let crafted: HashSet<String> = crafted.into_iter().collect();
+
+            Ok(ids.into_iter().filter(|id| !crafted.contains(id)).collect())
+        })
+    }
+}
+
+endpoint!(DailyCrafting, "/v2/dailycrafting", String, get_all);
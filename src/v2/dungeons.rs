@@ -2,13 +2,48 @@ use serde::{Deserialize, Serialize};
 
 use crate::endpoint;
 
+/// The id of the repeatable Dungeon Frequenter achievement.
+///
+/// The achievement is completed for every [`FREQUENTER_PATHS`] distinct dungeon paths.
+pub const FREQUENTER_ACHIEVEMENT: u64 = 2963;
+
+/// The number of distinct dungeon paths required to complete one cycle of the Dungeon Frequenter
+/// achievement.
+pub const FREQUENTER_PATHS: u64 = 8;
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Dungeon {
     pub id: String,
     pub paths: Vec<DungeonPath>,
 }
 
+impl Dungeon {
+    /// Returns the completion of all paths of this dungeon given the `completed` paths, e.g. as
+    /// returned by [`AccountDungeons`].
+    ///
+    /// [`AccountDungeons`]: crate::v2::account::AccountDungeons
+    pub fn completion<S>(&self, completed: &[S]) -> DungeonCompletion
+    where
+        S: AsRef<str>,
+    {
+        DungeonCompletion {
+            id: self.id.clone(),
+            paths: self
+                .paths
+                .iter()
+                .map(|path| PathCompletion {
+                    id: path.id.clone(),
+                    kind: path.kind,
+                    completed: completed.iter().any(|id| id.as_ref() == path.id),
+                })
+                .collect(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct DungeonPath {
     pub id: String,
     #[serde(rename = "type")]
@@ -23,3 +58,44 @@ pub enum DungeonKind {
 }
 
 endpoint!(Dungeon, "/v2/dungeons", String, get_all);
+
+/// The daily completion of a [`Dungeon`].
+///
+/// Returned by [`Dungeon::completion`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct DungeonCompletion {
+    /// The id of the dungeon.
+    pub id: String,
+    /// The paths of the dungeon in order.
+    pub paths: Vec<PathCompletion>,
+}
+
+impl DungeonCompletion {
+    /// Returns the number of paths completed today.
+    pub fn completed(&self) -> usize {
+        self.paths.iter().filter(|path| path.completed).count()
+    }
+
+    /// Returns an iterator over all paths not yet completed today.
+    pub fn remaining(&self) -> impl Iterator<Item = &PathCompletion> + '_ {
+        self.paths.iter().filter(|path| !path.completed)
+    }
+
+    /// Returns `true` if all paths of the dungeon were completed today.
+    pub fn is_completed(&self) -> bool {
+        self.paths.iter().all(|path| path.completed)
+    }
+}
+
+/// The daily completion of a [`DungeonPath`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct PathCompletion {
+    /// The id of the path.
+    pub id: String,
+    /// The kind of the path.
+    pub kind: DungeonKind,
+    /// Whether the path was completed since daily reset.
+    pub completed: bool,
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint;
+
+/// A background of a guild emblem.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct EmblemBackground {
+    /// The id of the background.
+    pub id: u64,
+    /// A list of urls pointing to the image layers of the background.
+    pub layers: Vec<String>,
+}
+
+endpoint!(EmblemBackground, "/v2/emblem/backgrounds", u64);
+
+/// A foreground of a guild emblem.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct EmblemForeground {
+    /// The id of the foreground.
+    pub id: u64,
+    /// A list of urls pointing to the image layers of the foreground.
+    pub layers: Vec<String>,
+}
+
+endpoint!(EmblemForeground, "/v2/emblem/foregrounds", u64);
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint;
+#[cfg(feature = "items")]
+use crate::v2::items::Item;
+#[cfg(feature = "items")]
+use crate::{bulk, Executor};
+
+/// An emote.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Emote {
+    /// The id of the emote.
+    pub id: String,
+    /// A list of chat commands triggering the emote.
+    pub commands: Vec<String>,
+    /// A list of ids of items that unlock the emote. Empty for emotes unlocked by default.
+    #[serde(default)]
+    pub unlock_items: Vec<u64>,
+}
+
+impl Emote {
+    #[cfg(feature = "items")]
+    /// Returns the [`Item`]s that unlock this emote.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::emotes::Emote;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let emote = Emote::get(&client, String::from("bless")).await?;
+    ///
+    /// for item in emote.unlock_items(&client).await? {
+    ///     println!("{}", item.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::emotes::Emote;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let emote = Emote::get(&client, String::from("bless"))?;
+    ///
+    /// for item in emote.unlock_items(&client)? {
+    ///     println!("{}", item.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`blocking`]: crate::blocking
+    pub fn unlock_items<C>(&self, client: &C) -> C::Output<Vec<Item>>
+    where
+        C: Executor,
+    {
+        let ids = self.unlock_items.clone();
+
+        client.execute(|client| async move { bulk::get_chunked(&client, Item::URI, &ids).await })
+    }
+}
+
+endpoint!(Emote, "/v2/emotes", String, get_all);
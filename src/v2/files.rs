@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use crate::endpoint;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct File {
     pub id: String,
     pub icon: String,
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint;
+
+/// A PvP finisher.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Finisher {
+    /// The id of the finisher.
+    pub id: u64,
+    /// A description explaining how to unlock the finisher. May contain markup.
+    pub unlock_details: String,
+    /// A list of ids of items that unlock the finisher.
+    #[serde(default)]
+    pub unlock_items: Vec<u64>,
+    /// The sorting order of the finisher.
+    pub order: u64,
+    /// A url pointing to the icon of the finisher.
+    pub icon: String,
+    /// The name of the finisher.
+    pub name: String,
+}
+
+endpoint!(Finisher, "/v2/finishers", u64, localized, get_all);
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint;
+
+/// A glider skin.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Glider {
+    /// The id of the glider.
+    pub id: u64,
+    /// A list of ids of items that unlock the glider.
+    #[serde(default)]
+    pub unlock_items: Vec<u64>,
+    /// The sorting order of the glider.
+    pub order: u64,
+    /// A url pointing to the icon of the glider.
+    pub icon: String,
+    /// The name of the glider.
+    pub name: String,
+    /// The description of the glider.
+    pub description: String,
+    /// A list of ids of the dyes applied on the glider by default.
+    pub default_dyes: Vec<u64>,
+}
+
+endpoint!(Glider, "/v2/gliders", u64, localized, get_all);
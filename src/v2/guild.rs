@@ -1,32 +1,108 @@
-use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use futures_util::future;
 use serde::{Deserialize, Serialize};
 
-use crate::{Authentication, ClientExecutor, RequestBuilder};
+use crate::bulk;
+use crate::datetime::DateTime;
+#[cfg(all(feature = "emblem", feature = "colors"))]
+use crate::v2::colors::Color;
+use crate::v2::commerce::{Coins, Prices};
+use crate::v2::common::RenderUrl;
+#[cfg(all(feature = "emblem", feature = "colors"))]
+use crate::v2::emblem::{EmblemBackground, EmblemForeground};
+use crate::v2::pvp::{GameResult, GameScores, GameTeam, Ladders, RatingType, WinLoss};
+use crate::{
+    collection, endpoint, permissions, Authentication, ClientExecutor, Executor, Language,
+    RequestBuilder,
+};
 
+/// A guild with its public fields.
+///
+/// Guild leaders can request the [`GuildDetails`] of their guild using
+/// [`Guild::get_authenticated`].
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Guild {
+    /// The id of the guild.
     pub id: String,
+    /// The name of the guild.
     pub name: String,
+    /// The tag of the guild.
     pub tag: String,
+    /// The emblem of the guild.
     pub emblem: GuildEmblem,
-    // Avaliable with leader token.
-    pub level: Option<u8>,
-    pub motd: Option<String>,
-    pub influence: Option<u64>,
-    pub aetherium: Option<u64>,
-    pub favor: Option<u64>,
-    pub member_count: Option<u16>,
-    pub member_capacity: Option<u16>,
 }
 
 impl Guild {
+    permissions!(
+        #[doc = "The permissions required to access [`get_authenticated`](Self::get_authenticated):"]
+        Guilds
+    );
+
     /// Returns the guild with the given `id`.
     pub fn get<C>(client: &C, id: &str) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        let uri = format!("/v2/guild/{}", id);
-        client.send(RequestBuilder::new(uri))
+        client.send(RequestBuilder::new("/v2/guild").segment(id))
+    }
+
+    /// Returns the guild with the given `id` including the fields only available to guild
+    /// leaders.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// If the account of the current access token is not a guild leader of the guild, the leader
+    /// fields are missing from the response and an [`Error`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::guild::Guild;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// # let id = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let details = Guild::get_authenticated(&client, id).await?;
+    /// println!("{}: {}", details.name, details.motd);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::guild::Guild;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// # let id = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let details = Guild::get_authenticated(&client, id)?;
+    /// println!("{}: {}", details.name, details.motd);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn get_authenticated<C>(client: &C, id: &str) -> C::Result
+    where
+        C: ClientExecutor<GuildDetails>,
+    {
+        client.send(
+            RequestBuilder::new("/v2/guild")
+                .segment(id)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 
     /// Returns a list of guild ids matching the searched `name`. If no matches are found this
@@ -66,12 +142,286 @@ impl Guild {
     where
         C: ClientExecutor<Vec<String>>,
     {
-        let uri = format!("/v2/guild/search?name={}", name);
-        client.send(RequestBuilder::new(uri))
+        client.send(RequestBuilder::new("/v2/guild/search").query("name", name))
+    }
+
+    /// Returns all guilds matching the searched `name`. If no matches are found this returns an
+    /// empty [`Vec`].
+    ///
+    /// This searches for the `name` using [`search`] and fetches all matching guilds
+    /// concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::guild::Guild;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let guilds = Guild::get_by_name(&client, "Covenant Of The Just").await?;
+    ///
+    /// for guild in guilds {
+    ///     println!("[{}] {}", guild.tag, guild.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::guild::Guild;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let guilds = Guild::get_by_name(&client, "Covenant Of The Just")?;
+    ///
+    /// for guild in guilds {
+    ///     println!("[{}] {}", guild.tag, guild.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`search`]: Self::search
+    /// [`blocking`]: crate::blocking
+    pub fn get_by_name<C>(client: &C, name: &str) -> C::Output<Vec<Self>>
+    where
+        C: Executor,
+    {
+        let name = name.to_owned();
+
+        client.execute(|client| async move {
+            let ids: Vec<String> = Self::search(&client, &name).await?;
+
+            future::try_join_all(ids.iter().map(|id| Self::get(&client, id))).await
+        })
+    }
+
+    /// Returns the id, name and tag of all guilds matching the searched `name`. If no matches are
+    /// found this returns an empty [`GuildSearch`].
+    ///
+    /// This searches for the `name` using [`search`] and fetches all matching guilds
+    /// concurrently. Multiple guilds may be returned for the same `name`; use
+    /// [`GuildSearch::find_by_tag`] to pick one by its tag.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::guild::Guild;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let guilds = Guild::search_detailed(&client, "Covenant Of The Just").await?;
+    ///
+    /// if let Some(guild) = guilds.find_by_tag("CJ") {
+    ///     println!("{}", guild.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::guild::Guild;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let guilds = Guild::search_detailed(&client, "Covenant Of The Just")?;
+    ///
+    /// if let Some(guild) = guilds.find_by_tag("CJ") {
+    ///     println!("{}", guild.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`search`]: Self::search
+    /// [`blocking`]: crate::blocking
+    pub fn search_detailed<C>(client: &C, name: &str) -> C::Output<GuildSearch>
+    where
+        C: Executor,
+    {
+        let name = name.to_owned();
+
+        client.execute(|client| async move {
+            let guilds: Vec<Self> = Self::get_by_name(&client, &name).await?;
+
+            Ok(GuildSearch(
+                guilds.into_iter().map(GuildSearchResult::from).collect(),
+            ))
+        })
+    }
+
+    /// Returns the members of the guild with the given `guild_id` annotated with their rank.
+    ///
+    /// This fetches [`GuildMembers`] and [`GuildRanks`] concurrently and joins them into a
+    /// [`GuildRoster`]. See [`GuildRoster::new`] for details on the ordering of the members.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// If the account of the current access token is not a guild leader of the guild, an [`Error`]
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::guild::Guild;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// # let guild = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let roster = Guild::roster(&client, guild).await?;
+    ///
+    /// for member in roster.0 {
+    ///     println!("{} ({})", member.name, member.rank_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::guild::Guild;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// # let guild = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let roster = Guild::roster(&client, guild)?;
+    ///
+    /// for member in roster.0 {
+    ///     println!("{} ({})", member.name, member.rank_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn roster<C>(client: &C, guild_id: &str) -> C::Output<GuildRoster>
+    where
+        C: Executor,
+    {
+        let guild_id = guild_id.to_owned();
+
+        client.execute(|client| async move {
+            let (members, ranks) = future::try_join(
+                GuildMembers::get(&client, &guild_id),
+                GuildRanks::get(&client, &guild_id),
+            )
+            .await?;
+
+            Ok(GuildRoster::new(members, ranks))
+        })
+    }
+}
+
+/// The guilds matching a search by name, returned by [`Guild::search_detailed`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GuildSearch(pub Vec<GuildSearchResult>);
+
+collection!(GuildSearch(GuildSearchResult));
+
+impl GuildSearch {
+    /// Returns the first guild with the given `tag`. Tags are compared ignoring ASCII case.
+    pub fn find_by_tag(&self, tag: &str) -> Option<&GuildSearchResult> {
+        self.0
+            .iter()
+            .find(|guild| guild.tag.eq_ignore_ascii_case(tag))
+    }
+
+    /// Returns an iterator over all guilds with the given `tag`. Tags are compared ignoring
+    /// ASCII case.
+    pub fn filter_by_tag<'a>(
+        &'a self,
+        tag: &'a str,
+    ) -> impl Iterator<Item = &'a GuildSearchResult> + 'a {
+        self.0
+            .iter()
+            .filter(move |guild| guild.tag.eq_ignore_ascii_case(tag))
+    }
+}
+
+/// A guild including the fields only available to guild leaders, returned by
+/// [`Guild::get_authenticated`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct GuildDetails {
+    /// The id of the guild.
+    pub id: String,
+    /// The name of the guild.
+    pub name: String,
+    /// The tag of the guild.
+    pub tag: String,
+    /// The emblem of the guild.
+    pub emblem: GuildEmblem,
+    /// The level of the guild.
+    pub level: u8,
+    /// The message of the day.
+    pub motd: String,
+    /// The amount of influence held by the guild.
+    pub influence: u64,
+    /// The amount of aetherium held by the guild.
+    pub aetherium: u64,
+    /// The amount of guild favor held by the guild.
+    pub favor: u64,
+    /// The number of members in the guild.
+    pub member_count: u16,
+    /// The maximum number of members in the guild.
+    pub member_capacity: u16,
+}
+
+impl From<GuildDetails> for Guild {
+    #[inline]
+    fn from(details: GuildDetails) -> Self {
+        Self {
+            id: details.id,
+            name: details.name,
+            tag: details.tag,
+            emblem: details.emblem,
+        }
+    }
+}
+
+/// A guild in a [`GuildSearch`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct GuildSearchResult {
+    /// The id of the guild.
+    pub id: String,
+    /// The name of the guild.
+    pub name: String,
+    /// The tag of the guild.
+    pub tag: String,
+}
+
+impl From<Guild> for GuildSearchResult {
+    fn from(guild: Guild) -> Self {
+        Self {
+            id: guild.id,
+            name: guild.name,
+            tag: guild.tag,
+        }
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct GuildEmblem {
     pub background: GuildEmblemSection,
     pub foreground: GuildEmblemSection,
@@ -79,12 +429,13 @@ pub struct GuildEmblem {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct GuildEmblemSection {
     pub id: u64,
     pub colors: Vec<u64>,
 }
 
-#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum GuildEmblemFlag {
     FlipBackgroundHorizontal,
     FlipBackgroundVertical,
@@ -92,12 +443,189 @@ pub enum GuildEmblemFlag {
     FlipForegroundVertical,
 }
 
+#[cfg(all(feature = "emblem", feature = "colors"))]
+impl GuildEmblem {
+    /// Resolves the image layers and colors of the emblem.
+    ///
+    /// This fetches the [`EmblemBackground`], the [`EmblemForeground`] and all [`Color`]s of the
+    /// emblem concurrently.
+    ///
+    /// Requires the `emblem` and `colors` features to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::guild::Guild;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let id = "";
+    /// let client = Client::new();
+    /// let guild = Guild::get(&client, id).await?;
+    /// let emblem = guild.emblem.resolve(&client).await?;
+    ///
+    /// for layer in &emblem.background.layers {
+    ///     println!("{}", layer);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::guild::Guild;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let id = "";
+    /// let client = Client::new();
+    /// let guild = Guild::get(&client, id)?;
+    /// let emblem = guild.emblem.resolve(&client)?;
+    ///
+    /// for layer in &emblem.background.layers {
+    ///     println!("{}", layer);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`blocking`]: crate::blocking
+    pub fn resolve<C>(&self, client: &C) -> C::Output<ResolvedEmblem>
+    where
+        C: Executor,
+    {
+        let emblem = self.clone();
+
+        client.execute(|client| async move {
+            let mut ids: Vec<u64> = emblem
+                .background
+                .colors
+                .iter()
+                .chain(&emblem.foreground.colors)
+                .copied()
+                .collect();
+            ids.sort_unstable();
+            ids.dedup();
+
+            let (background, foreground, colors): (EmblemBackground, EmblemForeground, Vec<Color>) =
+                future::try_join3(
+                    EmblemBackground::get(&client, emblem.background.id),
+                    EmblemForeground::get(&client, emblem.foreground.id),
+                    bulk::get_chunked(&client, Color::URI, &ids),
+                )
+                .await?;
+
+            Ok(ResolvedEmblem::new(
+                &emblem, background, foreground, &colors,
+            ))
+        })
+    }
+}
+
+/// A [`GuildEmblem`] with resolved image layers and colors, ready to be composited.
+///
+/// Returned by [`GuildEmblem::resolve`].
+#[cfg(all(feature = "emblem", feature = "colors"))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct ResolvedEmblem {
+    /// The background of the emblem, drawn first.
+    pub background: ResolvedEmblemSection,
+    /// The foreground of the emblem, drawn on top of the background.
+    pub foreground: ResolvedEmblemSection,
+}
+
+#[cfg(all(feature = "emblem", feature = "colors"))]
+impl ResolvedEmblem {
+    /// Creates a new `ResolvedEmblem` by joining the `emblem` with its `background`,
+    /// `foreground` and `colors`.
+    pub fn new(
+        emblem: &GuildEmblem,
+        background: EmblemBackground,
+        foreground: EmblemForeground,
+        colors: &[Color],
+    ) -> Self {
+        let flag = |flag| emblem.flags.contains(&flag);
+
+        Self {
+            background: ResolvedEmblemSection::new(
+                background.layers,
+                &emblem.background.colors,
+                colors,
+                flag(GuildEmblemFlag::FlipBackgroundHorizontal),
+                flag(GuildEmblemFlag::FlipBackgroundVertical),
+            ),
+            foreground: ResolvedEmblemSection::new(
+                foreground.layers,
+                &emblem.foreground.colors,
+                colors,
+                flag(GuildEmblemFlag::FlipForegroundHorizontal),
+                flag(GuildEmblemFlag::FlipForegroundVertical),
+            ),
+        }
+    }
+}
+
+/// The background or foreground of a [`ResolvedEmblem`].
+#[cfg(all(feature = "emblem", feature = "colors"))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct ResolvedEmblemSection {
+    /// A list of urls pointing to the image layers of the section.
+    pub layers: Vec<String>,
+    /// The RGB values of the colors of the section, in the order of [`GuildEmblemSection::colors`].
+    /// A color is `None` if it could not be found.
+    ///
+    /// The RGB values are those of the color applied on cloth.
+    pub colors: Vec<Option<[u8; 3]>>,
+    /// Whether the section is flipped horizontally.
+    pub flip_horizontal: bool,
+    /// Whether the section is flipped vertically.
+    pub flip_vertical: bool,
+}
+
+#[cfg(all(feature = "emblem", feature = "colors"))]
+impl ResolvedEmblemSection {
+    fn new(
+        layers: Vec<String>,
+        ids: &[u64],
+        colors: &[Color],
+        flip_horizontal: bool,
+        flip_vertical: bool,
+    ) -> Self {
+        let colors = ids
+            .iter()
+            .map(|id| {
+                let color = colors.iter().find(|color| color.id == *id)?;
+
+                match color.cloth.rgb[..] {
+                    [r, g, b] => Some([r, g, b].map(|c| c.min(255) as u8)),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        Self {
+            layers,
+            colors,
+            flip_horizontal,
+            flip_vertical,
+        }
+    }
+}
+
 /// A list of [`GuildMember`]s.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct GuildMembers(pub Vec<GuildMember>);
 
+collection!(GuildMembers(GuildMember));
+
 impl GuildMembers {
+    permissions!(Account, Guilds);
+
     /// Returns a list of all members in the guild with the provided `guild_id`.
     ///
     /// Note that the current access token must be a guild leader of the provided `guild_id`.
@@ -147,27 +675,39 @@ impl GuildMembers {
     where
         C: ClientExecutor<Self>,
     {
-        let uri = format!("/v2/guild/{}/members", guild_id);
-        client.send(RequestBuilder::new(uri).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new("/v2/guild")
+                .segment(guild_id)
+                .segment("members")
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
 /// A member in a guild.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct GuildMember {
     /// The account name of the member.
     pub name: String,
     /// The rank of the member.
     pub rank: String,
     /// The date the member joined the guild.
-    pub joined: DateTime<Utc>,
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub joined: DateTime,
 }
 
+/// A list of ranks of a guild.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(transparent)]
-pub struct GuildRanks(Vec<GuildRank>);
+pub struct GuildRanks(pub Vec<GuildRank>);
+
+collection!(GuildRanks(GuildRank));
 
 impl GuildRanks {
+    permissions!(Account, Guilds);
+
     /// Returns a list of ranks in the guild with the provided `guild_id`.
     ///
     /// Note that the current access token must be a guild leader of the provided `guild_id`.
@@ -217,12 +757,18 @@ impl GuildRanks {
     where
         C: ClientExecutor<Self>,
     {
-        let uri = format!("/v2/guild/{}/ranks", guild_id);
-        client.send(RequestBuilder::new(uri).authenticated(Authentication::Required))
+        client.send(
+            RequestBuilder::new("/v2/guild")
+                .segment(guild_id)
+                .segment("ranks")
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct GuildRank {
     /// The unique name of the rank.
     pub id: String,
@@ -233,3 +779,978 @@ pub struct GuildRank {
     /// A url pointing to the icon of the rank.
     pub icon: String,
 }
+
+/// The stash of a guild, split into multiple tabs.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GuildStash(pub Vec<GuildStashTab>);
+
+collection!(GuildStash(GuildStashTab));
+
+impl GuildStash {
+    permissions!(Account, Guilds);
+
+    /// Returns the stash of the guild with the provided `guild_id`.
+    ///
+    /// Note that the current access token must be a guild leader of the provided `guild_id`.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// If the account of the current access token is not a guild leader of the guild, an [`Error`]
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::guild::GuildStash;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// # let guild = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let stash = GuildStash::get(&client, guild).await?;
+    /// println!("The guild has {} in its stash.", stash.coins());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::guild::GuildStash;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// # let guild = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let stash = GuildStash::get(&client, guild)?;
+    /// println!("The guild has {} in its stash.", stash.coins());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C, guild_id: &str) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new("/v2/guild")
+                .segment(guild_id)
+                .segment("stash")
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns the total amount of coins stored in all tabs.
+    pub fn coins(&self) -> Coins {
+        self.0.iter().map(|tab| tab.coins).sum()
+    }
+
+    /// Returns an iterator over all items stored in all tabs.
+    pub fn items(&self) -> impl Iterator<Item = &GuildStashSlot> + '_ {
+        self.0.iter().flat_map(|tab| tab.items())
+    }
+
+    /// Returns the total value of the stash based on the given trading post `prices`.
+    ///
+    /// Items are valued at their lowest sell listing. Items without a sell listing or not
+    /// contained in `prices` (e.g. account bound items) are not counted.
+    pub fn value(&self, prices: &[Prices]) -> Coins {
+        let items = self
+            .items()
+            .filter_map(|slot| {
+                let price = prices.iter().find(|price| price.id == slot.id)?;
+                Some(price.sells.unit_price * slot.count)
+            })
+            .sum::<Coins>();
+
+        self.coins() + items
+    }
+
+    /// Returns the total value of the stash of the guild with the provided `guild_id`.
+    ///
+    /// This fetches the [`GuildStash`] and the [`Prices`] of all items in the stash and returns
+    /// the combined value as computed by [`value`].
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// If the account of the current access token is not a guild leader of the guild, an [`Error`]
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::guild::GuildStash;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// # let guild = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let value = GuildStash::get_value(&client, guild).await?;
+    /// println!("The stash is worth {}.", value);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::guild::GuildStash;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// # let guild = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let value = GuildStash::get_value(&client, guild)?;
+    /// println!("The stash is worth {}.", value);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`value`]: Self::value
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn get_value<C>(client: &C, guild_id: &str) -> C::Output<Coins>
+    where
+        C: Executor,
+    {
+        let guild_id = guild_id.to_owned();
+
+        client.execute(|client| async move {
+            let stash: Self = Self::get(&client, &guild_id).await?;
+
+            let mut ids: Vec<u64> = stash.items().map(|slot| slot.id).collect();
+            ids.sort_unstable();
+            ids.dedup();
+
+            let prices: Vec<Prices> = Prices::get_many(&client, &ids).await?;
+            Ok(stash.value(&prices))
+        })
+    }
+}
+
+/// A tab in a [`GuildStash`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct GuildStashTab {
+    /// The id of the guild upgrade which unlocked this tab.
+    pub upgrade_id: u64,
+    /// The number of slots in this tab.
+    pub size: u64,
+    /// The amount of coins stored in this tab.
+    pub coins: Coins,
+    /// The description of the tab set by the guild leader.
+    pub note: String,
+    /// The slots of the tab. An empty slot is `None`.
+    pub inventory: Vec<Option<GuildStashSlot>>,
+}
+
+impl GuildStashTab {
+    /// Returns an iterator over all items stored in this tab, skipping empty slots.
+    pub fn items(&self) -> impl Iterator<Item = &GuildStashSlot> + '_ {
+        self.inventory.iter().flatten()
+    }
+}
+
+/// An item stored in a slot of a [`GuildStashTab`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct GuildStashSlot {
+    /// The id of the item.
+    pub id: u64,
+    /// The number of items stored in this slot.
+    pub count: u64,
+}
+
+/// An upgrade which can be built in a guild hall.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct GuildUpgrade {
+    /// The id of the upgrade.
+    pub id: u64,
+    /// The name of the upgrade.
+    pub name: String,
+    /// The description of the upgrade.
+    pub description: String,
+    /// The type of the upgrade.
+    #[serde(rename = "type")]
+    pub kind: GuildUpgradeKind,
+    /// The url of the icon of the upgrade.
+    pub icon: RenderUrl,
+    /// The time it takes to build the upgrade in minutes.
+    pub build_time: u64,
+    /// The guild level required to build the upgrade.
+    pub required_level: u64,
+    /// The amount of guild experience gained by building the upgrade.
+    pub experience: u64,
+    /// The ids of the upgrades which must be built before this upgrade.
+    pub prerequisites: Vec<u64>,
+    /// The costs to build the upgrade.
+    pub costs: Vec<GuildUpgradeCost>,
+}
+
+endpoint!(GuildUpgrade, "/v2/guild/upgrades", u64, localized, get_all);
+
+impl GuildUpgrade {
+    /// Returns the costs of the upgrade which are not yet covered by a guild.
+    ///
+    /// Item costs are compared against the items deposited in the `treasury`, aetherium and guild
+    /// favor costs against the amounts held by the `guild`. Coin costs cannot be paid in advance
+    /// and are always returned in full.
+    ///
+    /// Aetherium and guild favor costs are recognized by their name, so the upgrade must be in
+    /// [`Language::En`]. Use [`get_localized`] to request it regardless of the client language.
+    ///
+    /// [`get_localized`]: Self::get_localized
+    pub fn remaining_costs(
+        &self,
+        guild: &GuildDetails,
+        treasury: &GuildTreasury,
+    ) -> RemainingUpgradeCosts {
+        let mut remaining = RemainingUpgradeCosts::default();
+
+        for cost in &self.costs {
+            match cost {
+                GuildUpgradeCost::Item { item_id, count, .. }
+                | GuildUpgradeCost::Collectible {
+                    item_id: Some(item_id),
+                    count,
+                    ..
+                } => {
+                    remaining.items.push(RemainingItem {
+                        item_id: *item_id,
+                        required: *count,
+                        available: treasury.count(*item_id),
+                    });
+                }
+                GuildUpgradeCost::Currency { name, count }
+                | GuildUpgradeCost::Collectible { name, count, .. } => match name.as_str() {
+                    "Aetherium" => remaining.aetherium += *count,
+                    "Guild Favor" => remaining.favor += *count,
+                    _ => (),
+                },
+                GuildUpgradeCost::Coins { count } => remaining.coins += *count,
+                GuildUpgradeCost::Unknown => (),
+            }
+        }
+
+        remaining.aetherium = remaining.aetherium.saturating_sub(guild.aetherium);
+        remaining.favor = remaining.favor.saturating_sub(guild.favor);
+
+        remaining
+    }
+
+    /// Returns the remaining costs of the upgrade with the provided `upgrade_id` for the guild
+    /// with the provided `guild_id`.
+    ///
+    /// This fetches the [`GuildDetails`], the [`GuildTreasury`] and the [`GuildUpgrade`] and
+    /// returns the costs as computed by [`remaining_costs`].
+    /// The upgrade is always requested in [`Language::En`], regardless of the language of the
+    /// client, since aetherium and guild favor costs are recognized by their name.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// If the account of the current access token is not a guild leader of the guild, an [`Error`]
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::guild::GuildUpgrade;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// # let guild = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let costs = GuildUpgrade::get_remaining_costs(&client, guild, 38).await?;
+    /// println!("{} aetherium and {} favor missing.", costs.aetherium, costs.favor);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::guild::GuildUpgrade;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// # let guild = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let costs = GuildUpgrade::get_remaining_costs(&client, guild, 38)?;
+    /// println!("{} aetherium and {} favor missing.", costs.aetherium, costs.favor);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`remaining_costs`]: Self::remaining_costs
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn get_remaining_costs<C>(
+        client: &C,
+        guild_id: &str,
+        upgrade_id: u64,
+    ) -> C::Output<RemainingUpgradeCosts>
+    where
+        C: Executor,
+    {
+        let guild_id = guild_id.to_owned();
+
+        client.execute(|client| async move {
+            let (guild, treasury, upgrade): (GuildDetails, GuildTreasury, Self) =
+                future::try_join3(
+                    Guild::get_authenticated(&client, &guild_id),
+                    GuildTreasury::get(&client, &guild_id),
+                    Self::get_localized(&client, upgrade_id, Language::En),
+                )
+                .await?;
+
+            Ok(upgrade.remaining_costs(&guild, &treasury))
+        })
+    }
+}
+
+/// The type of a [`GuildUpgrade`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GuildUpgradeKind {
+    AccumulatingCurrency,
+    BankBag,
+    Boost,
+    Claimable,
+    Consumable,
+    Decoration,
+    GuildHall,
+    GuildHallExpedition,
+    Hub,
+    Queue,
+    Unlock,
+    /// A type unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A cost of a [`GuildUpgrade`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum GuildUpgradeCost {
+    /// An item which must be deposited in the [`GuildTreasury`].
+    Item {
+        name: String,
+        count: u64,
+        item_id: u64,
+    },
+    /// A collectible, e.g. a decoration.
+    Collectible {
+        name: String,
+        count: u64,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        item_id: Option<u64>,
+    },
+    /// A guild currency, e.g. aetherium or guild favor.
+    Currency { name: String, count: u64 },
+    /// Coins paid on completion.
+    Coins { count: Coins },
+    /// A cost unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The costs of a [`GuildUpgrade`] which are not yet covered by a guild.
+///
+/// Returned by [`GuildUpgrade::remaining_costs`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct RemainingUpgradeCosts {
+    /// The item costs of the upgrade, including items which are already fully deposited.
+    pub items: Vec<RemainingItem>,
+    /// The amount of aetherium missing.
+    pub aetherium: u64,
+    /// The amount of guild favor missing.
+    pub favor: u64,
+    /// The coins required.
+    pub coins: Coins,
+}
+
+impl RemainingUpgradeCosts {
+    /// Returns `true` if all items, aetherium and favor required by the upgrade are available.
+    ///
+    /// Coins are not considered.
+    pub fn is_covered(&self) -> bool {
+        self.aetherium == 0
+            && self.favor == 0
+            && self.items.iter().all(|item| item.remaining() == 0)
+    }
+}
+
+/// An item cost of a [`GuildUpgrade`] together with the amount deposited in the
+/// [`GuildTreasury`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct RemainingItem {
+    /// The id of the item.
+    pub item_id: u64,
+    /// The number of items required by the upgrade.
+    pub required: u64,
+    /// The number of items deposited in the treasury.
+    pub available: u64,
+}
+
+impl RemainingItem {
+    /// Returns the number of items which still need to be deposited.
+    #[inline]
+    pub fn remaining(&self) -> u64 {
+        self.required.saturating_sub(self.available)
+    }
+}
+
+/// The items deposited in the treasury of a guild.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GuildTreasury(pub Vec<GuildTreasuryItem>);
+
+collection!(GuildTreasury(GuildTreasuryItem));
+
+impl GuildTreasury {
+    permissions!(Account, Guilds);
+
+    /// Returns the treasury of the guild with the provided `guild_id`.
+    ///
+    /// Note that the current access token must be a guild leader of the provided `guild_id`.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// If the account of the current access token is not a guild leader of the guild, an [`Error`]
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::guild::GuildTreasury;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// # let guild = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let treasury = GuildTreasury::get(&client, guild).await?;
+    /// println!("{:?}", treasury);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::guild::GuildTreasury;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// # let guild = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let treasury = GuildTreasury::get(&client, guild)?;
+    /// println!("{:?}", treasury);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C, guild_id: &str) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new("/v2/guild")
+                .segment(guild_id)
+                .segment("treasury")
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns the number of items with the given `item_id` deposited in the treasury.
+    pub fn count(&self, item_id: u64) -> u64 {
+        self.0
+            .iter()
+            .filter(|item| item.item_id == item_id)
+            .map(|item| item.count)
+            .sum()
+    }
+}
+
+/// An item deposited in a [`GuildTreasury`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct GuildTreasuryItem {
+    /// The id of the item.
+    pub item_id: u64,
+    /// The number of items deposited.
+    pub count: u64,
+    /// The upgrades which require this item.
+    pub needed_by: Vec<GuildTreasuryNeed>,
+}
+
+/// A [`GuildUpgrade`] requiring an item of the [`GuildTreasury`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct GuildTreasuryNeed {
+    /// The id of the upgrade.
+    pub upgrade_id: u64,
+    /// The number of items still required by the upgrade.
+    pub count: u64,
+}
+
+/// The consumables, decorations and schematics stored in the storage of a guild hall.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GuildStorage(pub Vec<GuildStorageSlot>);
+
+collection!(GuildStorage(GuildStorageSlot));
+
+impl GuildStorage {
+    permissions!(Account, Guilds);
+
+    /// Returns the storage of the guild with the provided `guild_id`.
+    ///
+    /// Note that the current access token must be a guild leader of the provided `guild_id`.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// If the account of the current access token is not a guild leader of the guild, an [`Error`]
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::guild::GuildStorage;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// # let guild = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let storage = GuildStorage::get(&client, guild).await?;
+    /// println!("{:?}", storage);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::guild::GuildStorage;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// # let guild = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let storage = GuildStorage::get(&client, guild)?;
+    /// println!("{:?}", storage);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C, guild_id: &str) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new("/v2/guild")
+                .segment(guild_id)
+                .segment("storage")
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns the storage of the guild with the provided `guild_id` with every slot joined
+    /// with its [`GuildUpgrade`].
+    ///
+    /// Slots whose upgrade is not returned by `/v2/guild/upgrades` are omitted.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication like [`get`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::guild::{GuildStorage, GuildStorageItem};
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// # let guild = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    ///
+    /// for item in GuildStorage::resolve(&client, guild).await? {
+    ///     if let GuildStorageItem::Decoration(decoration) = item {
+    ///         println!("{}x {}", decoration.count, decoration.upgrade.name);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`get`]: Self::get
+    pub fn resolve<C>(client: &C, guild_id: &str) -> C::Output<Vec<GuildStorageItem>>
+    where
+        C: Executor,
+    {
+        let guild_id = guild_id.to_owned();
+
+        client.execute(|client| async move {
+            let storage = Self::get(&client, &guild_id).await?;
+
+            let ids: Vec<u64> = storage.0.iter().map(|slot| slot.id).collect();
+            let upgrades: Vec<GuildUpgrade> =
+                bulk::get_chunked(&client, GuildUpgrade::URI, &ids).await?;
+
+            Ok(storage.join(upgrades))
+        })
+    }
+
+    /// Joins every slot with its upgrade from `upgrades`, keeping the order of the slots.
+    /// Slots whose upgrade is not contained in `upgrades` are omitted.
+    pub fn join<I>(&self, upgrades: I) -> Vec<GuildStorageItem>
+    where
+        I: IntoIterator<Item = GuildUpgrade>,
+    {
+        let upgrades: HashMap<u64, GuildUpgrade> = upgrades
+            .into_iter()
+            .map(|upgrade| (upgrade.id, upgrade))
+            .collect();
+
+        self.0
+            .iter()
+            .filter_map(|slot| {
+                let upgrade = upgrades.get(&slot.id)?.clone();
+                Some(GuildStorageItem::new(StoredUpgrade {
+                    upgrade,
+                    count: slot.count,
+                }))
+            })
+            .collect()
+    }
+}
+
+/// A slot of a [`GuildStorage`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct GuildStorageSlot {
+    /// The id of the [`GuildUpgrade`] stored in the slot.
+    pub id: u64,
+    /// The number of upgrades stored.
+    pub count: u64,
+}
+
+/// A slot of a [`GuildStorage`] joined with its [`GuildUpgrade`], distinguished by the
+/// [`kind`] of the upgrade.
+///
+/// Returned by [`GuildStorage::resolve`].
+///
+/// [`kind`]: GuildUpgrade::kind
+#[derive(Clone, Debug)]
+pub enum GuildStorageItem {
+    /// A consumable, e.g. a guild banquet or a WvW tactic.
+    Consumable(StoredUpgrade),
+    /// A decoration which can be placed in the guild hall.
+    Decoration(StoredUpgrade),
+    /// A schematic which can be claimed by guild members.
+    Schematic(StoredUpgrade),
+    /// An upgrade of any other kind.
+    Other(StoredUpgrade),
+}
+
+impl GuildStorageItem {
+    fn new(stored: StoredUpgrade) -> Self {
+        match stored.upgrade.kind {
+            GuildUpgradeKind::Consumable => Self::Consumable(stored),
+            GuildUpgradeKind::Decoration => Self::Decoration(stored),
+            GuildUpgradeKind::Claimable => Self::Schematic(stored),
+            _ => Self::Other(stored),
+        }
+    }
+
+    /// Returns the stored upgrade, regardless of its kind.
+    #[inline]
+    pub fn stored(&self) -> &StoredUpgrade {
+        match self {
+            Self::Consumable(stored)
+            | Self::Decoration(stored)
+            | Self::Schematic(stored)
+            | Self::Other(stored) => stored,
+        }
+    }
+
+    /// Returns the upgrade stored in the slot.
+    #[inline]
+    pub fn upgrade(&self) -> &GuildUpgrade {
+        &self.stored().upgrade
+    }
+
+    /// Returns the number of upgrades stored.
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.stored().count
+    }
+}
+
+/// A [`GuildUpgrade`] stored in a [`GuildStorage`] together with its count.
+#[derive(Clone, Debug)]
+pub struct StoredUpgrade {
+    /// The stored upgrade, including its name and icon.
+    pub upgrade: GuildUpgrade,
+    /// The number of upgrades stored.
+    pub count: u64,
+}
+
+/// The PvP teams of a guild.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GuildTeams(pub Vec<GuildTeam>);
+
+collection!(GuildTeams(GuildTeam));
+
+impl GuildTeams {
+    permissions!(Account, Guilds);
+
+    /// Returns the PvP teams of the guild with the provided `guild_id`.
+    ///
+    /// Note that the current access token must be a guild leader of the provided `guild_id`.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// If the account of the current access token is not a guild leader of the guild, an [`Error`]
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::guild::GuildTeams;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// # let guild = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let teams = GuildTeams::get(&client, guild).await?;
+    ///
+    /// for team in &teams {
+    ///     println!("{}: {} wins", team.name, team.aggregate.wins);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::guild::GuildTeams;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// # let guild = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let teams = GuildTeams::get(&client, guild)?;
+    ///
+    /// for team in &teams {
+    ///     println!("{}: {} wins", team.name, team.aggregate.wins);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C, guild_id: &str) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new("/v2/guild")
+                .segment(guild_id)
+                .segment("teams")
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+}
+
+/// A PvP team of a guild.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct GuildTeam {
+    /// The id of the team.
+    pub id: u64,
+    /// The name of the team.
+    pub name: String,
+    /// The members of the team.
+    pub members: Vec<GuildTeamMember>,
+    /// The stats of all games played by the team.
+    pub aggregate: WinLoss,
+    /// The stats of the team per ladder.
+    pub ladders: Ladders,
+    /// The most recent games played by the team.
+    pub games: Vec<GuildTeamGame>,
+    /// The records of the team in past and current PvP seasons.
+    pub seasons: Vec<GuildTeamSeason>,
+}
+
+impl GuildTeam {
+    /// Returns the captain of the team. Returns `None` if the team has no captain.
+    pub fn captain(&self) -> Option<&GuildTeamMember> {
+        self.members
+            .iter()
+            .find(|member| member.role == GuildTeamRole::Captain)
+    }
+}
+
+/// A member of a [`GuildTeam`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct GuildTeamMember {
+    /// The account name of the member.
+    pub name: String,
+    /// The role of the member in the team.
+    pub role: GuildTeamRole,
+}
+
+/// The role of a [`GuildTeamMember`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GuildTeamRole {
+    Captain,
+    Member,
+    /// A role unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A PvP game played by a [`GuildTeam`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct GuildTeamGame {
+    /// The id of the game.
+    pub id: String,
+    /// The id of the map the game was played on.
+    pub map_id: u64,
+    /// The time the game started.
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub started: DateTime,
+    /// The time the game ended.
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub ended: DateTime,
+    /// The result of the game for the team.
+    pub result: GameResult,
+    /// The side the team played on.
+    pub team: GameTeam,
+    /// The final scores of both sides.
+    pub scores: GameScores,
+    /// The type of rating the game counted towards.
+    pub rating_type: RatingType,
+    /// The change of the rating of the team. Only avaliable for ranked games.
+    pub rating_change: Option<i64>,
+    /// The id of the season the game was played in. Only avaliable for games played during a
+    /// season.
+    pub season: Option<String>,
+}
+
+/// The record of a [`GuildTeam`] in a PvP season.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct GuildTeamSeason {
+    /// The id of the season.
+    pub id: String,
+    /// The number of games won in the season.
+    pub wins: u64,
+    /// The number of games lost in the season.
+    pub losses: u64,
+    /// The rating of the team at the end of the season.
+    pub rating: u64,
+}
+
+/// A list of [`GuildMember`]s annotated with their [`GuildRank`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GuildRoster(pub Vec<RosterMember>);
+
+collection!(GuildRoster(RosterMember));
+
+impl GuildRoster {
+    /// Creates a new `GuildRoster` by joining the `members` of a guild with its `ranks`.
+    ///
+    /// The members are sorted by the order of their rank, highest rank first, then by the date
+    /// they joined the guild. Members with a rank not contained in `ranks` (e.g. invited members)
+    /// are sorted last.
+    pub fn new(members: GuildMembers, ranks: GuildRanks) -> Self {
+        let mut roster: Vec<RosterMember> = members
+            .0
+            .into_iter()
+            .map(|member| {
+                let rank = ranks.0.iter().find(|rank| rank.id == member.rank).cloned();
+
+                RosterMember {
+                    name: member.name,
+                    rank_id: member.rank,
+                    rank,
+                    joined: member.joined,
+                }
+            })
+            .collect();
+
+        roster.sort_by(|a, b| {
+            let order = match (&a.rank, &b.rank) {
+                (Some(a), Some(b)) => a.order.cmp(&b.order),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            };
+
+            order.then_with(|| a.joined.cmp(&b.joined))
+        });
+
+        Self(roster)
+    }
+}
+
+/// A member in a [`GuildRoster`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct RosterMember {
+    /// The account name of the member.
+    pub name: String,
+    /// The name of the rank of the member.
+    pub rank_id: String,
+    /// The rank of the member. This is `None` if the rank is not a regular guild rank, e.g. for
+    /// invited members.
+    pub rank: Option<GuildRank>,
+    /// The date the member joined the guild.
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub joined: DateTime,
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint;
+
+/// A cat that can be unlocked in the home instance.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Cat {
+    /// The id of the cat.
+    pub id: u64,
+    /// A hint on how to unlock the cat.
+    pub hint: String,
+}
+
+/// A gathering node that can be unlocked in the home instance.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Node {
+    /// The id of the node.
+    pub id: String,
+}
+
+endpoint!(Cat, "/v2/home/cats", u64, get_all);
+endpoint!(Node, "/v2/home/nodes", String, get_all);
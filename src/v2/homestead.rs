@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint;
+
+/// A decoration that can be placed in the homestead.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Decoration {
+    /// The id of the decoration.
+    pub id: u64,
+    /// The name of the decoration.
+    pub name: String,
+    /// The description of the decoration.
+    pub description: String,
+    /// The maximum number of this decoration that can be stored.
+    pub max_count: u64,
+    /// A url pointing to the icon of the decoration.
+    pub icon: String,
+    /// A list of ids of the [`DecorationCategory`]s the decoration belongs to.
+    pub categories: Vec<u64>,
+}
+
+/// A category of homestead [`Decoration`]s.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct DecorationCategory {
+    /// The id of the category.
+    pub id: u64,
+    /// The name of the category.
+    pub name: String,
+}
+
+/// A glyph that can be slotted into the homestead gathering tools.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Glyph {
+    /// The id of the glyph.
+    pub id: String,
+    /// The id of the item of the glyph.
+    pub item_id: u64,
+    /// The gathering tool slot the glyph applies to.
+    pub slot: GlyphSlot,
+}
+
+/// The gathering tool slot of a [`Glyph`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GlyphSlot {
+    Harvesting,
+    Logging,
+    Mining,
+    /// A slot unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+endpoint!(
+    Decoration,
+    "/v2/homestead/decorations",
+    u64,
+    localized,
+    get_all
+);
+endpoint!(
+    DecorationCategory,
+    "/v2/homestead/decorations/categories",
+    u64,
+    localized,
+    get_all
+);
+endpoint!(Glyph, "/v2/homestead/glyphs", String, get_all);
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint;
+use crate::v2::common::{ChatLink, Rarity};
+
+/// An item.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Item {
+    /// The id of the item.
+    pub id: u64,
+    /// The chat link of the item.
+    pub chat_link: ChatLink,
+    /// The name of the item.
+    pub name: String,
+    /// A url pointing to the icon of the item.
+    pub icon: Option<String>,
+    /// The description of the item.
+    pub description: Option<String>,
+    /// The type of the item.
+    #[serde(rename = "type")]
+    pub kind: ItemKind,
+    /// The rarity of the item.
+    pub rarity: Rarity,
+    /// The required level to use the item.
+    pub level: u8,
+    /// The value in coins when selling the item to a vendor.
+    pub vendor_value: u64,
+    /// The id of the default skin of the item. Only avaliable for some items.
+    pub default_skin: Option<u64>,
+    /// A list of flags applying to the item.
+    pub flags: Vec<String>,
+    /// A list of game types in which the item can be used.
+    pub game_types: Vec<String>,
+    /// A list of restrictions applying to the item.
+    pub restrictions: Vec<String>,
+}
+
+/// The type of an [`Item`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ItemKind {
+    Armor,
+    Back,
+    Bag,
+    Consumable,
+    Container,
+    CraftingMaterial,
+    Gathering,
+    Gizmo,
+    JadeTechModule,
+    Key,
+    MiniPet,
+    PowerCore,
+    Relic,
+    Tool,
+    Trait,
+    Trinket,
+    Trophy,
+    UpgradeComponent,
+    Weapon,
+    /// An item type unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+endpoint!(Item, "/v2/items", u64, localized);
+
+/// Ids of well-known items.
+pub mod ids {
+    /// Mystic Clover.
+    pub const MYSTIC_CLOVER: u64 = 19675;
+    /// Glob of Ectoplasm.
+    pub const GLOB_OF_ECTOPLASM: u64 = 19721;
+    /// Obsidian Shard.
+    pub const OBSIDIAN_SHARD: u64 = 19925;
+    /// Mystic Coin.
+    pub const MYSTIC_COIN: u64 = 19976;
+    /// Philosopher's Stone.
+    pub const PHILOSOPHERS_STONE: u64 = 20796;
+    /// Amalgamated Gemstone.
+    pub const AMALGAMATED_GEMSTONE: u64 = 68063;
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint;
+
+/// A jade bot skin.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct JadeBot {
+    /// The id of the jade bot.
+    pub id: u64,
+    /// The name of the jade bot.
+    pub name: String,
+    /// The description of the jade bot.
+    pub description: String,
+    /// The id of the item that unlocks the jade bot.
+    pub unlock_item: u64,
+}
+
+endpoint!(JadeBot, "/v2/jadebots", u64, localized, get_all);
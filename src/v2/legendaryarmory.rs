@@ -0,0 +1,17 @@
+use std::num::NonZeroU8;
+
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint;
+
+/// A legendary item that can be unlocked in the legendary armory.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct LegendaryItem {
+    /// The id of the item.
+    pub id: u64,
+    /// The maximum number of copies of the item that can be unlocked.
+    pub max_count: NonZeroU8,
+}
+
+endpoint!(LegendaryItem, "/v2/legendaryarmory", u64, get_all);
@@ -0,0 +1,80 @@
+use std::collections::HashSet;
+
+use futures_util::future;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::account::AccountMapChests;
+use crate::{endpoint, Executor};
+
+/// A Hero's Choice chest rewarded once per day for completing a meta event.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct MapChest {
+    /// The id of the chest, e.g. `auric_basin_heros_choice_chest`.
+    pub id: String,
+}
+
+impl MapChest {
+    /// Returns the ids of all Hero's Choice chests which the currently authenticated account has
+    /// not opened since the last daily reset.
+    ///
+    /// The chests and the [`AccountMapChests`] of the account are requested concurrently. Chests
+    /// opened by the account are removed from the list. All chests become available again after
+    /// the next daily reset, see [`AccountMapChests::expires_at`].
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::mapchests::MapChest;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let remaining = MapChest::remaining(&client).await?;
+    /// if remaining.iter().any(|id| id == "auric_basin_heros_choice_chest") {
+    ///     println!("Octovine is worth doing today");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::mapchests::MapChest;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let remaining = MapChest::remaining(&client)?;
+    /// if remaining.iter().any(|id| id == "auric_basin_heros_choice_chest") {
+    ///     println!("Octovine is worth doing today");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn remaining<C>(client: &C) -> C::Output<Vec<String>>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let (ids, opened) =
+                future::try_join(Self::ids(&client), AccountMapChests::get(&client)).await?;
+            let opened: HashSet<String> = opened.into_iter().collect();
+
+            Ok(ids.into_iter().filter(|id| !opened.contains(id)).collect())
+        })
+    }
+}
+
+endpoint!(MapChest, "/v2/mapchests", String, get_all);
@@ -0,0 +1,266 @@
+//! Maps and conversions between map and continent coordinates.
+//!
+//! Every [`Map`] is placed on a continent. Positions on the continent, e.g. of points of interest
+//! returned by `/v2/continents`, use continent coordinates in which the y axis points down.
+//! Positions within a map, e.g. the position of a character reported by the MumbleLink API,
+//! use map coordinates in which the y axis points up. [`Map::continent_to_map`] and
+//! [`Map::map_to_continent`] convert between both using the [`map_rect`] and
+//! [`continent_rect`] of the map.
+//!
+//! [`map_rect`]: Map::map_rect
+//! [`continent_rect`]: Map::continent_rect
+
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint, Executor};
+
+/// A map.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Map {
+    /// The id of the map.
+    pub id: u64,
+    /// The localized name of the map.
+    pub name: String,
+    /// The minimum level of the map.
+    pub min_level: u8,
+    /// The maximum level of the map.
+    pub max_level: u8,
+    /// The floor the map is displayed on by default.
+    pub default_floor: i32,
+    /// The type of the map.
+    #[serde(rename = "type")]
+    pub kind: MapKind,
+    /// The floors the map is displayed on.
+    pub floors: Vec<i32>,
+    /// The id of the region the map belongs to.
+    pub region_id: Option<u64>,
+    /// The localized name of the region the map belongs to.
+    pub region_name: Option<String>,
+    /// The id of the continent the map is placed on.
+    pub continent_id: Option<u64>,
+    /// The localized name of the continent the map is placed on.
+    pub continent_name: Option<String>,
+    /// The bounds of the map in map coordinates.
+    pub map_rect: Rect,
+    /// The bounds of the map in continent coordinates.
+    pub continent_rect: Rect,
+}
+
+impl Map {
+    /// Converts the continent coordinates `point` into map coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gw2api_rs::v2::maps::{Map, MapKind, Rect};
+    /// #
+    /// # let map = Map {
+    /// #     id: 15,
+    /// #     name: String::from("Queensdale"),
+    /// #     min_level: 1,
+    /// #     max_level: 15,
+    /// #     default_floor: 1,
+    /// #     kind: MapKind::Public,
+    /// #     floors: vec![0, 1],
+    /// #     region_id: Some(4),
+    /// #     region_name: Some(String::from("Kryta")),
+    /// #     continent_id: Some(1),
+    /// #     continent_name: Some(String::from("Tyria")),
+    /// #     map_rect: Rect([-43008.0, -27648.0], [43008.0, 30720.0]),
+    /// #     continent_rect: Rect([9856.0, 11648.0], [13440.0, 14080.0]),
+    /// # };
+    /// // Queensdale
+    /// let center = map.continent_rect.center();
+    /// assert_eq!(map.continent_to_map(center), [0.0, 1536.0]);
+    /// assert_eq!(map.map_to_continent([0.0, 1536.0]), center);
+    /// ```
+    pub fn continent_to_map(&self, point: [f64; 2]) -> [f64; 2] {
+        let (map, continent) = (&self.map_rect, &self.continent_rect);
+
+        [
+            map.min()[0] + (point[0] - continent.min()[0]) / continent.width() * map.width(),
+            map.max()[1] - (point[1] - continent.min()[1]) / continent.height() * map.height(),
+        ]
+    }
+
+    /// Converts the map coordinates `point` into continent coordinates.
+    pub fn map_to_continent(&self, point: [f64; 2]) -> [f64; 2] {
+        let (map, continent) = (&self.map_rect, &self.continent_rect);
+
+        [
+            continent.min()[0] + (point[0] - map.min()[0]) / map.width() * continent.width(),
+            continent.min()[1] + (map.max()[1] - point[1]) / map.height() * continent.height(),
+        ]
+    }
+
+    /// Returns `true` if the continent coordinates `point` are within the bounds of the map.
+    #[inline]
+    pub fn contains(&self, point: [f64; 2]) -> bool {
+        self.continent_rect.contains(point)
+    }
+
+    /// Returns all maps placed on the continent with the given `continent_id`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::maps::Map;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let maps = Map::by_continent(&client, 1).await?;
+    /// println!("{} maps in Tyria", maps.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::maps::Map;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let maps = Map::by_continent(&client, 1)?;
+    /// println!("{} maps in Tyria", maps.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`blocking`]: crate::blocking
+    pub fn by_continent<C>(client: &C, continent_id: u64) -> C::Output<Vec<Self>>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let maps = Self::get_all(&client).await?;
+
+            Ok(maps
+                .into_iter()
+                .filter(|map| map.continent_id == Some(continent_id))
+                .collect())
+        })
+    }
+
+    /// Returns all maps belonging to the region with the given `region_id`.
+    pub fn by_region<C>(client: &C, region_id: u64) -> C::Output<Vec<Self>>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let maps = Self::get_all(&client).await?;
+
+            Ok(maps
+                .into_iter()
+                .filter(|map| map.region_id == Some(region_id))
+                .collect())
+        })
+    }
+
+    /// Returns the maps containing the continent coordinates `point` on the continent with the
+    /// given `continent_id`.
+    ///
+    /// Maps may overlap, e.g. instances placed on top of the map they are entered from.
+    pub fn at<C>(client: &C, continent_id: u64, point: [f64; 2]) -> C::Output<Vec<Self>>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let maps = Self::get_all(&client).await?;
+
+            Ok(maps
+                .into_iter()
+                .filter(|map| map.continent_id == Some(continent_id) && map.contains(point))
+                .collect())
+        })
+    }
+}
+
+/// The type of a [`Map`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MapKind {
+    /// The home borderland of the blue team in WvW.
+    BlueHome,
+    /// The Eternal Battlegrounds in WvW.
+    Center,
+    /// The Edge of the Mists in WvW.
+    EdgeOfTheMists,
+    /// The home borderland of the green team in WvW.
+    GreenHome,
+    /// An instance, e.g. a story instance or dungeon.
+    Instance,
+    /// A jumping puzzle in WvW.
+    JumpPuzzle,
+    /// An open world map.
+    Public,
+    /// A PvP map.
+    Pvp,
+    /// The home borderland of the red team in WvW.
+    RedHome,
+    /// The tutorial of a race.
+    Tutorial,
+    /// A type unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A rectangle given by its top left and bottom right corners.
+///
+/// In map coordinates the first corner is the bottom left corner instead, since the y axis
+/// points up.
+///
+/// # Examples
+///
+/// ```
+/// use gw2api_rs::v2::maps::Rect;
+///
+/// let rect = Rect([9856.0, 11648.0], [13440.0, 14080.0]);
+/// assert_eq!(rect.width(), 3584.0);
+/// assert_eq!(rect.height(), 2432.0);
+/// assert!(rect.contains([10000.0, 12000.0]));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Rect(pub [f64; 2], pub [f64; 2]);
+
+impl Rect {
+    /// Returns the corner with the smallest coordinates.
+    #[inline]
+    pub fn min(&self) -> [f64; 2] {
+        self.0
+    }
+
+    /// Returns the corner with the largest coordinates.
+    #[inline]
+    pub fn max(&self) -> [f64; 2] {
+        self.1
+    }
+
+    /// Returns the width of the rectangle.
+    #[inline]
+    pub fn width(&self) -> f64 {
+        self.1[0] - self.0[0]
+    }
+
+    /// Returns the height of the rectangle.
+    #[inline]
+    pub fn height(&self) -> f64 {
+        self.1[1] - self.0[1]
+    }
+
+    /// Returns the center of the rectangle.
+    #[inline]
+    pub fn center(&self) -> [f64; 2] {
+        [(self.0[0] + self.1[0]) / 2.0, (self.0[1] + self.1[1]) / 2.0]
+    }
+
+    /// Returns `true` if `point` is within the rectangle, including its edges.
+    pub fn contains(&self, point: [f64; 2]) -> bool {
+        (self.0[0]..=self.1[0]).contains(&point[0]) && (self.0[1]..=self.1[1]).contains(&point[1])
+    }
+}
+
+endpoint!(Map, "/v2/maps", u64, localized, get_all);
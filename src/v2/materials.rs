@@ -0,0 +1,25 @@
+//! Categories of the material storage.
+//!
+//! The materials stored by an account are returned by [`AccountMaterials`].
+//!
+//! [`AccountMaterials`]: crate::v2::account::AccountMaterials
+
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint;
+
+/// A category of the material storage.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct MaterialCategory {
+    /// The id of the category.
+    pub id: u64,
+    /// The localized name of the category.
+    pub name: String,
+    /// The ids of the items stored in the category, in the order they are displayed in.
+    pub items: Vec<u64>,
+    /// The position of the category in the material storage.
+    pub order: u64,
+}
+
+endpoint!(MaterialCategory, "/v2/materials", u64, localized, get_all);
@@ -1,8 +1,17 @@
+use std::collections::{BTreeMap, HashSet};
+
+#[cfg(feature = "account")]
+use futures_util::future;
 use serde::{Deserialize, Serialize};
 
 use crate::endpoint;
+#[cfg(feature = "account")]
+use crate::v2::account::AccountMinis;
+#[cfg(feature = "account")]
+use crate::Executor;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Mini {
     pub id: u64,
     pub name: String,
@@ -12,4 +21,171 @@ pub struct Mini {
     pub item_id: u64,
 }
 
-endpoint!(Mini, "/v2/minis", u64, get_all);
+endpoint!(Mini, "/v2/minis", u64, localized, get_all);
+
+#[cfg(feature = "account")]
+impl Mini {
+    /// Returns all minis not unlocked by the currently authenticated account, sorted by their
+    /// [`order`].
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// [`order`]: Self::order
+    /// [`Error`]: struct@crate::Error
+    pub fn missing_for_account<C>(client: &C) -> C::Output<Vec<Self>>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let collection = Self::collection_for_account(&client).await?;
+            Ok(collection.missing)
+        })
+    }
+
+    /// Returns all minis split into the ones unlocked and not unlocked by the currently
+    /// authenticated account.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::minis::Mini;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let collection = Mini::collection_for_account(&client).await?;
+    ///
+    /// println!("{}/{} minis unlocked", collection.unlocked.len(), collection.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::minis::Mini;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let collection = Mini::collection_for_account(&client)?;
+    ///
+    /// println!("{}/{} minis unlocked", collection.unlocked.len(), collection.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn collection_for_account<C>(client: &C) -> C::Output<MiniCollection>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let (unlocked, minis) =
+                future::try_join(AccountMinis::get(&client), Self::get_all(&client)).await?;
+
+            Ok(MiniCollection::new(minis, &unlocked.0))
+        })
+    }
+}
+
+/// A collection of minis split into unlocked and missing minis.
+///
+/// Both lists are sorted by the [`order`] of the minis.
+///
+/// [`order`]: Mini::order
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct MiniCollection {
+    /// The unlocked minis.
+    pub unlocked: Vec<Mini>,
+    /// The minis not yet unlocked.
+    pub missing: Vec<Mini>,
+}
+
+impl MiniCollection {
+    /// Creates a new `MiniCollection` from all `minis` and the ids of the `unlocked` minis.
+    pub fn new(mut minis: Vec<Mini>, unlocked: &[u64]) -> Self {
+        let unlocked: HashSet<u64> = unlocked.iter().copied().collect();
+
+        minis.sort_by_key(|mini| mini.order);
+        let (unlocked, missing) = minis
+            .into_iter()
+            .partition(|mini| unlocked.contains(&mini.id));
+
+        Self { unlocked, missing }
+    }
+
+    /// Returns the total number of minis in the collection.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.unlocked.len() + self.missing.len()
+    }
+
+    /// Returns `true` if the collection contains no minis.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.unlocked.is_empty() && self.missing.is_empty()
+    }
+
+    /// Returns `true` if all minis in the collection are unlocked.
+    #[inline]
+    pub fn is_completed(&self) -> bool {
+        self.missing.is_empty()
+    }
+
+    /// Groups the minis by the key returned by `f`, e.g. a set derived from the [`order`] or
+    /// the [`item_id`] of the minis. The API does not expose the sets of minis directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gw2api_rs::v2::minis::{Mini, MiniCollection};
+    /// # let minis: Vec<Mini> = Vec::new();
+    /// # let unlocked: Vec<u64> = Vec::new();
+    /// let collection = MiniCollection::new(minis, &unlocked);
+    ///
+    /// // Group minis by the block of 100 their order falls into.
+    /// for (set, minis) in collection.group_by(|mini| mini.order / 100) {
+    ///     println!("set {}: {}/{}", set, minis.unlocked.len(), minis.len());
+    /// }
+    /// ```
+    ///
+    /// [`order`]: Mini::order
+    /// [`item_id`]: Mini::item_id
+    pub fn group_by<K, F>(&self, mut f: F) -> BTreeMap<K, MiniCollection>
+    where
+        K: Ord,
+        F: FnMut(&Mini) -> K,
+    {
+        let mut groups: BTreeMap<K, MiniCollection> = BTreeMap::new();
+
+        for mini in &self.unlocked {
+            groups
+                .entry(f(mini))
+                .or_default()
+                .unlocked
+                .push(mini.clone());
+        }
+
+        for mini in &self.missing {
+            groups
+                .entry(f(mini))
+                .or_default()
+                .missing
+                .push(mini.clone());
+        }
+
+        groups
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint;
+
+/// A type of mount.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MountType {
+    Raptor,
+    Springer,
+    Skimmer,
+    Jackal,
+    Griffon,
+    RollerBeetle,
+    Warclaw,
+    Skyscale,
+    #[serde(rename = "turtle")]
+    SiegeTurtle,
+    /// A mount type unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+impl Display for MountType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let string = match self {
+            Self::Raptor => "raptor",
+            Self::Springer => "springer",
+            Self::Skimmer => "skimmer",
+            Self::Jackal => "jackal",
+            Self::Griffon => "griffon",
+            Self::RollerBeetle => "roller_beetle",
+            Self::Warclaw => "warclaw",
+            Self::Skyscale => "skyscale",
+            Self::SiegeTurtle => "turtle",
+            Self::Unknown => "unknown",
+        };
+
+        write!(f, "{}", string)
+    }
+}
+
+/// A mount.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Mount {
+    /// The type of the mount.
+    pub id: MountType,
+    /// The name of the mount.
+    pub name: String,
+    /// The id of the default mount skin.
+    pub default_skin: u64,
+    /// A list of ids of all skins avaliable for the mount.
+    pub skins: Vec<u64>,
+    /// The skills of the mount.
+    pub skills: Vec<MountSkill>,
+}
+
+/// A skill of a [`Mount`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct MountSkill {
+    /// The id of the skill.
+    pub id: u64,
+    /// The slot of the skill, e.g. `Weapon_1`.
+    pub slot: String,
+}
+
+endpoint!(Mount, "/v2/mounts/types", MountType, localized, get_all);
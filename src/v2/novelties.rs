@@ -1,8 +1,13 @@
 use serde::{Deserialize, Serialize};
 
 use crate::endpoint;
+#[cfg(feature = "items")]
+use crate::v2::items::Item;
+#[cfg(feature = "items")]
+use crate::{bulk, Executor};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Novelty {
     pub id: u64,
     pub name: String,
@@ -22,4 +27,54 @@ pub enum NoveltySlot {
     Tonic,
 }
 
-endpoint!(Novelty, "/v2/novelties", u64, get_all);
+impl Novelty {
+    #[cfg(feature = "items")]
+    /// Returns the [`Item`]s that unlock this novelty.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::novelties::Novelty;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let novelty = Novelty::get(&client, 1).await?;
+    ///
+    /// for item in novelty.unlock_items(&client).await? {
+    ///     println!("{}", item.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::novelties::Novelty;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let novelty = Novelty::get(&client, 1)?;
+    ///
+    /// for item in novelty.unlock_items(&client)? {
+    ///     println!("{}", item.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`blocking`]: crate::blocking
+    pub fn unlock_items<C>(&self, client: &C) -> C::Output<Vec<Item>>
+    where
+        C: Executor,
+    {
+        let ids = self.unlock_item.clone();
+
+        client.execute(|client| async move { bulk::get_chunked(&client, Item::URI, &ids).await })
+    }
+}
+
+endpoint!(Novelty, "/v2/novelties", u64, localized, get_all);
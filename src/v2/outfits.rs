@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint;
+
+/// An outfit.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Outfit {
+    /// The id of the outfit.
+    pub id: u64,
+    /// The name of the outfit.
+    pub name: String,
+    /// A url pointing to the icon of the outfit.
+    pub icon: String,
+    /// A list of ids of items that unlock the outfit.
+    #[serde(default)]
+    pub unlock_items: Vec<u64>,
+}
+
+endpoint!(Outfit, "/v2/outfits", u64, localized, get_all);
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint;
+use crate::v2::common::{self, RenderUrl};
+
+/// A profession.
+///
+/// Not to be confused with [`common::Profession`], which only identifies a profession.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Profession {
+    /// The id of the profession.
+    pub id: common::Profession,
+    /// The localized name of the profession.
+    pub name: String,
+    /// The code of the profession used in build template chat links.
+    pub code: u8,
+    /// A url pointing to the icon of the profession.
+    pub icon: RenderUrl,
+    /// A url pointing to a larger icon of the profession.
+    pub icon_big: RenderUrl,
+    /// The ids of all specializations of the profession.
+    pub specializations: Vec<u64>,
+    /// A list of flags applying to the profession.
+    #[serde(default)]
+    pub flags: Vec<String>,
+    /// A list of pairs of palette ids and skill ids.
+    ///
+    /// Build templates store skills as palette ids, see [`palette_to_skill`].
+    ///
+    /// [`palette_to_skill`]: Self::palette_to_skill
+    pub skills_by_palette: Vec<(u64, u64)>,
+}
+
+impl Profession {
+    /// Returns the id of the skill with the given `palette_id`. Returns `None` if the palette id
+    /// is unknown.
+    pub fn palette_to_skill(&self, palette_id: u64) -> Option<u64> {
+        self.skills_by_palette
+            .iter()
+            .find(|(palette, _)| *palette == palette_id)
+            .map(|(_, skill)| *skill)
+    }
+
+    /// Returns the palette id of the skill with the given `skill_id`. Returns `None` if the
+    /// skill has no palette id.
+    pub fn skill_to_palette(&self, skill_id: u64) -> Option<u64> {
+        self.skills_by_palette
+            .iter()
+            .find(|(_, skill)| *skill == skill_id)
+            .map(|(palette, _)| *palette)
+    }
+}
+
+endpoint!(
+    Profession,
+    "/v2/professions",
+    common::Profession,
+    localized,
+    get_all
+);
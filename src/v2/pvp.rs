@@ -0,0 +1,1028 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+
+use futures_util::future;
+use serde::{Deserialize, Serialize};
+
+use crate::datetime::DateTime;
+use crate::v2::common::Profession;
+pub use crate::v2::common::Region;
+use crate::{
+    collection, endpoint, permissions, Authentication, ClientExecutor, Executor, RequestBuilder,
+};
+
+/// The kind of a PvP season leaderboard.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LeaderboardKind {
+    /// The ladder leaderboard, avaliable since season 5.
+    Ladder,
+    /// The legendary division leaderboard, avaliable in seasons 1 to 4.
+    Legendary,
+    /// The guild leaderboard, avaliable in seasons 1 to 4.
+    Guild,
+}
+
+impl Display for LeaderboardKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let string = match self {
+            Self::Ladder => "ladder",
+            Self::Legendary => "legendary",
+            Self::Guild => "guild",
+        };
+
+        write!(f, "{}", string)
+    }
+}
+
+/// A page of ranked entries on a PvP season leaderboard.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Leaderboard(pub Vec<LeaderboardEntry>);
+
+collection!(Leaderboard(LeaderboardEntry));
+
+impl Leaderboard {
+    const URI: &'static str = "/v2/pvp/seasons";
+
+    /// The maximum number of entries that can be requested in a single page.
+    pub const MAX_PAGE_SIZE: u16 = 200;
+
+    /// Returns a list of all leaderboards avaliable for the season with the given `season_id`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::pvp::Leaderboard;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let season = "";
+    /// let client = Client::new();
+    /// let boards = Leaderboard::kinds(&client, season).await?;
+    /// println!("{:?}", boards);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::pvp::Leaderboard;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let season = "";
+    /// let client = Client::new();
+    /// let boards = Leaderboard::kinds(&client, season)?;
+    /// println!("{:?}", boards);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`blocking`]: crate::blocking
+    pub fn kinds<C>(client: &C, season_id: &str) -> C::Result
+    where
+        C: ClientExecutor<Vec<LeaderboardKind>>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .segment(season_id)
+                .segment("leaderboards"),
+        )
+    }
+
+    /// Returns the first page of the `kind` leaderboard in the given `region` for the season
+    /// with the given `season_id`.
+    ///
+    /// Use [`get_page`] to request entries beyond the first page.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::pvp::{Leaderboard, LeaderboardKind, Region};
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let season = "";
+    /// let client = Client::new();
+    /// let leaderboard = Leaderboard::get(&client, season, LeaderboardKind::Ladder, Region::Eu).await?;
+    ///
+    /// for entry in leaderboard.0 {
+    ///     println!("#{} {}", entry.rank, entry.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::pvp::{Leaderboard, LeaderboardKind, Region};
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let season = "";
+    /// let client = Client::new();
+    /// let leaderboard = Leaderboard::get(&client, season, LeaderboardKind::Ladder, Region::Eu)?;
+    ///
+    /// for entry in leaderboard.0 {
+    ///     println!("#{} {}", entry.rank, entry.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`get_page`]: Self::get_page
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C, season_id: &str, kind: LeaderboardKind, region: Region) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .segment(season_id)
+                .segment("leaderboards")
+                .segment(kind)
+                .segment(region),
+        )
+    }
+
+    /// Returns the page with the index `page` of the `kind` leaderboard in the given `region` for
+    /// the season with the given `season_id`. Pages are zero-indexed.
+    ///
+    /// `page_size` is the number of entries per page and must not exceed [`MAX_PAGE_SIZE`]. If
+    /// the `page` is out of range the API returns an [`Error`].
+    ///
+    /// [`MAX_PAGE_SIZE`]: Self::MAX_PAGE_SIZE
+    /// [`Error`]: struct@crate::Error
+    pub fn get_page<C>(
+        client: &C,
+        season_id: &str,
+        kind: LeaderboardKind,
+        region: Region,
+        page: u32,
+        page_size: u16,
+    ) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .segment(season_id)
+                .segment("leaderboards")
+                .segment(kind)
+                .segment(region)
+                .query("page", page)
+                .query("page_size", page_size),
+        )
+    }
+}
+
+/// A single ranked entry on a [`Leaderboard`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct LeaderboardEntry {
+    /// The account name of the player, or the guild name for guild leaderboards.
+    pub name: String,
+    /// The rank of the entry on the leaderboard, starting at `1`.
+    pub rank: u64,
+    /// The guild id. Only avaliable on guild leaderboards.
+    pub id: Option<String>,
+    /// The name of the arena team. Only avaliable on guild leaderboards.
+    pub team: Option<String>,
+    /// The id of the arena team. Only avaliable on guild leaderboards.
+    pub team_id: Option<u64>,
+    /// The date when the entry reached its current rank.
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub date: DateTime,
+    /// A list of scores of the entry. The scoring ids are defined by the season.
+    pub scores: Vec<LeaderboardScore>,
+}
+
+/// A score of a [`LeaderboardEntry`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct LeaderboardScore {
+    /// The id of the scoring, as defined by the season.
+    pub id: String,
+    /// The value of the score.
+    pub value: i64,
+}
+
+/// A PvP league season.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct PvpSeason {
+    /// The id of the season.
+    pub id: String,
+    /// The name of the season.
+    pub name: String,
+    /// The time the season starts.
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub start: DateTime,
+    /// The time the season ends.
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub end: DateTime,
+    /// Whether the season is currently active.
+    pub active: bool,
+    /// The divisions of the reward track, in the order they are progressed.
+    pub divisions: Vec<SeasonDivision>,
+    /// The skill rating ranks of the season. Only avaliable for ranked seasons.
+    #[serde(default)]
+    pub ranks: Vec<SeasonRank>,
+}
+
+impl PvpSeason {
+    /// Returns the season active at the current time. Returns `None` if no season is active.
+    ///
+    /// The active season is determined by the [`start`] and [`end`] of the seasons rather than
+    /// the [`active`] flag, which is only updated with a delay.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::pvp::PvpSeason;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// if let Some(season) = PvpSeason::current(&client).await? {
+    ///     println!("{} ends at {}", season.name, season.end);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::pvp::PvpSeason;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// if let Some(season) = PvpSeason::current(&client)? {
+    ///     println!("{} ends at {}", season.name, season.end);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`start`]: Self::start
+    /// [`end`]: Self::end
+    /// [`active`]: Self::active
+    /// [`blocking`]: crate::blocking
+    pub fn current<C>(client: &C) -> C::Output<Option<Self>>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let seasons: Vec<Self> = Self::get_all(&client).await?;
+            let now = crate::datetime::now();
+
+            Ok(seasons.into_iter().find(|season| season.is_active_at(now)))
+        })
+    }
+
+    /// Returns `true` if the season is active at the given time `at`.
+    #[inline]
+    pub fn is_active_at(&self, at: DateTime) -> bool {
+        self.start <= at && at < self.end
+    }
+
+    /// Returns the total number of pips required to complete all divisions once.
+    pub fn total_pips(&self) -> u64 {
+        self.divisions.iter().map(SeasonDivision::pips).sum()
+    }
+
+    /// Returns the position on the reward track after earning `total_pips` pips.
+    ///
+    /// Pips earned after completing the last division continue in that division if it is
+    /// [`Repeatable`], counting the completed repeats. Otherwise the position stays at the end
+    /// of the last division.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gw2api_rs::v2::pvp::{DivisionFlag, DivisionTier, PvpSeason, SeasonDivision};
+    /// #
+    /// # let division = |points: &[u64], flags: Vec<DivisionFlag>| SeasonDivision {
+    /// #     name: String::new(),
+    /// #     flags,
+    /// #     large_icon: String::new(),
+    /// #     small_icon: String::new(),
+    /// #     pip_icon: String::new(),
+    /// #     tiers: points.iter().map(|&points| DivisionTier { points }).collect(),
+    /// # };
+    /// # let season: PvpSeason = serde_json::from_value(serde_json::json!({
+    /// #     "id": "", "name": "", "start": "2024-01-01T00:00:00Z", "end": "2024-02-01T00:00:00Z",
+    /// #     "active": false, "divisions": [],
+    /// # })).unwrap();
+    /// # let season = PvpSeason {
+    /// #     divisions: vec![
+    /// #         division(&[5, 5], vec![]),
+    /// #         division(&[10], vec![DivisionFlag::Repeatable]),
+    /// #     ],
+    /// #     ..season
+    /// # };
+    /// // Two tiers of 5 pips, followed by a repeatable tier of 10 pips.
+    /// let position = season.track_position(7);
+    /// assert_eq!((position.division, position.tier, position.points), (0, 1, 2));
+    ///
+    /// let position = season.track_position(43);
+    /// assert_eq!((position.division, position.points, position.repeats), (1, 3, 3));
+    /// ```
+    ///
+    /// [`Repeatable`]: DivisionFlag::Repeatable
+    pub fn track_position(&self, total_pips: u64) -> TrackPosition {
+        let mut remaining = total_pips;
+
+        for (division_index, division) in self.divisions.iter().enumerate() {
+            if let Some(position) = division.position(division_index, remaining, 0) {
+                return position;
+            }
+
+            remaining -= division.pips();
+        }
+
+        let Some(last) = self.divisions.last() else {
+            return TrackPosition::default();
+        };
+        let last_index = self.divisions.len() - 1;
+        let last_tier = last.tiers.len().saturating_sub(1);
+
+        if last.is_repeatable() && last.pips() != 0 {
+            let repeats = remaining / last.pips() + 1;
+            if let Some(position) = last.position(last_index, remaining % last.pips(), repeats) {
+                return position;
+            }
+        }
+
+        TrackPosition {
+            division: last_index as u64,
+            tier: last_tier as u64,
+            points: last.tiers.get(last_tier).map_or(0, |tier| tier.points),
+            repeats: 0,
+        }
+    }
+}
+
+endpoint!(PvpSeason, "/v2/pvp/seasons", String, localized, get_all);
+
+/// A division of the reward track of a [`PvpSeason`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct SeasonDivision {
+    /// The name of the division.
+    pub name: String,
+    /// The flags of the division.
+    pub flags: Vec<DivisionFlag>,
+    /// The url of the large icon of the division.
+    pub large_icon: String,
+    /// The url of the small icon of the division.
+    pub small_icon: String,
+    /// The url of the icon of the pips of the division.
+    pub pip_icon: String,
+    /// The tiers of the division.
+    pub tiers: Vec<DivisionTier>,
+}
+
+impl SeasonDivision {
+    /// Returns the number of pips required to complete all tiers of the division.
+    pub fn pips(&self) -> u64 {
+        self.tiers.iter().map(|tier| tier.points).sum()
+    }
+
+    /// Returns `true` if the division can be repeated after completing it.
+    #[inline]
+    pub fn is_repeatable(&self) -> bool {
+        self.flags.contains(&DivisionFlag::Repeatable)
+    }
+
+    /// Returns the position within this division after earning `pips` pips in it. Returns `None`
+    /// if `pips` completes the division.
+    fn position(&self, division: usize, mut pips: u64, repeats: u64) -> Option<TrackPosition> {
+        for (tier_index, tier) in self.tiers.iter().enumerate() {
+            if pips < tier.points {
+                return Some(TrackPosition {
+                    division: division as u64,
+                    tier: tier_index as u64,
+                    points: pips,
+                    repeats,
+                });
+            }
+
+            pips -= tier.points;
+        }
+
+        None
+    }
+}
+
+/// A flag of a [`SeasonDivision`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DivisionFlag {
+    /// Pips can be lost after losing a game.
+    CanLosePoints,
+    /// Tiers can be lost after losing a game.
+    CanLoseTiers,
+    /// The division can be repeated after completing it.
+    Repeatable,
+    /// A flag unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A tier of a [`SeasonDivision`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct DivisionTier {
+    /// The number of pips required to complete the tier.
+    pub points: u64,
+}
+
+/// A skill rating rank of a ranked [`PvpSeason`], e.g. `Gold`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct SeasonRank {
+    /// The name of the rank.
+    pub name: String,
+    /// The description of the rank.
+    pub description: String,
+    /// The url of the icon of the rank.
+    pub icon: String,
+    /// The url of the overlay of the rank.
+    pub overlay: String,
+    /// The url of the small overlay of the rank.
+    pub overlay_small: String,
+    /// The tiers of the rank.
+    pub tiers: Vec<SeasonRankTier>,
+}
+
+/// A tier of a [`SeasonRank`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct SeasonRankTier {
+    /// The minimum skill rating of the tier.
+    pub rating: i64,
+}
+
+/// A position on the reward track of a [`PvpSeason`], returned by
+/// [`PvpSeason::track_position`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TrackPosition {
+    /// The index of the division.
+    pub division: u64,
+    /// The index of the tier within the division.
+    pub tier: u64,
+    /// The number of pips earned in the tier.
+    pub points: u64,
+    /// The number of times the last division has been completed.
+    pub repeats: u64,
+}
+
+/// The PvP league standings of an account in all seasons.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Standings(pub Vec<Standing>);
+
+collection!(Standings(Standing));
+
+impl Standings {
+    const URI: &'static str = "/v2/pvp/standings";
+
+    permissions!(Account, Pvp);
+
+    /// Returns the PvP league standings of the currently authenticated account.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// When authenticated it returns the standings of the account of the current access token.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::pvp::Standings;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let standings = Standings::get(&client).await?;
+    ///
+    /// for standing in standings.0 {
+    ///     println!("{}: {:?}", standing.season_id, standing.current.rating);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::pvp::Standings;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let standings = Standings::get(&client)?;
+    ///
+    /// for standing in standings.0 {
+    ///     println!("{}: {:?}", standing.season_id, standing.current.rating);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+}
+
+/// The standing of an account in a single PvP season.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Standing {
+    /// The current standing of the account.
+    pub current: StandingCurrent,
+    /// The best standing the account reached during the season.
+    pub best: StandingBest,
+    /// The id of the season.
+    pub season_id: String,
+}
+
+/// The current standing of an account in a PvP season.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct StandingCurrent {
+    /// The total number of pips earned.
+    pub total_points: u64,
+    /// The index of the current division.
+    pub division: u64,
+    /// The index of the current tier within the division.
+    pub tier: u64,
+    /// The number of pips earned in the current tier.
+    pub points: u64,
+    /// The number of times the last division has been repeated.
+    pub repeats: u64,
+    /// The current skill rating. Only avaliable for ranked seasons.
+    pub rating: Option<i64>,
+    /// The current rating decay. Only avaliable for ranked seasons.
+    pub decay: Option<i64>,
+}
+
+/// The best standing of an account in a PvP season.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct StandingBest {
+    /// The total number of pips earned.
+    pub total_points: u64,
+    /// The index of the best division.
+    pub division: u64,
+    /// The index of the best tier within the division.
+    pub tier: u64,
+    /// The number of pips earned in the best tier.
+    pub points: u64,
+    /// The number of times the last division has been repeated.
+    pub repeats: u64,
+}
+
+/// A PvP game played by an account.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Game {
+    /// The id of the game.
+    pub id: String,
+    /// The id of the map the game was played on.
+    pub map_id: u64,
+    /// The time the game started.
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub started: DateTime,
+    /// The time the game ended.
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub ended: DateTime,
+    /// The result of the game for the account.
+    pub result: GameResult,
+    /// The team of the account.
+    pub team: GameTeam,
+    /// The profession the account played.
+    pub profession: Profession,
+    /// The final scores of both teams.
+    pub scores: GameScores,
+    /// The type of rating the game counted towards.
+    pub rating_type: RatingType,
+    /// The change of the rating of the account. Only avaliable for ranked games.
+    pub rating_change: Option<i64>,
+    /// The id of the season the game was played in. Only avaliable for games played during a
+    /// season.
+    pub season: Option<String>,
+}
+
+impl Game {
+    const URI: &'static str = "/v2/pvp/games";
+
+    /// The maximum number of ids accepted by the endpoint in a single request.
+    pub const MAX_IDS: usize = 10;
+
+    permissions!(Account, Pvp);
+
+    /// Returns the game with the given `id`.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// [`Error`]: struct@crate::Error
+    pub fn get<C>(client: &C, id: &str) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .query("id", id)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns the games with the given `ids`.
+    ///
+    /// Ids not found are omitted from the returned list. Note that the endpoint accepts at most
+    /// [`MAX_IDS`] `ids` per request, use [`recent`] to fetch more games.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// [`MAX_IDS`]: Self::MAX_IDS
+    /// [`recent`]: Self::recent
+    /// [`Error`]: struct@crate::Error
+    pub fn get_many<C, T>(client: &C, ids: &[T]) -> C::Result
+    where
+        C: ClientExecutor<Vec<Self>>,
+        T: AsRef<str>,
+    {
+        let ids: Vec<&str> = ids.iter().map(AsRef::as_ref).collect();
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .query_list("ids", &ids)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns the page with the index `page` of the most recent games of the currently
+    /// authenticated account. Pages are zero-indexed.
+    ///
+    /// `page_size` must not exceed [`MAX_IDS`]. If the `page` is out of range the API returns an
+    /// [`Error`].
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// [`MAX_IDS`]: Self::MAX_IDS
+    /// [`Error`]: struct@crate::Error
+    pub fn get_page<C>(client: &C, page: u32, page_size: u16) -> C::Result
+    where
+        C: ClientExecutor<Vec<Self>>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .query("page", page)
+                .query("page_size", page_size)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns the ids of the most recent games of the currently authenticated account, most
+    /// recent first.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// [`Error`]: struct@crate::Error
+    pub fn ids<C>(client: &C) -> C::Result
+    where
+        C: ClientExecutor<Vec<String>>,
+    {
+        client.send(
+            RequestBuilder::new(Self::URI)
+                .authenticated(Authentication::Required)
+                .permissions(Self::PERMISSIONS),
+        )
+    }
+
+    /// Returns up to `count` of the most recent games of the currently authenticated account,
+    /// most recent first.
+    ///
+    /// The games are requested concurrently in chunks of [`MAX_IDS`].
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::pvp::{Game, GameResult};
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let games = Game::recent(&client, 20).await?;
+    ///
+    /// let wins = games.iter().filter(|game| game.result == GameResult::Victory).count();
+    /// println!("won {} of the last {} games", wins, games.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::pvp::{Game, GameResult};
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let games = Game::recent(&client, 20)?;
+    ///
+    /// let wins = games.iter().filter(|game| game.result == GameResult::Victory).count();
+    /// println!("won {} of the last {} games", wins, games.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`MAX_IDS`]: Self::MAX_IDS
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn recent<C>(client: &C, count: usize) -> C::Output<Vec<Self>>
+    where
+        C: Executor,
+    {
+        client.execute(move |client| async move {
+            let mut ids: Vec<String> = Self::ids(&client).await?;
+            ids.truncate(count);
+
+            let futs = ids
+                .chunks(Self::MAX_IDS)
+                .map(|chunk| Self::get_many(&client, chunk));
+            let chunks: Vec<Vec<Self>> = future::try_join_all(futs).await?;
+
+            Ok(chunks.into_iter().flatten().collect())
+        })
+    }
+}
+
+/// The result of a [`Game`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GameResult {
+    Victory,
+    Defeat,
+    /// The account left the game before it ended.
+    Desertion,
+    /// The game was won because no opponent was found.
+    Bye,
+    /// The game was forfeited by one of the teams.
+    Forfeit,
+    /// A result unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+impl GameResult {
+    /// Returns `true` if the game counts as a win. A [`Bye`] is rewarded like a victory.
+    ///
+    /// [`Bye`]: Self::Bye
+    #[inline]
+    pub fn is_win(&self) -> bool {
+        matches!(self, Self::Victory | Self::Bye)
+    }
+}
+
+/// The team of an account in a [`Game`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GameTeam {
+    Red,
+    Blue,
+    /// A team unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The final scores of a [`Game`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct GameScores {
+    /// The score of the red team.
+    pub red: u64,
+    /// The score of the blue team.
+    pub blue: u64,
+}
+
+impl GameScores {
+    /// Returns the score of the given `team`. Returns `None` for [`GameTeam::Unknown`].
+    #[inline]
+    pub fn get(&self, team: GameTeam) -> Option<u64> {
+        match team {
+            GameTeam::Red => Some(self.red),
+            GameTeam::Blue => Some(self.blue),
+            GameTeam::Unknown => None,
+        }
+    }
+}
+
+/// The number of games won and lost.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct WinLoss {
+    /// The number of games won.
+    pub wins: u64,
+    /// The number of games lost.
+    pub losses: u64,
+    /// The number of games left before they ended.
+    pub desertions: u64,
+    /// The number of games won because no opponent was found.
+    pub byes: u64,
+    /// The number of games forfeited.
+    pub forfeits: u64,
+}
+
+impl WinLoss {
+    /// Returns the total number of games.
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.wins + self.losses + self.desertions + self.byes + self.forfeits
+    }
+
+    /// Returns the ratio of games won to all games, between `0.0` and `1.0`. Byes count as won.
+    /// Returns `None` if no games were played.
+    pub fn win_rate(&self) -> Option<f64> {
+        match self.total() {
+            0 => None,
+            total => Some((self.wins + self.byes) as f64 / total as f64),
+        }
+    }
+}
+
+/// The [`WinLoss`] stats per ladder.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Ladders {
+    /// The stats of ranked games. `None` if no ranked games were played.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ranked: Option<WinLoss>,
+    /// The stats of unranked games. `None` if no unranked games were played.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unranked: Option<WinLoss>,
+}
+
+/// The type of rating a [`Game`] counted towards.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RatingType {
+    /// A ranked game.
+    Ranked,
+    /// An unranked game.
+    Unranked,
+    /// A game that does not count towards any rating, e.g. a custom arena game.
+    None,
+    /// A rating type unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// An amulet equippable in PvP.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Amulet {
+    /// The id of the amulet.
+    pub id: u64,
+    /// The name of the amulet.
+    pub name: String,
+    /// A url pointing to the icon of the amulet.
+    pub icon: String,
+    /// The attribute bonuses granted by the amulet, keyed by the name of the attribute, e.g.
+    /// `Power`.
+    pub attributes: HashMap<String, u64>,
+}
+
+endpoint!(Amulet, "/v2/pvp/amulets", u64, localized, get_all);
+
+/// A PvP rank.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Rank {
+    /// The id of the rank.
+    pub id: u64,
+    /// The id of the [`Finisher`] unlocked by the rank.
+    ///
+    /// [`Finisher`]: crate::v2::finishers::Finisher
+    pub finisher_id: u64,
+    /// The name of the rank, e.g. `Rabbit`.
+    pub name: String,
+    /// A url pointing to the icon of the rank.
+    pub icon: String,
+    /// The minimum PvP level of the rank.
+    pub min_rank: u16,
+    /// The maximum PvP level of the rank.
+    pub max_rank: u16,
+    /// The levels of the rank.
+    pub levels: Vec<RankLevel>,
+}
+
+impl Rank {
+    /// Returns `true` if the PvP `level` is within this rank.
+    #[inline]
+    pub fn contains(&self, level: u16) -> bool {
+        self.min_rank <= level && level <= self.max_rank
+    }
+}
+
+endpoint!(Rank, "/v2/pvp/ranks", u64, localized, get_all);
+
+/// A single level of a [`Rank`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct RankLevel {
+    /// The minimum PvP level of this level.
+    pub min_rank: u16,
+    /// The maximum PvP level of this level.
+    pub max_rank: u16,
+    /// The number of points required to complete this level.
+    pub points: u64,
+}
+
+/// A hero playable in Stronghold.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Hero {
+    /// The id of the hero.
+    pub id: String,
+    /// The name of the hero.
+    pub name: String,
+    /// The type of the hero, e.g. `Tank`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The stats of the hero.
+    pub stats: HeroStats,
+    /// A url pointing to the overlay art of the hero.
+    pub overlay: String,
+    /// A url pointing to the underlay art of the hero.
+    pub underlay: String,
+    /// The skins of the hero.
+    pub skins: Vec<HeroSkin>,
+}
+
+endpoint!(Hero, "/v2/pvp/heroes", String, localized, get_all);
+
+/// The stats of a [`Hero`], each ranging from `1` to `10`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct HeroStats {
+    pub offense: u8,
+    pub defense: u8,
+    pub speed: u8,
+}
+
+/// A skin of a [`Hero`].
+///
+/// The ids of all hero skins unlocked by an account are returned by [`AccountPvPHeroes`].
+///
+/// [`AccountPvPHeroes`]: crate::v2::account::AccountPvPHeroes
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct HeroSkin {
+    /// The id of the skin.
+    pub id: u64,
+    /// The name of the skin.
+    pub name: String,
+    /// A url pointing to the icon of the skin.
+    pub icon: String,
+    /// Whether this is the default skin of the hero.
+    pub default: bool,
+    /// The ids of the items unlocking the skin.
+    #[serde(default)]
+    pub unlock_items: Vec<u64>,
+}
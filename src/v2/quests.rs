@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint;
+
+/// A quest of a story, e.g. a chapter of the personal story.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Quest {
+    /// The id of the quest.
+    pub id: u64,
+    /// The name of the quest.
+    pub name: String,
+    /// The minimum level required to start the quest.
+    pub level: u8,
+    /// The id of the story the quest belongs to.
+    pub story: u64,
+    /// The goals of the quest.
+    pub goals: Vec<QuestGoal>,
+}
+
+/// A goal of a [`Quest`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct QuestGoal {
+    /// The text displayed while the goal is active.
+    pub active: String,
+    /// The text displayed once the goal is completed.
+    pub complete: String,
+}
+
+endpoint!(Quest, "/v2/quests", u64, localized, get_all);
@@ -3,19 +3,65 @@ use serde::{Deserialize, Serialize};
 use crate::endpoint;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Raid {
     pub id: String,
+    pub wings: Vec<RaidWing>,
+}
+
+impl Raid {
+    /// Returns the completion of all wings of this raid given the `cleared` encounters, e.g. as
+    /// returned by [`AccountRaids`].
+    ///
+    /// [`AccountRaids`]: crate::v2::account::AccountRaids
+    pub fn completion<S>(&self, cleared: &[S]) -> RaidCompletion
+    where
+        S: AsRef<str>,
+    {
+        RaidCompletion {
+            id: self.id.clone(),
+            wings: self
+                .wings
+                .iter()
+                .map(|wing| wing.completion(cleared))
+                .collect(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct RaidWing {
     pub id: String,
-    pub events: RaidEvent,
+    pub events: Vec<RaidEvent>,
+}
+
+impl RaidWing {
+    /// Returns the completion of this wing given the `cleared` encounters.
+    pub fn completion<S>(&self, cleared: &[S]) -> WingCompletion
+    where
+        S: AsRef<str>,
+    {
+        WingCompletion {
+            id: self.id.clone(),
+            events: self
+                .events
+                .iter()
+                .map(|event| EventCompletion {
+                    id: event.id.clone(),
+                    kind: event.kind,
+                    cleared: cleared.iter().any(|id| id.as_ref() == event.id),
+                })
+                .collect(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct RaidEvent {
     pub id: String,
+    #[serde(rename = "type")]
     pub kind: RaidEventKind,
 }
 
@@ -27,3 +73,117 @@ pub enum RaidEventKind {
 }
 
 endpoint!(Raid, "/v2/raids", String, get_all);
+
+/// The weekly completion of a [`Raid`].
+///
+/// Returned by [`Raid::completion`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct RaidCompletion {
+    /// The id of the raid.
+    pub id: String,
+    /// The completion of all wings of the raid.
+    pub wings: Vec<WingCompletion>,
+}
+
+impl RaidCompletion {
+    /// Returns the completion of the wing with the given `id`.
+    pub fn wing(&self, id: &str) -> Option<&WingCompletion> {
+        self.wings.iter().find(|wing| wing.id == id)
+    }
+
+    /// Returns `true` if all encounters of all wings were cleared.
+    pub fn is_completed(&self) -> bool {
+        self.wings.iter().all(WingCompletion::is_completed)
+    }
+}
+
+/// The weekly completion of a [`RaidWing`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct WingCompletion {
+    /// The id of the wing.
+    pub id: String,
+    /// The encounters of the wing in order.
+    pub events: Vec<EventCompletion>,
+}
+
+impl WingCompletion {
+    /// Returns the number of cleared encounters.
+    pub fn cleared(&self) -> usize {
+        self.events.iter().filter(|event| event.cleared).count()
+    }
+
+    /// Returns the number of cleared [`Boss`] encounters.
+    ///
+    /// [`Boss`]: RaidEventKind::Boss
+    pub fn bosses_cleared(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|event| event.cleared && event.kind == RaidEventKind::Boss)
+            .count()
+    }
+
+    /// Returns `true` if all encounters of the wing were cleared.
+    pub fn is_completed(&self) -> bool {
+        self.events.iter().all(|event| event.cleared)
+    }
+}
+
+/// The weekly completion of a [`RaidEvent`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct EventCompletion {
+    /// The id of the encounter.
+    pub id: String,
+    /// The kind of the encounter.
+    pub kind: RaidEventKind,
+    /// Whether the encounter was cleared since weekly reset.
+    pub cleared: bool,
+}
+
+/// Ids of raids and their wings.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use gw2api_rs::{Client, Result};
+/// # use gw2api_rs::v2::raids::{ids, Raid};
+/// #
+/// # async fn run() -> Result<()> {
+/// let client = Client::new();
+/// let raid = Raid::get(&client, ids::FORSAKEN_THICKET.to_owned()).await?;
+/// println!("{} wings", raid.wings.len());
+/// # Ok(())
+/// # }
+/// ```
+pub mod ids {
+    /// Forsaken Thicket, containing wings 1 to 3.
+    pub const FORSAKEN_THICKET: &str = "forsaken_thicket";
+    /// Bastion of the Penitent, wing 4.
+    pub const BASTION_OF_THE_PENITENT: &str = "bastion_of_the_penitent";
+    /// Hall of Chains, wing 5.
+    pub const HALL_OF_CHAINS: &str = "hall_of_chains";
+    /// Mythwright Gambit, wing 6.
+    pub const MYTHWRIGHT_GAMBIT: &str = "mythwright_gambit";
+    /// The Key of Ahdashim, wing 7.
+    pub const THE_KEY_OF_AHDASHIM: &str = "the_key_of_ahdashim";
+
+    /// Ids of raid wings.
+    pub mod wings {
+        /// Spirit Vale, wing 1.
+        pub const SPIRIT_VALE: &str = "spirit_vale";
+        /// Salvation Pass, wing 2.
+        pub const SALVATION_PASS: &str = "salvation_pass";
+        /// Stronghold of the Faithful, wing 3.
+        pub const STRONGHOLD_OF_THE_FAITHFUL: &str = "stronghold_of_the_faithful";
+        /// Bastion of the Penitent, wing 4.
+        pub const BASTION_OF_THE_PENITENT: &str = "bastion_of_the_penitent";
+        /// Hall of Chains, wing 5.
+        pub const HALL_OF_CHAINS: &str = "hall_of_chains";
+        /// Mythwright Gambit, wing 6.
+        pub const MYTHWRIGHT_GAMBIT: &str = "mythwright_gambit";
+        /// The Key of Ahdashim, wing 7.
+        pub const THE_KEY_OF_AHDASHIM: &str = "the_key_of_ahdashim";
+    }
+}
@@ -0,0 +1,300 @@
+use futures_util::future;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::account::AccountRecipes;
+use crate::v2::characters::{CharacterCrafting, CharacterRecipes, CraftingDiscipline};
+use crate::v2::common::{ChatLink, Discipline};
+use crate::{endpoint, Executor};
+
+/// A crafting recipe.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Recipe {
+    /// The id of the recipe.
+    pub id: u64,
+    /// The type of the recipe.
+    #[serde(rename = "type")]
+    pub kind: RecipeKind,
+    /// The id of the item produced by the recipe.
+    pub output_item_id: u64,
+    /// The number of items produced by the recipe.
+    pub output_item_count: u64,
+    /// The time it takes to craft the recipe in milliseconds.
+    pub time_to_craft_ms: u64,
+    /// The disciplines that can craft the recipe.
+    pub disciplines: Vec<Discipline>,
+    /// The minimum rating in one of the [`disciplines`] required to craft the recipe.
+    ///
+    /// [`disciplines`]: Self::disciplines
+    pub min_rating: u16,
+    /// The flags of the recipe.
+    pub flags: Vec<RecipeFlag>,
+    /// The ingredients consumed by the recipe.
+    pub ingredients: Vec<RecipeIngredient>,
+    /// The id of the guild upgrade produced by the recipe. Only avaliable for guild recipes.
+    pub output_upgrade_id: Option<u64>,
+    /// The chat link of the recipe.
+    pub chat_link: ChatLink,
+}
+
+impl Recipe {
+    /// Returns `true` if the recipe is learned automatically once the [`min_rating`] is
+    /// reached.
+    ///
+    /// [`min_rating`]: Self::min_rating
+    #[inline]
+    pub fn is_auto_learned(&self) -> bool {
+        self.flags.contains(&RecipeFlag::AutoLearned)
+    }
+
+    /// Returns whether the character with the given `name` can craft the recipe with the given
+    /// `id`.
+    ///
+    /// This combines the recipe with [`AccountRecipes`], [`CharacterRecipes`] and
+    /// [`CharacterCrafting`], which are requested concurrently. See [`verdict`] for details.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// If the character does not belong to the account of the current access token, an [`Error`]
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::recipes::Recipe;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let verdict = Recipe::can_craft(&client, "Example Character", 7319).await?;
+    /// println!("{:?}", verdict);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::recipes::Recipe;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let verdict = Recipe::can_craft(&client, "Example Character", 7319)?;
+    /// println!("{:?}", verdict);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`verdict`]: Self::verdict
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn can_craft<C>(client: &C, name: &str, id: u64) -> C::Output<CraftVerdict>
+    where
+        C: Executor,
+    {
+        let name = name.to_owned();
+
+        client.execute(|client| async move {
+            let (recipe, account, character, crafting) = future::try_join4(
+                Self::get(&client, id),
+                AccountRecipes::get(&client),
+                CharacterRecipes::get(&client, &name),
+                CharacterCrafting::get(&client, &name),
+            )
+            .await?;
+
+            Ok(recipe.verdict(&crafting, &account, &character))
+        })
+    }
+
+    /// Returns whether a character with the given `crafting` disciplines can craft the recipe.
+    ///
+    /// The disciplines are checked first: The character needs an active discipline of the
+    /// recipe with at least the [`min_rating`]. The recipe is then learned if it is
+    /// [auto learned], unlocked for the account or discovered by the character.
+    ///
+    /// [`min_rating`]: Self::min_rating
+    /// [auto learned]: Self::is_auto_learned
+    pub fn verdict(
+        &self,
+        crafting: &CharacterCrafting,
+        account: &AccountRecipes,
+        character: &CharacterRecipes,
+    ) -> CraftVerdict {
+        let candidates: Vec<&CraftingDiscipline> = crafting
+            .crafting
+            .iter()
+            .filter(|crafting| self.disciplines.contains(&crafting.discipline))
+            .collect();
+
+        let sufficient: Vec<&CraftingDiscipline> = candidates
+            .iter()
+            .copied()
+            .filter(|crafting| crafting.rating >= self.min_rating)
+            .collect();
+
+        if !sufficient.iter().any(|crafting| crafting.active) {
+            if let Some(inactive) = sufficient.first() {
+                return CraftVerdict::InactiveDiscipline(inactive.discipline);
+            }
+
+            return match candidates
+                .iter()
+                .max_by_key(|crafting| (crafting.active, crafting.rating))
+            {
+                Some(best) => CraftVerdict::InsufficientRating {
+                    discipline: best.discipline,
+                    rating: best.rating,
+                    required: self.min_rating,
+                },
+                None => CraftVerdict::MissingDiscipline,
+            };
+        }
+
+        if self.is_auto_learned()
+            || account.0.contains(&self.id)
+            || character.recipes.contains(&self.id)
+        {
+            CraftVerdict::Craftable
+        } else {
+            CraftVerdict::NotLearned
+        }
+    }
+}
+
+endpoint!(Recipe, "/v2/recipes", u64);
+
+/// The type of a [`Recipe`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RecipeKind {
+    Axe,
+    Dagger,
+    Focus,
+    Greatsword,
+    Hammer,
+    Harpoon,
+    LongBow,
+    Mace,
+    Pistol,
+    Rifle,
+    Scepter,
+    Shield,
+    ShortBow,
+    Speargun,
+    Staff,
+    Sword,
+    Torch,
+    Trident,
+    Warhorn,
+    Boots,
+    Coat,
+    Gloves,
+    Helm,
+    Leggings,
+    Shoulders,
+    Amulet,
+    Earring,
+    Ring,
+    Dessert,
+    Feast,
+    IngredientCooking,
+    Meal,
+    Seasoning,
+    Snack,
+    Soup,
+    Food,
+    Component,
+    Inscription,
+    Insignia,
+    LegendaryComponent,
+    Refinement,
+    RefinementEctoplasm,
+    RefinementObsidian,
+    GuildConsumable,
+    GuildDecoration,
+    GuildConsumableWvw,
+    Backpack,
+    Bag,
+    Bulk,
+    Consumable,
+    Dye,
+    Potion,
+    UpgradeComponent,
+    /// A recipe type unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A flag of a [`Recipe`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RecipeFlag {
+    /// The recipe is learned automatically once the required rating is reached.
+    AutoLearned,
+    /// The recipe is learned by consuming a recipe sheet.
+    LearnedFromItem,
+    /// A flag unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// An ingredient consumed by a [`Recipe`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct RecipeIngredient {
+    /// The type of the ingredient.
+    #[serde(rename = "type")]
+    pub kind: IngredientKind,
+    /// The id of the item, currency or guild upgrade, depending on the [`kind`].
+    ///
+    /// [`kind`]: Self::kind
+    pub id: u64,
+    /// The number of the ingredient consumed.
+    pub count: u64,
+}
+
+/// The type of a [`RecipeIngredient`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IngredientKind {
+    Item,
+    Currency,
+    GuildUpgrade,
+    /// An ingredient type unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Whether a character can craft a [`Recipe`].
+///
+/// Returned by [`Recipe::can_craft`] and [`Recipe::verdict`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CraftVerdict {
+    /// The character can craft the recipe.
+    Craftable,
+    /// The character has not learned any discipline of the recipe.
+    MissingDiscipline,
+    /// The character has the required rating in the discipline, but the discipline is not
+    /// active.
+    InactiveDiscipline(Discipline),
+    /// The rating of the character is below the minimum rating of the recipe. Contains the
+    /// discipline of the recipe with the highest rating.
+    InsufficientRating {
+        discipline: Discipline,
+        rating: u16,
+        required: u16,
+    },
+    /// The recipe is neither learned by the account nor discovered by the character.
+    NotLearned,
+}
+
+impl CraftVerdict {
+    /// Returns `true` if the character can craft the recipe.
+    #[inline]
+    pub fn is_craftable(&self) -> bool {
+        matches!(self, Self::Craftable)
+    }
+}
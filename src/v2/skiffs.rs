@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint;
+
+/// A skiff skin.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Skiff {
+    /// The id of the skiff.
+    pub id: u64,
+    /// The name of the skiff.
+    pub name: String,
+    /// A url pointing to the icon of the skiff.
+    pub icon: String,
+    /// The dye slots of the skiff.
+    pub dye_slots: Vec<SkiffDyeSlot>,
+}
+
+/// A dye slot of a [`Skiff`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct SkiffDyeSlot {
+    /// The id of the color applied by default.
+    pub color_id: u64,
+    /// The material of the slot, e.g. `cloth` or `metal`.
+    pub material: String,
+}
+
+endpoint!(Skiff, "/v2/skiffs", u64, localized, get_all);
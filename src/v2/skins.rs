@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint;
+pub use crate::v2::common::WeightClass;
+use crate::v2::common::{DamageType, Rarity};
+
+/// A skin.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Skin {
+    /// The id of the skin.
+    pub id: u64,
+    /// The name of the skin.
+    pub name: String,
+    /// The type of the skin.
+    #[serde(rename = "type")]
+    pub kind: SkinKind,
+    /// A list of flags applying to the skin.
+    pub flags: Vec<String>,
+    /// A list of restrictions applying to the skin.
+    pub restrictions: Vec<String>,
+    /// A url pointing to the icon of the skin.
+    pub icon: Option<String>,
+    /// The rarity of the skin.
+    pub rarity: Rarity,
+    /// The description of the skin.
+    pub description: Option<String>,
+    /// Additional details depending on the [`kind`] of the skin.
+    ///
+    /// [`kind`]: Self::kind
+    #[serde(default)]
+    pub details: Option<SkinDetails>,
+}
+
+impl Skin {
+    /// Returns the weight class of the skin. This is `None` for all skins except armor skins.
+    #[inline]
+    pub fn weight_class(&self) -> Option<WeightClass> {
+        self.details
+            .as_ref()
+            .and_then(|details| details.weight_class)
+    }
+}
+
+/// Details of a [`Skin`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct SkinDetails {
+    /// The type of the armor piece, weapon or gathering tool, e.g. `Coat`, `Greatsword` or
+    /// `Foraging`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The weight class of an armor skin.
+    #[serde(default)]
+    pub weight_class: Option<WeightClass>,
+    /// The damage type of a weapon skin.
+    #[serde(default)]
+    pub damage_type: Option<DamageType>,
+}
+
+/// The type of a [`Skin`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SkinKind {
+    Armor,
+    Back,
+    Gathering,
+    Weapon,
+    /// A skin type unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+endpoint!(Skin, "/v2/skins", u64, localized);
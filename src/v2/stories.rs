@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+
+use futures_util::future;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::characters::{CharacterCore, CharacterQuests};
+use crate::v2::common::Race;
+use crate::v2::quests::Quest;
+use crate::{endpoint, Executor};
+
+/// A story, e.g. a part of the personal story or an episode of the living world.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Story {
+    /// The id of the story.
+    pub id: u64,
+    /// The id of the [`StorySeason`] the story belongs to.
+    pub season: String,
+    /// The name of the story.
+    pub name: String,
+    /// The description of the story.
+    pub description: String,
+    /// The time in the story line the story takes place at, e.g. `1325 AE`.
+    pub timeline: String,
+    /// The minimum level required to start the story.
+    pub level: u8,
+    /// The position of the story within its season.
+    pub order: u64,
+    /// The chapters of the story in order.
+    pub chapters: Vec<StoryChapter>,
+    /// The races the story is available to. Only avaliable for stories restricted to some
+    /// races.
+    #[serde(default)]
+    pub races: Option<Vec<Race>>,
+    /// The flags of the story.
+    #[serde(default)]
+    pub flags: Vec<StoryFlag>,
+}
+
+impl Story {
+    /// Returns `true` if the story is available to characters of the given `race`.
+    pub fn is_available_to(&self, race: Race) -> bool {
+        match &self.races {
+            Some(races) => races.contains(&race),
+            None => true,
+        }
+    }
+
+    /// Returns the progress of this story given all `quests` and the ids of the `completed`
+    /// quests, e.g. as returned by [`CharacterQuests`].
+    ///
+    /// A chapter is completed if a completed quest of this story has the same name as the
+    /// chapter.
+    ///
+    /// [`CharacterQuests`]: crate::v2::characters::CharacterQuests
+    pub fn progress(&self, quests: &[Quest], completed: &[u64]) -> StoryProgress {
+        let quests: HashMap<&str, u64> = quests
+            .iter()
+            .filter(|quest| quest.story == self.id)
+            .map(|quest| (quest.name.as_str(), quest.id))
+            .collect();
+
+        StoryProgress {
+            id: self.id,
+            season: self.season.clone(),
+            name: self.name.clone(),
+            chapters: self
+                .chapters
+                .iter()
+                .map(|chapter| {
+                    let quest = quests.get(chapter.name.as_str()).copied();
+
+                    ChapterProgress {
+                        name: chapter.name.clone(),
+                        quest,
+                        completed: quest.is_some_and(|quest| completed.contains(&quest)),
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the story progress of the character with the given `name`.
+    ///
+    /// Only stories available to the race of the character are included. The stories are
+    /// ordered by their [`StorySeason`] and their position within it. This combines
+    /// [`CharacterCore`] and [`CharacterQuests`] with all quests, stories and seasons, which are
+    /// requested concurrently.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    /// If the character does not belong to the account of the current access token, an [`Error`]
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::stories::Story;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let stories = Story::character_progress(&client, "Example Character").await?;
+    ///
+    /// for story in stories {
+    ///     println!("{}: {}/{}", story.name, story.completed(), story.chapters.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::stories::Story;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let stories = Story::character_progress(&client, "Example Character")?;
+    ///
+    /// for story in stories {
+    ///     println!("{}: {}/{}", story.name, story.completed(), story.chapters.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn character_progress<C>(client: &C, name: &str) -> C::Output<Vec<StoryProgress>>
+    where
+        C: Executor,
+    {
+        let name = name.to_owned();
+
+        client.execute(|client| async move {
+            let (character, completed, quests, stories, seasons) = future::try_join5(
+                CharacterCore::get(&client, &name),
+                CharacterQuests::get(&client, &name),
+                Quest::get_all(&client),
+                Self::get_all(&client),
+                StorySeason::get_all(&client),
+            )
+            .await?;
+
+            let seasons: HashMap<String, u64> = seasons
+                .into_iter()
+                .map(|season: StorySeason| (season.id, season.order))
+                .collect();
+
+            let mut stories: Vec<Self> = stories
+                .into_iter()
+                .filter(|story: &Self| story.is_available_to(character.race))
+                .collect();
+            stories.sort_by_key(|story| (seasons.get(&story.season).copied(), story.order));
+
+            Ok(stories
+                .iter()
+                .map(|story| story.progress(&quests, &completed.0))
+                .collect())
+        })
+    }
+}
+
+endpoint!(Story, "/v2/stories", u64, localized, get_all);
+
+/// A chapter of a [`Story`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct StoryChapter {
+    /// The name of the chapter.
+    pub name: String,
+}
+
+/// A flag of a [`Story`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StoryFlag {
+    /// The story must be unlocked before it can be played.
+    RequiresUnlock,
+    /// A flag unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A season of stories, e.g. the personal story or a season of the living world.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct StorySeason {
+    /// The id of the season.
+    pub id: String,
+    /// The name of the season.
+    pub name: String,
+    /// The position of the season in the story line.
+    pub order: u64,
+    /// The ids of the stories of the season.
+    pub stories: Vec<u64>,
+}
+
+endpoint!(
+    StorySeason,
+    "/v2/stories/seasons",
+    String,
+    localized,
+    get_all
+);
+
+/// The progress of a character in a [`Story`].
+///
+/// Returned by [`Story::progress`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct StoryProgress {
+    /// The id of the story.
+    pub id: u64,
+    /// The id of the season of the story.
+    pub season: String,
+    /// The name of the story.
+    pub name: String,
+    /// The chapters of the story in order.
+    pub chapters: Vec<ChapterProgress>,
+}
+
+impl StoryProgress {
+    /// Returns the number of completed chapters.
+    pub fn completed(&self) -> usize {
+        self.chapters
+            .iter()
+            .filter(|chapter| chapter.completed)
+            .count()
+    }
+
+    /// Returns `true` if all chapters are completed.
+    pub fn is_completed(&self) -> bool {
+        self.chapters.iter().all(|chapter| chapter.completed)
+    }
+
+    /// Returns the first chapter that is not completed. Returns `None` if all chapters are
+    /// completed.
+    pub fn next_chapter(&self) -> Option<&ChapterProgress> {
+        self.chapters.iter().find(|chapter| !chapter.completed)
+    }
+}
+
+/// The progress of a character in a chapter of a [`Story`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct ChapterProgress {
+    /// The name of the chapter.
+    pub name: String,
+    /// The id of the quest of the chapter. This is `None` if no quest matches the chapter.
+    pub quest: Option<u64>,
+    /// Whether the character completed the chapter.
+    pub completed: bool,
+}
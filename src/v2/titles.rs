@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use crate::endpoint;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Title {
     pub id: u64,
     pub name: String,
@@ -10,4 +11,4 @@ pub struct Title {
     pub ap_required: Option<u64>,
 }
 
-endpoint!(Title, "/v2/titles", u64, get_all);
+endpoint!(Title, "/v2/titles", u64, localized, get_all);
@@ -1,10 +1,15 @@
-use chrono::{DateTime, Utc};
+use std::fmt::{self, Display, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
+use crate::datetime::{self, DateTime};
 use crate::{Authentication, ClientExecutor, RequestBuilder};
 
 /// Details about an api token.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct TokenInfo {
     /// The unique id of the token.
     pub id: String,
@@ -16,16 +21,17 @@ pub struct TokenInfo {
     #[serde(rename = "type")]
     pub kind: TokenKind,
     /// Expiration time of the token. Only avaliable on subtokens.
-    #[serde(default)]
-    pub expires_at: Option<DateTime<Utc>>,
+    #[serde(default, with = "crate::datetime::rfc3339::option")]
+    pub expires_at: Option<DateTime>,
     /// Creation time of the token. Only avaliable on subtokens.
-    pub issued_at: Option<DateTime<Utc>>,
+    #[serde(default, with = "crate::datetime::rfc3339::option")]
+    pub issued_at: Option<DateTime>,
     /// A list of urls the subtoken is restricted to. (optional)
     pub urls: Option<Vec<String>>,
 }
 
 /// A token permission.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum TokenPermission {
     Account,
@@ -40,9 +46,84 @@ pub enum TokenPermission {
     Wallet,
 }
 
+impl Display for TokenPermission {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let string = match self {
+            Self::Account => "account",
+            Self::Builds => "builds",
+            Self::Characters => "characters",
+            Self::Guilds => "guilds",
+            Self::Inventories => "inventories",
+            Self::Progression => "progression",
+            Self::Pvp => "pvp",
+            Self::TradingPost => "tradingpost",
+            Self::Unlocks => "unlocks",
+            Self::Wallet => "wallet",
+        };
+
+        write!(f, "{}", string)
+    }
+}
+
+/// The permissions granted to the access token of a [`Client`].
+///
+/// Returned by [`Client::scopes`].
+///
+/// [`Client`]: crate::Client
+/// [`Client::scopes`]: crate::Client::scopes
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Scopes(Arc<[TokenPermission]>);
+
+impl Scopes {
+    pub(crate) fn new<T>(permissions: T) -> Self
+    where
+        T: Into<Arc<[TokenPermission]>>,
+    {
+        Self(permissions.into())
+    }
+
+    /// Returns `true` if the `permission` is granted.
+    #[inline]
+    pub fn contains(&self, permission: TokenPermission) -> bool {
+        self.0.contains(&permission)
+    }
+
+    /// Returns the first permission of `required` that is not granted. Returns `None` if all
+    /// permissions are granted.
+    pub fn missing(&self, required: &[TokenPermission]) -> Option<TokenPermission> {
+        required
+            .iter()
+            .copied()
+            .find(|permission| !self.contains(*permission))
+    }
+
+    /// Returns all granted permissions.
+    #[inline]
+    pub fn as_slice(&self) -> &[TokenPermission] {
+        &self.0
+    }
+
+    /// Returns an iterator over all granted permissions.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, TokenPermission> {
+        self.0.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Scopes {
+    type Item = &'a TokenPermission;
+    type IntoIter = std::slice::Iter<'a, TokenPermission>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
 /// Type of an api token.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TokenKind {
+    #[serde(rename = "APIKey")]
     ApiKey,
     Subtoken,
 }
@@ -54,4 +135,133 @@ impl TokenInfo {
     {
         client.send(RequestBuilder::new("/v2/tokeninfo").authenticated(Authentication::Required))
     }
+
+    /// Returns `true` if the token is a subtoken.
+    #[inline]
+    pub fn is_subtoken(&self) -> bool {
+        self.kind == TokenKind::Subtoken
+    }
+
+    /// Returns `true` if the token is allowed to access the endpoint at `url`, e.g.
+    /// `/v2/account`.
+    ///
+    /// Subtokens can be restricted to a list of [`urls`]. Tokens without restriction are allowed
+    /// to access all endpoints. The query string of `url` is ignored. Note that this does not
+    /// check whether the token has the [`permissions`] required by the endpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gw2api_rs::v2::tokeninfo::{TokenInfo, TokenKind};
+    /// #
+    /// # let mut token_info = TokenInfo {
+    /// #     id: String::new(),
+    /// #     name: String::new(),
+    /// #     permissions: vec![],
+    /// #     kind: TokenKind::Subtoken,
+    /// #     expires_at: None,
+    /// #     issued_at: None,
+    /// #     urls: None,
+    /// # };
+    /// token_info.urls = Some(vec![String::from("/v2/account")]);
+    ///
+    /// assert!(token_info.allows_url("/v2/account"));
+    /// assert!(token_info.allows_url("/v2/account?lang=de"));
+    /// assert!(!token_info.allows_url("/v2/account/bank"));
+    /// ```
+    ///
+    /// [`urls`]: Self::urls
+    /// [`permissions`]: Self::permissions
+    pub fn allows_url(&self, url: &str) -> bool {
+        let urls = match &self.urls {
+            Some(urls) => urls,
+            None => return true,
+        };
+
+        let path = url.split_once('?').map_or(url, |(path, _)| path);
+        let path = path.trim_end_matches('/');
+
+        urls.iter()
+            .any(|allowed| allowed.trim_end_matches('/') == path)
+    }
+
+    /// Returns the time remaining until the token expires. Returns `None` if the token does not
+    /// expire and a zero duration if the token already expired.
+    ///
+    /// Only subtokens expire.
+    pub fn expires_in(&self) -> Option<Duration> {
+        self.expires_at.map(datetime::until)
+    }
+}
+
+/// A subtoken created from the access token of the client.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Subtoken {
+    /// The subtoken, usable as an access token.
+    pub subtoken: String,
+}
+
+impl Subtoken {
+    /// Creates a new subtoken from the access token of the client. The subtoken is restricted to
+    /// the given `permissions` and expires at `expire`.
+    ///
+    /// The access token of the client must be granted all of the requested `permissions`.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::tokeninfo::{Subtoken, TokenPermission};
+    /// # #[cfg(feature = "chrono")]
+    /// # use chrono::{Duration, Utc};
+    /// #
+    /// # #[cfg(feature = "chrono")]
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let expire = Utc::now() + Duration::hours(1);
+    /// let subtoken = Subtoken::create(&client, &[TokenPermission::Account], expire).await?;
+    /// println!("{}", subtoken.subtoken);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::tokeninfo::{Subtoken, TokenPermission};
+    /// # #[cfg(feature = "chrono")]
+    /// # use chrono::{Duration, Utc};
+    /// #
+    /// # #[cfg(feature = "chrono")]
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let expire = Utc::now() + Duration::hours(1);
+    /// let subtoken = Subtoken::create(&client, &[TokenPermission::Account], expire)?;
+    /// println!("{}", subtoken.subtoken);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn create<C>(client: &C, permissions: &[TokenPermission], expire: DateTime) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(
+            RequestBuilder::new("/v2/createsubtoken")
+                .authenticated(Authentication::Required)
+                .query("expire", datetime::to_rfc3339_secs(expire))
+                .query_list("permissions", permissions),
+        )
+    }
 }
@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+
+use futures_util::future;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::account::AccountWorldBosses;
+use crate::{endpoint, Executor};
+
+/// A world boss whose chest can be looted once per day.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct WorldBoss {
+    /// The id of the world boss, e.g. `tequatl_the_sunless`.
+    pub id: String,
+}
+
+impl WorldBoss {
+    /// Returns the ids of all world bosses whose chest the currently authenticated account can
+    /// still loot today.
+    ///
+    /// The world bosses and the [`AccountWorldBosses`] of the account are requested concurrently.
+    /// Bosses already defeated by the account are removed from the list. Their chests can be
+    /// looted again after the next daily reset, see [`AccountWorldBosses::expires_at`].
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication and returns an [`Error`] if no access token is set.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::worldbosses::WorldBoss;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let remaining = WorldBoss::remaining(&client).await?;
+    /// println!("{} world boss chests left today", remaining.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::worldbosses::WorldBoss;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// # let token = "";
+    /// let client: Client = Client::builder().access_token(token).into();
+    /// let remaining = WorldBoss::remaining(&client)?;
+    /// println!("{} world boss chests left today", remaining.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct@crate::Error
+    /// [`blocking`]: crate::blocking
+    pub fn remaining<C>(client: &C) -> C::Output<Vec<String>>
+    where
+        C: Executor,
+    {
+        client.execute(|client| async move {
+            let (ids, defeated) =
+                future::try_join(Self::ids(&client), AccountWorldBosses::get(&client)).await?;
+            let defeated: HashSet<String> = defeated.into_iter().collect();
+
+            Ok(ids
+                .into_iter()
+                .filter(|id| !defeated.contains(id))
+                .collect())
+        })
+    }
+}
+
+endpoint!(WorldBoss, "/v2/worldbosses", String, get_all);
@@ -2,16 +2,72 @@ use std::cmp::Ordering;
 
 use serde::{Deserialize, Serialize};
 
-use crate::endpoint;
+use crate::v2::common::Region;
+use crate::{endpoint, Language};
 
 /// A game world.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct World {
     pub id: u64,
     pub name: String,
     pub population: Population,
 }
 
+impl World {
+    /// Returns the region of the world, derived from its id. Returns `None` if the id is not in
+    /// a known range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gw2api_rs::v2::common::Region;
+    /// # use gw2api_rs::v2::worlds::{Population, World};
+    /// #
+    /// let world = World {
+    ///     id: 2201,
+    ///     name: String::from("Kodasch [DE]"),
+    ///     population: Population::High,
+    /// };
+    /// assert_eq!(world.region(), Some(Region::Eu));
+    /// ```
+    pub fn region(&self) -> Option<Region> {
+        match self.id / 1000 {
+            1 => Some(Region::Na),
+            2 => Some(Region::Eu),
+            _ => None,
+        }
+    }
+
+    /// Returns the language of the world, derived from its id. Returns `None` if the id is not in
+    /// a known range.
+    ///
+    /// All NA worlds are english worlds. EU worlds are english, french, german or spanish worlds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gw2api_rs::Language;
+    /// # use gw2api_rs::v2::worlds::{Population, World};
+    /// #
+    /// let world = World {
+    ///     id: 2201,
+    ///     name: String::from("Kodasch [DE]"),
+    ///     population: Population::High,
+    /// };
+    /// assert_eq!(world.language(), Some(Language::De));
+    /// ```
+    pub fn language(&self) -> Option<Language> {
+        match self.id / 100 {
+            10..=20 => Some(Language::En),
+            21 => Some(Language::Fr),
+            22 => Some(Language::De),
+            23 => Some(Language::Es),
+            _ => None,
+        }
+    }
+}
+
 /// The population of a [`World`].
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
@@ -20,16 +76,18 @@ pub enum Population {
     VeryHigh,
     High,
     Medium,
+    Low,
 }
 
 impl Population {
     #[inline]
     fn as_u8(&self) -> u8 {
         match self {
-            Self::Full => 3,
-            Self::VeryHigh => 2,
-            Self::High => 1,
-            Self::Medium => 0,
+            Self::Full => 4,
+            Self::VeryHigh => 3,
+            Self::High => 2,
+            Self::Medium => 1,
+            Self::Low => 0,
         }
     }
 }
@@ -37,8 +95,15 @@ impl Population {
 impl PartialOrd for Population {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.as_u8().partial_cmp(&other.as_u8())
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Population {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_u8().cmp(&other.as_u8())
     }
 }
 
-endpoint!(World, "/v2/worlds", u64, get_all);
+endpoint!(World, "/v2/worlds", u64, localized, get_all);
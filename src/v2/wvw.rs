@@ -1,117 +1,207 @@
+#[cfg(feature = "worlds")]
+use crate::bulk;
+#[cfg(feature = "worlds")]
+use crate::v2::worlds::World;
+#[cfg(feature = "worlds")]
+use crate::Executor;
 use crate::{endpoint, ClientExecutor, RequestBuilder};
 
 use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
 
-use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::datetime::DateTime;
+use crate::v2::common::Region;
+use crate::v2::common::RenderUrl;
 
 /// A WvW ability
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Ability {
     pub id: u64,
     pub name: String,
     pub description: String,
-    pub icon: String,
+    pub icon: RenderUrl,
     pub ranks: Vec<AbilityRank>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct AbilityRank {
     pub cost: u64,
     pub effect: String,
 }
 
-impl Ability {
-    pub fn get<C>(client: &C, id: u64) -> C::Result
-    where
-        C: ClientExecutor<Self>,
-    {
-        let uri = format!("/v2/wvw/abilities?id={}", id);
-        client.send(RequestBuilder::new(uri))
-    }
-
-    pub fn get_all<C>(client: &C) -> C::Result
-    where
-        C: ClientExecutor<Vec<Self>>,
-    {
-        client.send(RequestBuilder::new("/v2/wvw/abilities?ids=all"))
-    }
-
-    pub fn ids<C>(client: &C) -> C::Result
-    where
-        C: ClientExecutor<Vec<u64>>,
-    {
-        client.send(RequestBuilder::new("/v2/wvw/abilities"))
-    }
-}
+endpoint!(Ability, "/v2/wvw/abilities", u64, localized, get_all);
 
 /// Details about a WvW match
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Match {
     /// Id of the match. NA matches have an `id` starting with `1`, EU matches have an `id`
     /// starting with `2`. The second number indicates the tier of match. Matches are sorted after
     /// their tier, but **not** NA/EU matches.
     pub id: String,
     /// The starting time of the match.
-    pub start_time: DateTime<Utc>,
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub start_time: DateTime,
     /// The ending time of the match.
-    pub end_time: DateTime<Utc>,
-    /// The total (sum) scores of all sides. The keys always include `red`, `green` and `blue`.
-    pub scores: HashMap<String, u64>,
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub end_time: DateTime,
+    /// The total (sum) scores of all sides.
+    pub scores: TeamScores,
     /// The hosting worlds of all sides.
-    pub worlds: HashMap<String, u64>,
+    pub worlds: Teams<u64>,
     /// All worlds of all sides.
-    pub all_worlds: HashMap<String, Vec<u64>>,
+    pub all_worlds: Teams<Vec<u64>>,
     /// The total deaths of all sides.
-    pub deaths: HashMap<String, u64>,
+    pub deaths: TeamScores,
     /// The total kills of all sides.
-    pub kills: HashMap<String, u64>,
+    pub kills: TeamScores,
     /// The total victory points of all sides.
-    pub victory_points: HashMap<String, u64>,
+    pub victory_points: TeamScores,
     /// Detailed skirmish information (2 hours)
     pub skirmishes: Vec<Skirmish>,
     /// Map information
     pub maps: Vec<Map>,
 }
 
+/// A value for each of the three teams in a WvW match.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Teams<T> {
+    pub red: T,
+    pub green: T,
+    pub blue: T,
+}
+
+impl<T> Teams<T> {
+    /// Returns the value for the given `team`. Returns `None` for [`TeamColor::Neutral`] and
+    /// [`TeamColor::Unknown`].
+    pub fn get(&self, team: TeamColor) -> Option<&T> {
+        match team {
+            TeamColor::Red => Some(&self.red),
+            TeamColor::Green => Some(&self.green),
+            TeamColor::Blue => Some(&self.blue),
+            TeamColor::Neutral | TeamColor::Unknown => None,
+        }
+    }
+
+    /// Returns an iterator over the values of all teams, in the order red, green, blue.
+    pub fn iter(&self) -> impl Iterator<Item = (TeamColor, &T)> {
+        [
+            (TeamColor::Red, &self.red),
+            (TeamColor::Green, &self.green),
+            (TeamColor::Blue, &self.blue),
+        ]
+        .into_iter()
+    }
+}
+
+/// The scores of all teams in a WvW match.
+pub type TeamScores = Teams<u64>;
+
+impl TeamScores {
+    /// Returns the sum of the scores of all teams.
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.red + self.green + self.blue
+    }
+
+    /// Returns the team with the highest score. Returns `None` if multiple teams share the
+    /// highest score.
+    pub fn leader(&self) -> Option<TeamColor> {
+        let (team, max) = self
+            .iter()
+            .max_by_key(|(_, score)| **score)
+            .expect("teams is never empty");
+
+        if self.iter().filter(|(_, score)| *score == max).count() > 1 {
+            None
+        } else {
+            Some(team)
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Skirmish {
     pub id: u64,
-    pub scores: HashMap<String, u64>,
+    pub scores: TeamScores,
     pub map_scores: Vec<MapScore>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct MapScore {
-    pub r#type: String,
-    pub scores: HashMap<String, u64>,
+    pub r#type: WvwMapKind,
+    pub scores: TeamScores,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Map {
     pub id: u64,
-    pub r#type: String,
-    pub scores: HashMap<String, u64>,
+    pub r#type: WvwMapKind,
+    pub scores: TeamScores,
     pub bonuses: Vec<Bonus>,
     pub objectives: Vec<Objective>,
-    pub deaths: HashMap<String, u64>,
-    pub kills: HashMap<String, u64>,
+    pub deaths: TeamScores,
+    pub kills: TeamScores,
+}
+
+/// The kind of a WvW map.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WvwMapKind {
+    #[serde(rename = "Center")]
+    EternalBattlegrounds,
+    #[serde(rename = "RedHome")]
+    RedBorderlands,
+    #[serde(rename = "GreenHome")]
+    GreenBorderlands,
+    #[serde(rename = "BlueHome")]
+    BlueBorderlands,
+    EdgeOfTheMists,
+    /// A map kind unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+impl WvwMapKind {
+    /// Returns the team owning this map. Returns `None` for maps not owned by any team.
+    #[inline]
+    pub fn home_team(&self) -> Option<TeamColor> {
+        match self {
+            Self::RedBorderlands => Some(TeamColor::Red),
+            Self::GreenBorderlands => Some(TeamColor::Green),
+            Self::BlueBorderlands => Some(TeamColor::Blue),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Bonus {
     pub r#type: String,
-    pub owner: String,
+    pub owner: TeamColor,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Objective {
     pub id: String,
-    pub r#type: String,
-    pub owner: String,
-    pub last_flipped: DateTime<Utc>,
+    pub r#type: ObjectiveKind,
+    pub owner: TeamColor,
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub last_flipped: DateTime,
     pub claimed_by: Option<String>,
-    pub claimed_at: Option<DateTime<Utc>>,
+    #[serde(default, with = "crate::datetime::rfc3339::option")]
+    pub claimed_at: Option<DateTime>,
     pub points_tick: u64,
     pub points_capture: u64,
     /// Only for camps, towers and keeps (and sm)
@@ -119,20 +209,198 @@ pub struct Objective {
     pub guild_upgrades: Option<Vec<u64>>,
 }
 
+impl Objective {
+    /// Returns the map icon of this objective and its current owner. Returns `None` if the
+    /// objective has no icon.
+    ///
+    /// See [`ObjectiveKind::icon`] for how the icon is colored.
+    #[inline]
+    pub fn icon(&self) -> Option<ObjectiveIcon> {
+        self.r#type.icon(self.owner)
+    }
+
+    /// Returns the upgrade progress of this objective using the upgrade tiers of the objective.
+    /// Returns `None` if the objective cannot be upgraded, i.e. [`yaks_delivered`] is `None`.
+    ///
+    /// The `upgrades` of an objective are referenced by the `upgrade_id` of its entry in
+    /// `/v2/wvw/objectives`.
+    ///
+    /// [`yaks_delivered`]: Self::yaks_delivered
+    #[inline]
+    pub fn upgrade_progress(&self, upgrades: &Upgrades) -> Option<UpgradeProgress> {
+        self.yaks_delivered
+            .map(|yaks_delivered| upgrades.progress(yaks_delivered))
+    }
+}
+
+/// The kind of a WvW objective.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ObjectiveKind {
+    Camp,
+    Tower,
+    Keep,
+    Castle,
+    Ruins,
+    Spawn,
+    Mercenary,
+    Resource,
+    Generic,
+    /// An objective kind unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+impl ObjectiveKind {
+    const CAMP_ICON: &'static str =
+        "https://render.guildwars2.com/file/015D365A08AAE105287A100AAE04529FDAE14155/102532.png";
+    const TOWER_ICON: &'static str =
+        "https://render.guildwars2.com/file/ABEC80C79576A103EA33EC66FCB99B77291A2F0D/102531.png";
+    const KEEP_ICON: &'static str =
+        "https://render.guildwars2.com/file/DB580419C8AD9449309A96C8E7C3D61631020EBB/102535.png";
+    const CASTLE_ICON: &'static str =
+        "https://render.guildwars2.com/file/F0F1DA1C807444F4DF53090343F43BED02E50523/102608.png";
+
+    /// Returns the map icon for this kind of objective owned by `team`. Returns `None` if this
+    /// kind of objective has no map icon.
+    ///
+    /// The render service has no team colored variants of the objective icons, the same icon is
+    /// referenced by the `marker` of all objectives of a kind in `/v2/wvw/objectives`. The
+    /// returned url is therefore the same for all teams and the icon has to be tinted using
+    /// [`ObjectiveIcon::rgb`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gw2api_rs::v2::wvw::{ObjectiveKind, TeamColor};
+    /// #
+    /// let red = ObjectiveKind::Keep.icon(TeamColor::Red).unwrap();
+    /// let blue = ObjectiveKind::Keep.icon(TeamColor::Blue).unwrap();
+    /// assert_eq!(red.url, blue.url);
+    /// assert_eq!(red.rgb(), TeamColor::Red.rgb());
+    ///
+    /// assert!(ObjectiveKind::Spawn.icon(TeamColor::Red).is_none());
+    /// ```
+    pub fn icon(&self, team: TeamColor) -> Option<ObjectiveIcon> {
+        let url = match self {
+            Self::Camp => Self::CAMP_ICON,
+            Self::Tower => Self::TOWER_ICON,
+            Self::Keep => Self::KEEP_ICON,
+            Self::Castle => Self::CASTLE_ICON,
+            _ => return None,
+        };
+
+        Some(ObjectiveIcon { url, team })
+    }
+}
+
+impl FromStr for ObjectiveKind {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Camp" => Ok(Self::Camp),
+            "Tower" => Ok(Self::Tower),
+            "Keep" => Ok(Self::Keep),
+            "Castle" => Ok(Self::Castle),
+            "Ruins" => Ok(Self::Ruins),
+            "Spawn" => Ok(Self::Spawn),
+            "Mercenary" => Ok(Self::Mercenary),
+            "Resource" => Ok(Self::Resource),
+            "Generic" => Ok(Self::Generic),
+            _ => Err(ParseEnumError),
+        }
+    }
+}
+
+/// The color of a team in a WvW match.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TeamColor {
+    Red,
+    Green,
+    Blue,
+    Neutral,
+    /// A team color unknown to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+impl TeamColor {
+    /// Returns the color used to tint map icons owned by this team as RGB values. Unknown teams
+    /// use the neutral color.
+    #[inline]
+    pub fn rgb(&self) -> [u8; 3] {
+        match self {
+            Self::Red => [0xD6, 0x3E, 0x3E],
+            Self::Green => [0x4C, 0xB0, 0x4E],
+            Self::Blue => [0x3E, 0x7F, 0xD6],
+            Self::Neutral | Self::Unknown => [0xFF, 0xFF, 0xFF],
+        }
+    }
+}
+
+impl FromStr for TeamColor {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Red" | "red" => Ok(Self::Red),
+            "Green" | "green" => Ok(Self::Green),
+            "Blue" | "blue" => Ok(Self::Blue),
+            "Neutral" | "neutral" => Ok(Self::Neutral),
+            _ => Err(ParseEnumError),
+        }
+    }
+}
+
+/// The map icon of an [`Objective`].
+///
+/// The `url` points to the uncolored icon on the render service, which is the same for all
+/// teams. The icon should be tinted using the [`TeamColor::rgb`] value of the owning `team`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ObjectiveIcon {
+    /// The url of the icon on the render service.
+    pub url: &'static str,
+    /// The team owning the objective.
+    pub team: TeamColor,
+}
+
+impl ObjectiveIcon {
+    /// Returns the RGB values the icon should be tinted with.
+    #[inline]
+    pub fn rgb(&self) -> [u8; 3] {
+        self.team.rgb()
+    }
+}
+
+/// An error returned when parsing an unknown enum value from a string.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ParseEnumError;
+
+impl Display for ParseEnumError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("unknown enum variant")
+    }
+}
+
+impl std::error::Error for ParseEnumError {}
+
 impl Match {
     pub fn get<C>(client: &C, id: &str) -> C::Result
     where
         C: ClientExecutor<Self>,
     {
-        let uri = format!("/v2/wvw/matches?id={}", id);
-        client.send(RequestBuilder::new(uri))
+        client.send(RequestBuilder::new("/v2/wvw/matches").query("id", id))
     }
 
     pub fn get_all<C>(client: &C) -> C::Result
     where
         C: ClientExecutor<Vec<Self>>,
     {
-        client.send(RequestBuilder::new("/v2/wvw/matches?ids=all").localized(true))
+        client.send(
+            RequestBuilder::new("/v2/wvw/matches")
+                .query("ids", "all")
+                .localized(true),
+        )
     }
 
     pub fn ids<C>(client: &C) -> C::Result
@@ -141,46 +409,270 @@ impl Match {
     {
         client.send(RequestBuilder::new("/v2/wvw/matches"))
     }
+
+    /// Returns the current match of the world or WvW team with the given `id`.
+    pub fn get_by_world<C>(client: &C, id: u64) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(RequestBuilder::new("/v2/wvw/matches").query("world", id))
+    }
+
+    /// Returns the side of the world or WvW team with the given `id` in this match. Returns
+    /// `None` if it does not participate in the match.
+    pub fn team_of(&self, id: u64) -> Option<TeamColor> {
+        self.all_worlds
+            .iter()
+            .find(|(_, worlds)| worlds.contains(&id))
+            .map(|(team, _)| team)
+            .or_else(|| {
+                self.worlds
+                    .iter()
+                    .find(|(_, world)| **world == id)
+                    .map(|(team, _)| team)
+            })
+    }
 }
 
+/// The victory points awarded per skirmish to the first, second and third placed team.
+#[cfg(feature = "worlds")]
+const SKIRMISH_VICTORY_POINTS: [u64; 3] = [5, 4, 3];
+
+/// A summary of the current WvW match of a world, as returned by [`matchup_summary`].
+#[cfg(feature = "worlds")]
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct Rank {
-    pub id: u64,
-    pub title: String,
-    pub min_rank: u64,
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct MatchupSummary {
+    /// The id of the match.
+    pub id: String,
+    /// The tier of the match. `None` if the match id has an unknown format.
+    pub tier: Option<u8>,
+    /// The starting time of the match.
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub start_time: DateTime,
+    /// The ending time of the match.
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub end_time: DateTime,
+    /// The side of the summarized world. `None` if the world does not participate in the match.
+    pub team: Option<TeamColor>,
+    /// The summaries of all sides.
+    pub teams: Teams<MatchupTeam>,
 }
 
-impl Rank {
-    pub fn get<C>(client: &C, id: u64) -> C::Result
-    where
-        C: ClientExecutor<Self>,
-    {
-        let uri = format!("/v2/wvw/ranks?id={}", id);
-        client.send(RequestBuilder::new(uri).localized(true))
+#[cfg(feature = "worlds")]
+impl MatchupSummary {
+    /// Creates a new `MatchupSummary` of the match `m` from the perspective of the world or WvW
+    /// team with the given `world_id`.
+    ///
+    /// World names are looked up in `worlds`.
+    pub fn new(m: &Match, world_id: u64, worlds: &[World]) -> Self {
+        let name = |id: u64| {
+            worlds
+                .iter()
+                .find(|world| world.id == id)
+                .map(|world| world.name.clone())
+        };
+
+        let skirmish_vp: Vec<Teams<u64>> = m
+            .skirmishes
+            .iter()
+            .map(|skirmish| skirmish_victory_points(&skirmish.scores))
+            .collect();
+
+        let team = |color: TeamColor| {
+            let get = |scores: &TeamScores| *scores.get(color).unwrap();
+
+            let host = *m.worlds.get(color).unwrap();
+            MatchupTeam {
+                world: MatchupWorld {
+                    id: host,
+                    name: name(host),
+                },
+                linked_worlds: m
+                    .all_worlds
+                    .get(color)
+                    .unwrap()
+                    .iter()
+                    .filter(|id| **id != host)
+                    .map(|id| MatchupWorld {
+                        id: *id,
+                        name: name(*id),
+                    })
+                    .collect(),
+                score: get(&m.scores),
+                kills: get(&m.kills),
+                deaths: get(&m.deaths),
+                victory_points: get(&m.victory_points),
+                skirmish_victory_points: skirmish_vp.iter().map(get).collect(),
+            }
+        };
+
+        Self {
+            id: m.id.clone(),
+            tier: m.id.split_once('-').and_then(|(_, tier)| tier.parse().ok()),
+            start_time: m.start_time,
+            end_time: m.end_time,
+            team: m.team_of(world_id),
+            teams: Teams {
+                red: team(TeamColor::Red),
+                green: team(TeamColor::Green),
+                blue: team(TeamColor::Blue),
+            },
+        }
     }
 
-    pub fn get_all<C>(client: &C) -> C::Result
-    where
-        C: ClientExecutor<Vec<Self>>,
-    {
-        client.send(RequestBuilder::new("/v2/wvw/ranks?ids=all").localized(true))
+    /// Returns the summary of the side of the summarized world. Returns `None` if the world
+    /// does not participate in the match.
+    pub fn own(&self) -> Option<&MatchupTeam> {
+        self.teams.get(self.team?)
     }
 
-    pub fn ids<C>(client: &C) -> C::Result
-    where
-        C: ClientExecutor<Vec<u64>>,
-    {
-        client.send(RequestBuilder::new("/v2/wvw/ranks"))
+    /// Returns the summaries of the opponents of the summarized world. Returns all three sides
+    /// if the world does not participate in the match.
+    pub fn opponents(&self) -> impl Iterator<Item = &MatchupTeam> + '_ {
+        self.teams
+            .iter()
+            .filter(move |(color, _)| Some(*color) != self.team)
+            .map(|(_, team)| team)
     }
 }
 
+/// A side in a [`MatchupSummary`].
+#[cfg(feature = "worlds")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct MatchupTeam {
+    /// The hosting world of the side.
+    pub world: MatchupWorld,
+    /// The other worlds linked to the hosting world.
+    pub linked_worlds: Vec<MatchupWorld>,
+    /// The current war score.
+    pub score: u64,
+    /// The total kills.
+    pub kills: u64,
+    /// The total deaths.
+    pub deaths: u64,
+    /// The total victory points.
+    pub victory_points: u64,
+    /// The victory points awarded in each skirmish so far. The last entry is the current
+    /// standing of the skirmish in progress.
+    pub skirmish_victory_points: Vec<u64>,
+}
+
+/// A world in a [`MatchupSummary`].
+#[cfg(feature = "worlds")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct MatchupWorld {
+    /// The id of the world or WvW team.
+    pub id: u64,
+    /// The name of the world. `None` if the id is not a known world, e.g. for WvW teams.
+    pub name: Option<String>,
+}
+
+/// Returns the victory points awarded for a skirmish with the given `scores`. Teams with equal
+/// scores share the higher placement.
+#[cfg(feature = "worlds")]
+fn skirmish_victory_points(scores: &TeamScores) -> Teams<u64> {
+    let vp = |score: u64| {
+        let placement = scores.iter().filter(|(_, other)| **other > score).count();
+        SKIRMISH_VICTORY_POINTS[placement]
+    };
+
+    Teams {
+        red: vp(scores.red),
+        green: vp(scores.green),
+        blue: vp(scores.blue),
+    }
+}
+
+/// Returns a [`MatchupSummary`] of the current match of the world or WvW team with the given
+/// `world_id`.
+///
+/// This fetches the current [`Match`] of the world and the names of all participating
+/// [`World`]s.
+///
+/// Requires the `worlds` feature to be enabled.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use gw2api_rs::{Client, Result};
+/// # use gw2api_rs::v2::wvw;
+/// #
+/// # async fn run() -> Result<()> {
+/// let client = Client::new();
+/// let summary = wvw::matchup_summary(&client, 2201).await?;
+///
+/// for team in summary.opponents() {
+///     println!("{:?}: {} VP", team.world.name, team.victory_points);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Using the [`blocking`] client:
+///
+/// ```no_run
+/// # use gw2api_rs::Result;
+/// # use gw2api_rs::blocking::Client;
+/// # use gw2api_rs::v2::wvw;
+/// #
+/// # fn run() -> Result<()> {
+/// let client = Client::new();
+/// let summary = wvw::matchup_summary(&client, 2201)?;
+///
+/// for team in summary.opponents() {
+///     println!("{:?}: {} VP", team.world.name, team.victory_points);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`blocking`]: crate::blocking
+#[cfg(feature = "worlds")]
+pub fn matchup_summary<C>(client: &C, world_id: u64) -> C::Output<MatchupSummary>
+where
+    C: Executor,
+{
+    client.execute(|client| async move {
+        let m: Match = Match::get_by_world(&client, world_id).await?;
+
+        let mut ids: Vec<u64> = m
+            .all_worlds
+            .iter()
+            .flat_map(|(_, ids)| ids.iter().copied())
+            .chain(m.worlds.iter().map(|(_, id)| *id))
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+
+        // Ids of WvW teams are not worlds and omitted.
+        let worlds: Vec<World> = bulk::get_chunked(&client, World::URI, &ids).await?;
+
+        Ok(MatchupSummary::new(&m, world_id, &worlds))
+    })
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Rank {
+    pub id: u64,
+    pub title: String,
+    pub min_rank: u64,
+}
+
+endpoint!(Rank, "/v2/wvw/ranks", u64, localized, get_all);
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Upgrades {
     pub id: u64,
     pub tiers: Vec<UpgradeTier>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct UpgradeTier {
     pub name: String,
     pub yaks_required: u64,
@@ -188,10 +680,306 @@ pub struct UpgradeTier {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Upgrade {
     pub name: String,
     pub description: String,
-    pub icon: String,
+    pub icon: RenderUrl,
 }
 
-endpoint!(Upgrades, "/v2/wvw/upgrades", u64, get_all);
+impl Upgrades {
+    /// Returns the upgrade progress of an objective after `yaks_delivered` dolyaks were
+    /// delivered.
+    ///
+    /// The [`yaks_required`] of every tier is the total number of dolyaks delivered to reach the
+    /// tier. Tiers are reached in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gw2api_rs::v2::wvw::{Upgrades, UpgradeTier};
+    /// #
+    /// let tier = |yaks_required| UpgradeTier {
+    ///     name: String::new(),
+    ///     yaks_required,
+    ///     upgrades: Vec::new(),
+    /// };
+    /// let upgrades = Upgrades {
+    ///     id: 1,
+    ///     tiers: vec![tier(20), tier(60), tier(140)],
+    /// };
+    ///
+    /// let progress = upgrades.progress(40);
+    /// assert_eq!(progress.tier, 1);
+    /// assert_eq!(progress.yaks_remaining(), Some(20));
+    /// assert_eq!(progress.fraction(), 0.5);
+    /// ```
+    ///
+    /// [`yaks_required`]: UpgradeTier::yaks_required
+    pub fn progress(&self, yaks_delivered: u64) -> UpgradeProgress {
+        let tier = self
+            .tiers
+            .iter()
+            .take_while(|tier| tier.yaks_required <= yaks_delivered)
+            .count();
+
+        UpgradeProgress {
+            tier,
+            yaks_delivered,
+            tier_yaks: tier
+                .checked_sub(1)
+                .map_or(0, |index| self.tiers[index].yaks_required),
+            next_tier_yaks: self.tiers.get(tier).map(|tier| tier.yaks_required),
+        }
+    }
+
+    /// Returns the highest tier reached after `yaks_delivered` dolyaks were delivered. Returns
+    /// `None` if no tier was reached yet.
+    pub fn current_tier(&self, yaks_delivered: u64) -> Option<&UpgradeTier> {
+        let tier = self.progress(yaks_delivered).tier;
+        tier.checked_sub(1).map(|index| &self.tiers[index])
+    }
+}
+
+endpoint!(Upgrades, "/v2/wvw/upgrades", u64, localized, get_all);
+
+/// The upgrade progress of an objective, returned by [`Upgrades::progress`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UpgradeProgress {
+    /// The number of tiers reached. This is `0` if no tier was reached yet.
+    pub tier: usize,
+    /// The number of dolyaks delivered to the objective.
+    pub yaks_delivered: u64,
+    /// The number of dolyaks required to reach the current tier, or `0` if no tier was reached
+    /// yet.
+    pub tier_yaks: u64,
+    /// The number of dolyaks required to reach the next tier. This is `None` if all tiers were
+    /// reached.
+    pub next_tier_yaks: Option<u64>,
+}
+
+impl UpgradeProgress {
+    /// Returns `true` if all tiers were reached.
+    #[inline]
+    pub fn is_max_tier(&self) -> bool {
+        self.next_tier_yaks.is_none()
+    }
+
+    /// Returns the number of dolyaks still required to reach the next tier. Returns `None` if
+    /// all tiers were reached.
+    #[inline]
+    pub fn yaks_remaining(&self) -> Option<u64> {
+        self.next_tier_yaks
+            .map(|next| next.saturating_sub(self.yaks_delivered))
+    }
+
+    /// Returns the progress from the current to the next tier, in the range of `0.0` to `1.0`.
+    /// Returns `1.0` if all tiers were reached.
+    pub fn fraction(&self) -> f64 {
+        match self.next_tier_yaks {
+            Some(next) => {
+                let delivered = self.yaks_delivered.saturating_sub(self.tier_yaks);
+                match next.saturating_sub(self.tier_yaks) {
+                    0 => 1.0,
+                    required => (delivered as f64 / required as f64).min(1.0),
+                }
+            }
+            None => 1.0,
+        }
+    }
+}
+
+/// The WvW teams of all guilds in a region.
+///
+/// Since the World Restructuring accounts are assigned to WvW teams by their guild instead of
+/// their world.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Guilds(#[serde(deserialize_with = "team_ids")] pub HashMap<String, u64>);
+
+impl Guilds {
+    /// Returns the WvW team of every guild in the given `region`, keyed by the id of the guild.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::common::Region;
+    /// # use gw2api_rs::v2::wvw::Guilds;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let guilds = Guilds::get(&client, Region::Eu).await?;
+    /// println!("{} guilds", guilds.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::common::Region;
+    /// # use gw2api_rs::v2::wvw::Guilds;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let guilds = Guilds::get(&client, Region::Eu)?;
+    /// println!("{} guilds", guilds.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C, region: Region) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(RequestBuilder::new("/v2/wvw/guilds").segment(region))
+    }
+
+    /// Returns the number of guilds.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no guilds.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the id of the WvW team of the guild with the given `guild_id`. Returns `None` if
+    /// the guild is not assigned to a team.
+    #[inline]
+    pub fn team(&self, guild_id: &str) -> Option<u64> {
+        self.0.get(guild_id).copied()
+    }
+
+    /// Returns an iterator over the ids of all guilds assigned to the WvW team with the given
+    /// `team_id`.
+    pub fn guilds(&self, team_id: u64) -> impl Iterator<Item = &str> + '_ {
+        self.0
+            .iter()
+            .filter(move |(_, team)| **team == team_id)
+            .map(|(guild, _)| guild.as_str())
+    }
+}
+
+/// Deserializes a map of team ids, which the API returns as strings.
+fn team_ids<'de, D>(deserializer: D) -> Result<HashMap<String, u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum TeamId {
+        Number(u64),
+        String(String),
+    }
+
+    HashMap::<String, TeamId>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(guild, team)| {
+            let team = match team {
+                TeamId::Number(team) => team,
+                TeamId::String(team) => team.parse().map_err(D::Error::custom)?,
+            };
+
+            Ok((guild, team))
+        })
+        .collect()
+}
+
+/// The times of the upcoming WvW lockout and team assignment.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Timers {
+    /// The start of the next lockout, during which accounts cannot change their WvW guild.
+    pub lockout: RegionTimes,
+    /// The time of the next assignment of guilds to WvW teams.
+    #[serde(rename = "teamAssignment")]
+    pub team_assignment: RegionTimes,
+}
+
+impl Timers {
+    /// Returns the times of the next lockout and team assignment.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::{Client, Result};
+    /// # use gw2api_rs::v2::wvw::Timers;
+    /// #
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let timers = Timers::get(&client).await?;
+    /// println!("next NA team assignment at {}", timers.team_assignment.na);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Using the [`blocking`] client:
+    ///
+    /// ```no_run
+    /// # use gw2api_rs::Result;
+    /// # use gw2api_rs::blocking::Client;
+    /// # use gw2api_rs::v2::wvw::Timers;
+    /// #
+    /// # fn run() -> Result<()> {
+    /// let client = Client::new();
+    /// let timers = Timers::get(&client)?;
+    /// println!("next NA team assignment at {}", timers.team_assignment.na);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`blocking`]: crate::blocking
+    pub fn get<C>(client: &C) -> C::Result
+    where
+        C: ClientExecutor<Self>,
+    {
+        client.send(RequestBuilder::new("/v2/wvw/timers"))
+    }
+
+    /// Returns the start of the next lockout.
+    pub fn lockout<C>(client: &C) -> C::Result
+    where
+        C: ClientExecutor<RegionTimes>,
+    {
+        client.send(RequestBuilder::new("/v2/wvw/timers/lockout"))
+    }
+
+    /// Returns the time of the next team assignment.
+    pub fn team_assignment<C>(client: &C) -> C::Result
+    where
+        C: ClientExecutor<RegionTimes>,
+    {
+        client.send(RequestBuilder::new("/v2/wvw/timers/teamAssignment"))
+    }
+}
+
+/// A time for each region.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct RegionTimes {
+    /// The time in North America.
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub na: DateTime,
+    /// The time in Europe.
+    #[serde(with = "crate::datetime::rfc3339")]
+    pub eu: DateTime,
+}
+
+impl RegionTimes {
+    /// Returns the time in the given `region`.
+    #[inline]
+    pub fn get(&self, region: Region) -> DateTime {
+        match region {
+            Region::Na => self.na,
+            Region::Eu => self.eu,
+        }
+    }
+}
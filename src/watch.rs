@@ -0,0 +1,620 @@
+//! Watching endpoints for changes.
+//!
+//! A watcher polls an endpoint in a fixed interval and yields an event for every change between
+//! two polls. The first poll only records the initial state and yields no events.
+//!
+//! - A [`MatchWatcher`] watches a WvW match and yields [`MatchEvent`]s.
+//! - A [`DeliveryWatcher`] watches the trading post delivery box and yields [`DeliveryEvent`]s.
+//!   Requires the `commerce` feature to be enabled.
+//! - An [`AchievementWatcher`] watches the progress of the achievements in a category and yields
+//!   [`AchievementEvent`]s. Requires the `achievements` feature to be enabled.
+//!
+//! Requires the `watch` feature to be enabled. Watchers use the timer of the [`tokio`] runtime
+//! and must be polled from within a runtime with the time driver enabled.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use futures_util::StreamExt;
+//! use gw2api_rs::watch::{MatchEvent, MatchWatcher};
+//! use gw2api_rs::Client;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new();
+//! let mut watcher = MatchWatcher::new(&client, "1-1").interval(Duration::from_secs(60));
+//!
+//! while let Some(event) = watcher.next().await {
+//!     if let MatchEvent::ObjectiveFlipped { objective_id, owner, .. } = event? {
+//!         println!("{} was captured by {:?}", objective_id, owner);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`tokio`]: https://docs.rs/tokio
+//! [`DeliveryWatcher`]: crate::watch::DeliveryWatcher
+//! [`DeliveryEvent`]: crate::watch::DeliveryEvent
+//! [`AchievementWatcher`]: crate::watch::AchievementWatcher
+//! [`AchievementEvent`]: crate::watch::AchievementEvent
+
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use std::time::Duration;
+
+use futures_util::Stream;
+use tokio::time::{self, Sleep};
+
+use crate::datetime::DateTime;
+#[cfg(feature = "achievements")]
+use crate::v2::account::{AccountAchievement, AccountAchievements};
+#[cfg(feature = "achievements")]
+use crate::v2::achievements::AchievementCategory;
+#[cfg(feature = "commerce")]
+use crate::v2::commerce::{Coins, Delivery};
+use crate::v2::wvw::{Match, TeamColor, TeamScores};
+use crate::{BoxFuture, Client, Result};
+
+/// The default interval between two polls.
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// A change in a WvW match between two polls of a [`MatchWatcher`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MatchEvent {
+    /// An objective changed its owner.
+    ObjectiveFlipped {
+        /// The id of the map of the objective.
+        map_id: u64,
+        /// The id of the objective.
+        objective_id: String,
+        /// The owner before the objective was flipped.
+        previous_owner: TeamColor,
+        /// The new owner of the objective.
+        owner: TeamColor,
+        /// The time when the objective was flipped.
+        flipped_at: DateTime,
+    },
+    /// The total scores of the match changed.
+    ScoreTick {
+        /// The scores before the tick.
+        previous: TeamScores,
+        /// The scores after the tick.
+        scores: TeamScores,
+    },
+    /// A skirmish ended.
+    SkirmishEnded {
+        /// The id of the skirmish.
+        skirmish_id: u64,
+        /// The final scores of the skirmish.
+        scores: TeamScores,
+        /// The team with the highest score. This is `None` if multiple teams share the highest
+        /// score.
+        winner: Option<TeamColor>,
+    },
+    /// A new match started. This happens on the weekly reset and when the match id now
+    /// refers to a different match.
+    MatchStarted {
+        /// The starting time of the new match.
+        start_time: DateTime,
+    },
+}
+
+impl MatchEvent {
+    /// Returns all changes between the `previous` and `current` state of a match.
+    ///
+    /// If `current` is a different match than `previous` only a [`MatchStarted`] event is
+    /// returned.
+    ///
+    /// [`MatchStarted`]: Self::MatchStarted
+    pub fn diff(previous: &Match, current: &Match) -> Vec<Self> {
+        if previous.id != current.id || previous.start_time != current.start_time {
+            return vec![Self::MatchStarted {
+                start_time: current.start_time,
+            }];
+        }
+
+        let mut events = Vec::new();
+
+        let objectives: HashMap<_, _> = previous
+            .maps
+            .iter()
+            .flat_map(|map| map.objectives.iter())
+            .map(|objective| (objective.id.as_str(), objective))
+            .collect();
+
+        for map in &current.maps {
+            for objective in &map.objectives {
+                let prev = match objectives.get(objective.id.as_str()) {
+                    Some(prev) => prev,
+                    None => continue,
+                };
+
+                if prev.owner != objective.owner || prev.last_flipped != objective.last_flipped {
+                    events.push(Self::ObjectiveFlipped {
+                        map_id: map.id,
+                        objective_id: objective.id.clone(),
+                        previous_owner: prev.owner,
+                        owner: objective.owner,
+                        flipped_at: objective.last_flipped,
+                    });
+                }
+            }
+        }
+
+        // A skirmish ended if the match has progressed past the latest previous skirmish.
+        if let Some(skirmish) = previous
+            .skirmishes
+            .iter()
+            .max_by_key(|skirmish| skirmish.id)
+        {
+            if current
+                .skirmishes
+                .iter()
+                .any(|current| current.id > skirmish.id)
+            {
+                let scores = current
+                    .skirmishes
+                    .iter()
+                    .find(|current| current.id == skirmish.id)
+                    .unwrap_or(skirmish)
+                    .scores;
+
+                events.push(Self::SkirmishEnded {
+                    skirmish_id: skirmish.id,
+                    scores,
+                    winner: scores.leader(),
+                });
+            }
+        }
+
+        if previous.scores != current.scores {
+            events.push(Self::ScoreTick {
+                previous: previous.scores,
+                scores: current.scores,
+            });
+        }
+
+        events
+    }
+}
+
+/// A [`Stream`] of [`MatchEvent`]s created by polling a WvW match.
+///
+/// See the [module level documentation] for more details.
+///
+/// [module level documentation]: self
+#[must_use = "streams do nothing unless polled"]
+pub struct MatchWatcher {
+    client: Client,
+    match_id: String,
+    poller: Poller<Match>,
+    previous: Option<Match>,
+    events: VecDeque<MatchEvent>,
+}
+
+impl MatchWatcher {
+    /// Creates a new `MatchWatcher` for the match with the given `match_id`. The match is
+    /// polled every 30 seconds by default.
+    pub fn new(client: &Client, match_id: &str) -> Self {
+        Self {
+            client: client.clone(),
+            match_id: match_id.to_owned(),
+            poller: Poller::new(),
+            previous: None,
+            events: VecDeque::new(),
+        }
+    }
+
+    /// Sets the interval between two polls.
+    ///
+    /// Note that the API caches match data, polling more often than every few seconds has no
+    /// effect.
+    #[inline]
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.poller.interval = interval;
+        self
+    }
+
+    /// Returns the state of the match recorded by the last successful poll.
+    #[inline]
+    pub fn current(&self) -> Option<&Match> {
+        self.previous.as_ref()
+    }
+}
+
+impl Stream for MatchWatcher {
+    type Item = Result<MatchEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            if let Some(event) = this.events.pop_front() {
+                return Poll::Ready(Some(Ok(event)));
+            }
+
+            let res = ready!(this
+                .poller
+                .poll_next(cx, || Match::get(&this.client, &this.match_id)));
+
+            let current = match res {
+                Ok(current) => current,
+                Err(err) => return Poll::Ready(Some(Err(err))),
+            };
+
+            if let Some(previous) = &this.previous {
+                this.events.extend(MatchEvent::diff(previous, &current));
+            }
+
+            this.previous = Some(current);
+        }
+    }
+}
+
+/// A change in the trading post delivery box between two polls of a [`DeliveryWatcher`].
+#[cfg(feature = "commerce")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DeliveryEvent {
+    /// Coins arrived in the delivery box.
+    CoinsReceived {
+        /// The amount of coins that arrived.
+        amount: Coins,
+        /// The total amount of coins waiting in the delivery box.
+        total: Coins,
+    },
+    /// Items arrived in the delivery box.
+    ItemsReceived {
+        /// The id of the item.
+        id: u64,
+        /// The number of items that arrived.
+        count: u64,
+    },
+    /// Coins or items were picked up from the delivery box.
+    PickedUp,
+}
+
+#[cfg(feature = "commerce")]
+impl DeliveryEvent {
+    /// Returns all changes between the `previous` and `current` state of a delivery box.
+    ///
+    /// Arrivals are only detected as an increase of the waiting coins or items. If coins or
+    /// items are picked up and new ones arrive between two polls, only the difference is
+    /// reported.
+    pub fn diff(previous: &Delivery, current: &Delivery) -> Vec<Self> {
+        let mut events = Vec::new();
+        let mut picked_up = current.coins < previous.coins;
+
+        if let Some(amount) = current.coins.checked_sub(previous.coins) {
+            if amount != Coins::ZERO {
+                events.push(Self::CoinsReceived {
+                    amount,
+                    total: current.coins,
+                });
+            }
+        }
+
+        let count = |delivery: &Delivery, id: u64| -> u64 {
+            delivery
+                .items
+                .iter()
+                .filter(|item| item.id == id)
+                .map(|item| item.count)
+                .sum()
+        };
+
+        let mut seen = Vec::new();
+        for item in &current.items {
+            if seen.contains(&item.id) {
+                continue;
+            }
+            seen.push(item.id);
+
+            let previous = count(previous, item.id);
+            let current = count(current, item.id);
+            if current > previous {
+                events.push(Self::ItemsReceived {
+                    id: item.id,
+                    count: current - previous,
+                });
+            } else if current < previous {
+                picked_up = true;
+            }
+        }
+
+        if previous.items.iter().any(|item| !seen.contains(&item.id)) {
+            picked_up = true;
+        }
+
+        if picked_up {
+            events.push(Self::PickedUp);
+        }
+
+        events
+    }
+}
+
+/// A [`Stream`] of [`DeliveryEvent`]s created by polling the trading post delivery box of the
+/// currently authenticated account.
+///
+/// Created by [`Delivery::watch`]. See the [module level documentation] for more details.
+///
+/// [module level documentation]: self
+#[cfg(feature = "commerce")]
+#[must_use = "streams do nothing unless polled"]
+pub struct DeliveryWatcher {
+    client: Client,
+    poller: Poller<Delivery>,
+    previous: Option<Delivery>,
+    events: VecDeque<DeliveryEvent>,
+}
+
+#[cfg(feature = "commerce")]
+impl DeliveryWatcher {
+    pub(crate) fn new(client: &Client, interval: Duration) -> Self {
+        let mut poller = Poller::new();
+        poller.interval = interval;
+
+        Self {
+            client: client.clone(),
+            poller,
+            previous: None,
+            events: VecDeque::new(),
+        }
+    }
+
+    /// Returns the state of the delivery box recorded by the last successful poll.
+    #[inline]
+    pub fn current(&self) -> Option<&Delivery> {
+        self.previous.as_ref()
+    }
+}
+
+#[cfg(feature = "commerce")]
+impl Stream for DeliveryWatcher {
+    type Item = Result<DeliveryEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            if let Some(event) = this.events.pop_front() {
+                return Poll::Ready(Some(Ok(event)));
+            }
+
+            let res = ready!(this.poller.poll_next(cx, || Delivery::get(&this.client)));
+
+            let current = match res {
+                Ok(current) => current,
+                Err(err) => return Poll::Ready(Some(Err(err))),
+            };
+
+            if let Some(previous) = &this.previous {
+                this.events.extend(DeliveryEvent::diff(previous, &current));
+            }
+
+            this.previous = Some(current);
+        }
+    }
+}
+
+/// A change in the progress of an account achievement between two polls of an
+/// [`AchievementWatcher`].
+#[cfg(feature = "achievements")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AchievementEvent {
+    /// The progress of an achievement changed.
+    Progressed {
+        /// The id of the achievement.
+        id: u64,
+        /// The previous progress.
+        previous: u64,
+        /// The current progress.
+        current: u64,
+        /// The progress required to complete the achievement, if known.
+        max: Option<u64>,
+    },
+    /// An achievement was completed for the first time.
+    Completed {
+        /// The id of the achievement.
+        id: u64,
+    },
+    /// A repeatable achievement was completed again.
+    Repeated {
+        /// The id of the achievement.
+        id: u64,
+        /// The number of times the achievement has been repeated.
+        repeated: u64,
+    },
+}
+
+#[cfg(feature = "achievements")]
+impl AchievementEvent {
+    /// Returns all changes between the `previous` and `current` progress of an account.
+    ///
+    /// Achievements missing from `previous` are treated as having no progress.
+    pub fn diff(previous: &[AccountAchievement], current: &[AccountAchievement]) -> Vec<Self> {
+        let mut events = Vec::new();
+
+        for achievement in current {
+            let previous = previous.iter().find(|a| a.id == achievement.id);
+
+            let previous_progress = previous.and_then(|a| a.current).unwrap_or(0);
+            let current_progress = achievement.current.unwrap_or(0);
+            if previous_progress != current_progress {
+                events.push(Self::Progressed {
+                    id: achievement.id,
+                    previous: previous_progress,
+                    current: current_progress,
+                    max: achievement.max,
+                });
+            }
+
+            if achievement.done && !previous.is_some_and(|a| a.done) {
+                events.push(Self::Completed { id: achievement.id });
+            }
+
+            let previous_repeated = previous.and_then(|a| a.repeated).unwrap_or(0);
+            let repeated = achievement.repeated.unwrap_or(0);
+            if repeated > previous_repeated {
+                events.push(Self::Repeated {
+                    id: achievement.id,
+                    repeated,
+                });
+            }
+        }
+
+        events
+    }
+}
+
+/// A [`Stream`] of [`AchievementEvent`]s created by polling the progress of the currently
+/// authenticated account on all achievements in a category.
+///
+/// The achievements of the category are resolved once on the first poll. Every poll only
+/// requests the progress of these achievements. Polls that are rejected because the rate limit
+/// was reached are skipped silently and retried after the next interval.
+///
+/// Created by [`AccountAchievements::watch_category`]. See the [module level documentation] for
+/// more details.
+///
+/// [module level documentation]: self
+#[cfg(feature = "achievements")]
+#[must_use = "streams do nothing unless polled"]
+pub struct AchievementWatcher {
+    client: Client,
+    category_id: u64,
+    ids: Option<Vec<u64>>,
+    poller: Poller<(Vec<u64>, AccountAchievements)>,
+    previous: Option<AccountAchievements>,
+    events: VecDeque<AchievementEvent>,
+}
+
+#[cfg(feature = "achievements")]
+impl AchievementWatcher {
+    pub(crate) fn new(client: &Client, category_id: u64, interval: Duration) -> Self {
+        let mut poller = Poller::new();
+        poller.interval = interval;
+
+        Self {
+            client: client.clone(),
+            category_id,
+            ids: None,
+            poller,
+            previous: None,
+            events: VecDeque::new(),
+        }
+    }
+
+    /// Returns the progress recorded by the last successful poll.
+    #[inline]
+    pub fn current(&self) -> Option<&AccountAchievements> {
+        self.previous.as_ref()
+    }
+
+    /// Requests the progress of all achievements with the given `ids`, resolving the ids of the
+    /// category first if they are unknown.
+    async fn request(
+        client: Client,
+        category_id: u64,
+        ids: Option<Vec<u64>>,
+    ) -> Result<(Vec<u64>, AccountAchievements)> {
+        let ids = match ids {
+            Some(ids) => ids,
+            None => {
+                let category: AchievementCategory =
+                    AchievementCategory::get(&client, category_id).await?;
+                category.achievements
+            }
+        };
+
+        let achievements = AccountAchievements::get_many(&client, &ids).await?;
+        Ok((ids, achievements))
+    }
+}
+
+#[cfg(feature = "achievements")]
+impl Stream for AchievementWatcher {
+    type Item = Result<AchievementEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            if let Some(event) = this.events.pop_front() {
+                return Poll::Ready(Some(Ok(event)));
+            }
+
+            let (client, category_id, ids) = (&this.client, this.category_id, &this.ids);
+            let res = ready!(this.poller.poll_next(cx, || {
+                Self::request(client.clone(), category_id, ids.clone())
+            }));
+
+            let (ids, current) = match res {
+                Ok(res) => res,
+                Err(err) if err.is_rate_limited() => continue,
+                Err(err) => return Poll::Ready(Some(Err(err))),
+            };
+
+            this.ids = Some(ids);
+
+            if let Some(previous) = &this.previous {
+                this.events
+                    .extend(AchievementEvent::diff(&previous.0, &current.0));
+            }
+
+            this.previous = Some(current);
+        }
+    }
+}
+
+/// Requests an endpoint in a fixed interval.
+struct Poller<T> {
+    interval: Duration,
+    state: PollState<T>,
+}
+
+enum PollState<T> {
+    Idle,
+    Fetching(BoxFuture<T>),
+    Sleeping(Pin<Box<Sleep>>),
+}
+
+impl<T> Poller<T> {
+    fn new() -> Self {
+        Self {
+            interval: DEFAULT_INTERVAL,
+            state: PollState::Idle,
+        }
+    }
+
+    /// Polls for the next response, sending a new request created by `request` once the interval
+    /// has elapsed.
+    fn poll_next<F, Fut>(&mut self, cx: &mut Context<'_>, mut request: F) -> Poll<Result<T>>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>> + Send + 'static,
+    {
+        loop {
+            match &mut self.state {
+                PollState::Idle => self.state = PollState::Fetching(Box::pin(request())),
+                PollState::Fetching(fut) => {
+                    let res = ready!(fut.as_mut().poll(cx));
+                    self.state = PollState::Sleeping(Box::pin(time::sleep(self.interval)));
+                    return Poll::Ready(res);
+                }
+                PollState::Sleeping(sleep) => {
+                    ready!(sleep.as_mut().poll(cx));
+                    self.state = PollState::Idle;
+                }
+            }
+        }
+    }
+}
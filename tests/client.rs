@@ -0,0 +1,832 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use gw2api_rs::health::TokenStatus;
+use gw2api_rs::metrics::{RequestInfo, ResponseInfo, REDACTED};
+use gw2api_rs::middleware::{Middleware, RequestParts};
+use gw2api_rs::offline::Dataset;
+use gw2api_rs::pacing::Pacing;
+use gw2api_rs::v2::account::Account;
+use gw2api_rs::v2::achievements::DailyAchievements;
+use gw2api_rs::v2::build::Build;
+use gw2api_rs::v2::characters::CharacterCore;
+use gw2api_rs::v2::colors::Color;
+use gw2api_rs::v2::commerce::Prices;
+use gw2api_rs::v2::guild::Guild;
+use gw2api_rs::v2::items::Item;
+use gw2api_rs::v2::quaggans::Quaggan;
+use gw2api_rs::{Builder, Client, Language, ResponseFuture};
+
+#[derive(Default)]
+struct Recorder(Mutex<Vec<(String, String)>>);
+
+impl Middleware for Recorder {
+    fn on_request(&self, request: &mut RequestParts) {
+        let schema_version = request.headers["X-Schema-Version"].to_str().unwrap();
+
+        self.0
+            .lock()
+            .unwrap()
+            .push((request.uri.to_string(), schema_version.to_owned()));
+    }
+}
+
+#[test]
+fn test_base_url_validation() {
+    assert!(Builder::new()
+        .base_url("https://api.guildwars2.com")
+        .is_ok());
+    assert!(Builder::new()
+        .base_url("http://localhost:8080/gw2/")
+        .is_ok());
+
+    assert!(Builder::new().base_url("").is_err());
+    assert!(Builder::new().base_url("api.guildwars2.com").is_err());
+    assert!(Builder::new().base_url("ftp://api.guildwars2.com").is_err());
+    assert!(Builder::new().base_url("https://localhost/?v=1").is_err());
+
+    assert!(Builder::new().schema_version("latest").is_ok());
+    assert!(Builder::new().schema_version("la\ntest").is_err());
+}
+
+#[tokio::test]
+async fn test_base_url() {
+    let recorder = Arc::new(Recorder::default());
+
+    // Nothing is listening on this port; only the outgoing request is inspected.
+    let client: Client = Builder::new()
+        .base_url("http://127.0.0.1:9/mirror/")
+        .unwrap()
+        .schema_version("latest")
+        .unwrap()
+        .layer(recorder.clone())
+        .into();
+
+    let _ = Build::get(&client).await;
+
+    let requests = recorder.0.lock().unwrap();
+    assert_eq!(
+        *requests,
+        [(
+            String::from("http://127.0.0.1:9/mirror/v2/build"),
+            String::from("latest")
+        )]
+    );
+}
+
+#[derive(Default)]
+struct HeaderRecorder(Mutex<Vec<hyper::HeaderMap>>);
+
+impl Middleware for HeaderRecorder {
+    fn on_request(&self, request: &mut RequestParts) {
+        self.0.lock().unwrap().push(request.headers.clone());
+    }
+}
+
+#[tokio::test]
+async fn test_default_headers() {
+    let recorder = Arc::new(HeaderRecorder::default());
+
+    let client: Client = Builder::new()
+        .base_url("http://127.0.0.1:9")
+        .unwrap()
+        .layer(recorder.clone())
+        .into();
+    let _ = Build::get(&client).await;
+
+    let client: Client = Builder::new()
+        .base_url("http://127.0.0.1:9")
+        .unwrap()
+        .access_token("token")
+        .user_agent("my-app/1.0")
+        .unwrap()
+        .default_header("X-Custom", "value")
+        .unwrap()
+        .default_header("Authorization", "Bearer other")
+        .unwrap()
+        .layer(recorder.clone())
+        .into();
+    let _ = Account::get(&client).await;
+
+    let requests = recorder.0.lock().unwrap();
+    assert_eq!(
+        requests[0]["User-Agent"],
+        concat!("gw2api-rs/", env!("CARGO_PKG_VERSION"))
+    );
+    assert_eq!(requests[1]["User-Agent"], "my-app/1.0");
+    assert_eq!(requests[1]["X-Custom"], "value");
+    // Headers of the client take precedence.
+    assert_eq!(requests[1]["Authorization"], "Bearer token");
+    assert_eq!(requests[1].get_all("Authorization").iter().count(), 1);
+
+    assert!(Builder::new().user_agent("my-app\n").is_err());
+    assert!(Builder::new().default_header("X Custom", "value").is_err());
+}
+
+#[tokio::test]
+async fn test_optional_authentication() {
+    let recorder = Arc::new(HeaderRecorder::default());
+
+    let client: Client = Builder::new()
+        .base_url("http://127.0.0.1:9")
+        .unwrap()
+        .layer(recorder.clone())
+        .into();
+    // Sent without an access token instead of failing.
+    let _ = DailyAchievements::get(&client).await;
+
+    let client: Client = Builder::new()
+        .base_url("http://127.0.0.1:9")
+        .unwrap()
+        .access_token("token")
+        .layer(recorder.clone())
+        .into();
+    let _ = DailyAchievements::get(&client).await;
+    let _ = Build::get(&client).await;
+
+    let requests = recorder.0.lock().unwrap();
+    assert_eq!(requests.len(), 3);
+    assert!(requests[0].get("Authorization").is_none());
+    assert_eq!(requests[1]["Authorization"], "Bearer token");
+    // Endpoints without authentication never receive the access token.
+    assert!(requests[2].get("Authorization").is_none());
+}
+
+#[tokio::test]
+async fn test_percent_encoding() {
+    let recorder = Arc::new(Recorder::default());
+
+    let client: Client = Builder::new()
+        .base_url("http://127.0.0.1:9")
+        .unwrap()
+        .access_token("token")
+        .layer(recorder.clone())
+        .into();
+
+    let _ = CharacterCore::get(&client, "Zoë Example/Name").await;
+    let _ = Guild::search(&client, "Covenant Of The Just & Co").await;
+    let _ = Quaggan::get_many(&client, &["404".to_owned(), "cheer up".to_owned()]).await;
+
+    let requests = recorder.0.lock().unwrap();
+    let uris: Vec<&str> = requests.iter().map(|(uri, _)| uri.as_str()).collect();
+    assert_eq!(
+        uris,
+        [
+            "http://127.0.0.1:9/v2/characters/Zo%C3%AB%20Example%2FName/core",
+            "http://127.0.0.1:9/v2/guild/search?name=Covenant%20Of%20The%20Just%20%26%20Co",
+            "http://127.0.0.1:9/v2/quaggans?ids=404,cheer%20up",
+        ]
+    );
+}
+
+#[derive(Default)]
+struct LanguageRecorder(Mutex<Vec<Option<String>>>);
+
+impl Middleware for LanguageRecorder {
+    fn on_request(&self, request: &mut RequestParts) {
+        let language = request
+            .headers
+            .get("Accept-Language")
+            .map(|value| value.to_str().unwrap().to_owned());
+
+        self.0.lock().unwrap().push(language);
+    }
+}
+
+#[tokio::test]
+async fn test_language() {
+    let recorder = Arc::new(LanguageRecorder::default());
+
+    let client: Client = Builder::new()
+        .base_url("http://127.0.0.1:9")
+        .unwrap()
+        .language(Language::Fr)
+        .layer(recorder.clone())
+        .into();
+
+    let _ = Build::get(&client).await;
+    let _ = Color::get(&client, 1).await;
+    let _ = Color::get_localized(&client, 1, Language::De).await;
+    let _ = Color::get_all_localized(&client, Language::Zh).await;
+    // Endpoints rejecting `ids=all` request the pages in the given language.
+    let _ = Item::get_all_localized(&client, Language::Es).await;
+
+    let languages = recorder.0.lock().unwrap();
+    assert_eq!(
+        *languages,
+        [
+            None,
+            Some(String::from("fr")),
+            Some(String::from("de")),
+            Some(String::from("zh")),
+            Some(String::from("es")),
+        ]
+    );
+}
+
+/// Moves the access token into the query, like older clients of the API do.
+struct QueryToken;
+
+impl Middleware for QueryToken {
+    fn on_request(&self, request: &mut RequestParts) {
+        let uri = format!("{}?v=1&access_token=secret", request.uri);
+        request.uri = uri.parse().unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_request_logging() {
+    let requests: Arc<Mutex<Vec<RequestInfo>>> = Arc::default();
+    let responses: Arc<Mutex<Vec<ResponseInfo>>> = Arc::default();
+
+    let (on_request, on_response) = (requests.clone(), responses.clone());
+    let client: Client = Builder::new()
+        .base_url("http://127.0.0.1:9")
+        .unwrap()
+        .access_token("secret")
+        .on_request(move |request| on_request.lock().unwrap().push(request.clone()))
+        .on_response(move |response| on_response.lock().unwrap().push(response.clone()))
+        .into();
+
+    let _ = Account::get(&client).await;
+    let _ = Build::get(&client.with_access_token("secret")).await;
+
+    let client: Client = Builder::new()
+        .base_url("http://127.0.0.1:9")
+        .unwrap()
+        .layer(QueryToken)
+        .on_request({
+            let requests = requests.clone();
+            move |request| requests.lock().unwrap().push(request.clone())
+        })
+        .into();
+    let _ = Build::get(&client).await;
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].uri, "http://127.0.0.1:9/v2/account");
+    assert_eq!(requests[0].headers["Authorization"], REDACTED);
+    assert!(requests[1].headers.get("Authorization").is_none());
+    assert_eq!(
+        requests[2].uri,
+        format!("http://127.0.0.1:9/v2/build?v=1&access_token={}", REDACTED)
+    );
+
+    let responses = responses.lock().unwrap();
+    assert_eq!(responses.len(), 2);
+    assert_eq!(responses[0].uri, "http://127.0.0.1:9/v2/account");
+    assert_eq!(responses[0].status, None);
+    assert!(!responses[0].success);
+}
+
+#[tokio::test]
+async fn test_response_meta() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut buf = vec![0; 4096];
+        let _ = stream.read(&mut buf).await.unwrap();
+
+        let body = r#"["404","aloha"]"#;
+        let head = format!(
+            "HTTP/1.1 200 OK\r\n\
+             Content-Length: {}\r\n\
+             X-Result-Total: 500\r\n\
+             X-Result-Count: 2\r\n\
+             X-Page-Total: 250\r\n\
+             X-Page-Size: 2\r\n\
+             X-Rate-Limit-Limit: 600\r\n\
+             X-Rate-Limit-Remaining: 599\r\n\
+             Cache-Control: public, max-age=300\r\n\
+             Expires: Sat, 07 May 2022 12:05:00 GMT\r\n\
+             \r\n",
+            body.len()
+        );
+        stream.write_all(head.as_bytes()).await.unwrap();
+        stream.write_all(body.as_bytes()).await.unwrap();
+    });
+
+    let client: Client = Builder::new()
+        .base_url(format!("http://{}", addr))
+        .unwrap()
+        .into();
+
+    let (ids, meta) = Quaggan::ids(&client).with_meta().await.unwrap();
+    assert_eq!(ids, ["404", "aloha"]);
+    assert_eq!(meta.status, 200);
+    assert_eq!(meta.uri, format!("http://{}/v2/quaggans", addr));
+    assert_eq!(meta.result_total, Some(500));
+    assert_eq!(meta.result_count, Some(2));
+    assert_eq!(meta.page_total, Some(250));
+    assert_eq!(meta.page_size, Some(2));
+    assert_eq!(meta.rate_limit_limit, Some(600));
+    assert_eq!(meta.rate_limit_remaining, Some(599));
+    assert_eq!(meta.cache_control.as_deref(), Some("public, max-age=300"));
+    #[cfg(feature = "chrono")]
+    let expires = "2022-05-07T12:05:00Z".parse().unwrap();
+    #[cfg(not(feature = "chrono"))]
+    let expires = time::OffsetDateTime::parse(
+        "2022-05-07T12:05:00Z",
+        &time::format_description::well_known::Rfc3339,
+    )
+    .unwrap();
+    assert_eq!(meta.expires, Some(expires));
+    assert_eq!(meta.last_modified, None);
+}
+
+/// Serves a single error response with the given `status` line and json `body`.
+async fn serve_error(status: &'static str, body: &'static str) -> Client {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut buf = vec![0; 4096];
+        let _ = stream.read(&mut buf).await.unwrap();
+
+        let head = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\n\r\n",
+            status,
+            body.len()
+        );
+        stream.write_all(head.as_bytes()).await.unwrap();
+        stream.write_all(body.as_bytes()).await.unwrap();
+    });
+
+    Builder::new()
+        .base_url(format!("http://{}", addr))
+        .unwrap()
+        .into()
+}
+
+#[tokio::test]
+async fn test_error_classification() {
+    let client = serve_error("429 Too Many Requests", r#"{"text":"too many requests"}"#).await;
+    let err = Quaggan::ids(&client).await.unwrap_err();
+    assert!(err.is_rate_limited());
+    assert!(err.is_retryable());
+    assert!(!err.is_auth());
+    assert_eq!(err.status(), Some(429));
+    assert_eq!(err.api_message(), Some("too many requests"));
+    assert_eq!(err.endpoint(), Some("/v2/quaggans"));
+
+    let client = serve_error("503 Service Unavailable", r#"{"text":"API not active"}"#).await;
+    let err = Quaggan::get(&client, String::from("aloha"))
+        .await
+        .unwrap_err();
+    assert!(err.is_retryable());
+    assert!(!err.is_rate_limited());
+    assert_eq!(err.status(), Some(503));
+    assert_eq!(err.endpoint(), Some("/v2/quaggans"));
+
+    let client = serve_error("401 Unauthorized", r#"{"text":"Invalid access token"}"#).await;
+    let err = CharacterCore::get(&client.with_access_token("token"), "Name")
+        .await
+        .unwrap_err();
+    assert!(err.is_auth());
+    assert!(!err.is_retryable());
+    assert_eq!(err.api_message(), Some("Invalid access token"));
+    assert_eq!(err.endpoint(), Some("/v2/characters/Name/core"));
+
+    let err = CharacterCore::get(&Client::new(), "Name")
+        .await
+        .unwrap_err();
+    assert!(err.is_auth());
+    assert_eq!(err.status(), None);
+}
+
+#[tokio::test]
+async fn test_bulk_not_found() {
+    let client = serve_error(
+        "404 Not Found",
+        r#"{"text":"all ids provided are invalid"}"#,
+    )
+    .await;
+    let prices = Prices::get_many(&client, &[1, 2]).await.unwrap();
+    assert!(prices.is_empty());
+}
+
+#[tokio::test]
+async fn test_healthcheck_invalid_token() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut buf = vec![0; 4096];
+        loop {
+            let n = stream.read(&mut buf).await.unwrap();
+            if n == 0 {
+                break;
+            }
+
+            let (status, body) = if buf[..n].starts_with(b"GET /v2/build") {
+                ("200 OK", r#"{"id":115267}"#)
+            } else {
+                ("401 Unauthorized", r#"{"text":"Invalid access token"}"#)
+            };
+            let head = format!(
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\n\r\n",
+                status,
+                body.len()
+            );
+            stream.write_all(head.as_bytes()).await.unwrap();
+            stream.write_all(body.as_bytes()).await.unwrap();
+        }
+    });
+
+    let client: Client = Builder::new()
+        .base_url(format!("http://{}", addr))
+        .unwrap()
+        .access_token("token")
+        .into();
+
+    let health = client.healthcheck().await.unwrap();
+    assert_eq!(health.build, 115267);
+    assert!(!health.is_healthy());
+    match health.token {
+        TokenStatus::Invalid(err) => assert_eq!(err.status(), Some(401)),
+        token => panic!("expected invalid token, got {:?}", token),
+    }
+}
+
+#[tokio::test]
+async fn test_invalid_json() {
+    let client = serve_error("200 OK", r#"["aloha","#).await;
+    let err = Quaggan::ids(&client).await.unwrap_err();
+    assert!(err.is_json());
+    assert!(!err.is_http());
+
+    let client = serve_error("200 OK", r#"["aloha","cheer"]"#).await;
+    let ids = Quaggan::ids(&client).await.unwrap();
+    assert_eq!(ids, ["aloha", "cheer"]);
+}
+
+#[tokio::test]
+async fn test_connection_options() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let connections = Arc::new(Mutex::new(0));
+
+    let counter = connections.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            *counter.lock().unwrap() += 1;
+
+            tokio::spawn(async move {
+                let body = r#"["aloha"]"#;
+                let mut buf = vec![0; 4096];
+                while stream.read(&mut buf).await.unwrap() != 0 {
+                    let head = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n", body.len());
+                    stream.write_all(head.as_bytes()).await.unwrap();
+                    stream.write_all(body.as_bytes()).await.unwrap();
+                }
+            });
+        }
+    });
+
+    let client: Client = Builder::new()
+        .base_url(format!("http://{}", addr))
+        .unwrap()
+        .http2_adaptive_window(true)
+        .http2_keep_alive_interval(Duration::from_secs(30))
+        .tcp_keepalive(Duration::from_secs(60))
+        .pool_idle_timeout(Duration::from_secs(30))
+        .pool_max_idle(1)
+        .into();
+
+    for _ in 0..3 {
+        assert_eq!(Quaggan::ids(&client).await.unwrap(), ["aloha"]);
+    }
+
+    // All requests reuse the same keep-alive connection.
+    assert_eq!(*connections.lock().unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_coalesce_requests() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let requests = Arc::new(Mutex::new(0));
+
+    let counter = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let counter = counter.clone();
+
+            tokio::spawn(async move {
+                let body = r#"["aloha"]"#;
+                let mut buf = vec![0; 4096];
+                while stream.read(&mut buf).await.unwrap() != 0 {
+                    *counter.lock().unwrap() += 1;
+
+                    let head = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n", body.len());
+                    stream.write_all(head.as_bytes()).await.unwrap();
+                    stream.write_all(body.as_bytes()).await.unwrap();
+                }
+            });
+        }
+    });
+
+    let client: Client = Builder::new()
+        .base_url(format!("http://{}", addr))
+        .unwrap()
+        .coalesce_requests(true)
+        .into();
+
+    // Requests are coalesced as soon as they are created, before being polled.
+    let futs: Vec<ResponseFuture<Vec<String>>> = (0..3).map(|_| Quaggan::ids(&client)).collect();
+    let (ids, meta) = Quaggan::ids(&client).with_meta().await.unwrap();
+    assert_eq!(ids, ["aloha"]);
+    assert_eq!(meta.status, 200);
+    for fut in futs {
+        assert_eq!(fut.await.unwrap(), ["aloha"]);
+    }
+    assert_eq!(*requests.lock().unwrap(), 1);
+
+    // Completed requests are not reused.
+    assert_eq!(Quaggan::ids(&client).await.unwrap(), ["aloha"]);
+    assert_eq!(*requests.lock().unwrap(), 2);
+
+    // Requests with a different access token are not coalesced.
+    let other = client.with_access_token("token");
+    let futs = [
+        Account::get(&client.with_access_token("token")),
+        Account::get(&other),
+        Account::get(&client.with_access_token("other")),
+    ];
+    for fut in futs {
+        assert!(fut.await.unwrap_err().is_json());
+    }
+    assert_eq!(*requests.lock().unwrap(), 4);
+}
+
+#[tokio::test]
+async fn test_pacing() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener.accept().await.unwrap();
+
+            tokio::spawn(async move {
+                let body = r#"["aloha"]"#;
+                let mut buf = vec![0; 4096];
+                while stream.read(&mut buf).await.unwrap() != 0 {
+                    let head = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n", body.len());
+                    stream.write_all(head.as_bytes()).await.unwrap();
+                    stream.write_all(body.as_bytes()).await.unwrap();
+                }
+            });
+        }
+    });
+
+    let client = |pacing| -> Client {
+        Builder::new()
+            .base_url(format!("http://{}", addr))
+            .unwrap()
+            .pacing(pacing)
+            .into()
+    };
+
+    // 600 requests per minute are one request every 100ms.
+    let smooth = client(Pacing::smooth(600));
+    let start = Instant::now();
+    let futs: Vec<_> = (0..4).map(|_| Quaggan::ids(&smooth)).collect();
+    for fut in futs {
+        fut.await.unwrap();
+    }
+    assert!(start.elapsed() >= Duration::from_millis(300));
+
+    // 4 requests per minute are one request every 15s, but the first 4 are sent at once.
+    let limit = client(Pacing::limit(4));
+    let start = Instant::now();
+    let futs: Vec<_> = (0..4).map(|_| Quaggan::ids(&limit)).collect();
+    for fut in futs {
+        fut.await.unwrap();
+    }
+    assert!(start.elapsed() < Duration::from_secs(5));
+
+    // A request polled after its slot has passed is sent immediately.
+    let late = client(Pacing::smooth(60));
+    let first = Quaggan::ids(&late);
+    let second = Quaggan::ids(&late);
+    first.await.unwrap();
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    let start = Instant::now();
+    second.await.unwrap();
+    assert!(start.elapsed() < Duration::from_millis(500));
+
+    // A request dropped before it is sent releases its slot.
+    let dropped = client(Pacing::smooth(60));
+    let first = Quaggan::ids(&dropped);
+    drop(Quaggan::ids(&dropped));
+    let second = Quaggan::ids(&dropped);
+    let start = Instant::now();
+    first.await.unwrap();
+    second.await.unwrap();
+    assert!(start.elapsed() < Duration::from_millis(1500));
+
+    // Offline requests are never delayed.
+    let offline: Client = Builder::new()
+        .offline(Dataset::new())
+        .pacing(Pacing::smooth(1))
+        .into();
+    let start = Instant::now();
+    for _ in 0..2 {
+        assert!(Quaggan::ids(&offline).await.is_err());
+    }
+    assert!(start.elapsed() < Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn test_retries() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    // The first request fails with a server error, all further requests succeed.
+    let requests = Arc::new(Mutex::new(0));
+    let counter = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let counter = counter.clone();
+
+            tokio::spawn(async move {
+                let mut buf = vec![0; 4096];
+                while stream.read(&mut buf).await.unwrap() != 0 {
+                    let first = {
+                        let mut requests = counter.lock().unwrap();
+                        *requests += 1;
+                        *requests == 1
+                    };
+
+                    let (status, body) = match first {
+                        true => ("503 Service Unavailable", r#"{"text":"unavailable"}"#),
+                        false => ("200 OK", r#"["aloha"]"#),
+                    };
+                    let head = format!(
+                        "HTTP/1.1 {}\r\nContent-Length: {}\r\n\r\n",
+                        status,
+                        body.len()
+                    );
+                    stream.write_all(head.as_bytes()).await.unwrap();
+                    stream.write_all(body.as_bytes()).await.unwrap();
+                }
+            });
+        }
+    });
+
+    let metrics = Arc::new(Mutex::new(Vec::new()));
+    let log = metrics.clone();
+    let client: Client = Builder::new()
+        .base_url(format!("http://{}", addr))
+        .unwrap()
+        .retries(1)
+        .pacing(Pacing::smooth(600))
+        .on_metrics(move |metrics| log.lock().unwrap().push(metrics.clone()))
+        .into();
+
+    let (first, second) = tokio::join!(Quaggan::ids(&client), Quaggan::ids(&client));
+    assert_eq!(first.unwrap(), ["aloha"]);
+    assert_eq!(second.unwrap(), ["aloha"]);
+    assert_eq!(*requests.lock().unwrap(), 3);
+
+    let metrics = metrics.lock().unwrap().clone();
+    assert_eq!(metrics.len(), 2);
+    assert!(metrics.iter().all(|metrics| metrics.success));
+    assert_eq!(
+        metrics.iter().map(|metrics| metrics.retries).sum::<u32>(),
+        1
+    );
+    // The second request is paced 100ms after the first one.
+    assert!(metrics.iter().any(|metrics| metrics.wait > Duration::ZERO));
+
+    // Without retries the error is returned.
+    let client = serve_error("503 Service Unavailable", r#"{"text":"unavailable"}"#).await;
+    assert!(Quaggan::ids(&client).await.unwrap_err().is_retryable());
+}
+
+#[tokio::test]
+async fn test_abort() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    // Accept connections, but never respond.
+    tokio::spawn(async move {
+        let mut streams = Vec::new();
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            streams.push(stream);
+        }
+    });
+
+    let client: Client = Builder::new()
+        .base_url(format!("http://{}", addr))
+        .unwrap()
+        .into();
+
+    let fut = Quaggan::ids(&client);
+    let handle = fut.abort_handle();
+    let task = tokio::spawn(fut);
+
+    handle.abort();
+    assert!(handle.is_aborted());
+    let err = task.await.unwrap().unwrap_err();
+    assert!(err.is_aborted());
+    assert_eq!(err.endpoint(), Some("/v2/quaggans"));
+
+    // `abort_all` aborts requests of all clones.
+    let clone = client.with_access_token("token");
+    let tasks = [
+        tokio::spawn(Quaggan::ids(&client)),
+        tokio::spawn(Quaggan::ids(&clone)),
+    ];
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    client.abort_all();
+    for task in tasks {
+        assert!(task.await.unwrap().unwrap_err().is_aborted());
+    }
+
+    // Requests sent afterwards are not aborted.
+    let fut = Quaggan::ids(&client);
+    let handle = fut.abort_handle();
+    let res = tokio::time::timeout(Duration::from_millis(100), fut).await;
+    assert!(res.is_err());
+    assert!(!handle.is_aborted());
+}
+
+/// Serves a single request with the raw HTTP `response`. Returns the base url of the server.
+async fn serve_raw(response: String) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut buf = vec![0; 4096];
+        let _ = stream.read(&mut buf).await.unwrap();
+
+        let _ = stream.write_all(response.as_bytes()).await;
+    });
+
+    format!("http://{}", addr)
+}
+
+#[tokio::test]
+async fn test_max_response_size() {
+    let body = r#"["aloha","attack"]"#;
+    let client = |base_url: String, max_size| -> Client {
+        Builder::new()
+            .base_url(base_url)
+            .unwrap()
+            .max_response_size(max_size)
+            .into()
+    };
+
+    // Body within the limit.
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+        body.len(),
+        body
+    );
+    let ids = Quaggan::ids(&client(serve_raw(response).await, body.len()))
+        .await
+        .unwrap();
+    assert_eq!(ids, ["aloha", "attack"]);
+
+    // Rejected by the Content-Length header.
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+        body.len(),
+        body
+    );
+    let err = Quaggan::ids(&client(serve_raw(response).await, 8))
+        .await
+        .unwrap_err();
+    assert!(err.is_response_too_large());
+    assert_eq!(err.endpoint(), Some("/v2/quaggans"));
+
+    // Rejected while streaming a chunked body.
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n{:x}\r\n{}\r\n{:x}\r\n{}\r\n0\r\n\r\n",
+        body.len(),
+        body,
+        body.len(),
+        body
+    );
+    let err = Quaggan::ids(&client(serve_raw(response).await, body.len() + 1))
+        .await
+        .unwrap_err();
+    assert!(err.is_response_too_large());
+    assert!(!err.is_retryable());
+}
@@ -0,0 +1,108 @@
+use std::io::Write;
+
+use flate2::write::{GzEncoder, ZlibEncoder};
+use flate2::Compression;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use gw2api_rs::v2::build::Build;
+use gw2api_rs::{Builder, Client};
+
+const BODY: &[u8] = br#"{"id":115267}"#;
+
+/// Serves a single request with the given `Content-Encoding` and `body`. Returns the base url of
+/// the server and the raw request received by it.
+async fn serve(encoding: &'static str, body: Vec<u8>) -> (String, tokio::task::JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let handle = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+
+        let mut buf = vec![0; 4096];
+        let len = stream.read(&mut buf).await.unwrap();
+        let request = String::from_utf8_lossy(&buf[..len]).into_owned();
+
+        let head = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Encoding: {}\r\nContent-Length: {}\r\n\r\n",
+            encoding,
+            body.len()
+        );
+        stream.write_all(head.as_bytes()).await.unwrap();
+        stream.write_all(&body).await.unwrap();
+
+        request
+    });
+
+    (format!("http://{}", addr), handle)
+}
+
+fn client(base_url: &str) -> Client {
+    Builder::new().base_url(base_url).unwrap().into()
+}
+
+#[tokio::test]
+async fn test_gzip() {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(BODY).unwrap();
+
+    let (base_url, handle) = serve("gzip", encoder.finish().unwrap()).await;
+    let build = Build::get(&client(&base_url)).await.unwrap();
+    assert_eq!(build.id, 115267);
+
+    let request = handle.await.unwrap().to_lowercase();
+    let accept_encoding = request
+        .lines()
+        .find_map(|line| line.strip_prefix("accept-encoding: "))
+        .unwrap();
+    assert!(accept_encoding
+        .split(", ")
+        .any(|encoding| encoding == "gzip"));
+    assert!(accept_encoding
+        .split(", ")
+        .any(|encoding| encoding == "deflate"));
+}
+
+#[tokio::test]
+async fn test_deflate() {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(BODY).unwrap();
+
+    let (base_url, _) = serve("deflate", encoder.finish().unwrap()).await;
+    let build = Build::get(&client(&base_url)).await.unwrap();
+    assert_eq!(build.id, 115267);
+}
+
+#[tokio::test]
+async fn test_identity() {
+    let (base_url, _) = serve("identity", BODY.to_vec()).await;
+    let build = Build::get(&client(&base_url)).await.unwrap();
+    assert_eq!(build.id, 115267);
+}
+
+#[tokio::test]
+async fn test_invalid_body() {
+    let (base_url, _) = serve("gzip", BODY.to_vec()).await;
+    assert!(Build::get(&client(&base_url)).await.is_err());
+}
+
+#[tokio::test]
+async fn test_max_response_size() {
+    // Compresses to a small fraction of its size.
+    let body = format!("[{}0]", "0,".repeat(1 << 16));
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(body.as_bytes()).unwrap();
+    let compressed = encoder.finish().unwrap();
+    assert!(compressed.len() < 4096);
+
+    let (base_url, _) = serve("gzip", compressed).await;
+    let client: Client = Builder::new()
+        .base_url(&base_url)
+        .unwrap()
+        .max_response_size(4096)
+        .into();
+
+    let err = Build::get(&client).await.unwrap_err();
+    assert!(err.is_response_too_large());
+}
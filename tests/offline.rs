@@ -0,0 +1,2507 @@
+use std::time::Duration;
+
+use chrono::{TimeZone, Utc};
+use futures_util::{StreamExt, TryStreamExt};
+use gw2api_rs::export::{JsonLinesWriter, TransactionRecords, TransactionSide, TransactionStatus};
+use gw2api_rs::health::TokenStatus;
+use gw2api_rs::lookup::{Lookup, LookupCache};
+use gw2api_rs::market;
+use gw2api_rs::offline::Dataset;
+use gw2api_rs::snapshot::{AccountSnapshot, SnapshotPart};
+use gw2api_rs::v2::account::{
+    Account, AccountAchievements, AccountBank, AccountDungeons, AccountDyes, AccountEmotes,
+    AccountFinishers, AccountLegendaryArmory, AccountLuck, AccountMaterials, AccountMountTypes,
+    AccountPvPHeroes, AccountRaids, AccountTitles, AccountWallet, ResolveUnlocks, Wardrobe,
+};
+use gw2api_rs::v2::achievements::{
+    AchievementPoints, AchievementTree, DailyAchievements, DailyFractals,
+};
+use gw2api_rs::v2::build::Build;
+use gw2api_rs::v2::colors::{Color, ColorFilter, ColorHue, ColorMaterial, ColorRarity};
+use gw2api_rs::v2::commerce::{Coins, Exchange};
+use gw2api_rs::v2::common::{Binding, ChatLink, Discipline, ItemLink, Rarity, WeightClass};
+use gw2api_rs::v2::dailycrafting::DailyCrafting;
+use gw2api_rs::v2::emblem::EmblemBackground;
+use gw2api_rs::v2::guild::{Guild, GuildStash, GuildStorage, GuildStorageItem, GuildUpgrade};
+use gw2api_rs::v2::items::ItemKind;
+use gw2api_rs::v2::mapchests::MapChest;
+use gw2api_rs::v2::maps::Map;
+use gw2api_rs::v2::minis::Mini;
+use gw2api_rs::v2::mounts::MountType;
+use gw2api_rs::v2::pvp::{Game, GameResult, GameTeam, PvpSeason, TrackPosition};
+use gw2api_rs::v2::quaggans::Quaggan;
+use gw2api_rs::v2::raids::RaidEventKind;
+use gw2api_rs::v2::recipes::{CraftVerdict, Recipe};
+use gw2api_rs::v2::skins::SkinKind;
+use gw2api_rs::v2::stories::Story;
+use gw2api_rs::v2::tokeninfo::{TokenInfo, TokenPermission};
+use gw2api_rs::v2::worldbosses::WorldBoss;
+use gw2api_rs::v2::wvw::{self, TeamColor};
+use gw2api_rs::{Builder, Client};
+use serde_json::json;
+
+fn dataset() -> Dataset {
+    let mut dataset = Dataset::new();
+    dataset
+        .insert("/v2/build", &json!({ "id": 115267 }))
+        .unwrap();
+    dataset
+        .insert("/v2/quaggans", &json!(["404", "aloha"]))
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/quaggans?id=aloha",
+            &json!({ "id": "aloha", "url": "https://static.staticwars.com/quaggans/aloha.jpg" }),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/account/wallet",
+            &json!([{ "id": 1, "value": 100 }, { "id": 2, "value": 5000 }]),
+        )
+        .unwrap();
+    dataset
+}
+
+#[tokio::test]
+async fn test_offline() {
+    let client = Client::offline(dataset());
+
+    let build = Build::get(&client).await.unwrap();
+    assert_eq!(build.id, 115267);
+
+    let ids = Quaggan::ids(&client).await.unwrap();
+    assert_eq!(ids, ["404", "aloha"]);
+
+    let quaggan = Quaggan::get(&client, String::from("aloha")).await.unwrap();
+    assert_eq!(quaggan.id, "aloha");
+
+    let err = Quaggan::get(&client, String::from("404"))
+        .await
+        .unwrap_err();
+    assert!(err.is_not_in_dataset());
+    assert_eq!(err.endpoint(), Some("/v2/quaggans"));
+    assert_eq!(err.status(), None);
+    assert!(!err.is_retryable());
+}
+
+#[tokio::test]
+async fn test_offline_meta() {
+    let client = Client::offline(dataset());
+
+    let (build, meta) = Build::get(&client).with_meta().await.unwrap();
+    assert_eq!(build.id, 115267);
+    assert_eq!(meta.status, 200);
+    assert_eq!(meta.uri, "/v2/build");
+    assert_eq!(meta.result_total, None);
+}
+
+#[tokio::test]
+async fn test_offline_pagination() {
+    let quaggan = |id: &str| json!({ "id": id, "url": "" });
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/quaggans?page=0&page_size=2",
+            &json!([quaggan("404"), quaggan("aloha")]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/quaggans?page=1&page_size=2",
+            &json!([quaggan("attack")]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/quaggans?page=0&page_size=200",
+            &json!([quaggan("404"), quaggan("aloha"), quaggan("attack")]),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset);
+
+    let pages: Vec<Vec<Quaggan>> = Quaggan::pages(&client)
+        .page_size(2)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[1][0].id, "attack");
+
+    let ids: Vec<String> = Quaggan::ids_stream(&client).try_collect().await.unwrap();
+    assert_eq!(ids, ["404", "aloha", "attack"]);
+
+    // Errors end the stream.
+    let mut pages = Quaggan::pages(&client).page_size(3);
+    assert!(pages.next().await.unwrap().is_err());
+    assert!(pages.next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_offline_get_all_paged() {
+    let background = |id: u64| json!({ "id": id, "layers": [] });
+
+    // The page total is unknown offline, so pages are requested until a page is not full.
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/emblem/backgrounds?page=0&page_size=200",
+            &json!((1..=200).map(background).collect::<Vec<_>>()),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/emblem/backgrounds?page=1&page_size=200",
+            &json!([background(201), background(202)]),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset);
+
+    let backgrounds = EmblemBackground::get_all(&client).await.unwrap();
+    assert_eq!(backgrounds.len(), 202);
+    assert_eq!(backgrounds[201].id, 202);
+}
+
+#[tokio::test]
+async fn test_offline_authentication() {
+    let client = Client::offline(dataset());
+    assert!(AccountWallet::get(&client).await.is_err());
+
+    let client: Client = Builder::new()
+        .access_token("token")
+        .offline(dataset())
+        .into();
+
+    let wallet = AccountWallet::get(&client).await.unwrap();
+    assert_eq!(wallet.len(), 2);
+    assert_eq!(wallet[1].value, 5000);
+    assert_eq!(wallet.iter().map(|currency| currency.id).sum::<u64>(), 3);
+
+    let wallet: AccountWallet = wallet.into_iter().skip(1).collect();
+    assert_eq!(wallet.len(), 1);
+    assert_eq!(wallet[0].id, 2);
+
+    let client = Client::offline(dataset()).with_access_token("token");
+    AccountWallet::get(&client).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_offline_permissions() {
+    let mut dataset = dataset();
+    dataset
+        .insert(
+            "/v2/tokeninfo",
+            &json!({ "id": "", "name": "key", "permissions": ["account"], "type": "APIKey" }),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset).with_access_token("token");
+    let token_info = TokenInfo::get(&client).await.unwrap();
+
+    let client = client.with_token_info(&token_info);
+    let err = AccountWallet::get(&client).await.unwrap_err();
+    assert_eq!(err.missing_permission(), Some(TokenPermission::Wallet));
+    assert!(err.is_auth());
+    assert_eq!(err.endpoint(), Some("/v2/account/wallet"));
+
+    let client = client.with_access_token("other");
+    AccountWallet::get(&client).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_offline_scopes() {
+    let mut dataset = dataset();
+    dataset
+        .insert(
+            "/v2/tokeninfo",
+            &json!({ "id": "", "name": "key", "permissions": ["account", "wallet"], "type": "APIKey" }),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset);
+    assert!(client.scopes().await.is_err());
+
+    let client = client.with_access_token("token");
+    let err = AccountBank::get(&client).await.unwrap_err();
+    assert_eq!(err.missing_permission(), None);
+
+    let scopes = client.clone().scopes().await.unwrap();
+    assert_eq!(
+        scopes.as_slice(),
+        [TokenPermission::Account, TokenPermission::Wallet]
+    );
+    assert_eq!(
+        scopes.missing(AccountBank::PERMISSIONS),
+        Some(TokenPermission::Inventories)
+    );
+    assert!(client.has_scope(TokenPermission::Wallet).await.unwrap());
+    assert!(!client
+        .has_scope(TokenPermission::Inventories)
+        .await
+        .unwrap());
+
+    AccountWallet::get(&client).await.unwrap();
+    let err = AccountBank::get(&client).await.unwrap_err();
+    assert_eq!(err.missing_permission(), Some(TokenPermission::Inventories));
+
+    let client = client.with_access_token("other");
+    let err = AccountBank::get(&client).await.unwrap_err();
+    assert_eq!(err.missing_permission(), None);
+}
+
+#[tokio::test]
+async fn test_offline_healthcheck() {
+    let mut dataset = dataset();
+    dataset
+        .insert(
+            "/v2/tokeninfo",
+            &json!({ "id": "", "name": "key", "permissions": ["account"], "type": "APIKey" }),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset);
+    let health = client.healthcheck().await.unwrap();
+    assert_eq!(health.build, 115267);
+    assert!(matches!(health.token, TokenStatus::Missing));
+    assert!(health.is_healthy());
+    assert_eq!(health.permissions(), None);
+
+    let client = client.with_access_token("token");
+    let health = client.healthcheck().await.unwrap();
+    assert!(matches!(&health.token, TokenStatus::Valid(info) if info.name == "key"));
+    assert_eq!(health.permissions(), Some(&[TokenPermission::Account][..]));
+
+    // The permissions are cached.
+    let err = AccountBank::get(&client).await.unwrap_err();
+    assert_eq!(err.missing_permission(), Some(TokenPermission::Inventories));
+
+    // The API is unreachable.
+    assert!(Client::offline(Dataset::new()).healthcheck().await.is_err());
+}
+
+#[tokio::test]
+async fn test_offline_datetime_rfc3339() {
+    let mut dataset = dataset();
+    dataset
+        .insert(
+            "/v2/tokeninfo",
+            &json!({
+                "id": "",
+                "name": "key",
+                "permissions": ["account"],
+                "type": "Subtoken",
+                "expires_at": "2030-01-01T00:00:00Z",
+            }),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset).with_access_token("token");
+    let token_info = TokenInfo::get(&client).await.unwrap();
+    assert!(token_info.expires_at.is_some());
+    assert!(token_info.issued_at.is_none());
+
+    let value = serde_json::to_value(&token_info).unwrap();
+    assert_eq!(value["expires_at"], "2030-01-01T00:00:00Z");
+    assert!(value["issued_at"].is_null());
+}
+
+#[test]
+fn test_tokeninfo_subtoken() {
+    let token_info: TokenInfo = serde_json::from_value(json!({
+        "id": "",
+        "name": "key",
+        "permissions": ["account", "characters"],
+        "type": "Subtoken",
+        "expires_at": "2100-01-01T00:00:00Z",
+        "issued_at": "2022-01-01T00:00:00Z",
+        "urls": ["/v2/account", "/v2/characters/My Character/"],
+    }))
+    .unwrap();
+
+    assert!(token_info.is_subtoken());
+    assert!(token_info.allows_url("/v2/account"));
+    assert!(token_info.allows_url("/v2/account?v=latest"));
+    assert!(token_info.allows_url("/v2/characters/My Character"));
+    assert!(!token_info.allows_url("/v2/account/bank"));
+    assert!(!token_info.allows_url("/v2/characters"));
+    assert!(token_info.expires_in().unwrap() > Duration::from_secs(3600));
+
+    let token_info: TokenInfo = serde_json::from_value(json!({
+        "id": "",
+        "name": "key",
+        "permissions": ["account"],
+        "type": "Subtoken",
+        "expires_at": "2022-01-01T00:00:00Z",
+    }))
+    .unwrap();
+    assert!(token_info.allows_url("/v2/account/bank"));
+    assert_eq!(token_info.expires_in(), Some(Duration::ZERO));
+
+    let token_info: TokenInfo = serde_json::from_value(json!({
+        "id": "",
+        "name": "key",
+        "permissions": ["account"],
+        "type": "APIKey",
+    }))
+    .unwrap();
+    assert!(!token_info.is_subtoken());
+    assert_eq!(token_info.expires_in(), None);
+}
+
+#[tokio::test]
+async fn test_offline_scoped() {
+    let mut dataset = dataset();
+    dataset
+        .insert(
+            "/v2/createsubtoken?expire=2030-01-01T00%3A00%3A00Z&permissions=account,wallet",
+            &json!({ "subtoken": "subtoken" }),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset);
+    let expire = Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap();
+    let permissions = [TokenPermission::Account, TokenPermission::Wallet];
+    assert!(client.scoped(&permissions, expire).await.is_err());
+
+    let client = client.with_access_token("token");
+    let scoped = client.scoped(&permissions, expire).await.unwrap();
+    AccountWallet::get(&scoped).await.unwrap();
+
+    let err = AccountBank::get(&scoped).await.unwrap_err();
+    assert_eq!(err.missing_permission(), Some(TokenPermission::Inventories));
+}
+
+#[tokio::test]
+async fn test_offline_guild_get_by_name() {
+    let guild = |id: &str| {
+        json!({
+            "id": id, "name": "Covenant Of The Just", "tag": "CJ",
+            "emblem": {
+                "background": { "id": 27, "colors": [11] },
+                "foreground": { "id": 40, "colors": [473, 673] },
+                "flags": [],
+            },
+        })
+    };
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/guild/search?name=Covenant%20Of%20The%20Just",
+            &json!(["A", "B"]),
+        )
+        .unwrap();
+    dataset.insert("/v2/guild/A", &guild("A")).unwrap();
+    dataset.insert("/v2/guild/B", &guild("B")).unwrap();
+
+    let client = Client::offline(dataset);
+
+    let guilds = Guild::get_by_name(&client, "Covenant Of The Just")
+        .await
+        .unwrap();
+    assert_eq!(guilds.len(), 2);
+    assert_eq!(guilds[0].id, "A");
+    assert_eq!(guilds[1].id, "B");
+}
+
+#[tokio::test]
+async fn test_offline_guild_emblem_resolve() {
+    let color = |id: u64, rgb: [u8; 3]| {
+        let armor = json!({
+            "brightness": 0, "contrast": 1.0, "hue": 0, "saturation": 0.0, "lightness": 1.0,
+            "rgb": rgb,
+        });
+
+        json!({
+            "id": id, "name": "Dye", "base_rgb": [128, 26, 26], "cloth": armor,
+            "leather": armor, "metal": armor,
+        })
+    };
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/guild/A",
+            &json!({
+                "id": "A", "name": "Covenant Of The Just", "tag": "CJ",
+                "emblem": {
+                    "background": { "id": 27, "colors": [11] },
+                    "foreground": { "id": 40, "colors": [473, 11, 999] },
+                    "flags": ["FlipForegroundHorizontal"],
+                },
+            }),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/emblem/backgrounds?id=27",
+            &json!({ "id": 27, "layers": ["bg.png"] }),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/emblem/foregrounds?id=40",
+            &json!({ "id": 40, "layers": ["fg0.png", "fg1.png", "fg2.png"] }),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/colors?ids=11,473,999",
+            &json!([color(11, [10, 20, 30]), color(473, [255, 0, 0])]),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset);
+
+    let guild = Guild::get(&client, "A").await.unwrap();
+    let emblem = guild.emblem.resolve(&client).await.unwrap();
+
+    assert_eq!(emblem.background.layers, ["bg.png"]);
+    assert_eq!(emblem.background.colors, [Some([10, 20, 30])]);
+    assert!(!emblem.background.flip_horizontal);
+
+    assert_eq!(emblem.foreground.layers.len(), 3);
+    assert_eq!(
+        emblem.foreground.colors,
+        [Some([255, 0, 0]), Some([10, 20, 30]), None]
+    );
+    assert!(emblem.foreground.flip_horizontal);
+    assert!(!emblem.foreground.flip_vertical);
+}
+
+#[tokio::test]
+async fn test_offline_guild_upgrade_remaining_costs() {
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/guild/A",
+            &json!({
+                "id": "A", "name": "Covenant Of The Just", "tag": "CJ",
+                "emblem": { "background": { "id": 1, "colors": [] }, "foreground": { "id": 1, "colors": [] }, "flags": [] },
+                "level": 10, "motd": "", "influence": 0, "aetherium": 100, "favor": 80,
+                "member_count": 1, "member_capacity": 50,
+            }),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/guild/A/treasury",
+            &json!([
+                { "item_id": 19747, "count": 20, "needed_by": [{ "upgrade_id": 38, "count": 30 }] },
+                { "item_id": 19748, "count": 60, "needed_by": [{ "upgrade_id": 38, "count": 0 }] },
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/guild/upgrades?id=38",
+            &json!({
+                "id": 38, "name": "Guild Armorer 1", "description": "", "type": "Unlock",
+                "icon": "", "build_time": 0, "required_level": 3, "experience": 20,
+                "prerequisites": [],
+                "costs": [
+                    { "type": "Item", "name": "Bolt of Silk", "count": 50, "item_id": 19747 },
+                    { "type": "Item", "name": "Bolt of Linen", "count": 50, "item_id": 19748 },
+                    { "type": "Collectible", "name": "Guild Favor", "count": 50 },
+                    { "type": "Currency", "name": "Aetherium", "count": 150 },
+                    { "type": "Coins", "count": 10000 },
+                ],
+            }),
+        )
+        .unwrap();
+
+    let client: Client = Builder::new().access_token("token").offline(dataset).into();
+
+    let costs = GuildUpgrade::get_remaining_costs(&client, "A", 38)
+        .await
+        .unwrap();
+    assert_eq!(costs.aetherium, 50);
+    assert_eq!(costs.favor, 0);
+    assert_eq!(costs.coins, Coins(10000));
+    assert_eq!(costs.items.len(), 2);
+    assert_eq!(costs.items[0].remaining(), 30);
+    assert_eq!(costs.items[1].remaining(), 0);
+    assert!(!costs.is_covered());
+}
+
+#[tokio::test]
+async fn test_offline_market_quote() {
+    let item = |id: u64, name: &str| {
+        json!({
+            "id": id, "chat_link": ChatLink::Item(ItemLink::new(id as u32)), "name": name,
+            "type": "CraftingMaterial", "rarity": "Basic", "level": 0, "vendor_value": 8,
+            "flags": [], "game_types": [], "restrictions": [],
+        })
+    };
+    let price = |id: u64, buy: u64, sell: u64| {
+        json!({
+            "id": id, "whitelisted": true,
+            "buys": { "unit_price": buy, "quantity": 100 },
+            "sells": { "unit_price": sell, "quantity": 100 },
+        })
+    };
+    let listings = json!({
+        "id": 19721,
+        "buys": [
+            { "listings": 1, "unit_price": 20, "quantity": 250 },
+            { "listings": 3, "unit_price": 19, "quantity": 750 },
+        ],
+        "sells": [
+            { "listings": 2, "unit_price": 30, "quantity": 500 },
+            { "listings": 1, "unit_price": 31, "quantity": 250 },
+        ],
+    });
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert("/v2/items?id=19721", &item(19721, "Glob of Ectoplasm"))
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/commerce/prices?ids=19721",
+            &json!([price(19721, 20, 30)]),
+        )
+        .unwrap();
+    dataset
+        .insert("/v2/commerce/listings?ids=19721", &json!([listings]))
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/items?ids=19721,24295",
+            &json!([
+                item(19721, "Glob of Ectoplasm"),
+                item(24295, "Vial of Powerful Blood")
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/commerce/prices?ids=19721,24295",
+            &json!([price(19721, 20, 30), price(24295, 1000, 1200)]),
+        )
+        .unwrap();
+    dataset
+        .insert("/v2/commerce/listings?ids=19721,24295", &json!([listings]))
+        .unwrap();
+
+    let client = Client::offline(dataset);
+
+    let quote = market::quote(&client, 19721).await.unwrap();
+    assert_eq!(quote.item.name, "Glob of Ectoplasm");
+    assert_eq!(quote.prices.as_ref().unwrap().sells.unit_price, Coins(30));
+    assert_eq!(quote.best_buy.as_ref().unwrap().unit_price, Coins(20));
+    assert_eq!(quote.best_sell.as_ref().unwrap().quantity, 500);
+    assert_eq!(quote.spread(), Some(Coins(10)));
+
+    let quotes = market::quote_many(&client, &[19721, 24295]).await.unwrap();
+    assert_eq!(quotes.len(), 2);
+    assert_eq!(quotes[0].spread(), Some(Coins(10)));
+    assert_eq!(quotes[1].item.id, 24295);
+    assert!(quotes[1].prices.is_some());
+    assert!(quotes[1].best_sell.is_none());
+    assert_eq!(quotes[1].spread(), None);
+}
+
+/// A writer failing every write.
+struct FailingWriter;
+
+impl std::io::Write for FailingWriter {
+    fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::ErrorKind::BrokenPipe.into())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_offline_export_transactions() {
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/commerce/transactions/current/buys?page=0&page_size=200",
+            &json!([{
+                "id": 3, "item_id": 19721, "price": 20, "quantity": 10,
+                "created": "2024-05-03T00:00:00Z",
+            }]),
+        )
+        .unwrap();
+    // A full first page is followed by the next page.
+    let sells: Vec<_> = (100..300)
+        .map(|id| {
+            json!({
+                "id": id, "item_id": 1, "price": 5, "quantity": 1,
+                "created": "2024-06-01T00:00:00Z",
+            })
+        })
+        .collect();
+    dataset
+        .insert(
+            "/v2/commerce/transactions/current/sells?page=0&page_size=200",
+            &sells,
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/commerce/transactions/current/sells?page=1&page_size=200",
+            &json!([{
+                "id": 300, "item_id": 1, "price": 5, "quantity": 1,
+                "created": "2024-06-02T00:00:00Z",
+            }]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/commerce/transactions/history/buys?page=0&page_size=200",
+            &json!([]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/commerce/transactions/history/sells?page=0&page_size=200",
+            &json!([
+                {
+                    "id": 2, "item_id": 24295, "price": 1200, "quantity": 2,
+                    "created": "2024-05-02T00:00:00Z", "purchased": "2024-05-04T00:00:00Z",
+                },
+                {
+                    "id": 1, "item_id": 1, "price": 5, "quantity": 1,
+                    "created": "2024-05-01T00:00:00Z", "purchased": "2024-05-01T12:00:00Z",
+                },
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/items?ids=1,19721,24295",
+            &json!([{
+                "id": 19721, "chat_link": "[&AgEJTQAA]", "name": "Glob of Ectoplasm",
+                "type": "CraftingMaterial", "rarity": "Exotic", "level": 0, "vendor_value": 8,
+                "flags": [], "game_types": [], "restrictions": [],
+            }]),
+        )
+        .unwrap();
+
+    let client: Client = Builder::new().access_token("token").offline(dataset).into();
+
+    let records = TransactionRecords::fetch(&client).await.unwrap();
+    let ids: Vec<u64> = records.iter().map(|record| record.id).collect();
+    assert_eq!(ids.len(), 204);
+    assert_eq!(ids[..4], [1, 2, 3, 100]);
+    assert_eq!(ids.last(), Some(&300));
+
+    assert_eq!(records[1].side, TransactionSide::Sell);
+    assert_eq!(records[1].status, TransactionStatus::History);
+    assert_eq!(records[1].total, Coins(2400));
+    assert_eq!(records[1].item_name, None);
+
+    assert_eq!(records[2].status, TransactionStatus::Current);
+    assert_eq!(records[2].item_name.as_deref(), Some("Glob of Ectoplasm"));
+    assert_eq!(records[2].purchased, None);
+
+    let mut writer = JsonLinesWriter::new(Vec::new());
+    for record in &records {
+        writer.write(record).unwrap();
+    }
+    let output = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+    assert_eq!(output.lines().count(), 204);
+
+    let err = JsonLinesWriter::new(FailingWriter)
+        .write(&records[0])
+        .unwrap_err();
+    assert!(err.is_io());
+    assert!(!err.is_json());
+
+    #[cfg(feature = "csv")]
+    {
+        use gw2api_rs::export::CsvWriter;
+
+        let mut writer = CsvWriter::new(Vec::new());
+        for record in &records {
+            writer.write(record).unwrap();
+        }
+        let output = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+        let mut lines = output.lines();
+        assert_eq!(
+            lines.next(),
+            Some("id,side,status,item_id,item_name,price,quantity,total,created,purchased")
+        );
+        assert_eq!(
+            lines.nth(2),
+            Some("3,Buy,Current,19721,Glob of Ectoplasm,20,10,200,2024-05-03T00:00:00Z,")
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_offline_lookup() {
+    let mini = |id: u64, name: &str| {
+        json!({
+            "id": id, "name": name, "icon": "", "order": id, "item_id": id + 1000,
+        })
+    };
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/minis?ids=1,2",
+            &json!([mini(1, "Mini Rytlock"), mini(2, "Mini Queen Jennah")]),
+        )
+        .unwrap();
+    dataset
+        .insert("/v2/minis?ids=3,4", &json!([mini(3, "Mini Logan")]))
+        .unwrap();
+
+    let client = Client::offline(dataset);
+
+    let minis = client.lookup::<Mini>(&[1, 2]).await.unwrap();
+    assert_eq!(minis.len(), 2);
+    assert_eq!(*minis[1].id(), 2);
+
+    let cache = LookupCache::<Mini>::new();
+    cache.lookup(&client, &[1, 2]).await.unwrap();
+    assert_eq!(cache.len(), 2);
+
+    // Only the ids 3 and 4 are requested, 4 does not exist.
+    let minis = cache.lookup(&client, &[3, 1, 4, 3]).await.unwrap();
+    let names: Vec<&str> = minis.iter().map(|mini| mini.name.as_str()).collect();
+    assert_eq!(names, ["Mini Logan", "Mini Rytlock", "Mini Logan"]);
+    assert_eq!(cache.len(), 3);
+    assert_eq!(cache.get(&2).unwrap().name, "Mini Queen Jennah");
+}
+
+#[tokio::test]
+async fn test_offline_guild_stash_value() {
+    let price = |id: u64, buy: u64, sell: u64| {
+        json!({
+            "id": id, "whitelisted": true,
+            "buys": { "unit_price": buy, "quantity": 1 },
+            "sells": { "unit_price": sell, "quantity": 1 },
+        })
+    };
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/guild/A/stash",
+            &json!([
+                {
+                    "upgrade_id": 58, "size": 50, "coins": 1000, "note": "",
+                    "inventory": [{ "id": 19721, "count": 10 }, null, { "id": 24295, "count": 1 }],
+                },
+                {
+                    "upgrade_id": 184, "size": 100, "coins": 500, "note": "Materials",
+                    "inventory": [{ "id": 19721, "count": 5 }, { "id": 1, "count": 1 }],
+                },
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/commerce/prices?ids=1,19721,24295",
+            &json!([price(19721, 20, 30), price(24295, 1000, 1200)]),
+        )
+        .unwrap();
+
+    let client: Client = Builder::new().access_token("token").offline(dataset).into();
+
+    let stash = GuildStash::get(&client, "A").await.unwrap();
+    assert_eq!(stash.coins(), Coins(1500));
+    assert_eq!(stash.items().count(), 4);
+
+    let value = GuildStash::get_value(&client, "A").await.unwrap();
+    assert_eq!(value, Coins(1500 + 15 * 30 + 1200));
+}
+
+#[tokio::test]
+async fn test_offline_guild_storage_resolve() {
+    let upgrade = |id: u64, name: &str, kind: &str| {
+        json!({
+            "id": id, "name": name, "description": "", "type": kind,
+            "icon": "https://render.guildwars2.com/file/0/1.png", "build_time": 0,
+            "required_level": 0, "experience": 0, "prerequisites": [], "costs": [],
+        })
+    };
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/guild/A/storage",
+            &json!([
+                { "id": 251, "count": 3 },
+                { "id": 270, "count": 1 },
+                { "id": 300, "count": 2 },
+                { "id": 512, "count": 5 },
+                { "id": 999, "count": 1 },
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/guild/upgrades?ids=251,270,300,512,999",
+            &json!([
+                upgrade(251, "Guild Banquet", "Consumable"),
+                upgrade(270, "Guild Catapult Blueprints", "Claimable"),
+                upgrade(300, "Statue of Dwayna", "Decoration"),
+                upgrade(512, "Guild Armorer 1", "Unlock"),
+            ]),
+        )
+        .unwrap();
+
+    let client: Client = Builder::new().access_token("token").offline(dataset).into();
+
+    let items = GuildStorage::resolve(&client, "A").await.unwrap();
+    assert_eq!(items.len(), 4);
+
+    assert!(matches!(&items[0], GuildStorageItem::Consumable(stored) if stored.count == 3));
+    assert!(matches!(&items[1], GuildStorageItem::Schematic(_)));
+    assert!(matches!(&items[2], GuildStorageItem::Decoration(_)));
+    assert!(matches!(&items[3], GuildStorageItem::Other(_)));
+    assert_eq!(items[2].upgrade().name, "Statue of Dwayna");
+    assert_eq!(items[3].count(), 5);
+}
+
+#[tokio::test]
+async fn test_offline_guild_search_detailed() {
+    let guild = |id: &str, tag: &str| {
+        json!({
+            "id": id, "name": "Covenant Of The Just", "tag": tag,
+            "emblem": {
+                "background": { "id": 27, "colors": [11] },
+                "foreground": { "id": 40, "colors": [473, 673] },
+                "flags": [],
+            },
+        })
+    };
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/guild/search?name=Covenant%20Of%20The%20Just",
+            &json!(["A", "B"]),
+        )
+        .unwrap();
+    dataset.insert("/v2/guild/A", &guild("A", "CJ")).unwrap();
+    dataset.insert("/v2/guild/B", &guild("B", "CotJ")).unwrap();
+
+    let client = Client::offline(dataset);
+
+    let guilds = Guild::search_detailed(&client, "Covenant Of The Just")
+        .await
+        .unwrap();
+    assert_eq!(guilds.len(), 2);
+    assert_eq!(guilds.find_by_tag("cotj").unwrap().id, "B");
+    assert_eq!(guilds.filter_by_tag("CJ").count(), 1);
+    assert!(guilds.find_by_tag("XX").is_none());
+}
+
+#[tokio::test]
+async fn test_offline_maps() {
+    let map = |id: u64, region_id: u64, continent_id: u64, continent_rect: [[f64; 2]; 2]| {
+        json!({
+            "id": id,
+            "name": "",
+            "min_level": 1,
+            "max_level": 80,
+            "default_floor": 1,
+            "type": "Public",
+            "floors": [1],
+            "region_id": region_id,
+            "continent_id": continent_id,
+            "map_rect": [[-21504, -21504], [21504, 21504]],
+            "continent_rect": continent_rect,
+        })
+    };
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/maps?ids=all",
+            &json!([
+                map(15, 4, 1, [[9856.0, 11648.0], [13440.0, 14080.0]]),
+                map(18, 4, 1, [[9472.0, 14080.0], [12032.0, 16640.0]]),
+                map(38, 7, 2, [[8958.0, 12798.0], [12030.0, 15870.0]]),
+            ]),
+        )
+        .unwrap();
+    let client = Client::offline(dataset);
+
+    let maps = Map::by_continent(&client, 1).await.unwrap();
+    assert_eq!(maps.iter().map(|m| m.id).collect::<Vec<_>>(), [15, 18]);
+
+    let maps = Map::by_region(&client, 7).await.unwrap();
+    assert_eq!(maps.iter().map(|m| m.id).collect::<Vec<_>>(), [38]);
+
+    // Continent coordinates overlap across continents.
+    let maps = Map::at(&client, 1, [10000.0, 12000.0]).await.unwrap();
+    assert_eq!(maps.iter().map(|m| m.id).collect::<Vec<_>>(), [15]);
+
+    let map = &maps[0];
+    assert_eq!(map.continent_to_map([9856.0, 11648.0]), [-21504.0, 21504.0]);
+    assert_eq!(
+        map.continent_to_map([13440.0, 14080.0]),
+        [21504.0, -21504.0]
+    );
+    assert_eq!(
+        map.map_to_continent([-21504.0, -21504.0]),
+        [9856.0, 14080.0]
+    );
+    assert_eq!(
+        map.map_to_continent([0.0, 0.0]),
+        map.continent_rect.center()
+    );
+}
+
+#[tokio::test]
+async fn test_offline_guild_roster() {
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/guild/guild/members",
+            &json!([
+                { "name": "Member.1234", "rank": "Member", "joined": "2020-01-01T00:00:00Z" },
+                { "name": "Invited.1234", "rank": "invited", "joined": "2019-01-01T00:00:00Z" },
+                { "name": "Leader.1234", "rank": "Leader", "joined": "2021-01-01T00:00:00Z" },
+                { "name": "Early.1234", "rank": "Member", "joined": "2018-01-01T00:00:00Z" },
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/guild/guild/ranks",
+            &json!([
+                { "id": "Member", "order": 2, "permissions": [], "icon": "" },
+                { "id": "Leader", "order": 1, "permissions": ["Admin"], "icon": "" },
+            ]),
+        )
+        .unwrap();
+
+    let client: Client = Builder::new().access_token("token").offline(dataset).into();
+
+    let roster = Guild::roster(&client, "guild").await.unwrap();
+    let names: Vec<_> = roster.0.iter().map(|member| member.name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["Leader.1234", "Early.1234", "Member.1234", "Invited.1234"]
+    );
+    assert!(roster.0[3].rank.is_none());
+}
+
+#[tokio::test]
+async fn test_offline_bank_resolve() {
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/account/bank",
+            &json!([
+                { "id": 24, "count": 250, "binding": null },
+                null,
+                { "id": 30, "count": 1, "skin": 7, "upgrades": [24], "binding": "Account" },
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/items?ids=24,30",
+            &json!([
+                {
+                    "id": 24, "chat_link": "[&AgEYAAAA]", "name": "Sealed Package of Snowballs",
+                    "type": "Consumable", "rarity": "Basic", "level": 0, "vendor_value": 8,
+                    "flags": [], "game_types": [], "restrictions": [],
+                },
+                {
+                    "id": 30, "chat_link": "[&AgEeAAAA]", "name": "Frostfang",
+                    "type": "Weapon", "rarity": "Legendary", "level": 80, "vendor_value": 100000,
+                    "flags": [], "game_types": [], "restrictions": [],
+                },
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/skins?ids=7",
+            &json!([
+                {
+                    "id": 7, "name": "Frostfang", "type": "Weapon", "flags": [],
+                    "restrictions": [], "rarity": "Legendary",
+                },
+            ]),
+        )
+        .unwrap();
+
+    let client: Client = Builder::new().access_token("token").offline(dataset).into();
+
+    let bank = AccountBank::resolve(&client).await.unwrap();
+    assert_eq!(bank.len(), 3);
+    assert!(bank[1].is_none());
+
+    let frostfang = bank[2].as_ref().unwrap();
+    assert_eq!(frostfang.item.as_ref().unwrap().name, "Frostfang");
+    assert_eq!(frostfang.item.as_ref().unwrap().rarity, Rarity::Legendary);
+    assert_eq!(frostfang.slot.binding, Some(Binding::Account));
+    assert_eq!(frostfang.skin.as_ref().unwrap().id, 7);
+    assert_eq!(frostfang.upgrades[0].id, 24);
+}
+
+#[tokio::test]
+async fn test_offline_dyes_resolve() {
+    let color = |id, categories| {
+        let armor = json!({
+            "brightness": 0, "contrast": 1.0, "hue": 0, "saturation": 0.0, "lightness": 1.0,
+            "rgb": [0, 0, 0],
+        });
+
+        json!({
+            "id": id, "name": "Dye", "base_rgb": [128, 26, 26], "cloth": armor,
+            "leather": armor, "metal": armor, "categories": categories,
+        })
+    };
+
+    let mut dataset = Dataset::new();
+    dataset.insert("/v2/account/dyes", &json!([1, 2])).unwrap();
+    dataset
+        .insert(
+            "/v2/colors?ids=1,2",
+            &json!([
+                color(1, json!([])),
+                color(2, json!(["Gray", "Metal", "Rare"])),
+            ]),
+        )
+        .unwrap();
+
+    let client: Client = Builder::new().access_token("token").offline(dataset).into();
+
+    let colors = AccountDyes::resolve_unlocks(&client).await.unwrap().items;
+    assert!(colors[0].categories.is_none());
+
+    let categories = colors[1].categories.unwrap();
+    assert_eq!(categories.hue, ColorHue::Gray);
+    assert_eq!(categories.material, ColorMaterial::Metal);
+    assert_eq!(categories.rarity, ColorRarity::Rare);
+
+    let value = serde_json::to_value(&colors[1]).unwrap();
+    assert_eq!(value["categories"], json!(["Gray", "Metal", "Rare"]));
+}
+
+#[tokio::test]
+async fn test_offline_colors_search() {
+    let color = |id, rgb: [u8; 3], categories| {
+        let armor = json!({
+            "brightness": 0, "contrast": 1.0, "hue": 0, "saturation": 0.0, "lightness": 1.0,
+            "rgb": rgb,
+        });
+
+        json!({
+            "id": id, "name": "Dye", "base_rgb": [128, 26, 26], "cloth": armor,
+            "leather": armor, "metal": armor, "categories": categories,
+        })
+    };
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/colors?ids=all",
+            &json!([
+                color(1, [124, 108, 83], json!([])),
+                color(2, [180, 30, 30], json!(["Red", "Metal", "Rare"])),
+                color(3, [40, 40, 160], json!(["Blue", "Metal", "Common"])),
+                color(4, [230, 230, 230], json!(["Gray", "Vibrant", "Common"])),
+            ]),
+        )
+        .unwrap();
+    let client = Client::offline(dataset);
+
+    let ids = |colors: &[Color]| colors.iter().map(|c| c.id).collect::<Vec<_>>();
+
+    let colors = Color::search(&client, ColorFilter::new()).await.unwrap();
+    assert_eq!(ids(&colors), [1, 2, 3, 4]);
+
+    let filter = ColorFilter::new().material(ColorMaterial::Metal);
+    let colors = Color::search(&client, filter).await.unwrap();
+    assert_eq!(ids(&colors), [2, 3]);
+
+    let filter = filter.rarity(ColorRarity::Common);
+    let colors = Color::search(&client, filter).await.unwrap();
+    assert_eq!(ids(&colors), [3]);
+
+    let colors = Color::get_all(&client).await.unwrap();
+    let closest = |rgb| Color::closest_to(&colors, rgb).unwrap().id;
+    assert_eq!(closest([255, 0, 0]), 2);
+    assert_eq!(closest([0, 0, 255]), 3);
+    assert_eq!(closest([255, 255, 255]), 4);
+    assert_eq!(closest([120, 110, 80]), 1);
+    assert!(Color::closest_to(&[], [0, 0, 0]).is_none());
+}
+
+#[tokio::test]
+async fn test_offline_emotes_resolve() {
+    let mut dataset = Dataset::new();
+    dataset
+        .insert("/v2/account/emotes", &json!(["bless", "geargrind"]))
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/emotes?ids=bless,geargrind",
+            &json!([
+                { "id": "bless", "commands": ["/bless"], "unlock_items": [88887] },
+                { "id": "geargrind", "commands": ["/geargrind"], "unlock_items": [] },
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/items?ids=88887",
+            &json!([{
+                "id": 88887, "chat_link": "[&AgE3WwEA]", "name": "Unlock: Bless",
+                "type": "Consumable", "rarity": "Rare", "level": 0, "vendor_value": 0,
+                "flags": [], "game_types": [], "restrictions": [],
+            }]),
+        )
+        .unwrap();
+
+    let client: Client = Builder::new().access_token("token").offline(dataset).into();
+
+    let emotes = AccountEmotes::resolve_unlocks(&client).await.unwrap().items;
+    assert_eq!(emotes.len(), 2);
+    assert_eq!(emotes[0].commands, ["/bless"]);
+
+    let items = emotes[0].unlock_items(&client).await.unwrap();
+    assert_eq!(items[0].name, "Unlock: Bless");
+}
+
+#[tokio::test]
+async fn test_offline_finishers_resolve() {
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/account/finishers",
+            &json!([
+                { "id": 1, "permanent": true },
+                { "id": 4, "permanent": false, "quantity": 2 },
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/finishers?ids=1,4",
+            &json!([
+                { "id": 1, "unlock_details": "", "order": 9, "icon": "", "name": "Rabbit" },
+                {
+                    "id": 4, "unlock_details": "", "unlock_items": [44857], "order": 1,
+                    "icon": "", "name": "Scarecrow",
+                },
+            ]),
+        )
+        .unwrap();
+
+    let client: Client = Builder::new().access_token("token").offline(dataset).into();
+
+    let finishers = AccountFinishers::resolve_unlocks(&client)
+        .await
+        .unwrap()
+        .items;
+    assert_eq!(finishers.len(), 2);
+    assert!(finishers[0].unlock_items.is_empty());
+    assert_eq!(finishers[1].unlock_items, [44857]);
+}
+
+#[tokio::test]
+async fn test_offline_legendary_armory_capacity() {
+    let item = |id: u64, kind: &str| {
+        json!({
+            "id": id, "chat_link": ChatLink::Item(ItemLink::new(id as u32)), "name": "",
+            "type": kind, "rarity": "Legendary", "level": 80, "vendor_value": 0, "flags": [],
+            "game_types": [], "restrictions": [],
+        })
+    };
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/account/legendaryarmory",
+            &json!([{ "id": 30684, "count": 1 }, { "id": 80111, "count": 2 }]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/legendaryarmory?ids=all",
+            &json!([
+                { "id": 30684, "max_count": 2 },
+                { "id": 30685, "max_count": 2 },
+                { "id": 80111, "max_count": 6 },
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/items?ids=30684,30685,80111",
+            &json!([
+                item(30684, "Weapon"),
+                item(30685, "Weapon"),
+                item(80111, "Armor")
+            ]),
+        )
+        .unwrap();
+
+    let client: Client = Builder::new().access_token("token").offline(dataset).into();
+
+    let capacity = AccountLegendaryArmory::capacity(&client).await.unwrap();
+    assert_eq!(capacity.len(), 2);
+
+    assert_eq!(capacity[0].kind, ItemKind::Weapon);
+    assert_eq!(capacity[0].unlocked, 1);
+    assert_eq!(capacity[0].max_count, 4);
+    assert_eq!(capacity[0].remaining(), 3);
+
+    assert_eq!(capacity[1].kind, ItemKind::Armor);
+    assert_eq!(capacity[1].remaining(), 4);
+}
+
+#[tokio::test]
+async fn test_offline_material_storage() {
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/account/materials",
+            &json!([
+                { "id": 12134, "category": 5, "count": 250 },
+                { "id": 12238, "category": 5, "count": 20 },
+                { "id": 19697, "category": 6, "count": 251 },
+                { "id": 19721, "category": 6, "binding": "Account", "count": 500 },
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/materials?ids=all",
+            &json!([
+                { "id": 5, "name": "Cooking Materials", "items": [12134, 12238, 12147], "order": 10 },
+                { "id": 6, "name": "Basic Crafting Materials", "items": [19697, 19721], "order": 0 },
+            ]),
+        )
+        .unwrap();
+
+    let client: Client = Builder::new().access_token("token").offline(dataset).into();
+
+    let storage = AccountMaterials::storage(&client).await.unwrap();
+    assert_eq!(storage.limit, 500);
+    assert_eq!(storage.stored(), 1021);
+    assert_eq!(storage.capacity(), 2500);
+
+    let basic = &storage.categories[0];
+    assert_eq!(basic.id, 6);
+    assert_eq!(basic.capacity(), 1000);
+    assert_eq!(basic.fill(), 0.751);
+    let full: Vec<u64> = basic.full().map(|material| material.id).collect();
+    assert_eq!(full, [19721]);
+
+    let cooking = &storage.categories[1];
+    assert_eq!(cooking.materials.len(), 2);
+    assert_eq!(cooking.stored(), 270);
+    assert_eq!(cooking.full().count(), 0);
+}
+
+#[tokio::test]
+async fn test_offline_exchange_rate() {
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/commerce/exchange/coins?quantity=1000000",
+            &json!({ "coins_per_gem": 2531, "quantity": 395 }),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/commerce/exchange/gems?quantity=100",
+            &json!({ "coins_per_gem": 1862, "quantity": 186200 }),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset);
+    let rate = Exchange::rate(&client).await.unwrap();
+    assert_eq!(rate.coins_to_gems.quantity, 395);
+    assert_eq!(rate.buy_price(), Coins(2532));
+    assert_eq!(rate.sell_price(), Coins(1862));
+    assert_eq!(rate.spread(), Coins(670));
+    assert_eq!(rate.coins_for(10), Coins(18620));
+}
+
+#[tokio::test]
+async fn test_offline_resolve_unlocks() {
+    let mut dataset = Dataset::new();
+    dataset
+        .insert("/v2/account/titles", &json!([1, 2, 3]))
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/titles?ids=1,2,3",
+            &json!([
+                { "id": 3, "name": "Dragonslayer" },
+                { "id": 1, "name": "Traveler" },
+            ]),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset).with_access_token("token");
+    let titles = AccountTitles::resolve_unlocks(&client).await.unwrap();
+
+    let names: Vec<&str> = titles.iter().map(|title| title.name.as_str()).collect();
+    assert_eq!(names, ["Traveler", "Dragonslayer"]);
+    assert_eq!(titles.missing, [2]);
+}
+
+#[tokio::test]
+async fn test_offline_pvp_heroes_resolve() {
+    let skin = |id: u64, name: &str| json!({ "id": id, "name": name, "icon": "", "default": id == 1, "unlock_items": [] });
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert("/v2/account/pvp/heroes", &json!([7, 1, 99]))
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/pvp/heroes?ids=all",
+            &json!([{
+                "id": "115C140F-C2F5-40EB-8EA2-C3773F2AE468", "name": "Nika", "type": "Assassin",
+                "stats": { "offense": 7, "defense": 3, "speed": 10 },
+                "overlay": "", "underlay": "",
+                "skins": [skin(1, "Nika"), skin(7, "Shadow Assassin Nika")],
+            }]),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset).with_access_token("token");
+    let skins = AccountPvPHeroes::resolve_unlocks(&client).await.unwrap();
+
+    let names: Vec<&str> = skins.iter().map(|skin| skin.name.as_str()).collect();
+    assert_eq!(names, ["Shadow Assassin Nika", "Nika"]);
+    assert_eq!(skins.missing, [99]);
+}
+
+#[tokio::test]
+async fn test_offline_mount_types_resolve() {
+    let mount = |id: &str, name: &str| json!({ "id": id, "name": name, "default_skin": 1, "skins": [1], "skills": [] });
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert("/v2/account/mounts/types", &json!(["raptor", "turtle"]))
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/mounts/types?ids=raptor,turtle",
+            &json!([mount("raptor", "Raptor"), mount("turtle", "Siege Turtle")]),
+        )
+        .unwrap();
+
+    let client: Client = Builder::new().access_token("token").offline(dataset).into();
+
+    let types = AccountMountTypes::get(&client).await.unwrap();
+    assert_eq!(types.0, [MountType::Raptor, MountType::SiegeTurtle]);
+
+    let mounts = AccountMountTypes::resolve_unlocks(&client)
+        .await
+        .unwrap()
+        .items;
+    assert_eq!(mounts[1].id, MountType::SiegeTurtle);
+    assert_eq!(mounts[1].name, "Siege Turtle");
+}
+
+#[tokio::test]
+async fn test_offline_pvp_games_recent() {
+    let game = |id: u64| {
+        json!({
+            "id": id.to_string(), "map_id": 894, "started": "2015-07-08T21:29:50Z",
+            "ended": "2015-07-08T21:37:02Z", "result": "Victory", "team": "Blue",
+            "profession": "Mesmer", "scores": { "red": 165, "blue": 500 },
+            "rating_type": "Unranked",
+        })
+    };
+
+    let ids: Vec<String> = (1..=12).map(|id| id.to_string()).collect();
+    let mut dataset = Dataset::new();
+    dataset.insert("/v2/pvp/games", &ids).unwrap();
+    dataset
+        .insert(
+            "/v2/pvp/games?ids=1,2,3,4,5,6,7,8,9,10",
+            &(1..=10).map(game).collect::<Vec<_>>(),
+        )
+        .unwrap();
+    dataset
+        .insert("/v2/pvp/games?ids=11", &json!([game(11)]))
+        .unwrap();
+
+    let client: Client = Builder::new().access_token("token").offline(dataset).into();
+
+    let games = Game::recent(&client, 11).await.unwrap();
+    assert_eq!(games.len(), 11);
+    assert_eq!(games[10].id, "11");
+    assert_eq!(games[0].result, GameResult::Victory);
+    assert_eq!(games[0].scores.get(games[0].team), Some(500));
+    assert_eq!(games[0].team, GameTeam::Blue);
+    assert_eq!(games[0].rating_change, None);
+}
+
+#[tokio::test]
+async fn test_offline_pvp_season_current() {
+    let division = |points: &[u64], flags| {
+        let tiers: Vec<_> = points
+            .iter()
+            .map(|points| json!({ "points": points }))
+            .collect();
+        json!({
+            "name": "", "flags": flags, "large_icon": "", "small_icon": "", "pip_icon": "",
+            "tiers": tiers,
+        })
+    };
+    let season = |id: &str, start: &str, end: &str, active| {
+        json!({
+            "id": id, "name": id, "start": start, "end": end, "active": active,
+            "divisions": [
+                division(&[5, 5], json!(["CanLosePoints"])),
+                division(&[10], json!(["CanLosePoints", "Repeatable"])),
+            ],
+        })
+    };
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/pvp/seasons?ids=all",
+            &json!([
+                season("past", "2015-12-01T20:00:00Z", "2016-01-28T05:00:00Z", true),
+                season(
+                    "current",
+                    "2016-01-28T05:00:00Z",
+                    "2999-01-01T00:00:00Z",
+                    false
+                ),
+            ]),
+        )
+        .unwrap();
+    let client = Client::offline(dataset);
+
+    let season = PvpSeason::current(&client).await.unwrap().unwrap();
+    assert_eq!(season.id, "current");
+    assert_eq!(season.total_pips(), 20);
+
+    let position = |division, tier, points, repeats| TrackPosition {
+        division,
+        tier,
+        points,
+        repeats,
+    };
+    assert_eq!(season.track_position(0), position(0, 0, 0, 0));
+    assert_eq!(season.track_position(7), position(0, 1, 2, 0));
+    assert_eq!(season.track_position(10), position(1, 0, 0, 0));
+    assert_eq!(season.track_position(20), position(1, 0, 0, 1));
+    assert_eq!(season.track_position(43), position(1, 0, 3, 3));
+
+    assert!(GameResult::Bye.is_win());
+    assert!(!GameResult::Defeat.is_win());
+}
+
+#[tokio::test]
+async fn test_offline_remaining_dailies() {
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/dailycrafting",
+            &json!([
+                "charged_quartz_crystal",
+                "glob_of_elder_spirit_residue",
+                "lump_of_mithrilium"
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert("/v2/account/dailycrafting", &json!(["lump_of_mithrilium"]))
+        .unwrap();
+    dataset
+        .insert("/v2/mapchests", &json!(["auric_basin_heros_choice_chest"]))
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/account/mapchests",
+            &json!(["auric_basin_heros_choice_chest"]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/worldbosses",
+            &json!(["admiral_taidha_covington", "shadow_behemoth"]),
+        )
+        .unwrap();
+    dataset
+        .insert("/v2/account/worldbosses", &json!([]))
+        .unwrap();
+
+    let client: Client = Builder::new().access_token("token").offline(dataset).into();
+
+    let crafts = DailyCrafting::remaining(&client).await.unwrap();
+    assert_eq!(
+        crafts,
+        ["charged_quartz_crystal", "glob_of_elder_spirit_residue"]
+    );
+
+    let chests = MapChest::remaining(&client).await.unwrap();
+    assert!(chests.is_empty());
+
+    let bosses = WorldBoss::remaining(&client).await.unwrap();
+    assert_eq!(bosses, ["admiral_taidha_covington", "shadow_behemoth"]);
+
+    let client = Client::offline(Dataset::new());
+    assert!(DailyCrafting::remaining(&client).await.is_err());
+}
+
+#[tokio::test]
+async fn test_offline_recipe_can_craft() {
+    let recipe = |id: u64, min_rating: u16, flags| {
+        json!({
+            "id": id, "type": "Refinement", "output_item_id": 1, "output_item_count": 1,
+            "time_to_craft_ms": 1000, "disciplines": ["Armorsmith", "Weaponsmith"],
+            "min_rating": min_rating, "flags": flags, "ingredients": [],
+            "chat_link": ChatLink::Recipe(id as u32),
+        })
+    };
+
+    let mut dataset = Dataset::new();
+    for (id, min_rating, flags) in [
+        (1, 0, json!(["AutoLearned"])),
+        (2, 300, json!(["LearnedFromItem"])),
+        (3, 300, json!(["LearnedFromItem"])),
+        (4, 400, json!(["AutoLearned"])),
+        (5, 500, json!(["AutoLearned"])),
+        (6, 0, json!([])),
+    ] {
+        dataset
+            .insert(
+                format!("/v2/recipes?id={}", id),
+                &recipe(id, min_rating, flags),
+            )
+            .unwrap();
+    }
+    dataset.insert("/v2/account/recipes", &json!([2])).unwrap();
+    dataset
+        .insert("/v2/characters/Crafter/recipes", &json!({ "recipes": [6] }))
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/characters/Crafter/crafting",
+            &json!({ "crafting": [
+                { "discipline": "Armorsmith", "rating": 350, "active": true },
+                { "discipline": "Weaponsmith", "rating": 450, "active": false },
+            ] }),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset).with_access_token("token");
+    let verdict = |id| Recipe::can_craft(&client, "Crafter", id);
+
+    assert_eq!(verdict(1).await.unwrap(), CraftVerdict::Craftable);
+    assert_eq!(verdict(2).await.unwrap(), CraftVerdict::Craftable);
+    assert_eq!(verdict(3).await.unwrap(), CraftVerdict::NotLearned);
+    assert_eq!(
+        verdict(4).await.unwrap(),
+        CraftVerdict::InactiveDiscipline(Discipline::Weaponsmith)
+    );
+    assert_eq!(
+        verdict(5).await.unwrap(),
+        CraftVerdict::InsufficientRating {
+            discipline: Discipline::Armorsmith,
+            rating: 350,
+            required: 500,
+        }
+    );
+    assert!(verdict(6).await.unwrap().is_craftable());
+}
+
+#[tokio::test]
+async fn test_offline_story_progress() {
+    let quest = |id: u64, name: &str, story: u64| json!({ "id": id, "name": name, "level": 1, "story": story, "goals": [] });
+    let story = |id: u64, season: &str, order: u64, chapters: &[&str], races| {
+        let chapters: Vec<_> = chapters
+            .iter()
+            .map(|name| json!({ "name": name }))
+            .collect();
+        json!({
+            "id": id, "season": season, "name": id.to_string(), "description": "",
+            "timeline": "", "level": 1, "order": order, "chapters": chapters, "races": races,
+        })
+    };
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/characters/Crafter/core",
+            &json!({
+                "name": "Crafter", "race": "Charr", "gender": "Male", "profession": "Guardian",
+                "level": 80, "guild": null, "age": 1, "created": "2015-07-22T06:17:00Z",
+                "deaths": 0, "title": null,
+            }),
+        )
+        .unwrap();
+    dataset
+        .insert("/v2/characters/Crafter/quests", &json!([1, 3]))
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/quests?ids=all",
+            &json!([
+                quest(1, "Chapter 1", 10),
+                quest(2, "Chapter 2", 10),
+                quest(3, "Chapter 1", 20),
+                quest(4, "Chapter 1", 30),
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/stories?ids=all",
+            &json!([
+                story(20, "B", 0, &["Chapter 1"], json!(null)),
+                story(
+                    10,
+                    "A",
+                    0,
+                    &["Chapter 1", "Chapter 2", "Chapter 3"],
+                    json!(["Charr"])
+                ),
+                story(30, "A", 1, &["Chapter 1"], json!(["Asura"])),
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/stories/seasons?ids=all",
+            &json!([
+                { "id": "B", "name": "B", "order": 1, "stories": [20] },
+                { "id": "A", "name": "A", "order": 0, "stories": [10, 30] },
+            ]),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset).with_access_token("token");
+    let stories = Story::character_progress(&client, "Crafter").await.unwrap();
+
+    let ids: Vec<u64> = stories.iter().map(|story| story.id).collect();
+    assert_eq!(ids, [10, 20]);
+
+    assert_eq!(stories[0].completed(), 1);
+    assert_eq!(stories[0].chapters[1].quest, Some(2));
+    assert_eq!(stories[0].chapters[2].quest, None);
+    assert_eq!(stories[0].next_chapter().unwrap().name, "Chapter 2");
+    assert!(!stories[0].is_completed());
+    assert!(stories[1].is_completed());
+}
+
+#[tokio::test]
+async fn test_offline_daily_achievements() {
+    let daily = |id, min, access| {
+        let level = json!({ "min": min, "max": 80 });
+        json!({ "id": id, "level": level, "required_access": access })
+    };
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/achievements/daily",
+            &json!({
+                "pve": [
+                    daily(1, 1, json!({ "product": "GuildWars2", "condition": "HasAccess" })),
+                    daily(2, 1, json!({ "product": "HeartOfThorns", "condition": "NoAccess" })),
+                    daily(3, 1, json!({ "product": "PathOfFire", "condition": "HasAccess" })),
+                    daily(4, 11, json!(null)),
+                ],
+                "pvp": [daily(5, 1, json!(null))],
+                "wvw": [],
+                "fractals": [],
+                "special": [],
+            }),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/account",
+            &json!({
+                "id": "", "age": 0, "name": "Example.1234", "world": 1001, "guilds": [],
+                "created": "2014-01-01T00:00:00Z", "access": ["GuildWars2", "HeartOfThorns"],
+                "commander": false, "last_modified": "2022-01-01T00:00:00Z",
+            }),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/characters?ids=all",
+            &json!([{
+                "name": "Example", "race": "Charr", "gender": "Male", "profession": "Guardian",
+                "level": 10, "age": 0, "created": "2014-01-01T00:00:00Z", "deaths": 0,
+            }]),
+        )
+        .unwrap();
+
+    let client: Client = Builder::new().access_token("token").offline(dataset).into();
+
+    let dailies = DailyAchievements::for_account(&client).await.unwrap();
+    let ids: Vec<_> = dailies.pve.iter().map(|daily| daily.id).collect();
+    assert_eq!(ids, [1]);
+    assert_eq!(dailies.pvp.len(), 1);
+}
+
+#[tokio::test]
+async fn test_offline_achievement_points() {
+    let achievement = |id: u64, flags: serde_json::Value, point_cap: Option<u64>| {
+        json!({
+            "id": id, "name": "", "description": "", "requirement": "", "locked_text": "",
+            "type": "Default", "flags": flags, "point_cap": point_cap,
+            "tiers": [{ "count": 1, "points": 5 }, { "count": 5, "points": 10 }],
+        })
+    };
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/account",
+            &json!({
+                "id": "", "age": 0, "name": "Example.1234", "world": 1001, "guilds": [],
+                "created": "2014-01-01T00:00:00Z", "access": ["GuildWars2"], "commander": false,
+                "last_modified": "2022-01-01T00:00:00Z", "daily_ap": 100, "monthly_ap": 20,
+            }),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/account/achievements",
+            &json!([
+                // Completed: all tiers.
+                { "id": 1, "current": 5, "max": 5, "done": true },
+                // In progress: first tier only.
+                { "id": 2, "current": 3, "max": 5, "done": false },
+                // Repeated twice and in the first tier again: 2 * 15 + 5.
+                { "id": 3, "current": 1, "max": 5, "done": true, "repeated": 2 },
+                // Repeated five times, capped at 50.
+                { "id": 4, "current": 0, "max": 5, "done": true, "repeated": 5 },
+                // Unknown achievement.
+                { "id": 5, "current": 5, "max": 5, "done": true },
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/achievements?ids=1,2,3,4,5",
+            &json!([
+                achievement(1, json!([]), None),
+                achievement(2, json!([]), None),
+                achievement(3, json!(["Repeatable"]), Some(100)),
+                achievement(4, json!(["Repeatable"]), Some(50)),
+            ]),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset).with_access_token("token");
+    let points = AchievementPoints::get(&client).await.unwrap();
+
+    assert_eq!(points.achievements, 15 + 5 + 35 + 50);
+    assert_eq!(points.daily, 100);
+    assert_eq!(points.monthly, 20);
+    assert_eq!(points.total(), 225);
+}
+
+#[tokio::test]
+async fn test_offline_account_achievements_get_many() {
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/account/achievements?ids=1,2963",
+            &json!([{ "id": 2963, "current": 3, "max": 8, "done": false }]),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset).with_access_token("token");
+
+    let achievements = AccountAchievements::get_many(&client, &[1, 2963])
+        .await
+        .unwrap();
+    assert_eq!(achievements.len(), 1);
+    assert_eq!(achievements[0].id, 2963);
+}
+
+#[tokio::test]
+async fn test_offline_daily_fractals() {
+    let daily = |id| json!({ "id": id, "level": { "min": 1, "max": 80 }, "required_access": null });
+    let achievement = |id, name| {
+        json!({
+            "id": id, "name": name, "description": "", "requirement": "", "locked_text": "",
+            "type": "Default", "flags": [], "tiers": [{ "count": 1, "points": 0 }],
+        })
+    };
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/achievements/daily",
+            &json!({
+                "pve": [daily(1)],
+                "pvp": [],
+                "wvw": [],
+                "fractals": [daily(10), daily(11), daily(12), daily(13), daily(20)],
+                "special": [],
+            }),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/achievements/categories?id=88",
+            &json!({
+                "id": 88, "name": "Daily Fractals", "description": "", "order": 0, "icon": "",
+                "achievements": [10, 11, 12, 13],
+            }),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/achievements?ids=10,11,12,13",
+            &json!([
+                achievement(10, "Daily Tier 1 Deepstone"),
+                achievement(11, "Daily Tier 4 Deepstone"),
+                achievement(12, "Daily Recommended Fractal—Scale 47"),
+                achievement(13, "Daily Fractal Bonus Rewards"),
+            ]),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset);
+
+    let fractals = DailyFractals::get(&client).await.unwrap();
+    assert_eq!(fractals.tiers.len(), 2);
+    assert_eq!(fractals.tiers[1].tier, 4);
+    assert_eq!(fractals.tiers[1].achievement_id, 11);
+    assert_eq!(fractals.fractals(), ["Deepstone"]);
+    assert_eq!(fractals.recommended.len(), 1);
+    assert_eq!(fractals.recommended[0].scale, 47);
+}
+
+#[tokio::test]
+async fn test_offline_achievement_tree() {
+    let group = |id, order, categories: &[u64]| json!({ "id": id, "name": id, "description": "", "order": order, "categories": categories });
+    let category = |id: u64, order, achievements: &[u64]| {
+        json!({
+            "id": id, "name": "", "description": "", "order": order, "icon": "category.png",
+            "achievements": achievements,
+        })
+    };
+    let achievement = |id: u64, icon: Option<&str>| {
+        json!({
+            "id": id, "icon": icon, "name": "", "description": "", "requirement": "",
+            "locked_text": "", "type": "Default", "flags": [], "tiers": [{ "count": 1, "points": 0 }],
+        })
+    };
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/achievements/groups?ids=all",
+            &json!([group("B", 2, &[3]), group("A", 1, &[2, 1, 4])]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/achievements/categories?ids=all",
+            &json!([
+                category(1, 1, &[10, 11]),
+                category(2, 2, &[12, 10]),
+                category(3, 1, &[13]),
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/achievements?ids=10,11,12,13",
+            &json!([
+                achievement(10, Some("achievement.png")),
+                achievement(11, None),
+                achievement(12, None),
+                // Achievement 13 is missing.
+            ]),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset);
+
+    let tree = AchievementTree::build(&client).await.unwrap();
+    let groups: Vec<_> = tree.groups.iter().map(|g| g.group.id.as_str()).collect();
+    assert_eq!(groups, ["A", "B"]);
+
+    // Category 4 does not exist.
+    let categories: Vec<_> = tree.groups[0]
+        .categories
+        .iter()
+        .map(|c| c.category.id)
+        .collect();
+    assert_eq!(categories, [1, 2]);
+
+    let achievements: Vec<_> = tree.groups[0].categories[1]
+        .achievements
+        .iter()
+        .map(|a| a.id)
+        .collect();
+    assert_eq!(achievements, [12, 10]);
+    assert!(tree.groups[1].categories[0].achievements.is_empty());
+
+    let (category, achievement) = tree.achievement(11).unwrap();
+    assert_eq!(category.category.id, 1);
+    assert_eq!(category.icon(achievement), "category.png");
+    let (category, achievement) = tree.achievement(10).unwrap();
+    assert_eq!(category.icon(achievement), "achievement.png");
+
+    assert_eq!(tree.group_of(3).unwrap().group.id, "B");
+    assert!(tree.category(4).is_none());
+}
+
+#[tokio::test]
+async fn test_offline_account_luck() {
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/account/luck",
+            &json!([
+                { "value": 13_499, "id": "luck", "unknown": true },
+                { "id": "unknown", "value": "ignored" },
+            ]),
+        )
+        .unwrap();
+
+    let client: Client = Builder::new().access_token("token").offline(dataset).into();
+
+    let luck = AccountLuck::get(&client).await.unwrap();
+    assert_eq!(luck, AccountLuck(13_499));
+    assert_eq!(luck.magic_find_percent(), 25);
+    assert_eq!(luck.luck_to_next_percent(), Some(1));
+    assert_eq!(
+        serde_json::to_value(luck).unwrap(),
+        json!([{ "id": "luck", "value": 13_499 }])
+    );
+
+    let max = AccountLuck(AccountLuck::luck_for_magic_find(
+        AccountLuck::MAX_MAGIC_FIND,
+    ));
+    assert_eq!(max.magic_find_percent(), AccountLuck::MAX_MAGIC_FIND);
+    assert_eq!(max.luck_to_next_percent(), None);
+
+    let luck: AccountLuck = serde_json::from_value(json!([])).unwrap();
+    assert_eq!(luck, AccountLuck::default());
+}
+
+#[tokio::test]
+async fn test_offline_account_wvw_team() {
+    let wvw_match: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/wvw_matches.json")).unwrap();
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/account",
+            &json!({
+                "id": "", "age": 0, "name": "Example.1234", "world": 1002, "guilds": [],
+                "created": "2014-01-01T00:00:00Z", "access": ["GuildWars2"],
+                "commander": false, "last_modified": "2022-01-01T00:00:00Z",
+            }),
+        )
+        .unwrap();
+    dataset
+        .insert("/v2/account/wvw", &json!({ "team": 1010, "rank": 42 }))
+        .unwrap();
+    dataset
+        .insert("/v2/wvw/matches?world=1010", &wvw_match)
+        .unwrap();
+
+    let client = Client::offline(dataset).with_access_token("token");
+    let team = Account::wvw_team(&client).await.unwrap();
+    assert_eq!(team.world, 1002);
+    assert_eq!(team.team, Some(1010));
+    assert_eq!(team.rank, Some(42));
+    assert_eq!(team.color, Some(TeamColor::Red));
+    assert_eq!(team.wvw_match.unwrap().id, "1-1");
+}
+
+#[tokio::test]
+async fn test_offline_wvw_upgrade_progress() {
+    let tier = |name: &str, yaks_required: u64| json!({ "name": name, "yaks_required": yaks_required, "upgrades": [] });
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/wvw/matches?world=1010",
+            &serde_json::from_str::<serde_json::Value>(include_str!("fixtures/wvw_matches.json"))
+                .unwrap(),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/wvw/upgrades?id=3",
+            &json!({
+                "id": 3,
+                "tiers": [tier("Secure", 20), tier("Reinforce", 60), tier("Fortify", 140)],
+            }),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset);
+    let wvw_match = wvw::Match::get_by_world(&client, 1010).await.unwrap();
+    let upgrades = wvw::Upgrades::get(&client, 3).await.unwrap();
+
+    let objectives = &wvw_match.maps[0].objectives;
+    let progress = objectives[0].upgrade_progress(&upgrades).unwrap();
+    assert_eq!(progress.tier, 1);
+    assert_eq!(progress.yaks_remaining(), Some(20));
+    assert_eq!(progress.fraction(), 0.5);
+    assert_eq!(upgrades.current_tier(40).unwrap().name, "Secure");
+
+    // Ruins cannot be upgraded.
+    assert_eq!(objectives[1].upgrade_progress(&upgrades), None);
+
+    let progress = upgrades.progress(10);
+    assert_eq!(progress.tier, 0);
+    assert_eq!(progress.fraction(), 0.5);
+    assert!(upgrades.current_tier(10).is_none());
+
+    let progress = upgrades.progress(200);
+    assert_eq!(progress.tier, 3);
+    assert!(progress.is_max_tier());
+    assert_eq!(progress.yaks_remaining(), None);
+    assert_eq!(progress.fraction(), 1.0);
+}
+
+#[tokio::test]
+async fn test_offline_wvw_matchup_summary() {
+    let wvw_match: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/wvw_matches.json")).unwrap();
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert("/v2/wvw/matches?world=1010", &wvw_match)
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/worlds?ids=1001,1002,1003,1010",
+            &json!([
+                { "id": 1001, "name": "Anvil Rock", "population": "High" },
+                { "id": 1002, "name": "Borlis Pass", "population": "Medium" },
+                { "id": 1003, "name": "Yak's Bend", "population": "Full" },
+            ]),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset);
+    let summary = wvw::matchup_summary(&client, 1010).await.unwrap();
+    assert_eq!(summary.tier, Some(1));
+    assert_eq!(summary.team, Some(TeamColor::Red));
+
+    let own = summary.own().unwrap();
+    assert_eq!(own.world.name.as_deref(), Some("Anvil Rock"));
+    assert_eq!(own.linked_worlds.len(), 1);
+    assert_eq!(own.linked_worlds[0].id, 1010);
+    assert_eq!(own.linked_worlds[0].name, None);
+    assert_eq!(own.victory_points, 150);
+    assert_eq!(own.skirmish_victory_points, [3]);
+
+    let opponents: Vec<_> = summary
+        .opponents()
+        .map(|team| team.world.name.as_deref().unwrap())
+        .collect();
+    assert_eq!(opponents, ["Borlis Pass", "Yak's Bend"]);
+    assert_eq!(summary.teams.blue.skirmish_victory_points, [5]);
+}
+
+#[tokio::test]
+async fn test_offline_raid_completion() {
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/account/raids",
+            &json!(["vale_guardian", "spirit_woods", "slothasor"]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/raids?ids=all",
+            &json!([{
+                "id": "forsaken_thicket",
+                "wings": [
+                    {
+                        "id": "spirit_vale",
+                        "events": [
+                            { "id": "vale_guardian", "type": "Boss" },
+                            { "id": "spirit_woods", "type": "Checkpoint" },
+                        ],
+                    },
+                    {
+                        "id": "salvation_pass",
+                        "events": [
+                            { "id": "slothasor", "type": "Boss" },
+                            { "id": "bandit_trio", "type": "Boss" },
+                        ],
+                    },
+                ],
+            }]),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset).with_access_token("token");
+    let raids = AccountRaids::get_completion(&client).await.unwrap();
+    assert_eq!(raids.len(), 1);
+    assert!(!raids[0].is_completed());
+
+    let spirit_vale = raids[0].wing("spirit_vale").unwrap();
+    assert!(spirit_vale.is_completed());
+    assert_eq!(spirit_vale.bosses_cleared(), 1);
+
+    let salvation_pass = raids[0].wing("salvation_pass").unwrap();
+    assert!(!salvation_pass.is_completed());
+    assert_eq!(salvation_pass.cleared(), 1);
+    assert_eq!(salvation_pass.events[1].kind, RaidEventKind::Boss);
+    assert!(!salvation_pass.events[1].cleared);
+}
+
+#[tokio::test]
+async fn test_offline_dungeon_completion() {
+    let mut dataset = Dataset::new();
+    dataset
+        .insert("/v2/account/dungeons", &json!(["ac_story", "hodgins"]))
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/dungeons?ids=all",
+            &json!([
+                {
+                    "id": "ascalonian_catacombs",
+                    "paths": [
+                        { "id": "ac_story", "type": "Story" },
+                        { "id": "hodgins", "type": "Explorable" },
+                    ],
+                },
+                {
+                    "id": "caudecus_manor",
+                    "paths": [
+                        { "id": "cm_story", "type": "Story" },
+                        { "id": "asura", "type": "Explorable" },
+                    ],
+                },
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/account/achievements",
+            &json!([{ "id": 2963, "current": 3, "max": 8, "done": false, "repeated": 12 }]),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset).with_access_token("token");
+    let progress = AccountDungeons::get_completion(&client).await.unwrap();
+    assert_eq!(progress.completed(), 2);
+    assert_eq!(progress.remaining(), 2);
+    assert!(progress.dungeons[0].is_completed());
+
+    let remaining: Vec<&str> = progress.dungeons[1]
+        .remaining()
+        .map(|path| path.id.as_str())
+        .collect();
+    assert_eq!(remaining, ["cm_story", "asura"]);
+
+    assert_eq!(progress.frequenter.current, 3);
+    assert_eq!(progress.frequenter.repeated, 12);
+    assert_eq!(progress.frequenter.remaining(), 5);
+}
+
+#[tokio::test]
+async fn test_offline_wardrobe_missing() {
+    let skin = |id: u64, kind: &str, details: serde_json::Value| {
+        json!({
+            "id": id, "name": "", "type": kind, "flags": ["ShowInWardrobe"], "restrictions": [],
+            "icon": null, "rarity": "Exotic", "description": null, "details": details,
+        })
+    };
+    let coat = |id: u64, weight_class: &str| {
+        skin(
+            id,
+            "Armor",
+            json!({ "type": "Coat", "weight_class": weight_class }),
+        )
+    };
+
+    let mut dataset = Dataset::new();
+    dataset.insert("/v2/account/skins", &json!([1, 3])).unwrap();
+    dataset
+        .insert("/v2/skins", &json!([1, 2, 3, 4, 5]))
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/skins?ids=2,4,5",
+            &json!([
+                coat(2, "Heavy"),
+                coat(4, "Light"),
+                skin(
+                    5,
+                    "Weapon",
+                    json!({ "type": "Sword", "damage_type": "Physical" })
+                ),
+            ]),
+        )
+        .unwrap();
+    dataset
+        .insert("/v2/skins?ids=2", &json!([coat(2, "Heavy")]))
+        .unwrap();
+
+    let client = Client::offline(dataset).with_access_token("token");
+
+    let missing = Wardrobe::new().missing(&client).await.unwrap();
+    let ids: Vec<u64> = missing.iter().map(|skin| skin.id).collect();
+    assert_eq!(ids, [2, 4, 5]);
+
+    let missing = Wardrobe::new()
+        .kind(SkinKind::Armor)
+        .weight_class(WeightClass::Heavy)
+        .missing(&client)
+        .await
+        .unwrap();
+    assert_eq!(missing.len(), 1);
+    assert_eq!(missing[0].id, 2);
+
+    let missing = Wardrobe::new()
+        .detail_type("Sword")
+        .missing(&client)
+        .await
+        .unwrap();
+    assert_eq!(missing[0].id, 5);
+
+    // Only the missing skins of the set are requested.
+    let missing = Wardrobe::new().set(&[1, 2]).missing(&client).await.unwrap();
+    assert_eq!(missing.len(), 1);
+}
+
+#[tokio::test]
+async fn test_offline_mini_collection() {
+    let mini = |id: u64, order: u64| json!({ "id": id, "name": "", "unlock": null, "icon": "", "order": order, "item_id": 0 });
+
+    let mut dataset = Dataset::new();
+    dataset.insert("/v2/account/minis", &json!([1, 3])).unwrap();
+    dataset
+        .insert(
+            "/v2/minis?ids=all",
+            &json!([mini(1, 10), mini(2, 120), mini(3, 110), mini(4, 5)]),
+        )
+        .unwrap();
+
+    let client = Client::offline(dataset).with_access_token("token");
+
+    let missing = Mini::missing_for_account(&client).await.unwrap();
+    let ids: Vec<u64> = missing.iter().map(|mini| mini.id).collect();
+    assert_eq!(ids, [4, 2]);
+
+    let collection = Mini::collection_for_account(&client).await.unwrap();
+    assert_eq!(collection.len(), 4);
+    assert!(!collection.is_completed());
+
+    let sets = collection.group_by(|mini| mini.order / 100);
+    assert_eq!(sets.len(), 2);
+    assert_eq!(sets[&0].unlocked.len(), 1);
+    assert_eq!(sets[&0].missing.len(), 1);
+    assert_eq!(sets[&1].unlocked[0].id, 3);
+    assert_eq!(sets[&1].missing[0].id, 2);
+}
+
+#[test]
+fn test_offline_export() {
+    let dataset = dataset();
+
+    let mut buf = Vec::new();
+    dataset.to_writer(&mut buf).unwrap();
+
+    let imported = Dataset::from_reader(&buf[..]).unwrap();
+    assert_eq!(imported.len(), dataset.len());
+    assert!(imported.contains("/v2/build"));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_offline_blocking() {
+    use gw2api_rs::blocking;
+
+    let client: blocking::Client = Builder::new().offline(dataset()).into();
+
+    let build = Build::get(&client).unwrap();
+    assert_eq!(build.id, 115267);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_offline_blocking_pages() {
+    use gw2api_rs::blocking;
+
+    let quaggan = |id: &str| json!({ "id": id, "url": "" });
+
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/quaggans?page=0&page_size=2",
+            &json!([quaggan("404"), quaggan("aloha")]),
+        )
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/quaggans?page=1&page_size=2",
+            &json!([quaggan("attack")]),
+        )
+        .unwrap();
+
+    let client: blocking::Client = Builder::new().offline(dataset).into();
+
+    let pages: Vec<Vec<Quaggan>> = Quaggan::pages(&client)
+        .page_size(2)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(pages.len(), 2);
+
+    let ids: Vec<String> = Quaggan::pages(&client)
+        .page_size(2)
+        .items()
+        .map(|quaggan| quaggan.unwrap().id)
+        .collect();
+    assert_eq!(ids, ["404", "aloha", "attack"]);
+
+    // Errors end the iterator.
+    let mut pages = Quaggan::pages(&client).page_size(3);
+    assert!(pages.next().unwrap().is_err());
+    assert!(pages.next().is_none());
+}
+
+#[tokio::test]
+async fn test_offline_get_many_chunked() {
+    let ids: Vec<String> = (0..250).map(|id| format!("quaggan{}", id)).collect();
+
+    let mut dataset = Dataset::new();
+    for chunk in ids.chunks(200) {
+        let quaggans: Vec<_> = chunk
+            .iter()
+            .map(|id| json!({ "id": id, "url": "" }))
+            .collect();
+        dataset
+            .insert(format!("/v2/quaggans?ids={}", chunk.join(",")), &quaggans)
+            .unwrap();
+    }
+
+    let client = Client::offline(dataset);
+    let quaggans = Quaggan::get_many_chunked(&client, &ids).await.unwrap();
+    assert_eq!(quaggans.len(), 250);
+    assert_eq!(quaggans[249].id, "quaggan249");
+}
+
+#[tokio::test]
+async fn test_offline_account_snapshot() {
+    let mut dataset = Dataset::new();
+    dataset
+        .insert(
+            "/v2/tokeninfo",
+            &json!({
+                "id": "", "name": "key", "permissions": ["account", "wallet", "inventories"],
+                "type": "APIKey",
+            }),
+        )
+        .unwrap();
+    dataset
+        .insert("/v2/account/wallet", &json!([{ "id": 1, "value": 100 }]))
+        .unwrap();
+    dataset
+        .insert(
+            "/v2/account/materials",
+            &json!([{ "id": 19721, "category": 5, "count": 250 }]),
+        )
+        .unwrap();
+
+    let client: Client = Builder::new().access_token("token").offline(dataset).into();
+
+    let snapshot = AccountSnapshot::fetch(&client).await.unwrap();
+    assert_eq!(snapshot.wallet.unwrap().len(), 1);
+    assert_eq!(snapshot.materials.unwrap().len(), 1);
+    assert!(snapshot.unlocks.is_none());
+    assert_eq!(snapshot.skipped, [SnapshotPart::Unlocks]);
+
+    // Bank and inventory are not contained in the dataset.
+    let failed: Vec<_> = snapshot
+        .failures
+        .iter()
+        .map(|failure| failure.part)
+        .collect();
+    assert_eq!(failed, [SnapshotPart::Bank, SnapshotPart::Inventory]);
+
+    let snapshot = AccountSnapshot::fetch_parts(&client, &[SnapshotPart::Wallet])
+        .await
+        .unwrap();
+    assert!(snapshot.wallet.is_some());
+    assert!(snapshot.materials.is_none());
+    assert!(snapshot.is_complete());
+}
+
+#[tokio::test]
+async fn test_offline_account_snapshot_diff() {
+    let snapshot = |wallet, bank, materials| async move {
+        let mut dataset = Dataset::new();
+        dataset
+            .insert(
+                "/v2/tokeninfo",
+                &json!({
+                    "id": "", "name": "key", "permissions": ["account", "wallet", "inventories"],
+                    "type": "APIKey",
+                }),
+            )
+            .unwrap();
+        dataset.insert("/v2/account/wallet", &wallet).unwrap();
+        dataset.insert("/v2/account/bank", &bank).unwrap();
+        dataset.insert("/v2/account/materials", &materials).unwrap();
+        dataset.insert("/v2/account/inventory", &json!([])).unwrap();
+
+        let client: Client = Builder::new().access_token("token").offline(dataset).into();
+        AccountSnapshot::fetch(&client).await.unwrap()
+    };
+
+    let previous = snapshot(
+        json!([{ "id": 1, "value": 100 }, { "id": 2, "value": 5 }]),
+        json!([{ "id": 24, "count": 10 }, null, { "id": 30, "count": 1 }]),
+        json!([{ "id": 19721, "category": 5, "count": 250 }]),
+    )
+    .await;
+    let current = snapshot(
+        json!([{ "id": 1, "value": 150 }, { "id": 2, "value": 5 }, { "id": 3, "value": 1 }]),
+        json!([{ "id": 24, "count": 10 }, { "id": 24, "count": 2 }, null]),
+        json!([{ "id": 19721, "category": 5, "count": 200 }]),
+    )
+    .await;
+
+    // Snapshots can be stored and compared later.
+    let previous: AccountSnapshot =
+        serde_json::from_str(&serde_json::to_string(&previous).unwrap()).unwrap();
+
+    let diff = AccountSnapshot::diff(&previous, &current);
+
+    let currencies: Vec<_> = diff
+        .currencies
+        .iter()
+        .map(|change| (change.id, change.delta()))
+        .collect();
+    assert_eq!(currencies, [(1, 50), (3, 1)]);
+
+    let gained: Vec<_> = diff.items_gained().map(|change| change.id).collect();
+    assert_eq!(gained, [24]);
+    let lost: Vec<_> = diff
+        .items_lost()
+        .map(|change| (change.id, change.delta()))
+        .collect();
+    assert_eq!(lost, [(30, -1), (19721, -50)]);
+
+    assert!(diff.unlocks.is_empty());
+    assert!(AccountSnapshot::diff(&current, &current).is_empty());
+}
@@ -0,0 +1,47 @@
+use chrono::{DateTime, TimeZone, Utc};
+
+use gw2api_rs::reset::{
+    daily_reset_after, next_daily_reset, next_weekly_reset, weekly_reset_after,
+};
+
+fn time(day: u32, hour: u32, min: u32) -> DateTime<Utc> {
+    // 2024-05-13 is a Monday.
+    Utc.with_ymd_and_hms(2024, 5, day, hour, min, 0).unwrap()
+}
+
+#[test]
+fn test_daily_reset() {
+    assert_eq!(daily_reset_after(time(13, 12, 0)), time(14, 0, 0));
+    assert_eq!(daily_reset_after(time(13, 23, 59)), time(14, 0, 0));
+    // Exactly at reset returns the following one.
+    assert_eq!(daily_reset_after(time(14, 0, 0)), time(15, 0, 0));
+    // Across month boundaries.
+    assert_eq!(
+        daily_reset_after(time(31, 8, 0)),
+        Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap()
+    );
+}
+
+#[test]
+fn test_weekly_reset() {
+    // Monday before reset.
+    assert_eq!(weekly_reset_after(time(13, 7, 29)), time(13, 7, 30));
+    // Monday at and after reset.
+    assert_eq!(weekly_reset_after(time(13, 7, 30)), time(20, 7, 30));
+    assert_eq!(weekly_reset_after(time(13, 12, 0)), time(20, 7, 30));
+    // Sunday.
+    assert_eq!(weekly_reset_after(time(19, 23, 0)), time(20, 7, 30));
+}
+
+#[test]
+fn test_next_reset() {
+    let now = Utc::now();
+
+    let daily = next_daily_reset();
+    assert!(daily > now);
+    assert!(daily - now <= chrono::Duration::days(1));
+
+    let weekly = next_weekly_reset();
+    assert!(weekly > now);
+    assert!(weekly - now <= chrono::Duration::weeks(1));
+}
@@ -0,0 +1,353 @@
+//! Schema tests using recorded responses from the `fixtures` directory.
+//!
+//! Every fixture is deserialized into its endpoint type, serialized and deserialized again. The
+//! test fails if any step fails or if the serialized output changes between the round trips.
+
+use std::fs;
+use std::path::PathBuf;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use gw2api_rs::v2::account::*;
+use gw2api_rs::v2::achievements::{
+    Achievement, AchievementCategory, AchievementGroup, DailyAchievements,
+};
+use gw2api_rs::v2::build::Build;
+use gw2api_rs::v2::characters::{
+    CharacterCore, CharacterCrafting, CharacterQuests, CharacterRecipes, CharacterSab,
+};
+use gw2api_rs::v2::colors::Color;
+use gw2api_rs::v2::commerce::{
+    Coins, CurrentTransactions, Delivery, Exchange, ExchangeRate, HistoryTransactions, Listings,
+    Prices,
+};
+use gw2api_rs::v2::common::{self, ChatLink, EliteSpecialization, ItemLink, Rarity};
+use gw2api_rs::v2::currencies::Currency;
+use gw2api_rs::v2::dungeons::Dungeon;
+use gw2api_rs::v2::emotes::Emote;
+use gw2api_rs::v2::files::File;
+use gw2api_rs::v2::finishers::Finisher;
+use gw2api_rs::v2::gliders::Glider;
+use gw2api_rs::v2::guild::{
+    Guild, GuildDetails, GuildMembers, GuildRanks, GuildStorage, GuildTeams, GuildTreasury,
+    GuildUpgrade,
+};
+use gw2api_rs::v2::home::{Cat, Node};
+use gw2api_rs::v2::homestead::{Decoration, DecorationCategory, Glyph};
+use gw2api_rs::v2::items::Item;
+use gw2api_rs::v2::jadebots::JadeBot;
+use gw2api_rs::v2::legendaryarmory::LegendaryItem;
+use gw2api_rs::v2::maps::Map;
+use gw2api_rs::v2::materials::MaterialCategory;
+use gw2api_rs::v2::minis::Mini;
+use gw2api_rs::v2::mounts::Mount;
+use gw2api_rs::v2::novelties::Novelty;
+use gw2api_rs::v2::outfits::Outfit;
+use gw2api_rs::v2::professions::Profession;
+use gw2api_rs::v2::pvp::{Amulet, Game, Hero, Leaderboard, PvpSeason, Rank as PvPRank, Standings};
+use gw2api_rs::v2::quaggans::Quaggan;
+use gw2api_rs::v2::quests::Quest;
+use gw2api_rs::v2::raids::Raid;
+use gw2api_rs::v2::recipes::Recipe;
+use gw2api_rs::v2::skiffs::Skiff;
+use gw2api_rs::v2::skins::Skin;
+use gw2api_rs::v2::stories::{Story, StorySeason};
+use gw2api_rs::v2::titles::Title;
+use gw2api_rs::v2::tokeninfo::TokenInfo;
+use gw2api_rs::v2::worlds::World;
+use gw2api_rs::v2::wvw::{
+    Ability, Guilds, Match, Rank, TeamColor, Timers, UpgradeProgress, Upgrades,
+};
+
+/// Loads the fixture with the given `name`.
+fn fixture(name: &str) -> String {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "fixtures", name]
+        .iter()
+        .collect();
+
+    fs::read_to_string(&path).unwrap_or_else(|err| panic!("failed to read {:?}: {}", path, err))
+}
+
+fn round_trip<T>(name: &str)
+where
+    T: Serialize + DeserializeOwned,
+{
+    let input = fixture(name);
+
+    let value: T = serde_json::from_str(&input)
+        .unwrap_or_else(|err| panic!("failed to deserialize {}: {}", name, err));
+    let first: Value = serde_json::to_value(&value).unwrap();
+
+    let value: T = serde_json::from_value(first.clone())
+        .unwrap_or_else(|err| panic!("failed to deserialize serialized {}: {}", name, err));
+    let second: Value = serde_json::to_value(&value).unwrap();
+
+    assert_eq!(first, second, "{} changed during round trip", name);
+}
+
+macro_rules! schema {
+    ($($test:ident: $ty:ty => $fixture:expr,)*) => {
+        $(
+            #[test]
+            fn $test() {
+                round_trip::<$ty>($fixture);
+            }
+        )*
+    };
+}
+
+schema! {
+    test_account: Account => "account.json",
+    test_account_achievements: AccountAchievements => "account_achievements.json",
+    test_account_bank: AccountBank => "account_bank.json",
+    test_account_dailycrafting: AccountDailyCrafting => "account_dailycrafting.json",
+    test_account_dungeons: AccountDungeons => "account_dungeons.json",
+    test_account_dyes: AccountDyes => "account_dyes.json",
+    test_account_emotes: AccountEmotes => "account_emotes.json",
+    test_account_finishers: AccountFinishers => "account_finishers.json",
+    test_account_gliders: AccountGliders => "account_gliders.json",
+    test_account_home_cats: AccountHomeCats => "account_home_cats.json",
+    test_account_home_nodes: AccountHomeNodes => "account_home_nodes.json",
+    test_account_homestead_decorations: AccountHomesteadDecorations => "account_homestead_decorations.json",
+    test_account_homestead_glyphs: AccountHomesteadGlyphs => "account_homestead_glyphs.json",
+    test_account_inventory: AccountInventory => "account_inventory.json",
+    test_account_jadebots: AccountJadeBots => "account_jadebots.json",
+    test_account_legendaryarmory: AccountLegendaryArmory => "account_legendaryarmory.json",
+    test_account_luck: AccountLuck => "account_luck.json",
+    test_account_mailcarriers: AccountMailCarriers => "account_mailcarriers.json",
+    test_account_mapchests: AccountMapChests => "account_mapchests.json",
+    test_account_masteries: AccountMasteries => "account_masteries.json",
+    test_account_mastery_points: AccountMasteryPoints => "account_mastery_points.json",
+    test_account_materials: AccountMaterials => "account_materials.json",
+    test_account_minis: AccountMinis => "account_minis.json",
+    test_account_mount_skins: AccountMountSkins => "account_mount_skins.json",
+    test_account_mount_types: AccountMountTypes => "account_mount_types.json",
+    test_account_novelties: AccountNovelties => "account_novelties.json",
+    test_account_outfits: AccountOutfits => "account_outfits.json",
+    test_account_progression: AccountProgression => "account_progression.json",
+    test_account_pvp_heroes: AccountPvPHeroes => "account_pvp_heroes.json",
+    test_account_raids: AccountRaids => "account_raids.json",
+    test_account_recipes: AccountRecipes => "account_recipes.json",
+    test_account_skiffs: AccountSkiffs => "account_skiffs.json",
+    test_account_skins: AccountSkins => "account_skins.json",
+    test_account_titles: AccountTitles => "account_titles.json",
+    test_account_wallet: AccountWallet => "account_wallet.json",
+    test_account_worldbosses: AccountWorldBosses => "account_worldbosses.json",
+    test_account_wvw: AccountWvw => "account_wvw.json",
+    test_achievements: Achievement => "achievements.json",
+    test_achievements_categories: AchievementCategory => "achievements_categories.json",
+    test_achievements_daily: DailyAchievements => "achievements_daily.json",
+    test_achievements_groups: AchievementGroup => "achievements_groups.json",
+    test_build: Build => "build.json",
+    test_characters_core: CharacterCore => "characters_core.json",
+    test_characters_crafting: CharacterCrafting => "characters_crafting.json",
+    test_characters_quests: CharacterQuests => "characters_quests.json",
+    test_characters_recipes: CharacterRecipes => "characters_recipes.json",
+    test_characters_sab: CharacterSab => "characters_sab.json",
+    test_colors: Color => "colors.json",
+    test_commerce_delivery: Delivery => "commerce_delivery.json",
+    test_commerce_exchange: Exchange => "commerce_exchange.json",
+    test_commerce_listings: Listings => "commerce_listings.json",
+    test_commerce_prices: Prices => "commerce_prices.json",
+    test_commerce_transactions_current: CurrentTransactions => "commerce_transactions_current.json",
+    test_commerce_transactions_history: HistoryTransactions => "commerce_transactions_history.json",
+    test_currencies: Currency => "currencies.json",
+    test_dungeons: Dungeon => "dungeons.json",
+    test_emotes: Emote => "emotes.json",
+    test_files: File => "files.json",
+    test_finishers: Finisher => "finishers.json",
+    test_gliders: Glider => "gliders.json",
+    test_guild: Guild => "guild.json",
+    test_guild_details: GuildDetails => "guild_details.json",
+    test_guild_members: GuildMembers => "guild_members.json",
+    test_guild_ranks: GuildRanks => "guild_ranks.json",
+    test_guild_teams: GuildTeams => "guild_teams.json",
+    test_guild_storage: GuildStorage => "guild_storage.json",
+    test_guild_treasury: GuildTreasury => "guild_treasury.json",
+    test_guild_upgrades: GuildUpgrade => "guild_upgrades.json",
+    test_home_cats: Cat => "home_cats.json",
+    test_home_nodes: Node => "home_nodes.json",
+    test_homestead_decorations: Decoration => "homestead_decorations.json",
+    test_homestead_decorations_categories: DecorationCategory => "homestead_decorations_categories.json",
+    test_homestead_glyphs: Glyph => "homestead_glyphs.json",
+    test_items: Item => "items.json",
+    test_jadebots: JadeBot => "jadebots.json",
+    test_legendaryarmory: LegendaryItem => "legendaryarmory.json",
+    test_maps: Map => "maps.json",
+    test_materials: MaterialCategory => "materials.json",
+    test_minis: Mini => "minis.json",
+    test_mounts_types: Mount => "mounts_types.json",
+    test_novelties: Novelty => "novelties.json",
+    test_outfits: Outfit => "outfits.json",
+    test_professions: Profession => "professions.json",
+    test_pvp_amulets: Amulet => "pvp_amulets.json",
+    test_pvp_games: Game => "pvp_games.json",
+    test_pvp_heroes: Hero => "pvp_heroes.json",
+    test_pvp_leaderboard: Leaderboard => "pvp_leaderboard.json",
+    test_pvp_ranks: PvPRank => "pvp_ranks.json",
+    test_pvp_seasons: PvpSeason => "pvp_seasons.json",
+    test_pvp_standings: Standings => "pvp_standings.json",
+    test_quaggans: Quaggan => "quaggans.json",
+    test_quests: Quest => "quests.json",
+    test_raids: Raid => "raids.json",
+    test_recipes: Recipe => "recipes.json",
+    test_skiffs: Skiff => "skiffs.json",
+    test_skins: Skin => "skins.json",
+    test_stories: Story => "stories.json",
+    test_stories_seasons: StorySeason => "stories_seasons.json",
+    test_titles: Title => "titles.json",
+    test_tokeninfo: TokenInfo => "tokeninfo.json",
+    test_tokeninfo_subtoken: TokenInfo => "tokeninfo_subtoken.json",
+    test_worlds: World => "worlds.json",
+    test_wvw_abilities: Ability => "wvw_abilities.json",
+    test_wvw_guilds: Guilds => "wvw_guilds.json",
+    test_wvw_matches: Match => "wvw_matches.json",
+    test_wvw_ranks: Rank => "wvw_ranks.json",
+    test_wvw_timers: Timers => "wvw_timers.json",
+    test_wvw_upgrades: Upgrades => "wvw_upgrades.json",
+}
+
+#[cfg(feature = "strict-schema")]
+#[test]
+fn test_strict_schema_unknown_field() {
+    // The details of a guild contain all fields of a guild and the fields only available to
+    // guild leaders.
+    let input = fixture("guild_details.json");
+    assert!(serde_json::from_str::<Guild>(&input).is_err());
+}
+
+#[test]
+fn test_chat_links() {
+    let links = [
+        ("[&AdsnAAA=]", ChatLink::Coin(10203)),
+        (
+            "[&AgGqtgDgfQ4AAP9fAAAnYAAA]",
+            ChatLink::Item(ItemLink {
+                id: 46762,
+                count: 1,
+                skin: Some(3709),
+                upgrades: [Some(24575), Some(24615)],
+            }),
+        ),
+        ("[&BDgAAAA=]", ChatLink::Map(56)),
+        ("[&CZ8cAAA=]", ChatLink::Recipe(7327)),
+        (
+            "[&DAYAAAAmAAAA]",
+            ChatLink::Objective {
+                map: 38,
+                objective: 6,
+            },
+        ),
+        (
+            "[&DQQAAAA=]",
+            ChatLink::Other {
+                header: 0x0D,
+                data: vec![4, 0, 0, 0],
+            },
+        ),
+    ];
+
+    for (input, link) in links {
+        assert_eq!(input.parse::<ChatLink>().unwrap(), link);
+        assert_eq!(link.to_string(), input);
+    }
+
+    for input in [
+        "",
+        "[&]",
+        "[&AgE=]",
+        "[&AdsnAAAA]",
+        "AgEYAAAA",
+        "[&not base64]",
+    ] {
+        assert!(input.parse::<ChatLink>().is_err(), "{}", input);
+    }
+}
+
+#[test]
+fn test_chat_link_raw() {
+    let link: ChatLink = serde_json::from_str(r#""[&AgE=]""#).unwrap();
+    assert_eq!(link, ChatLink::Raw(String::from("[&AgE=]")));
+    assert_eq!(link.to_bytes(), None);
+    assert_eq!(serde_json::to_string(&link).unwrap(), r#""[&AgE=]""#);
+
+    let link: ChatLink = serde_json::from_str(r#""[&AgEYAAAA]""#).unwrap();
+    assert_eq!(link, ChatLink::Item(ItemLink::new(24)));
+}
+
+#[test]
+fn test_rarity_order() {
+    let unknown: Rarity = serde_json::from_str(r#""Mythic""#).unwrap();
+    assert_eq!(unknown, Rarity::Unknown);
+    assert!(unknown < Rarity::Junk);
+    assert!(Rarity::Exotic < Rarity::Ascended);
+    assert!(Rarity::Ascended < Rarity::Legendary);
+}
+
+#[test]
+fn test_elite_specialization_unknown() {
+    let spec: EliteSpecialization = serde_json::from_str(r#""Luminary""#).unwrap();
+    assert_eq!(spec, EliteSpecialization::Unknown);
+    assert_eq!(spec.id(), None);
+    assert_eq!(spec.profession(), common::Profession::Unknown);
+    assert_eq!(EliteSpecialization::Untamed.id(), Some(72));
+}
+
+#[test]
+fn test_team_color_unknown() {
+    let color: TeamColor = serde_json::from_str(r#""Purple""#).unwrap();
+    assert_eq!(color, TeamColor::Unknown);
+    assert_eq!(color.rgb(), TeamColor::Neutral.rgb());
+}
+
+#[test]
+fn test_exchange_rate_coins_for() {
+    let rate = ExchangeRate {
+        coins_to_gems: Exchange {
+            coins_per_gem: Coins(2531),
+            quantity: 395,
+        },
+        gems_to_coins: Exchange {
+            coins_per_gem: Coins(1862),
+            quantity: 186_250,
+        },
+    };
+
+    // The per-gem price is truncated, the amount of coins for many gems is not.
+    assert_eq!(rate.sell_price(), Coins(1862));
+    assert_eq!(rate.coins_for(10), Coins(18625));
+    assert_eq!(rate.coins_for(0), Coins::ZERO);
+}
+
+#[test]
+fn test_upgrade_progress_fraction() {
+    // Progress built by hand may be inconsistent, but never underflows.
+    let progress = UpgradeProgress {
+        tier: 1,
+        yaks_delivered: 10,
+        tier_yaks: 20,
+        next_tier_yaks: Some(60),
+    };
+    assert_eq!(progress.fraction(), 0.0);
+
+    let progress = UpgradeProgress {
+        tier: 1,
+        yaks_delivered: 80,
+        tier_yaks: 20,
+        next_tier_yaks: Some(20),
+    };
+    assert_eq!(progress.fraction(), 1.0);
+}
+
+#[test]
+fn test_coins_parse() {
+    assert_eq!("12g 34s 56c".parse(), Ok(Coins(123456)));
+    assert_eq!("5s".parse(), Ok(Coins(500)));
+    assert_eq!("42".parse(), Ok(Coins(42)));
+
+    for input in ["", "g", "1s 2g", "12€", "5ü", "3 €c", "1x"] {
+        assert!(input.parse::<Coins>().is_err(), "{}", input);
+    }
+}
@@ -2,13 +2,15 @@ mod support;
 
 mod account {
     use gw2api_rs::v2::account::{
-        Account, AccountAchievements, AccountBank, AccountDailyCrafting, AccountDungeons,
-        AccountDyes, AccountFinishers, AccountGliders, AccountHomeCats, AccountHomeNodes,
-        AccountInventory, AccountLegendaryArmory, AccountLuck, AccountMailCarriers,
-        AccountMapChests, AccountMasteries, AccountMasteryPoints, AccountMaterials, AccountMinis,
-        AccountMountSkins, AccountMountTypes, AccountNovelties, AccountOutfits, AccountProgression,
-        AccountPvPHeroes, AccountRaids, AccountRecipes, AccountSkins, AccountTitles, AccountWallet,
-        AccountWorldBosses,
+        material_categories, Account, AccountAchievements, AccountBank, AccountDailyCrafting,
+        AccountDungeons, AccountDyes, AccountEmotes, AccountFinishers, AccountGliders,
+        AccountHomeCats, AccountHomeNodes, AccountHomesteadDecorations, AccountHomesteadGlyphs,
+        AccountInventory, AccountJadeBots, AccountLegendaryArmory, AccountLuck,
+        AccountMailCarriers, AccountMapChests, AccountMasteries, AccountMasteryPoints,
+        AccountMaterials, AccountMinis, AccountMountSkins, AccountMountTypes, AccountNovelties,
+        AccountOutfits, AccountProgression, AccountPvPHeroes, AccountRaids, AccountRecipes,
+        AccountSkiffs, AccountSkins, AccountTitles, AccountWallet, AccountWorldBosses, AccountWvw,
+        ResolveUnlocks, Wardrobe,
     };
 
     use crate::support::CLIENT;
@@ -26,6 +28,7 @@ mod account {
     #[test]
     fn test_account_bank() {
         AccountBank::get(&*CLIENT).unwrap();
+        AccountBank::resolve(&*CLIENT).unwrap();
     }
 
     #[test]
@@ -36,36 +39,66 @@ mod account {
     #[test]
     fn test_account_dungeons() {
         AccountDungeons::get(&*CLIENT).unwrap();
+        AccountDungeons::get_completion(&*CLIENT).unwrap();
     }
 
     #[test]
     fn test_account_dyes() {
         AccountDyes::get(&*CLIENT).unwrap();
+        AccountDyes::resolve_unlocks(&*CLIENT).unwrap();
+    }
+
+    #[test]
+    fn test_account_emotes() {
+        AccountEmotes::get(&*CLIENT).unwrap();
     }
 
     #[test]
     fn test_account_finishers() {
         AccountFinishers::get(&*CLIENT).unwrap();
+        AccountFinishers::resolve_unlocks(&*CLIENT).unwrap();
     }
 
     #[test]
     fn test_account_gliders() {
         AccountGliders::get(&*CLIENT).unwrap();
+        AccountGliders::resolve_unlocks(&*CLIENT).unwrap();
     }
 
     #[test]
     fn test_account_home_cats() {
         AccountHomeCats::get(&*CLIENT).unwrap();
+        AccountHomeCats::resolve_unlocks(&*CLIENT).unwrap();
     }
 
     #[test]
     fn test_account_home_nodes() {
         AccountHomeNodes::get(&*CLIENT).unwrap();
+        AccountHomeNodes::resolve_unlocks(&*CLIENT).unwrap();
+    }
+
+    #[test]
+    fn test_account_homestead_decorations() {
+        AccountHomesteadDecorations::get(&*CLIENT).unwrap();
+        AccountHomesteadDecorations::resolve_unlocks(&*CLIENT).unwrap();
+    }
+
+    #[test]
+    fn test_account_homestead_glyphs() {
+        AccountHomesteadGlyphs::get(&*CLIENT).unwrap();
+        AccountHomesteadGlyphs::resolve_unlocks(&*CLIENT).unwrap();
     }
 
     #[test]
     fn test_account_inventory() {
         AccountInventory::get(&*CLIENT).unwrap();
+        AccountInventory::resolve(&*CLIENT).unwrap();
+    }
+
+    #[test]
+    fn test_account_jadebots() {
+        AccountJadeBots::get(&*CLIENT).unwrap();
+        AccountJadeBots::resolve_unlocks(&*CLIENT).unwrap();
     }
 
     #[test]
@@ -103,6 +136,31 @@ mod account {
         AccountMaterials::get(&*CLIENT).unwrap();
     }
 
+    #[test]
+    fn test_material_categories() {
+        let materials = AccountMaterials::get(&*CLIENT).unwrap();
+
+        for category in [
+            material_categories::COOKING_MATERIALS,
+            material_categories::COMMON_CRAFTING_MATERIALS,
+            material_categories::FINE_CRAFTING_MATERIALS,
+            material_categories::GEMSTONES_AND_JEWELS,
+            material_categories::RARE_CRAFTING_MATERIALS,
+            material_categories::FESTIVE_MATERIALS,
+            material_categories::ASCENDED_MATERIALS,
+            material_categories::COOKING_INGREDIENTS,
+            material_categories::SCRIBING_MATERIALS,
+        ] {
+            assert!(
+                materials
+                    .iter()
+                    .any(|material| material.category == category),
+                "missing material category {}",
+                category
+            );
+        }
+    }
+
     #[test]
     fn test_account_minis() {
         AccountMinis::get(&*CLIENT).unwrap();
@@ -121,6 +179,7 @@ mod account {
     #[test]
     fn test_account_novelties() {
         AccountNovelties::get(&*CLIENT).unwrap();
+        AccountNovelties::resolve_unlocks(&*CLIENT).unwrap();
     }
 
     #[test]
@@ -136,11 +195,13 @@ mod account {
     #[test]
     fn test_account_pvp_heroes() {
         AccountPvPHeroes::get(&*CLIENT).unwrap();
+        AccountPvPHeroes::resolve_unlocks(&*CLIENT).unwrap();
     }
 
     #[test]
     fn test_account_raids() {
         AccountRaids::get(&*CLIENT).unwrap();
+        AccountRaids::get_completion(&*CLIENT).unwrap();
     }
 
     #[test]
@@ -148,11 +209,22 @@ mod account {
         AccountRecipes::get(&*CLIENT).unwrap();
     }
 
+    #[test]
+    fn test_account_skiffs() {
+        AccountSkiffs::get(&*CLIENT).unwrap();
+        AccountSkiffs::resolve_unlocks(&*CLIENT).unwrap();
+    }
+
     #[test]
     fn test_account_skins() {
         AccountSkins::get(&*CLIENT).unwrap();
     }
 
+    #[test]
+    fn test_wardrobe_missing() {
+        Wardrobe::new().set(&[4678]).missing(&*CLIENT).unwrap();
+    }
+
     #[test]
     fn test_account_titles() {
         AccountTitles::get(&*CLIENT).unwrap();
@@ -167,10 +239,19 @@ mod account {
     fn test_account_world_bosses() {
         AccountWorldBosses::get(&*CLIENT).unwrap();
     }
+
+    #[test]
+    fn test_account_wvw() {
+        AccountWvw::get(&*CLIENT).unwrap();
+        Account::wvw_team(&*CLIENT).unwrap();
+    }
 }
 
 mod achievements {
-    use gw2api_rs::v2::achievements::Achievement;
+    use gw2api_rs::v2::achievements::{
+        Achievement, AchievementCategory, AchievementGroup, AchievementPoints, AchievementTree,
+        DailyAchievements, DailyFractals,
+    };
 
     use crate::support::CLIENT;
 
@@ -180,6 +261,41 @@ mod achievements {
         Achievement::get(&*CLIENT, 4219).unwrap();
         Achievement::get(&*CLIENT, 6395).unwrap();
     }
+
+    #[test]
+    fn test_achievement_categories() {
+        AchievementCategory::ids(&*CLIENT).unwrap();
+        AchievementCategory::get(&*CLIENT, AchievementCategory::DAILY_FRACTALS).unwrap();
+    }
+
+    #[test]
+    fn test_achievement_groups() {
+        AchievementGroup::ids(&*CLIENT).unwrap();
+        AchievementGroup::get_all(&*CLIENT).unwrap();
+    }
+
+    #[test]
+    fn test_achievement_tree() {
+        let tree = AchievementTree::build(&*CLIENT).unwrap();
+        assert!(!tree.groups.is_empty());
+    }
+
+    #[test]
+    fn test_achievement_points() {
+        let points = AchievementPoints::get(&*CLIENT).unwrap();
+        assert!(points.total() >= points.achievements);
+    }
+
+    #[test]
+    fn test_daily_achievements() {
+        DailyAchievements::get(&*CLIENT).unwrap();
+        DailyAchievements::for_account(&*CLIENT).unwrap();
+    }
+
+    #[test]
+    fn test_daily_fractals() {
+        DailyFractals::get(&*CLIENT).unwrap();
+    }
 }
 
 mod build {
@@ -193,22 +309,55 @@ mod build {
     }
 }
 
+mod characters {
+    use gw2api_rs::v2::characters::{
+        CharacterCore, CharacterCrafting, CharacterQuests, CharacterRecipes, CharacterSab,
+    };
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_characters() {
+        let names = CharacterCore::ids(&*CLIENT).unwrap();
+        CharacterCore::get_all(&*CLIENT).unwrap();
+
+        if let Some(name) = names.first() {
+            CharacterCore::get(&*CLIENT, name).unwrap();
+            CharacterSab::get(&*CLIENT, name).unwrap();
+            CharacterCrafting::get(&*CLIENT, name).unwrap();
+            CharacterRecipes::get(&*CLIENT, name).unwrap();
+            CharacterQuests::get(&*CLIENT, name).unwrap();
+        }
+    }
+}
+
 mod colors {
-    use gw2api_rs::v2::colors::Color;
+    use gw2api_rs::v2::colors::{Color, ColorFilter, ColorHue};
 
     use crate::support::CLIENT;
 
     #[test]
     fn test_colors() {
         Color::ids(&*CLIENT).unwrap();
-        Color::get_all(&*CLIENT).unwrap();
+        let colors = Color::get_all(&*CLIENT).unwrap();
+        assert!(Color::closest_to(&colors, [255, 0, 0]).is_some());
+    }
+
+    #[test]
+    fn test_colors_search() {
+        let colors = Color::search(&*CLIENT, ColorFilter::new().hue(ColorHue::Red)).unwrap();
+        assert!(colors
+            .iter()
+            .all(|c| c.categories.unwrap().hue == ColorHue::Red));
     }
 }
 
 mod commerce {
+    use gw2api_rs::market;
     use gw2api_rs::v2::commerce::{
-        CurrentTransactions, Delivery, Exchange, HistoryTransactions, Listings, Prices,
+        Coins, CurrentTransactions, Delivery, Exchange, HistoryTransactions, Listings, Prices,
     };
+    use gw2api_rs::v2::items::ids;
 
     use crate::support::CLIENT;
 
@@ -219,8 +368,11 @@ mod commerce {
 
     #[test]
     fn test_exchange() {
-        Exchange::coins(&*CLIENT, 10000).unwrap();
+        Exchange::coins(&*CLIENT, Coins::from_parts(1, 0, 0)).unwrap();
         Exchange::gems(&*CLIENT, 10000).unwrap();
+
+        let rate = Exchange::rate(&*CLIENT).unwrap();
+        assert!(rate.buy_price() >= rate.sell_price());
     }
 
     #[test]
@@ -230,7 +382,21 @@ mod commerce {
 
     #[test]
     fn test_prices() {
-        Prices::ids(&*CLIENT).unwrap();
+        let ids = Prices::ids(&*CLIENT).unwrap();
+        let prices = Prices::get_many(&*CLIENT, &ids[..10]).unwrap();
+        assert_eq!(prices.len(), 10);
+    }
+
+    #[test]
+    fn test_quote() {
+        // Glob of Ectoplasm
+        let quote = market::quote(&*CLIENT, 19721).unwrap();
+        assert!(quote.prices.is_some());
+
+        // Mystic Clover is account bound and not traded.
+        let quotes = market::quote_many(&*CLIENT, &[19721, ids::MYSTIC_CLOVER]).unwrap();
+        assert_eq!(quotes.len(), 2);
+        assert!(quotes[1].prices.is_none());
     }
 
     #[test]
@@ -247,7 +413,7 @@ mod commerce {
 }
 
 mod currencies {
-    use gw2api_rs::v2::currencies::Currency;
+    use gw2api_rs::v2::currencies::{ids, Currency};
 
     use crate::support::CLIENT;
 
@@ -256,6 +422,51 @@ mod currencies {
         Currency::ids(&*CLIENT).unwrap();
         Currency::get_all(&*CLIENT).unwrap();
     }
+
+    #[test]
+    fn test_currency_ids() {
+        let expected = [
+            (ids::COIN, "Coin"),
+            (ids::KARMA, "Karma"),
+            (ids::LAUREL, "Laurel"),
+            (ids::GEM, "Gem"),
+            (ids::FRACTAL_RELIC, "Fractal Relic"),
+            (ids::BADGE_OF_HONOR, "Badge of Honor"),
+            (ids::GUILD_COMMENDATION, "Guild Commendation"),
+            (ids::TRANSMUTATION_CHARGE, "Transmutation Charge"),
+            (ids::SPIRIT_SHARD, "Spirit Shard"),
+            (ids::PRISTINE_FRACTAL_RELIC, "Pristine Fractal Relic"),
+            (ids::MAGNETITE_SHARD, "Magnetite Shard"),
+            (ids::UNBOUND_MAGIC, "Unbound Magic"),
+            (ids::VOLATILE_MAGIC, "Volatile Magic"),
+            (ids::RESEARCH_NOTE, "Research Note"),
+            (ids::ASTRAL_ACCLAIM, "Astral Acclaim"),
+        ];
+
+        let ids: Vec<u64> = expected.iter().map(|(id, _)| *id).collect();
+        let currencies = Currency::get_many(&*CLIENT, &ids).unwrap();
+
+        for (id, name) in expected {
+            let currency = currencies
+                .iter()
+                .find(|currency| currency.id == id)
+                .unwrap();
+            assert_eq!(currency.name, name);
+        }
+    }
+}
+
+mod dailycrafting {
+    use gw2api_rs::v2::dailycrafting::DailyCrafting;
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_dailycrafting() {
+        DailyCrafting::ids(&*CLIENT).unwrap();
+        DailyCrafting::get_all(&*CLIENT).unwrap();
+        DailyCrafting::remaining(&*CLIENT).unwrap();
+    }
 }
 
 mod dungeons {
@@ -270,6 +481,38 @@ mod dungeons {
     }
 }
 
+mod emblem {
+    use gw2api_rs::v2::emblem::{EmblemBackground, EmblemForeground};
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_emblem() {
+        EmblemBackground::ids(&*CLIENT).unwrap();
+        EmblemBackground::get(&*CLIENT, 1).unwrap();
+
+        EmblemForeground::ids(&*CLIENT).unwrap();
+        EmblemForeground::get(&*CLIENT, 1).unwrap();
+
+        // The emblem endpoints reject `ids=all`.
+        EmblemForeground::get_all(&*CLIENT).unwrap();
+    }
+}
+
+mod emotes {
+    use gw2api_rs::v2::emotes::Emote;
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_emotes() {
+        Emote::ids(&*CLIENT).unwrap();
+
+        let emote = Emote::get(&*CLIENT, String::from("bless")).unwrap();
+        emote.unlock_items(&*CLIENT).unwrap();
+    }
+}
+
 mod files {
     use gw2api_rs::v2::files::File;
 
@@ -282,8 +525,34 @@ mod files {
     }
 }
 
+mod finishers {
+    use gw2api_rs::v2::finishers::Finisher;
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_finishers() {
+        Finisher::ids(&*CLIENT).unwrap();
+        Finisher::get_all(&*CLIENT).unwrap();
+    }
+}
+
+mod gliders {
+    use gw2api_rs::v2::gliders::Glider;
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_gliders() {
+        Glider::ids(&*CLIENT).unwrap();
+        Glider::get_all(&*CLIENT).unwrap();
+    }
+}
+
 mod guild {
-    use gw2api_rs::v2::guild::{Guild, GuildMembers, GuildRanks};
+    use gw2api_rs::v2::guild::{
+        Guild, GuildMembers, GuildRanks, GuildStash, GuildTeams, GuildTreasury, GuildUpgrade,
+    };
 
     use crate::support::CLIENT;
 
@@ -295,10 +564,29 @@ mod guild {
         Guild::get(&*CLIENT, GUILD_ID).unwrap();
     }
 
+    #[test]
+    fn test_guild_authenticated() {
+        Guild::get_authenticated(&*CLIENT, GUILD_ID).unwrap();
+    }
+
+    #[test]
+    fn test_guild_emblem() {
+        let guild = Guild::get(&*CLIENT, GUILD_ID).unwrap();
+        guild.emblem.resolve(&*CLIENT).unwrap();
+    }
+
     #[test]
     fn test_guild_search() {
         let guilds = Guild::search(&*CLIENT, GUILD_NAME).unwrap();
         assert_eq!(guilds, [GUILD_ID]);
+
+        let guilds = Guild::get_by_name(&*CLIENT, GUILD_NAME).unwrap();
+        assert_eq!(guilds.len(), 1);
+        assert_eq!(guilds[0].id, GUILD_ID);
+
+        let guilds = Guild::search_detailed(&*CLIENT, GUILD_NAME).unwrap();
+        assert_eq!(guilds.len(), 1);
+        assert_eq!(guilds.0[0].id, GUILD_ID);
     }
 
     #[test]
@@ -310,6 +598,181 @@ mod guild {
     fn test_guild_ranks() {
         GuildRanks::get(&*CLIENT, GUILD_ID).unwrap();
     }
+
+    #[test]
+    fn test_guild_stash() {
+        GuildStash::get(&*CLIENT, GUILD_ID).unwrap();
+        GuildStash::get_value(&*CLIENT, GUILD_ID).unwrap();
+    }
+
+    #[test]
+    fn test_guild_teams() {
+        GuildTeams::get(&*CLIENT, GUILD_ID).unwrap();
+    }
+
+    #[test]
+    fn test_guild_treasury() {
+        GuildTreasury::get(&*CLIENT, GUILD_ID).unwrap();
+    }
+
+    #[test]
+    fn test_guild_upgrades() {
+        GuildUpgrade::ids(&*CLIENT).unwrap();
+        GuildUpgrade::get_all(&*CLIENT).unwrap();
+        GuildUpgrade::get_remaining_costs(&*CLIENT, GUILD_ID, 38).unwrap();
+    }
+
+    #[test]
+    fn test_guild_roster() {
+        Guild::roster(&*CLIENT, GUILD_ID).unwrap();
+    }
+}
+
+mod home {
+    use gw2api_rs::v2::home::{Cat, Node};
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_cats() {
+        Cat::ids(&*CLIENT).unwrap();
+        Cat::get_all(&*CLIENT).unwrap();
+    }
+
+    #[test]
+    fn test_nodes() {
+        Node::ids(&*CLIENT).unwrap();
+        Node::get_all(&*CLIENT).unwrap();
+    }
+}
+
+mod homestead {
+    use gw2api_rs::v2::homestead::{Decoration, DecorationCategory, Glyph};
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_decorations() {
+        Decoration::ids(&*CLIENT).unwrap();
+        Decoration::get_all(&*CLIENT).unwrap();
+    }
+
+    #[test]
+    fn test_decoration_categories() {
+        DecorationCategory::ids(&*CLIENT).unwrap();
+        DecorationCategory::get_all(&*CLIENT).unwrap();
+    }
+
+    #[test]
+    fn test_glyphs() {
+        Glyph::ids(&*CLIENT).unwrap();
+        Glyph::get_all(&*CLIENT).unwrap();
+    }
+}
+
+mod items {
+    use gw2api_rs::v2::items::{ids, Item};
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_items() {
+        let ids = Item::ids(&*CLIENT).unwrap();
+        Item::get(&*CLIENT, ids[0]).unwrap();
+        Item::get_many(&*CLIENT, &ids[..200]).unwrap();
+    }
+
+    #[test]
+    fn test_item_ids() {
+        let expected = [
+            (ids::MYSTIC_CLOVER, "Mystic Clover"),
+            (ids::GLOB_OF_ECTOPLASM, "Glob of Ectoplasm"),
+            (ids::OBSIDIAN_SHARD, "Obsidian Shard"),
+            (ids::MYSTIC_COIN, "Mystic Coin"),
+            (ids::PHILOSOPHERS_STONE, "Philosopher's Stone"),
+            (ids::AMALGAMATED_GEMSTONE, "Amalgamated Gemstone"),
+        ];
+
+        let ids: Vec<u64> = expected.iter().map(|(id, _)| *id).collect();
+        let items = Item::get_many(&*CLIENT, &ids).unwrap();
+
+        for (id, name) in expected {
+            let item = items.iter().find(|item| item.id == id).unwrap();
+            assert_eq!(item.name, name);
+        }
+    }
+}
+
+mod jadebots {
+    use gw2api_rs::v2::jadebots::JadeBot;
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_jadebots() {
+        JadeBot::ids(&*CLIENT).unwrap();
+        JadeBot::get_all(&*CLIENT).unwrap();
+    }
+}
+
+mod legendaryarmory {
+    use gw2api_rs::v2::legendaryarmory::LegendaryItem;
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_legendaryarmory() {
+        LegendaryItem::ids(&*CLIENT).unwrap();
+        LegendaryItem::get_all(&*CLIENT).unwrap();
+    }
+}
+
+mod mapchests {
+    use gw2api_rs::v2::mapchests::MapChest;
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_mapchests() {
+        MapChest::ids(&*CLIENT).unwrap();
+        MapChest::get_all(&*CLIENT).unwrap();
+        MapChest::remaining(&*CLIENT).unwrap();
+    }
+}
+
+mod maps {
+    use gw2api_rs::v2::maps::Map;
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_maps() {
+        Map::ids(&*CLIENT).unwrap();
+
+        // Queensdale
+        let map = Map::get(&*CLIENT, 15).unwrap();
+        let center = map.continent_rect.center();
+        let point = map.continent_to_map(center);
+        assert_eq!(map.map_to_continent(point), center);
+
+        let maps = Map::by_continent(&*CLIENT, 1).unwrap();
+        assert!(maps.iter().any(|m| m.id == 15));
+
+        let maps = Map::at(&*CLIENT, 1, center).unwrap();
+        assert!(maps.iter().any(|m| m.id == 15));
+    }
+}
+
+mod materials {
+    use gw2api_rs::v2::materials::MaterialCategory;
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_materials() {
+        MaterialCategory::ids(&*CLIENT).unwrap();
+        MaterialCategory::get_all(&*CLIENT).unwrap();
+    }
 }
 
 mod minis {
@@ -322,6 +785,26 @@ mod minis {
         Mini::ids(&*CLIENT).unwrap();
         Mini::get_all(&*CLIENT).unwrap();
     }
+
+    #[test]
+    fn test_minis_missing_for_account() {
+        Mini::missing_for_account(&*CLIENT).unwrap();
+    }
+}
+
+mod mounts {
+    use gw2api_rs::v2::mounts::{Mount, MountType};
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_mounts_types() {
+        let ids = Mount::ids(&*CLIENT).unwrap();
+        assert!(!ids.contains(&MountType::Unknown));
+
+        Mount::get(&*CLIENT, MountType::SiegeTurtle).unwrap();
+        Mount::get_all(&*CLIENT).unwrap();
+    }
 }
 
 mod novelties {
@@ -332,7 +815,111 @@ mod novelties {
     #[test]
     fn test_novelties() {
         Novelty::ids(&*CLIENT).unwrap();
-        Novelty::get_all(&*CLIENT).unwrap();
+        let novelties = Novelty::get_all(&*CLIENT).unwrap();
+        novelties[0].unlock_items(&*CLIENT).unwrap();
+    }
+}
+
+mod outfits {
+    use gw2api_rs::v2::outfits::Outfit;
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_outfits() {
+        Outfit::ids(&*CLIENT).unwrap();
+        Outfit::get_all(&*CLIENT).unwrap();
+    }
+}
+
+mod professions {
+    use gw2api_rs::v2::common::{self, EliteSpecialization};
+    use gw2api_rs::v2::professions::Profession;
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_professions() {
+        Profession::ids(&*CLIENT).unwrap();
+
+        let professions = Profession::get_all(&*CLIENT).unwrap();
+        assert!(professions
+            .iter()
+            .all(|p| p.id != common::Profession::Unknown));
+
+        let guardian = Profession::get(&*CLIENT, common::Profession::Guardian).unwrap();
+        let (palette, skill) = guardian.skills_by_palette[0];
+        assert_eq!(guardian.palette_to_skill(palette), Some(skill));
+
+        let spec = EliteSpecialization::Dragonhunter;
+        assert!(guardian.specializations.contains(&spec.id().unwrap()));
+    }
+}
+
+mod pvp {
+    use gw2api_rs::v2::pvp::{
+        Amulet, Game, Hero, Leaderboard, LeaderboardKind, PvpSeason, Rank, Region, Standings,
+    };
+
+    use crate::support::CLIENT;
+
+    const SEASON_ID: &str = "44B85826-B5ED-4890-8C77-82DDF9F2CF2B";
+
+    #[test]
+    fn test_amulets() {
+        Amulet::ids(&*CLIENT).unwrap();
+        Amulet::get_all(&*CLIENT).unwrap();
+    }
+
+    #[test]
+    fn test_games() {
+        let ids = Game::ids(&*CLIENT).unwrap();
+        Game::get_many(&*CLIENT, &ids).unwrap();
+        Game::get_page(&*CLIENT, 0, 5).unwrap();
+        Game::recent(&*CLIENT, 3).unwrap();
+    }
+
+    #[test]
+    fn test_heroes() {
+        Hero::ids(&*CLIENT).unwrap();
+        Hero::get_all(&*CLIENT).unwrap();
+    }
+
+    #[test]
+    fn test_leaderboards() {
+        let kinds = Leaderboard::kinds(&*CLIENT, SEASON_ID).unwrap();
+        assert!(kinds.contains(&LeaderboardKind::Legendary));
+
+        Leaderboard::get(&*CLIENT, SEASON_ID, LeaderboardKind::Legendary, Region::Eu).unwrap();
+        Leaderboard::get_page(
+            &*CLIENT,
+            SEASON_ID,
+            LeaderboardKind::Legendary,
+            Region::Na,
+            0,
+            Leaderboard::MAX_PAGE_SIZE,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_standings() {
+        Standings::get(&*CLIENT).unwrap();
+    }
+
+    #[test]
+    fn test_ranks() {
+        Rank::ids(&*CLIENT).unwrap();
+        Rank::get_all(&*CLIENT).unwrap();
+    }
+
+    #[test]
+    fn test_seasons() {
+        PvpSeason::ids(&*CLIENT).unwrap();
+        let seasons = PvpSeason::get_all(&*CLIENT).unwrap();
+        assert!(seasons.iter().any(|season| season.id == SEASON_ID));
+
+        PvpSeason::current(&*CLIENT).unwrap();
     }
 }
 
@@ -348,8 +935,20 @@ mod quaggans {
     }
 }
 
+mod quests {
+    use gw2api_rs::v2::quests::Quest;
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_quests() {
+        Quest::ids(&*CLIENT).unwrap();
+        Quest::get_all(&*CLIENT).unwrap();
+    }
+}
+
 mod raids {
-    use gw2api_rs::v2::raids::Raid;
+    use gw2api_rs::v2::raids::{ids, Raid};
 
     use crate::support::CLIENT;
 
@@ -358,6 +957,79 @@ mod raids {
         Raid::ids(&*CLIENT).unwrap();
         Raid::get_all(&*CLIENT).unwrap();
     }
+
+    #[test]
+    fn test_raid_ids() {
+        let raid = Raid::get(&*CLIENT, ids::FORSAKEN_THICKET.to_owned()).unwrap();
+        assert!(raid
+            .wings
+            .iter()
+            .any(|wing| wing.id == ids::wings::SPIRIT_VALE));
+    }
+}
+
+mod recipes {
+    use gw2api_rs::v2::characters::CharacterCore;
+    use gw2api_rs::v2::recipes::Recipe;
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_recipes() {
+        let ids = Recipe::ids(&*CLIENT).unwrap();
+        Recipe::get(&*CLIENT, ids[0]).unwrap();
+        Recipe::get_many(&*CLIENT, &ids[..200]).unwrap();
+
+        let names = CharacterCore::ids(&*CLIENT).unwrap();
+        if let Some(name) = names.first() {
+            Recipe::can_craft(&*CLIENT, name, ids[0]).unwrap();
+        }
+    }
+}
+
+mod skiffs {
+    use gw2api_rs::v2::skiffs::Skiff;
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_skiffs() {
+        Skiff::ids(&*CLIENT).unwrap();
+        Skiff::get_all(&*CLIENT).unwrap();
+    }
+}
+
+mod skins {
+    use gw2api_rs::v2::skins::Skin;
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_skins() {
+        let ids = Skin::ids(&*CLIENT).unwrap();
+        Skin::get(&*CLIENT, ids[0]).unwrap();
+        Skin::get_many(&*CLIENT, &ids[..200]).unwrap();
+    }
+}
+
+mod stories {
+    use gw2api_rs::v2::characters::CharacterCore;
+    use gw2api_rs::v2::stories::{Story, StorySeason};
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_stories() {
+        Story::ids(&*CLIENT).unwrap();
+        Story::get_all(&*CLIENT).unwrap();
+        StorySeason::ids(&*CLIENT).unwrap();
+        StorySeason::get_all(&*CLIENT).unwrap();
+
+        let names = CharacterCore::ids(&*CLIENT).unwrap();
+        if let Some(name) = names.first() {
+            Story::character_progress(&*CLIENT, name).unwrap();
+        }
+    }
 }
 
 mod titles {
@@ -372,6 +1044,19 @@ mod titles {
     }
 }
 
+mod worldbosses {
+    use gw2api_rs::v2::worldbosses::WorldBoss;
+
+    use crate::support::CLIENT;
+
+    #[test]
+    fn test_worldbosses() {
+        WorldBoss::ids(&*CLIENT).unwrap();
+        WorldBoss::get_all(&*CLIENT).unwrap();
+        WorldBoss::remaining(&*CLIENT).unwrap();
+    }
+}
+
 mod worlds {
     use gw2api_rs::v2::worlds::World;
 
@@ -380,11 +1065,17 @@ mod worlds {
     #[test]
     fn test_worlds() {
         World::ids(&*CLIENT).unwrap();
-        World::get_all(&*CLIENT).unwrap();
+
+        let worlds = World::get_all(&*CLIENT).unwrap();
+        for world in worlds {
+            assert!(world.region().is_some(), "{}", world.id);
+            assert!(world.language().is_some(), "{}", world.id);
+        }
     }
 }
 
 mod wvw {
+    use gw2api_rs::v2::common::Region;
     use gw2api_rs::v2::wvw::*;
 
     use crate::support::CLIENT;
@@ -401,12 +1092,32 @@ mod wvw {
         Rank::get_all(&*CLIENT).unwrap();
     }
 
+    #[test]
+    fn test_guilds() {
+        let guilds = Guilds::get(&*CLIENT, Region::Eu).unwrap();
+        assert!(!guilds.is_empty());
+        Guilds::get(&*CLIENT, Region::Na).unwrap();
+    }
+
     #[test]
     fn test_matches() {
         Match::ids(&*CLIENT).unwrap();
         Match::get_all(&*CLIENT).unwrap();
     }
 
+    #[test]
+    fn test_matchup_summary() {
+        let summary = matchup_summary(&*CLIENT, 2201).unwrap();
+        assert_eq!(summary.opponents().count(), 2);
+    }
+
+    #[test]
+    fn test_timers() {
+        Timers::get(&*CLIENT).unwrap();
+        Timers::lockout(&*CLIENT).unwrap();
+        Timers::team_assignment(&*CLIENT).unwrap();
+    }
+
     #[test]
     fn test_upgrades() {
         Upgrades::ids(&*CLIENT).unwrap();
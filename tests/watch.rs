@@ -0,0 +1,194 @@
+use gw2api_rs::v2::account::AccountAchievement;
+use gw2api_rs::v2::commerce::{Coins, Delivery};
+use gw2api_rs::v2::wvw::{Match, TeamColor, Teams};
+use gw2api_rs::watch::{AchievementEvent, DeliveryEvent, MatchEvent};
+
+fn fixture() -> Match {
+    serde_json::from_str(include_str!("fixtures/wvw_matches.json")).unwrap()
+}
+
+#[test]
+fn test_diff_unchanged() {
+    let previous = fixture();
+    let current = fixture();
+
+    assert!(MatchEvent::diff(&previous, &current).is_empty());
+}
+
+#[test]
+fn test_diff() {
+    let previous = fixture();
+    let mut current = fixture();
+
+    let objective = &mut current.maps[0].objectives[1];
+    objective.owner = TeamColor::Red;
+    objective.last_flipped = "2022-05-06T04:00:00Z".parse().unwrap();
+
+    let mut skirmish = current.skirmishes[0].clone();
+    current.skirmishes[0].scores = Teams {
+        red: 100,
+        green: 250,
+        blue: 300,
+    };
+    skirmish.id = 2;
+    current.skirmishes.push(skirmish);
+
+    current.scores.red += 5;
+
+    let events = MatchEvent::diff(&previous, &current);
+    assert_eq!(
+        events,
+        [
+            MatchEvent::ObjectiveFlipped {
+                map_id: 38,
+                objective_id: String::from("38-15"),
+                previous_owner: TeamColor::Neutral,
+                owner: TeamColor::Red,
+                flipped_at: "2022-05-06T04:00:00Z".parse().unwrap(),
+            },
+            MatchEvent::SkirmishEnded {
+                skirmish_id: 1,
+                scores: current.skirmishes[0].scores,
+                winner: Some(TeamColor::Blue),
+            },
+            MatchEvent::ScoreTick {
+                previous: previous.scores,
+                scores: current.scores,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_diff_new_match() {
+    let previous = fixture();
+    let mut current = fixture();
+    current.start_time = "2022-05-13T02:00:00Z".parse().unwrap();
+    current.scores.red += 5;
+
+    let events = MatchEvent::diff(&previous, &current);
+    assert_eq!(
+        events,
+        [MatchEvent::MatchStarted {
+            start_time: current.start_time
+        }]
+    );
+}
+
+fn delivery(coins: u64, items: &[(u64, u64)]) -> Delivery {
+    let items: Vec<_> = items
+        .iter()
+        .map(|(id, count)| serde_json::json!({ "id": id, "count": count }))
+        .collect();
+
+    serde_json::from_value(serde_json::json!({ "coins": coins, "items": items })).unwrap()
+}
+
+#[test]
+fn test_delivery_diff_unchanged() {
+    let previous = delivery(100, &[(19721, 5)]);
+    let current = delivery(100, &[(19721, 5)]);
+
+    assert!(DeliveryEvent::diff(&previous, &current).is_empty());
+}
+
+#[test]
+fn test_delivery_diff_received() {
+    let previous = delivery(100, &[(19721, 5)]);
+    let current = delivery(150, &[(19721, 7), (24295, 1)]);
+
+    assert_eq!(
+        DeliveryEvent::diff(&previous, &current),
+        [
+            DeliveryEvent::CoinsReceived {
+                amount: Coins(50),
+                total: Coins(150),
+            },
+            DeliveryEvent::ItemsReceived {
+                id: 19721,
+                count: 2,
+            },
+            DeliveryEvent::ItemsReceived {
+                id: 24295,
+                count: 1
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_delivery_diff_picked_up() {
+    let previous = delivery(100, &[(19721, 5)]);
+
+    let current = delivery(0, &[]);
+    assert_eq!(
+        DeliveryEvent::diff(&previous, &current),
+        [DeliveryEvent::PickedUp]
+    );
+
+    let current = delivery(0, &[(24295, 1)]);
+    assert_eq!(
+        DeliveryEvent::diff(&previous, &current),
+        [
+            DeliveryEvent::ItemsReceived {
+                id: 24295,
+                count: 1
+            },
+            DeliveryEvent::PickedUp,
+        ]
+    );
+}
+
+fn achievement(id: u64, current: u64, done: bool, repeated: Option<u64>) -> AccountAchievement {
+    serde_json::from_value(serde_json::json!({
+        "id": id, "current": current, "max": 10, "done": done, "repeated": repeated,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_achievement_diff_unchanged() {
+    let previous = [achievement(1, 5, false, None)];
+    let current = [achievement(1, 5, false, None)];
+
+    assert!(AchievementEvent::diff(&previous, &current).is_empty());
+}
+
+#[test]
+fn test_achievement_diff() {
+    let previous = [
+        achievement(1, 5, false, None),
+        achievement(2, 9, true, Some(1)),
+    ];
+    let current = [
+        achievement(1, 10, true, None),
+        achievement(2, 0, true, Some(2)),
+        achievement(3, 1, false, None),
+    ];
+
+    assert_eq!(
+        AchievementEvent::diff(&previous, &current),
+        [
+            AchievementEvent::Progressed {
+                id: 1,
+                previous: 5,
+                current: 10,
+                max: Some(10),
+            },
+            AchievementEvent::Completed { id: 1 },
+            AchievementEvent::Progressed {
+                id: 2,
+                previous: 9,
+                current: 0,
+                max: Some(10),
+            },
+            AchievementEvent::Repeated { id: 2, repeated: 2 },
+            AchievementEvent::Progressed {
+                id: 3,
+                previous: 0,
+                current: 1,
+                max: Some(10),
+            },
+        ]
+    );
+}